@@ -1,6 +1,6 @@
 use admin_contract::msg::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, PlatformQueryResponse, ProtocolQueryResponse,
-    ProtocolsQueryResponse, QueryMsg, SudoMsg,
+    ExecuteMsg, InstantiateMsg, InventoryQueryResponse, MigrateMsg, PlatformQueryResponse,
+    ProtocolQueryResponse, ProtocolsQueryResponse, QueryMsg, SudoMsg,
 };
 use sdk::cosmwasm_schema::{export_schema, schema_for};
 
@@ -15,4 +15,5 @@ fn main() {
     export_schema(&schema_for!(PlatformQueryResponse), &out_dir);
     export_schema(&schema_for!(ProtocolsQueryResponse), &out_dir);
     export_schema(&schema_for!(ProtocolQueryResponse), &out_dir);
+    export_schema(&schema_for!(InventoryQueryResponse), &out_dir);
 }
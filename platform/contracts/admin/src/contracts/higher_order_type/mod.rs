@@ -60,6 +60,19 @@ where
         F: FnMut(Unit) -> MappedUnit;
 }
 
+#[cfg(feature = "contract")]
+pub(super) trait TryMap
+where
+    Self: HigherOrderType,
+{
+    fn try_map<Unit, F, MappedUnit, Err>(
+        this: Self::Of<Unit>,
+        f: F,
+    ) -> Result<Self::Of<MappedUnit>, Err>
+    where
+        F: FnMut(Unit) -> Result<MappedUnit, Err>;
+}
+
 #[cfg(feature = "contract")]
 pub(super) trait MapAsRef
 where
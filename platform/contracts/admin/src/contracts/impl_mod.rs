@@ -1,26 +1,30 @@
 use serde::Serialize;
 
 use platform::{batch::Batch, message::Response as MessageResponse};
-use sdk::cosmwasm_std::{self, Addr, Binary, Storage, WasmMsg};
+use sdk::cosmwasm_std::{
+    self, Addr, Binary, ContractInfoResponse, QuerierWrapper, Storage, WasmMsg,
+};
 use versioning::{
+    query::{self as release_query, Error as ReleaseQueryError},
     MigrationMessage, PlatformPackageRelease, ProtocolPackageRelease, ProtocolPackageReleaseId,
     ReleaseId, UpdatablePackage,
 };
 
 use crate::{
     error::Error,
+    msg::{ContractInventory, InventoryQueryResponse},
     result::Result,
     state::contracts as state_contracts,
     validate::{Validate, ValidateValues},
 };
 
 use super::{
-    higher_order_type::TryForEachPair, Contracts, ContractsExecute, ContractsMigration,
-    ContractsTemplate, ExecuteSpec, Granularity, HigherOrderOption, HigherOrderPlatformContracts,
-    HigherOrderPlatformContractsWithoutAdmin, HigherOrderProtocolContracts, HigherOrderType,
-    MigrationSpec, PlatformContractAddresses, PlatformContractAddressesWithoutAdmin,
-    PlatformExecute, PlatformMigration, Protocol, ProtocolContractAddresses, ProtocolExecute,
-    ProtocolMigration, Protocols,
+    higher_order_type::{TryForEachPair, TryMap},
+    Contracts, ContractsExecute, ContractsMigration, ContractsTemplate, ExecuteSpec, Granularity,
+    HigherOrderOption, HigherOrderPlatformContracts, HigherOrderPlatformContractsWithoutAdmin,
+    HigherOrderProtocolContracts, HigherOrderType, MigrationSpec, PlatformContractAddresses,
+    PlatformContractAddressesWithoutAdmin, PlatformExecute, PlatformMigration, Protocol,
+    ProtocolContractAddresses, ProtocolExecute, ProtocolMigration, Protocols,
 };
 
 pub(crate) fn migrate(
@@ -45,6 +49,18 @@ pub(crate) fn execute(
         .map(MessageResponse::messages_only)
 }
 
+pub(crate) fn inventory(
+    storage: &dyn Storage,
+    querier: QuerierWrapper<'_>,
+    admin_contract: Addr,
+    admin_release: PlatformPackageRelease,
+) -> Result<InventoryQueryResponse> {
+    state_contracts::load_all(storage).and_then(|contracts| {
+        contract_inventory(querier, admin_contract, |_, _| Ok(admin_release))
+            .and_then(|admin_inventory| contracts.inventory(querier, admin_inventory))
+    })
+}
+
 pub(super) fn migrate_contract<Package>(
     migration_batch: &mut Batch,
     post_migration_execute_batch: &mut Batch,
@@ -205,6 +221,31 @@ impl Contracts {
         })
     }
 
+    fn inventory(
+        self,
+        querier: QuerierWrapper<'_>,
+        admin_inventory: ContractInventory<PlatformPackageRelease>,
+    ) -> Result<InventoryQueryResponse> {
+        HigherOrderPlatformContractsWithoutAdmin::try_map(self.platform, |address| {
+            contract_inventory(querier, address, release_query::platform_release)
+        })
+        .and_then(|platform| {
+            self.protocol
+                .into_iter()
+                .map(|(name, Protocol { contracts, .. })| {
+                    HigherOrderProtocolContracts::try_map(contracts, |address| {
+                        contract_inventory(querier, address, release_query::protocol_release)
+                    })
+                    .map(|contracts| (name, contracts))
+                })
+                .collect::<Result<_>>()
+                .map(|protocol| InventoryQueryResponse {
+                    platform: platform.with_admin(admin_inventory),
+                    protocol,
+                })
+        })
+    }
+
     fn try_for_each_protocol_pair<T, F>(
         protocols: Protocols<Protocol<Addr>>,
         mut paired_with: Protocols<T>,
@@ -276,6 +317,27 @@ where
     }
 }
 
+fn contract_inventory<Release, QueryRelease>(
+    querier: QuerierWrapper<'_>,
+    address: Addr,
+    query_release: QueryRelease,
+) -> Result<ContractInventory<Release>>
+where
+    QueryRelease: FnOnce(Addr, QuerierWrapper<'_>) -> Result<Release, ReleaseQueryError>,
+{
+    querier
+        .query_wasm_contract_info(address.clone())
+        .map_err(Into::into)
+        .and_then(|ContractInfoResponse { code_id, .. }| {
+            query_release(address, querier)
+                .map(|release| ContractInventory {
+                    code_id: code_id.into(),
+                    release,
+                })
+                .map_err(Into::into)
+        })
+}
+
 fn execute_contract(
     batch: &mut Batch,
     address: Addr,
@@ -303,3 +365,200 @@ impl Batches {
             .merge(self.post_migration_execute_batch)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use platform::contract::CodeId;
+    use sdk::cosmwasm_std::{
+        self,
+        testing::{MockQuerier, MockStorage},
+        Addr, Binary, ContractInfoResponse, ContractResult, QuerierResult, QuerierWrapper,
+        SystemError, SystemResult, WasmQuery,
+    };
+    use versioning::{query, PlatformPackageRelease, ProtocolPackageRelease};
+
+    use crate::{
+        contracts::{
+            ContractsTemplate, Dex, Network, PlatformContracts, PlatformContractsWithoutAdmin,
+            Protocol, ProtocolContracts,
+        },
+        error::Error,
+        msg::{ContractInventory, InventoryQueryResponse},
+        state::contracts as state_contracts,
+    };
+
+    const ADMIN: &str = "admin";
+    const PROTOCOL: &str = "osmosis-osmosis-usdc";
+    const CODE_IDS: [(&str, CodeId); 8] = [
+        (ADMIN, 1),
+        ("timealarms", 2),
+        ("treasury", 3),
+        ("leaser", 4),
+        ("lpp", 5),
+        ("oracle", 6),
+        ("profit", 7),
+        ("reserve", 8),
+    ];
+
+    #[test]
+    fn inventory() {
+        let mut storage = MockStorage::new();
+        store_contracts(&mut storage);
+
+        let mut querier = MockQuerier::default();
+        querier.update_wasm(|query| contract_handler(query, true));
+
+        assert!(
+            expected_inventory()
+                == super::inventory(
+                    &storage,
+                    QuerierWrapper::new(&querier),
+                    Addr::unchecked(ADMIN),
+                    platform_release(ADMIN),
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn inventory_no_release() {
+        let mut storage = MockStorage::new();
+        store_contracts(&mut storage);
+
+        let mut querier = MockQuerier::default();
+        querier.update_wasm(|query| contract_handler(query, false));
+
+        assert!(matches!(
+            super::inventory(
+                &storage,
+                QuerierWrapper::new(&querier),
+                Addr::unchecked(ADMIN),
+                platform_release(ADMIN),
+            ),
+            Err(Error::ReleaseQuery(_))
+        ));
+    }
+
+    fn store_contracts(storage: &mut MockStorage) {
+        state_contracts::store(
+            storage,
+            ContractsTemplate {
+                platform: PlatformContractsWithoutAdmin {
+                    timealarms: Addr::unchecked("timealarms"),
+                    treasury: Addr::unchecked("treasury"),
+                },
+                protocol: BTreeMap::from([(
+                    PROTOCOL.into(),
+                    Protocol {
+                        network: Network::Osmosis,
+                        dex: Dex::Osmosis,
+                        contracts: ProtocolContracts {
+                            leaser: Addr::unchecked("leaser"),
+                            lpp: Addr::unchecked("lpp"),
+                            oracle: Addr::unchecked("oracle"),
+                            profit: Addr::unchecked("profit"),
+                            reserve: Addr::unchecked("reserve"),
+                        },
+                    },
+                )]),
+            },
+        )
+        .unwrap()
+    }
+
+    fn expected_inventory() -> InventoryQueryResponse {
+        InventoryQueryResponse {
+            platform: PlatformContracts {
+                admin: inventory_of(ADMIN, platform_release),
+                timealarms: inventory_of("timealarms", platform_release),
+                treasury: inventory_of("treasury", platform_release),
+            },
+            protocol: BTreeMap::from([(
+                PROTOCOL.into(),
+                ProtocolContracts {
+                    leaser: inventory_of("leaser", protocol_release),
+                    lpp: inventory_of("lpp", protocol_release),
+                    oracle: inventory_of("oracle", protocol_release),
+                    profit: inventory_of("profit", protocol_release),
+                    reserve: inventory_of("reserve", protocol_release),
+                },
+            )]),
+        }
+    }
+
+    fn inventory_of<Release>(
+        contract: &str,
+        release: fn(&str) -> Release,
+    ) -> ContractInventory<Release> {
+        ContractInventory {
+            code_id: code_id(contract).into(),
+            release: release(contract),
+        }
+    }
+
+    fn contract_handler(query: &WasmQuery, with_releases: bool) -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { contract_addr } => ok_response(&ContractInfoResponse::new(
+                code_id(contract_addr),
+                Addr::unchecked(ADMIN),
+                Some(Addr::unchecked(ADMIN)),
+                false,
+                None,
+            )),
+            WasmQuery::Smart { contract_addr, msg } if with_releases => {
+                if *msg == to_binary(&query::PlatformPackage::Release {}) {
+                    ok_response(&platform_release(contract_addr))
+                } else if *msg == to_binary(&query::ProtocolPackage::Release {}) {
+                    ok_response(&protocol_release(contract_addr))
+                } else {
+                    unimplemented!()
+                }
+            }
+            WasmQuery::Smart { contract_addr, .. } => {
+                SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.clone(),
+                })
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn code_id(contract: &str) -> CodeId {
+        CODE_IDS
+            .into_iter()
+            .find_map(|(name, code_id)| (name == contract).then_some(code_id))
+            .expect("the contract should be known")
+    }
+
+    fn platform_release(contract: &str) -> PlatformPackageRelease {
+        cosmwasm_std::from_json(format!(
+            r#"{{"id":"v0.8.1","code":{{"name":"{contract}","version":{{"major":0,"minor":6,"patch":{}}},"storage":2}}}}"#,
+            code_id(contract)
+        ))
+        .unwrap()
+    }
+
+    fn protocol_release(contract: &str) -> ProtocolPackageRelease {
+        cosmwasm_std::from_json(format!(
+            r#"{{"software":{},"protocol":{{"id":"v0.8.1-osmosis","protocol":{{"name":"{PROTOCOL}","network":"Osmosis"}}}}}}"#,
+            cosmwasm_std::to_json_string(&platform_release(contract)).unwrap()
+        ))
+        .unwrap()
+    }
+
+    fn ok_response<T>(response: &T) -> QuerierResult
+    where
+        T: serde::Serialize,
+    {
+        SystemResult::Ok(ContractResult::Ok(to_binary(response)))
+    }
+
+    fn to_binary<T>(msg: &T) -> Binary
+    where
+        T: serde::Serialize,
+    {
+        cosmwasm_std::to_json_binary(msg).unwrap()
+    }
+}
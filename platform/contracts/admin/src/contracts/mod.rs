@@ -11,7 +11,7 @@ use sdk::{
 use versioning::ReleaseId;
 
 #[cfg(feature = "contract")]
-pub(crate) use self::impl_mod::{execute, migrate};
+pub(crate) use self::impl_mod::{execute, inventory, migrate};
 pub use self::{
     granular::{Granularity, HigherOrderType as HigherOrderGranularity},
     higher_order_type::{
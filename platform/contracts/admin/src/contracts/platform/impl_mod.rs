@@ -1,7 +1,7 @@
 use crate::validate::Validate;
 
 use super::{
-    super::higher_order_type::{Map, MapAsRef, TryForEach, Zip},
+    super::higher_order_type::{Map, MapAsRef, TryForEach, TryMap, Zip},
     higher_order_type::{Contracts, ContractsWithoutAdmin},
 };
 
@@ -63,6 +63,22 @@ impl Map for ContractsWithoutAdmin {
     }
 }
 
+impl TryMap for ContractsWithoutAdmin {
+    #[inline]
+    fn try_map<Unit, F, MappedUnit, Err>(
+        this: Self::Of<Unit>,
+        mut f: F,
+    ) -> Result<Self::Of<MappedUnit>, Err>
+    where
+        F: FnMut(Unit) -> Result<MappedUnit, Err>,
+    {
+        Ok(super::ContractsWithoutAdmin {
+            timealarms: f(this.timealarms)?,
+            treasury: f(this.treasury)?,
+        })
+    }
+}
+
 impl MapAsRef for ContractsWithoutAdmin {
     #[inline]
     fn map_as_ref<T>(this: &Self::Of<T>) -> Self::Of<&T> {
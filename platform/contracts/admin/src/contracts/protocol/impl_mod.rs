@@ -6,7 +6,7 @@ use crate::{result::Result, validate::Validate};
 
 use super::{
     super::{
-        higher_order_type::{Map, MapAsRef, TryForEach, TryForEachPair as _, Zip},
+        higher_order_type::{Map, MapAsRef, TryForEach, TryForEachPair as _, TryMap, Zip},
         impl_mod::migrate_contract,
         MigrationSpec,
     },
@@ -89,6 +89,24 @@ impl Map for higher_order_type::Contracts {
     }
 }
 
+impl TryMap for higher_order_type::Contracts {
+    fn try_map<Unit, F, MappedUnit, Err>(
+        this: Self::Of<Unit>,
+        mut f: F,
+    ) -> std::result::Result<Self::Of<MappedUnit>, Err>
+    where
+        F: FnMut(Unit) -> std::result::Result<MappedUnit, Err>,
+    {
+        Ok(Contracts {
+            leaser: f(this.leaser)?,
+            lpp: f(this.lpp)?,
+            oracle: f(this.oracle)?,
+            profit: f(this.profit)?,
+            reserve: f(this.reserve)?,
+        })
+    }
+}
+
 impl MapAsRef for higher_order_type::Contracts {
     #[inline]
     fn map_as_ref<T>(this: &Self::Of<T>) -> Self::Of<&T> {
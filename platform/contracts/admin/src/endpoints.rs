@@ -16,8 +16,8 @@ use crate::{
     contracts::{MigrationSpec, Protocol, ProtocolContracts},
     error::Error as ContractError,
     msg::{
-        ExecuteMsg, InstantiateMsg, MigrateContracts, MigrateMsg, PlatformQueryResponse,
-        ProtocolQueryResponse, ProtocolsQueryResponse, QueryMsg, SudoMsg,
+        ExecuteMsg, InstantiateMsg, InventoryQueryResponse, MigrateContracts, MigrateMsg,
+        PlatformQueryResponse, ProtocolQueryResponse, ProtocolsQueryResponse, QueryMsg, SudoMsg,
     },
     result::Result as ContractResult,
     state::{contract::ExpectedInstantiation, contracts as state_contracts},
@@ -187,6 +187,15 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary>
         QueryMsg::PlatformPackageRelease {} => {
             cosmwasm_std::to_json_binary(&CURRENT_RELEASE).map_err(Into::into)
         }
+        QueryMsg::Inventory {} => crate::contracts::inventory(
+            deps.storage,
+            deps.querier,
+            env.contract.address,
+            CURRENT_RELEASE,
+        )
+        .and_then(|ref inventory| {
+            cosmwasm_std::to_json_binary::<InventoryQueryResponse>(inventory).map_err(Into::into)
+        }),
     }
 }
 
@@ -16,6 +16,8 @@ pub enum Error {
     Platform(#[from] platform::error::Error),
     #[error("[Admin] {0}")]
     Versioning(#[from] versioning::Error),
+    #[error("[Admin] {0}")]
+    ReleaseQuery(#[from] versioning::query::Error),
     #[error("[Admin] No data in migration response!")]
     NoMigrationResponseData {},
     #[error(
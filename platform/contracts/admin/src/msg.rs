@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use platform::contract::CodeId;
@@ -5,7 +7,7 @@ use sdk::{
     cosmwasm_std::{Addr, Uint64},
     schemars::{self, JsonSchema},
 };
-use versioning::ReleaseId;
+use versioning::{PlatformPackageRelease, ProtocolPackageRelease, ReleaseId};
 
 pub use crate::contracts::{
     Contracts, ContractsExecute, ContractsMigration, Dex, ExecuteSpec, Granularity,
@@ -90,6 +92,10 @@ where
     Protocol(String),
     /// Implementation of [versioning::query::PlatformPackage::Release]
     PlatformPackageRelease {},
+    /// Report the code id and the release of each managed contract, including this one
+    ///
+    /// The result is [InventoryQueryResponse]
+    Inventory {},
 }
 
 pub type ProtocolsQueryResponse = Vec<String>;
@@ -97,3 +103,19 @@ pub type ProtocolsQueryResponse = Vec<String>;
 pub type PlatformQueryResponse = PlatformContractAddressesWithoutAdmin;
 
 pub type ProtocolQueryResponse = Protocol<Addr>;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct InventoryQueryResponse {
+    pub platform: PlatformContracts<ContractInventory<PlatformPackageRelease>>,
+    pub protocol: BTreeMap<String, ProtocolContracts<ContractInventory<ProtocolPackageRelease>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ContractInventory<Release> {
+    /// The code id the contract currently runs
+    pub code_id: Uint64,
+    /// The release the contract reports
+    pub release: Release,
+}
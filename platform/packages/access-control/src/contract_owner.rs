@@ -41,7 +41,7 @@ where
 mod tests {
     use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage};
 
-    use crate::{error::Error, ContractOwnerAccess};
+    use crate::{contract_owner::CONTRACT_OWNER_NAMESPACE, error::Error, ContractOwnerAccess};
 
     #[test]
     fn grant_check() {
@@ -67,4 +67,23 @@ mod tests {
             Error::Std(_)
         ));
     }
+
+    #[test]
+    fn check_not_owner() {
+        let mut storage = MockStorage::new();
+        let storage_ref: &mut dyn Storage = &mut storage;
+        let mut access = ContractOwnerAccess::new(storage_ref);
+        let owner = Addr::unchecked("happy user");
+        let not_authorized = Addr::unchecked("hacker");
+
+        access.grant_to(&owner).unwrap();
+        assert_eq!(
+            Error::UnauthorizedRole {
+                role: CONTRACT_OWNER_NAMESPACE,
+                expected: owner,
+                found: not_authorized.clone(),
+            },
+            access.check(&not_authorized).unwrap_err()
+        );
+    }
 }
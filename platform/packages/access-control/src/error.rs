@@ -1,4 +1,4 @@
-use sdk::cosmwasm_std::StdError;
+use sdk::cosmwasm_std::{Addr, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -6,6 +6,15 @@ pub enum Error {
     #[error("[Access Control] Unauthorized access!")]
     Unauthorized {},
 
+    #[error(
+        "[Access Control] Unauthorized access to '{role}'! Granted to '{expected}', requested by '{found}'"
+    )]
+    UnauthorizedRole {
+        role: &'static str,
+        expected: Addr,
+        found: Addr,
+    },
+
     #[error("[Access Control] [Std] {0}")]
     Std(#[from] StdError),
 }
@@ -19,11 +19,21 @@ pub fn check(permitted_to: &Addr, accessed_by: &Addr) -> Result {
     }
 }
 
+/// Check the access to a named role reporting the expected and actual users on failure
+pub fn check_role(role: &'static str, permitted_to: &Addr, accessed_by: &Addr) -> Result {
+    check(permitted_to, accessed_by).map_err(|_| Error::UnauthorizedRole {
+        role,
+        expected: permitted_to.clone(),
+        found: accessed_by.clone(),
+    })
+}
+
 pub struct SingleUserAccess<'storage, S>
 where
     S: Deref<Target = dyn Storage + 'storage>,
 {
     storage: S,
+    role: &'static str,
     storage_item: Item<Addr>,
 }
 
@@ -34,6 +44,7 @@ where
     pub const fn new(storage: S, storage_namespace: &'static str) -> Self {
         Self {
             storage,
+            role: storage_namespace,
             storage_item: Item::new(storage_namespace),
         }
     }
@@ -42,7 +53,7 @@ where
         self.storage_item
             .load(self.storage.deref())
             .map_err(Into::into)
-            .and_then(|granted_to| check(&granted_to, user))
+            .and_then(|granted_to| check_role(self.role, &granted_to, user))
     }
 }
 
@@ -97,6 +108,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn check_other_user() {
+        let mut storage = MockStorage::new();
+        let storage_ref: &mut dyn Storage = &mut storage;
+        let mut access = SingleUserAccess::new(storage_ref, NAMESPACE);
+        let user = Addr::unchecked("cosmic address");
+        let not_authorized = Addr::unchecked("hacker");
+
+        access.grant_to(&user).unwrap();
+        assert_eq!(
+            Error::UnauthorizedRole {
+                role: NAMESPACE,
+                expected: user,
+                found: not_authorized.clone(),
+            },
+            access.check(&not_authorized).unwrap_err()
+        );
+    }
+
     #[test]
     fn check() {
         const ADDRESS: &str = "admin";
@@ -112,6 +142,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_role_fail() {
+        let err = super::check_role(
+            NAMESPACE,
+            &Addr::unchecked("user12"),
+            &Addr::unchecked("user21"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            Error::UnauthorizedRole {
+                role: NAMESPACE,
+                expected: Addr::unchecked("user12"),
+                found: Addr::unchecked("user21"),
+            },
+            err
+        );
+        assert!(err.to_string().contains(NAMESPACE));
+    }
+
     fn check_permission(granted_to: &str, asked_for: &str) -> Result {
         super::check(&Addr::unchecked(granted_to), &Addr::unchecked(asked_for))
     }
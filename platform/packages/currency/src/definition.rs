@@ -19,17 +19,39 @@ pub struct Definition {
     /// unit represented by the trait.
     ///
     /// Example: `(10 ^ DECIMAL_DIGITS) uUSDC = 1 USDC`
+    ///
+    /// It does not exceed [`Definition::MAX_DECIMAL_DIGITS`].
     pub decimal_digits: u8,
 }
 pub type DefinitionRef = &'static Definition;
 
 impl Definition {
+    /// The maximum number of decimal digits a currency may declare
+    ///
+    /// Keeps a whole unit, `10 ^ decimal_digits`, well within the range of the amounts.
+    pub const MAX_DECIMAL_DIGITS: u8 = 24;
+
+    /// Define a currency
+    ///
+    /// The currency definitions are constructed in a constant context, hence
+    /// an out-of-range number of decimal digits fails the build:
+    ///
+    /// ```compile_fail
+    /// use currency::Definition;
+    ///
+    /// const INVALID: Definition = Definition::new("INVALID", "uinvalid", "uinvalid", 25);
+    /// ```
     pub const fn new(
         ticker: SymbolStatic,
         bank: SymbolStatic,
         dex: SymbolStatic,
         decimal_digits: u8,
     ) -> Self {
+        assert!(
+            decimal_digits <= Self::MAX_DECIMAL_DIGITS,
+            "The number of decimal digits should not exceed the maximum"
+        );
+
         Self {
             ticker,
             bank_symbol: bank,
@@ -50,3 +72,27 @@ impl Hash for Definition {
         self.ticker.hash(state);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Definition;
+
+    #[test]
+    fn max_decimal_digits() {
+        const MAX: Definition =
+            Definition::new("MAX", "umax", "umax", Definition::MAX_DECIMAL_DIGITS);
+
+        assert_eq!(Definition::MAX_DECIMAL_DIGITS, MAX.decimal_digits);
+    }
+
+    #[test]
+    #[should_panic = "should not exceed the maximum"]
+    fn too_many_decimal_digits() {
+        let _ = Definition::new(
+            "TOO_MANY",
+            "umany",
+            "umany",
+            Definition::MAX_DECIMAL_DIGITS + 1,
+        );
+    }
+}
@@ -12,7 +12,8 @@ use crate::{
     error::{Error, Result},
     group::MemberOf,
     pairs::{MaybePairsVisitorResult, PairsGroup, PairsVisitor, PairsVisitorResult},
-    CurrencyDef, Group, MaybeAnyVisitResult, Symbol, SymbolStatic, Tickers, TypeMatcher,
+    CurrencyDef, Group, MaybeAnyVisitResult, Symbol, SymbolOwned, SymbolStatic, Tickers,
+    TypeMatcher,
 };
 
 use super::{AnyVisitor, AnyVisitorResult};
@@ -89,6 +90,16 @@ where
         }
     }
 
+    /// Narrow down to a currency of a sub-group
+    ///
+    /// Fail if the currency is not a member of `SubG`.
+    pub fn try_into_sub_group<SubG>(self) -> Result<CurrencyDTO<SubG>>
+    where
+        SubG: Group + MemberOf<G>,
+    {
+        CurrencyDTO::<SubG>::from_symbol::<Tickers<SubG>>(self.into_symbol::<Tickers<G>>())
+    }
+
     pub fn definition(&self) -> DefinitionRef {
         self.def
     }
@@ -111,6 +122,18 @@ where
         }
     }
 
+    /// Check if `other` is the same currency irrespective of the groups both are expressed in
+    ///
+    /// Unlike [`Self::of_currency`], there is no requirement the group of `other` to be
+    /// a member of `G`, for example, a currency from the payment group may be checked against
+    /// the LPN.
+    pub fn same_as<OtherG>(&self, other: &CurrencyDTO<OtherG>) -> bool
+    where
+        OtherG: Group,
+    {
+        self == other
+    }
+
     #[cfg(any(test, feature = "testing"))]
     pub fn from_symbol_testing<S>(symbol: &str) -> Result<CurrencyDTO<S::Group>>
     where
@@ -200,6 +223,34 @@ where
     C::dto().into_super_group::<G>()
 }
 
+/// Check that `ticker` designates a currency pertaining to the group `G`
+pub fn validate<G>(ticker: &str) -> Result<()>
+where
+    G: Group,
+{
+    CurrencyDTO::<G>::from_symbol::<Tickers<G>>(ticker).map(|_| ())
+}
+
+/// Check that all `tickers` designate currencies pertaining to the group `G`
+///
+/// Unlike a loop over [`validate`], the scan does not stop on the first failure
+/// but reports all tickers that are not members of the group, in their original order.
+pub fn validate_all<G>(tickers: &[SymbolOwned]) -> std::result::Result<(), Vec<SymbolOwned>>
+where
+    G: Group,
+{
+    let invalid: Vec<SymbolOwned> = tickers
+        .iter()
+        .filter(|ticker| validate::<G>(ticker).is_err())
+        .cloned()
+        .collect();
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
 impl<G> Display for CurrencyDTO<G>
 where
     G: Group,
@@ -276,6 +327,18 @@ mod test {
         )
     }
 
+    #[test]
+    fn try_into_sub_group() {
+        assert_eq!(
+            Ok(dto::<SubGroup, SubGroupTestC10>()),
+            dto::<SuperGroup, SubGroupTestC10>().try_into_sub_group::<SubGroup>()
+        );
+
+        assert!(dto::<SuperGroup, SuperGroupTestC1>()
+            .try_into_sub_group::<SubGroup>()
+            .is_err());
+    }
+
     #[test]
     fn from_super_group() {
         assert_eq!(
@@ -297,6 +360,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn same_as() {
+        let sub_currency = dto::<SubGroup, SubGroupTestC10>();
+
+        assert!(sub_currency.same_as(&dto::<SuperGroup, SubGroupTestC10>()));
+        assert!(dto::<SuperGroup, SubGroupTestC10>().same_as(&sub_currency));
+
+        assert!(!sub_currency.same_as(&dto::<SuperGroup, SuperGroupTestC1>()));
+        assert!(!dto::<SuperGroup, SuperGroupTestC1>().same_as(&sub_currency));
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(
@@ -330,6 +404,42 @@ mod test {
         assert_eq!(c.to_string(), c.into_symbol::<Tickers::<TheG>>());
     }
 
+    #[test]
+    fn validate() {
+        assert_eq!(
+            Ok(()),
+            super::validate::<SuperGroup>(SubGroupTestC10::ticker())
+        );
+        assert!(super::validate::<SubGroup>(SuperGroupTestC1::ticker()).is_err());
+        assert!(super::validate::<SuperGroup>(SuperGroupTestC1::bank()).is_err());
+    }
+
+    #[test]
+    fn validate_all() {
+        assert_eq!(Ok(()), super::validate_all::<SuperGroup>(&[]));
+        assert_eq!(
+            Ok(()),
+            super::validate_all::<SuperGroup>(&[
+                SuperGroupTestC1::ticker().into(),
+                SubGroupTestC10::ticker().into(),
+            ])
+        );
+
+        assert_eq!(
+            Err(vec![
+                SuperGroupTestC1::ticker().into(),
+                "unknown".into(),
+                SuperGroupTestC2::dex().into(),
+            ]),
+            super::validate_all::<SubGroup>(&[
+                SuperGroupTestC1::ticker().into(),
+                SubGroupTestC10::ticker().into(),
+                "unknown".into(),
+                SuperGroupTestC2::dex().into(),
+            ])
+        );
+    }
+
     fn dto<G, C>() -> CurrencyDTO<G>
     where
         G: Group,
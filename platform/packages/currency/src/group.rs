@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::CurrencyDef;
+use crate::{CurrencyDef, SymbolStatic};
 
 use super::{matcher::Matcher, AnyVisitor, AnyVisitorResult};
 
@@ -10,6 +10,13 @@ where
     Self: MemberOf<Self::TopG>,
 {
     const DESCR: &'static str;
+
+    /// The `(dex_symbol, ticker)` pairs of the group members
+    ///
+    /// Intended for lookups in a constant context. A union group lists the members of
+    /// its sub-groups in the order they are declared in [`group_union!`].
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)];
+
     type TopG: Group<TopG = Self::TopG>;
 
     // Visit this group directly by a visitor
@@ -27,6 +34,60 @@ where
 
 pub type MaybeAnyVisitResult<VisitedG, V> = Result<AnyVisitorResult<VisitedG, V>, V>;
 
+/// Implement [`Group::maybe_visit`] and [`Group::DEX_SYMBOLS`] of a group that is a union of sub-groups
+///
+/// Expands to a chain of `maybe_visit_member` calls on the sub-groups in the order they are listed,
+/// stopping at the first one the matcher finds a member of, and to the concatenation of
+/// their dex symbols in the same order. Intended to be invoked in the `impl Group` block
+/// of the union group, so the sub-groups are declared in a single place.
+///
+/// Each sub-group should have the union group as its [`Group::TopG`].
+#[macro_export]
+macro_rules! group_union {
+    ($($sub_group: ty),+ $(,)?) => {
+        const DEX_SYMBOLS: &'static [($crate::SymbolStatic, $crate::SymbolStatic)] =
+            &$crate::concat_dex_symbols::<{ 0 $(+ <$sub_group as $crate::Group>::DEX_SYMBOLS.len())+ }>(
+                &[$(<$sub_group as $crate::Group>::DEX_SYMBOLS),+],
+            );
+
+        fn maybe_visit<M, V>(matcher: &M, visitor: V) -> $crate::MaybeAnyVisitResult<Self, V>
+        where
+            M: $crate::Matcher,
+            V: $crate::AnyVisitor<Self>,
+        {
+            ::core::result::Result::Err(visitor)
+                $(.or_else(|visitor| <$sub_group as $crate::Group>::maybe_visit_member(matcher, visitor)))+
+        }
+    };
+}
+
+/// Concatenate the dex symbols of groups in a constant context
+///
+/// `N` should be equal to the total number of the symbols, otherwise the function panics,
+/// and fails the build if evaluated in a constant context.
+#[doc(hidden)]
+pub const fn concat_dex_symbols<const N: usize>(
+    groups: &[&[(SymbolStatic, SymbolStatic)]],
+) -> [(SymbolStatic, SymbolStatic); N] {
+    let mut symbols = [("", ""); N];
+    let mut total = 0;
+    let mut group = 0;
+    while group < groups.len() {
+        let mut member = 0;
+        while member < groups[group].len() {
+            symbols[total] = groups[group][member];
+            total += 1;
+            member += 1;
+        }
+        group += 1;
+    }
+    assert!(
+        total == N,
+        "The total number of dex symbols should match the expected one"
+    );
+    symbols
+}
+
 pub trait MemberOf<G>
 where
     G: Group,
@@ -40,3 +101,49 @@ where
     G: Group,
 {
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        test::{
+            self, SubGroup, SubGroupTestC10, SubGroupTestC6, SuperGroup, SuperGroupTestC1,
+            SuperGroupTestC2, SuperGroupTestC3, SuperGroupTestC4, SuperGroupTestC5,
+        },
+        CurrencyDef,
+    };
+
+    #[test]
+    fn dex_symbols() {
+        test::assert_dex_symbols::<SubGroup>(&[
+            SubGroupTestC6::dto().definition(),
+            SubGroupTestC10::dto().definition(),
+        ]);
+        test::assert_dex_symbols::<SuperGroup>(&[
+            SuperGroupTestC1::dto().definition(),
+            SuperGroupTestC2::dto().definition(),
+            SuperGroupTestC3::dto().definition(),
+            SuperGroupTestC4::dto().definition(),
+            SuperGroupTestC5::dto().definition(),
+            SubGroupTestC6::dto().definition(),
+            SubGroupTestC10::dto().definition(),
+        ]);
+    }
+
+    #[test]
+    fn concat_dex_symbols() {
+        const FIRST: &[(&str, &str)] = &[("dex_a", "A"), ("dex_b", "B")];
+        const SECOND: &[(&str, &str)] = &[("dex_c", "C")];
+
+        assert_eq!(
+            [("dex_a", "A"), ("dex_b", "B"), ("dex_c", "C")],
+            super::concat_dex_symbols::<3>(&[FIRST, &[], SECOND])
+        );
+        assert_eq!([("", ""); 0], super::concat_dex_symbols::<0>(&[]));
+    }
+
+    #[test]
+    #[should_panic = "should match the expected one"]
+    fn concat_dex_symbols_mismatch() {
+        let _ = super::concat_dex_symbols::<2>(&[&[("dex_a", "A")]]);
+    }
+}
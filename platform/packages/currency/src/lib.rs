@@ -2,13 +2,13 @@ use std::{any::TypeId, fmt::Debug};
 
 pub use crate::{
     definition::{Definition, DefinitionRef},
-    dto::{dto, to_string, CurrencyDTO},
+    dto::{dto, to_string, validate, validate_all, CurrencyDTO},
     from_symbol::{CurrencyVisit, SingleVisitor},
     from_symbol_any::{
         visit_any_on_currencies, AnyVisitor, AnyVisitorPair, AnyVisitorPairResult,
         AnyVisitorResult, GroupVisit, InPoolWith,
     },
-    group::{Group, MaybeAnyVisitResult, MemberOf},
+    group::{concat_dex_symbols, Group, MaybeAnyVisitResult, MemberOf},
     matcher::{Matcher, TypeMatcher},
     pairs::{MaybePairsVisitorResult, PairsGroup, PairsVisitor, PairsVisitorResult},
     symbol::{BankSymbols, DexSymbols, Symbol, Tickers},
@@ -24,6 +24,8 @@ mod matcher;
 pub mod never;
 mod pairs;
 pub mod platform;
+#[cfg(feature = "schema")]
+pub mod schema;
 mod symbol;
 #[cfg(any(test, feature = "testing"))]
 pub mod test;
@@ -4,7 +4,7 @@ use sdk::schemars::{self, JsonSchema};
 
 use crate::{
     AnyVisitor, CurrencyDTO, CurrencyDef, Definition, Group, Matcher, MaybeAnyVisitResult,
-    MaybePairsVisitorResult, MemberOf, PairsGroup, PairsVisitor,
+    MaybePairsVisitorResult, MemberOf, PairsGroup, PairsVisitor, SymbolStatic,
 };
 
 #[derive(
@@ -78,6 +78,8 @@ impl PairsGroup for Nls {
 pub struct PlatformGroup;
 impl Group for PlatformGroup {
     const DESCR: &'static str = "platform currencies";
+    // the platform currencies are dex-independent
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = &[];
     type TopG = Self;
 
     fn maybe_visit<M, V>(matcher: &M, visitor: V) -> MaybeAnyVisitResult<Self, V>
@@ -0,0 +1,132 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use sdk::cosmwasm_std::{self, StdError, StdResult};
+
+use crate::{
+    definition::DefinitionRef, never::Never, AnyVisitor, AnyVisitorResult, CurrencyDTO,
+    CurrencyDef, Group, Matcher, MemberOf,
+};
+
+/// Export the definitions of all currencies of a group as a JSON array
+///
+/// The currencies are listed in the order the group visits them, each one
+/// with its ticker, bank and dex symbols, and decimal digits.
+pub fn currencies_json<G>() -> StdResult<String>
+where
+    G: Group,
+{
+    members::<G>().and_then(|members| cosmwasm_std::to_json_string(&members))
+}
+
+/// Collect the definitions of all currencies of a group
+///
+/// Relies on [`Group::maybe_visit`] asking the matcher about each member in turn.
+fn members<G>() -> StdResult<Vec<DefinitionRef>>
+where
+    G: Group,
+{
+    let collector = Collector::default();
+    G::maybe_visit(&collector, NoVisit::<G>(PhantomData)).map_or_else(
+        |_not_matched| Ok(collector.0.into_inner()),
+        |visited| visited.map(|never| match never {}),
+    )
+}
+
+#[derive(Default)]
+struct Collector(RefCell<Vec<DefinitionRef>>);
+
+impl Matcher for Collector {
+    fn r#match(&self, def: DefinitionRef) -> bool {
+        self.0.borrow_mut().push(def);
+        false
+    }
+}
+
+struct NoVisit<G>(PhantomData<G>);
+
+impl<G> AnyVisitor<G> for NoVisit<G>
+where
+    G: Group,
+{
+    type Output = Never;
+    type Error = StdError;
+
+    fn on<C>(self, def: &CurrencyDTO<C::Group>) -> AnyVisitorResult<G, Self>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<G>,
+    {
+        Err(StdError::generic_err(format!(
+            "[Currency] Unexpected visit of {def} while listing the members of {group}",
+            group = G::DESCR,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use sdk::cosmwasm_std;
+
+    use crate::{
+        test::{
+            SubGroup, SubGroupTestC10, SubGroupTestC6, SuperGroup, SuperGroupTestC1,
+            SuperGroupTestC2, SuperGroupTestC3, SuperGroupTestC4, SuperGroupTestC5,
+        },
+        CurrencyDef, Group,
+    };
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Currency {
+        ticker: String,
+        bank_symbol: String,
+        dex_symbol: String,
+        decimal_digits: u8,
+    }
+
+    #[test]
+    fn super_group() {
+        let currencies = exported::<SuperGroup>();
+        assert_eq!(7, currencies.len());
+        assert_member::<SuperGroupTestC1>(&currencies);
+        assert_member::<SuperGroupTestC2>(&currencies);
+        assert_member::<SuperGroupTestC3>(&currencies);
+        assert_member::<SuperGroupTestC4>(&currencies);
+        assert_member::<SuperGroupTestC5>(&currencies);
+        assert_member::<SubGroupTestC6>(&currencies);
+        assert_member::<SubGroupTestC10>(&currencies);
+    }
+
+    #[test]
+    fn sub_group() {
+        let currencies = exported::<SubGroup>();
+        assert_eq!(2, currencies.len());
+        assert_member::<SubGroupTestC6>(&currencies);
+        assert_member::<SubGroupTestC10>(&currencies);
+    }
+
+    fn exported<G>() -> Vec<Currency>
+    where
+        G: Group,
+    {
+        super::currencies_json::<G>()
+            .and_then(cosmwasm_std::from_json)
+            .expect("the export is a valid JSON array of currencies")
+    }
+
+    #[track_caller]
+    fn assert_member<C>(currencies: &[Currency])
+    where
+        C: CurrencyDef,
+    {
+        let def = C::dto().definition();
+        assert!(currencies
+            .iter()
+            .any(|currency| currency.ticker == def.ticker
+                && currency.bank_symbol == def.bank_symbol
+                && currency.dex_symbol == def.dex_symbol
+                && currency.decimal_digits == def.decimal_digits));
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     from_symbol_any::InPoolWith,
     group::MemberOf,
     pairs::{MaybePairsVisitorResult, PairsGroup, PairsVisitor},
-    AnyVisitor, CurrencyDTO, Group, Matcher, MaybeAnyVisitResult,
+    AnyVisitor, CurrencyDTO, Group, Matcher, MaybeAnyVisitResult, SymbolStatic,
 };
 
 pub type SuperGroupTestC1 = impl_::TestC1;
@@ -23,6 +23,17 @@ pub type SuperGroupCurrency = CurrencyDTO<SuperGroup>;
 impl MemberOf<Self> for SuperGroup {}
 impl Group for SuperGroup {
     const DESCR: &'static str = "super_group";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] =
+        &crate::concat_dex_symbols::<{ 5 + SubGroup::DEX_SYMBOLS.len() }>(&[
+            &[
+                ("ibc/dex_ticker#1", "ticker#1"),
+                ("ibc/dex_ticker#2", "ticker#2"),
+                ("ibc/dex_ticker#3", "ticker#3"),
+                ("ibc/dex_ticker#4", "ticker#4"),
+                ("ibc/dex_ticker#5", "ticker#5"),
+            ],
+            SubGroup::DEX_SYMBOLS,
+        ]);
     type TopG = Self;
 
     fn maybe_visit<M, V>(matcher: &M, visitor: V) -> MaybeAnyVisitResult<Self, V>
@@ -162,6 +173,10 @@ impl MemberOf<Self> for SubGroup {}
 impl MemberOf<SuperGroup> for SubGroup {}
 impl Group for SubGroup {
     const DESCR: &'static str = "sub_group";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = &[
+        ("ibc/dex_ticker#6", "ticker#6"),
+        ("ibc/dex_ticker#10", "ticker#10"),
+    ];
     type TopG = SuperGroup;
 
     fn maybe_visit<M, V>(matcher: &M, visitor: V) -> MaybeAnyVisitResult<Self, V>
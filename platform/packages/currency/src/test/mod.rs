@@ -2,13 +2,39 @@ use std::marker::PhantomData;
 
 use crate::{
     error::Error, AnyVisitor, AnyVisitorPair, AnyVisitorPairResult, AnyVisitorResult, Currency,
-    CurrencyDTO, CurrencyDef, Group, MemberOf, SingleVisitor,
+    CurrencyDTO, CurrencyDef, DefinitionRef, DexSymbols, Group, MemberOf, SingleVisitor,
 };
 
 pub use self::group::*;
 
 mod group;
 
+/// Assert that [`Group::DEX_SYMBOLS`] lists exactly the `members` of the group
+///
+/// Each listed dex symbol should also resolve to the member of the group with the paired ticker.
+#[track_caller]
+pub fn assert_dex_symbols<G>(members: &[DefinitionRef])
+where
+    G: Group,
+{
+    assert_eq!(members.len(), G::DEX_SYMBOLS.len());
+    members.iter().for_each(|member| {
+        assert!(
+            G::DEX_SYMBOLS.contains(&(member.dex_symbol, member.ticker)),
+            "'{}' is not listed in the dex symbols of '{}'",
+            member.ticker,
+            G::DESCR
+        )
+    });
+    G::DEX_SYMBOLS.iter().for_each(|&(dex_symbol, ticker)| {
+        assert_eq!(
+            Ok(ticker),
+            CurrencyDTO::<G>::from_symbol_testing::<DexSymbols<G>>(dex_symbol)
+                .map(|member| member.definition().ticker)
+        )
+    });
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Expect<CDef, VisitedG, VisitorG>(
     PhantomData<CDef>,
@@ -7,7 +7,10 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 use currency::{never::Never, Currency, CurrencyDTO, CurrencyDef, Group, MemberOf};
-use sdk::schemars::{self, JsonSchema};
+use sdk::{
+    cosmwasm_std::{OverflowError, OverflowOperation},
+    schemars::{self, JsonSchema},
+};
 use transformer::CoinTransformerAny;
 
 use crate::{
@@ -92,12 +95,89 @@ where
         Coin::new(self.amount)
     }
 
+    /// Convert to a coin of `C`
+    ///
+    /// Fail if the currency is not `C`.
+    pub fn checked_convert<C>(&self) -> Result<Coin<C>>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<G>,
+    {
+        self.of_currency_dto(C::dto())
+            .map(|()| self.as_specific(C::dto()))
+    }
+
+    /// Check if the currency is `C`
+    pub fn is_convertible<C>(&self) -> bool
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<G>,
+    {
+        self.of_currency_dto(C::dto()).is_ok()
+    }
+
     pub fn of_currency_dto<SubG>(&self, dto: &CurrencyDTO<SubG>) -> Result<()>
     where
         SubG: Group + MemberOf<G>,
     {
         self.currency.of_currency(dto).map_err(Into::into)
     }
+
+    /// Widen up to a coin of a super-group
+    ///
+    /// Infallible since the membership of `G` in `SuperG` is checked at compile time.
+    pub fn into_super_group<SuperG>(self) -> CoinDTO<SuperG>
+    where
+        SuperG: Group,
+        G: MemberOf<SuperG>,
+    {
+        CoinDTO::new(self.amount, self.currency.into_super_group())
+    }
+
+    /// Narrow down to a coin of a sub-group
+    ///
+    /// Fail if the currency is not a member of `Narrow`.
+    pub fn try_narrow<Narrow>(self) -> Result<CoinDTO<Narrow>>
+    where
+        Narrow: Group + MemberOf<G>,
+    {
+        self.currency
+            .try_into_sub_group()
+            .map(|currency| CoinDTO::new(self.amount, currency))
+            .map_err(Into::into)
+    }
+
+    /// Add an amount of the same currency
+    ///
+    /// Fail on a currency mismatch or on an overflow.
+    pub fn checked_add(self, other: Self) -> Result<Self>
+    where
+        G: MemberOf<G>,
+    {
+        self.checked_op(other, Amount::checked_add, OverflowOperation::Add)
+    }
+
+    /// Subtract an amount of the same currency
+    ///
+    /// Fail on a currency mismatch or on an underflow.
+    pub fn checked_sub(self, other: Self) -> Result<Self>
+    where
+        G: MemberOf<G>,
+    {
+        self.checked_op(other, Amount::checked_sub, OverflowOperation::Sub)
+    }
+
+    fn checked_op<Op>(self, other: Self, op: Op, op_kind: OverflowOperation) -> Result<Self>
+    where
+        G: MemberOf<G>,
+        Op: FnOnce(Amount, Amount) -> Option<Amount>,
+    {
+        self.of_currency_dto(&other.currency).and_then(|()| {
+            op(self.amount, other.amount)
+                .map(|amount| Self::new(amount, self.currency))
+                .ok_or_else(|| OverflowError::new(op_kind).into())
+        })
+    }
 }
 
 impl<G> Display for CoinDTO<G>
@@ -119,8 +199,7 @@ where
     type Error = Error;
 
     fn try_from(coin: CoinDTO<G>) -> Result<Self> {
-        coin.of_currency_dto(C::dto())
-            .map(|()| coin.as_specific(C::dto()))
+        coin.checked_convert()
     }
 }
 
@@ -183,12 +262,98 @@ mod test {
     use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
     use currency::{
+        error::Error as CurrencyError,
         test::{SubGroup, SubGroupTestC10, SuperGroup, SuperGroupTestC1, SuperGroupTestC2},
         CurrencyDef, Group, MemberOf,
     };
-    use sdk::cosmwasm_std;
+    use sdk::cosmwasm_std::{self, OverflowError, OverflowOperation};
 
-    use crate::coin::{Amount, Coin, CoinDTO};
+    use crate::{
+        coin::{Amount, Coin, CoinDTO},
+        error::Error,
+    };
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            Ok(test_coin::<SuperGroupTestC1, SuperGroup>(30)),
+            test_coin::<SuperGroupTestC1, SuperGroup>(12)
+                .checked_add(test_coin::<SuperGroupTestC1, SuperGroup>(18))
+        );
+        assert_eq!(
+            Err(Error::OverflowError(OverflowError::new(
+                OverflowOperation::Add
+            ))),
+            test_coin::<SuperGroupTestC1, SuperGroup>(Amount::MAX)
+                .checked_add(test_coin::<SuperGroupTestC1, SuperGroup>(1))
+        );
+    }
+
+    #[test]
+    fn checked_sub() {
+        assert_eq!(
+            Ok(test_coin::<SuperGroupTestC1, SuperGroup>(0)),
+            test_coin::<SuperGroupTestC1, SuperGroup>(18)
+                .checked_sub(test_coin::<SuperGroupTestC1, SuperGroup>(18))
+        );
+        assert_eq!(
+            Err(Error::OverflowError(OverflowError::new(
+                OverflowOperation::Sub
+            ))),
+            test_coin::<SuperGroupTestC1, SuperGroup>(17)
+                .checked_sub(test_coin::<SuperGroupTestC1, SuperGroup>(18))
+        );
+    }
+
+    #[test]
+    fn checked_ops_currency_mismatch() {
+        let c1 = test_coin::<SuperGroupTestC1, SuperGroup>(20);
+        let c2 = test_coin::<SuperGroupTestC2, SuperGroup>(10);
+        assert!(matches!(
+            c1.checked_add(c2),
+            Err(Error::CurrencyError(CurrencyError::CurrencyMismatch { .. }))
+        ));
+        assert!(matches!(
+            c1.checked_sub(c2),
+            Err(Error::CurrencyError(CurrencyError::CurrencyMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn into_super_group() {
+        let amount = 20;
+        assert_eq!(
+            test_coin::<SubGroupTestC10, SuperGroup>(amount),
+            test_coin::<SubGroupTestC10, SubGroup>(amount).into_super_group::<SuperGroup>()
+        );
+    }
+
+    #[test]
+    fn into_super_group_try_narrow() {
+        let coin = test_coin::<SubGroupTestC10, SubGroup>(20);
+        assert_eq!(
+            Ok(coin),
+            coin.into_super_group::<SuperGroup>()
+                .try_narrow::<SubGroup>()
+        );
+    }
+
+    #[test]
+    fn try_narrow() {
+        let amount = 20;
+        assert_eq!(
+            Ok(test_coin::<SubGroupTestC10, SubGroup>(amount)),
+            test_coin::<SubGroupTestC10, SuperGroup>(amount).try_narrow::<SubGroup>()
+        );
+    }
+
+    #[test]
+    fn try_narrow_not_a_member() {
+        assert!(matches!(
+            test_coin::<SuperGroupTestC1, SuperGroup>(20).try_narrow::<SubGroup>(),
+            Err(Error::CurrencyError(CurrencyError::NotInCurrencyGroup(..)))
+        ));
+    }
 
     #[test]
     fn longer_representation() {
@@ -242,6 +407,40 @@ mod test {
             .expect_err("Try_into another currency of the same group should fail");
     }
 
+    #[test]
+    fn checked_convert() {
+        let amount = 123;
+        let coin = test_coin::<SubGroupTestC10, SuperGroup>(amount);
+
+        assert!(coin.is_convertible::<SubGroupTestC10>());
+        assert_eq!(
+            Ok(Coin::<SubGroupTestC10>::new(amount)),
+            coin.checked_convert::<SubGroupTestC10>()
+        );
+    }
+
+    #[test]
+    fn checked_convert_wrong_ticker() {
+        let coin = test_coin::<SuperGroupTestC1, SuperGroup>(123);
+
+        assert!(!coin.is_convertible::<SuperGroupTestC2>());
+        assert!(matches!(
+            coin.checked_convert::<SuperGroupTestC2>(),
+            Err(Error::CurrencyError(CurrencyError::CurrencyMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn checked_convert_out_of_group() {
+        let coin = test_coin::<SuperGroupTestC1, SuperGroup>(123);
+
+        assert!(!coin.is_convertible::<SubGroupTestC10>());
+        assert!(matches!(
+            coin.checked_convert::<SubGroupTestC10>(),
+            Err(Error::CurrencyError(CurrencyError::CurrencyMismatch { .. }))
+        ));
+    }
+
     #[test]
     fn deser_same_group() {
         let coin = test_coin::<SuperGroupTestC1, SuperGroup>(4215);
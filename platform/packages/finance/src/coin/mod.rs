@@ -381,6 +381,19 @@ mod test {
         assert_eq!(None, coin1(Amount::MAX / 5).checked_mul(5 + 1));
     }
 
+    #[test]
+    fn checked_mul_overflow_boundary() {
+        assert_eq!(Some(coin1(0)), coin1(0).checked_mul(Amount::MAX));
+        assert_eq!(Some(coin1(Amount::MAX)), coin1(1).checked_mul(Amount::MAX));
+        assert_eq!(None, coin1(2).checked_mul(Amount::MAX));
+        assert_eq!(None, coin1(Amount::MAX).checked_mul(2));
+
+        let half = Amount::MAX / 2;
+        assert_eq!(Some(coin1(Amount::MAX - 1)), coin1(half).checked_mul(2));
+        assert_eq!(None, coin1(half + 1).checked_mul(2));
+        assert_eq!(None, coin1(2).checked_mul(half + 1));
+    }
+
     #[test]
     #[should_panic]
     fn of_overflow() {
@@ -26,6 +26,15 @@ pub enum Error {
         value: PercentUnits,
     },
 
+    #[error("[Finance] [Price] The amount of the price {0} should not be zero")]
+    PriceZeroAmount(String),
+
+    #[error("[Finance] [Price] The quote amount of the price {0} should not be zero")]
+    PriceZeroQuote(String),
+
+    #[error("[Finance] [Price] The price {price} is not of the expected currencies {expected}")]
+    PriceCurrencyMismatch { price: String, expected: String },
+
     #[error("[Finance] [Std] {0}")]
     CosmWasmError(#[from] StdError),
 }
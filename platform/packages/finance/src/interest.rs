@@ -2,8 +2,10 @@ use std::{cmp, fmt::Debug, ops::Sub};
 
 use crate::{
     duration::Duration,
+    error::Result as FinanceResult,
     fraction::Fraction,
     fractionable::{Fractionable, TimeSliceable},
+    percent::Percent,
     zero::Zero,
 };
 
@@ -17,6 +19,14 @@ where
     period.annualized_slice_of(interest_per_year)
 }
 
+/// Computes the effective annual interest rate of a loan charged with a margin
+///
+/// Both the loan and the margin interest accrue on the same principal over
+/// the same period, so the effective rate is their sum.
+pub fn effective_apr(loan_rate: Percent, margin_rate: Percent) -> FinanceResult<Percent> {
+    loan_rate.checked_add(margin_rate)
+}
+
 /// Computes how much time this payment covers, return.0, and the change, return.1
 ///
 /// The actual payment is equal to the payment minus the returned change.
@@ -44,7 +54,11 @@ mod tests {
     use currency::test::SubGroupTestC10;
 
     use crate::{
-        coin::Coin, duration::Duration, fraction::Fraction, percent::Percent, ratio::Rational,
+        coin::Coin,
+        duration::Duration,
+        fraction::Fraction,
+        percent::{Percent, Units as PercentUnits},
+        ratio::Rational,
         zero::Zero,
     };
 
@@ -148,6 +162,33 @@ mod tests {
         assert_eq!(part, res);
     }
 
+    #[test]
+    fn effective_apr() {
+        let loan_rate = Percent::from_permille(72);
+        let margin_rate = Percent::from_permille(30);
+        assert_eq!(
+            Ok(Percent::from_permille(102)),
+            super::effective_apr(loan_rate, margin_rate)
+        );
+        assert_eq!(
+            Ok(loan_rate),
+            super::effective_apr(loan_rate, Percent::ZERO)
+        );
+        assert_eq!(
+            Ok(margin_rate),
+            super::effective_apr(Percent::ZERO, margin_rate)
+        );
+    }
+
+    #[test]
+    fn effective_apr_overflow() {
+        assert!(super::effective_apr(
+            Percent::from_permille(PercentUnits::MAX),
+            Percent::from_permille(1)
+        )
+        .is_err());
+    }
+
     #[test]
     fn interest_zero() {
         let principal = MyCoin::new(1001);
@@ -43,12 +43,29 @@ impl Percent {
         Self(permille)
     }
 
+    /// Calculate the percent `nominator` is of `denominator`
+    ///
+    /// A zero `denominator` yields [`Percent::ZERO`]. Use [`Percent::checked_from_ratio`]
+    /// if that case should be handled differently.
     pub fn from_ratio<FractionUnit>(nominator: FractionUnit, denominator: FractionUnit) -> Self
     where
         FractionUnit: Copy + Debug + PartialEq + Zero,
         Self: Fractionable<FractionUnit>,
     {
-        Rational::new(nominator, denominator).of(Percent::HUNDRED)
+        Self::checked_from_ratio(nominator, denominator).unwrap_or(Self::ZERO)
+    }
+
+    /// Calculate the percent `nominator` is of `denominator`, or `None` if `denominator` is zero
+    pub fn checked_from_ratio<FractionUnit>(
+        nominator: FractionUnit,
+        denominator: FractionUnit,
+    ) -> Option<Self>
+    where
+        FractionUnit: Copy + Debug + PartialEq + Zero,
+        Self: Fractionable<FractionUnit>,
+    {
+        (denominator != FractionUnit::ZERO)
+            .then(|| Rational::new(nominator, denominator).of(Percent::HUNDRED))
     }
 
     pub const fn units(&self) -> Units {
@@ -72,6 +89,11 @@ impl Percent {
             .map(Self::from_permille)
             .ok_or_else(|| OverflowError::new(OverflowOperation::Sub).into())
     }
+
+    /// Subtract `other` clamping the result at [`Percent::ZERO`]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::from_permille(self.0.saturating_sub(other.0))
+    }
 }
 
 impl Zero for Percent {
@@ -215,6 +237,38 @@ pub(super) mod test {
         assert_eq!(from_parts(a2, a3), Percent::from_ratio(c2, c3));
     }
 
+    #[test]
+    fn from_ratio_zero_denominator() {
+        let zero = Coin::<SubGroupTestC10>::new(0);
+        assert_eq!(Percent::ZERO, Percent::from_ratio(zero, zero));
+        assert_eq!(
+            Percent::ZERO,
+            Percent::from_ratio(Coin::<SubGroupTestC10>::new(1352), zero)
+        );
+    }
+
+    #[test]
+    fn checked_from_ratio() {
+        let a2 = 5000;
+        let a3 = 1352;
+        let c1 = Coin::<SubGroupTestC10>::new(0);
+        let c2 = Coin::<SubGroupTestC10>::new(a2);
+        let c3 = Coin::<SubGroupTestC10>::new(a3);
+
+        assert_eq!(None, Percent::checked_from_ratio(c1, c1));
+        assert_eq!(None, Percent::checked_from_ratio(c3, c1));
+        assert_eq!(Some(Percent::ZERO), Percent::checked_from_ratio(c1, c2));
+        assert_eq!(Some(Percent::HUNDRED), Percent::checked_from_ratio(c3, c3));
+        assert_eq!(
+            Some(from_parts(a3, a2)),
+            Percent::checked_from_ratio(c3, c2)
+        );
+        assert_eq!(
+            Some(Percent::from_percent(250)),
+            Percent::checked_from_ratio(250, 100)
+        );
+    }
+
     #[test]
     fn test_zero() {
         assert_eq!(
@@ -263,6 +317,37 @@ pub(super) mod test {
         let _ = from(34) - from(35);
     }
 
+    #[test]
+    fn checked_add() {
+        assert_eq!(Ok(from(40)), from(25).checked_add(from(15)));
+        assert_eq!(Ok(from(Units::MAX)), from(Units::MAX).checked_add(from(0)));
+        assert_eq!(
+            Ok(from(Units::MAX)),
+            from(Units::MAX - 1).checked_add(from(1))
+        );
+        assert!(from(Units::MAX).checked_add(from(1)).is_err());
+        assert!(from(1).checked_add(from(Units::MAX)).is_err());
+        assert!(from(Units::MAX).checked_add(from(Units::MAX)).is_err());
+    }
+
+    #[test]
+    fn checked_sub() {
+        assert_eq!(Ok(from(67)), from(79).checked_sub(from(12)));
+        assert_eq!(Ok(from(0)), from(Units::MAX).checked_sub(from(Units::MAX)));
+        assert_eq!(Ok(from(Units::MAX)), from(Units::MAX).checked_sub(from(0)));
+        assert!(from(34).checked_sub(from(35)).is_err());
+        assert!(from(0).checked_sub(from(Units::MAX)).is_err());
+    }
+
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(from(67), from(79).saturating_sub(from(12)));
+        assert_eq!(from(0), from(34).saturating_sub(from(35)));
+        assert_eq!(from(0), Percent::HUNDRED.saturating_sub(Percent::HUNDRED));
+        assert_eq!(from(0), Percent::HUNDRED.saturating_sub(from(1001)));
+        assert_eq!(from(0), from(0).saturating_sub(from(Units::MAX)));
+    }
+
     #[test]
     fn display() {
         test_display("0%", 0);
@@ -76,6 +76,11 @@ where
         }
     }
 
+    /// The currency this is a price of
+    pub const fn currency(&self) -> CurrencyDTO<BaseG> {
+        self.amount.currency()
+    }
+
     pub fn try_as_specific<C, SubG>(
         &self,
         amount_c: &CurrencyDTO<SubG>,
@@ -69,7 +69,8 @@ where
             amount: base,
             amount_quote: quote,
         }
-        .invariant_held()
+        .non_zero()
+        .and_then(Self::invariant_held)
     }
 
     pub const fn base(&self) -> &CoinDTO<G> {
@@ -80,6 +81,16 @@ where
         &self.amount_quote
     }
 
+    fn non_zero(self) -> Result<Self> {
+        if self.amount.is_zero() {
+            Err(Error::PriceZeroAmount(self.to_string()))
+        } else if self.amount_quote.is_zero() {
+            Err(Error::PriceZeroQuote(self.to_string()))
+        } else {
+            Ok(self)
+        }
+    }
+
     fn invariant_held(self) -> Result<Self> {
         struct InvariantCheck<G> {
             g: PhantomData<G>,
@@ -135,7 +146,10 @@ where
         self.amount
             .of_currency_dto(amount_c)
             .and_then(|()| self.amount_quote.of_currency_dto(quote_c))
-            .map_err(Into::into)
+            .map_err(|_| Error::PriceCurrencyMismatch {
+                price: self.to_string(),
+                expected: format!("{amount_c}/{quote_c}"),
+            })
     }
 }
 
@@ -234,6 +248,7 @@ mod test_invariant {
     use crate::{
         coin::{Coin, CoinDTO},
         error::{Error, Result},
+        price::{self, Price},
     };
 
     use super::PriceDTO;
@@ -249,6 +264,13 @@ mod test_invariant {
             ),
             "zero",
         );
+        assert!(matches!(
+            new_invalid(
+                Coin::<SuperGroupTestC1>::new(0),
+                Coin::<SuperGroupTestC2>::new(5),
+            ),
+            Err(Error::PriceZeroAmount(_))
+        ));
     }
 
     #[test]
@@ -269,7 +291,14 @@ mod test_invariant {
                 Coin::<SuperGroupTestC2>::new(0),
             ),
             "zero",
-        )
+        );
+        assert!(matches!(
+            new_invalid(
+                Coin::<SuperGroupTestC1>::new(10),
+                Coin::<SuperGroupTestC2>::new(0),
+            ),
+            Err(Error::PriceZeroQuote(_))
+        ));
     }
 
     #[test]
@@ -366,6 +395,29 @@ mod test_invariant {
         assert_load_err(load(&json.into_bytes()), "No records for a pool with");
     }
 
+    #[test]
+    fn currency_mismatch() {
+        let dto =
+            PriceDTO::<TC>::from(price::total_of(Coin::<SuperGroupTestC1>::new(4)).is(Coin::<
+                SuperGroupTestC2,
+            >::new(
+                5
+            )));
+        assert_eq!(
+            Ok(price::total_of(Coin::<SuperGroupTestC1>::new(4))
+                .is(Coin::<SuperGroupTestC2>::new(5))),
+            Price::<SuperGroupTestC1, SuperGroupTestC2>::try_from(&dto)
+        );
+        assert!(matches!(
+            Price::<SuperGroupTestC2, SuperGroupTestC1>::try_from(&dto),
+            Err(Error::PriceCurrencyMismatch { .. })
+        ));
+        assert!(matches!(
+            Price::<SuperGroupTestC1, SuperGroupTestC4>::try_from(dto),
+            Err(Error::PriceCurrencyMismatch { .. })
+        ));
+    }
+
     fn new_invalid<C, QuoteC>(base: Coin<C>, quote: Coin<QuoteC>) -> Result<PriceDTO<TC>>
     where
         C: CurrencyDef,
@@ -5,13 +5,18 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use sdk::schemars::{self, JsonSchema};
+use sdk::{
+    cosmwasm_std::{Timestamp, Uint128, Uint512},
+    schemars::{self, JsonSchema},
+};
 
 use crate::{
     coin::{Amount, Coin},
+    duration::Duration,
     error::{Error, Result},
     fraction::Fraction,
     fractionable::HigherRank,
+    percent::{Percent, Units as PercentUnits},
     ratio::{Ratio, Rational},
 };
 
@@ -104,6 +109,15 @@ where
     /// where the pairs (amount, nominator) and (amount_quote, denominator) are transformed into co-prime numbers.
     /// Please note that Price(amount, amount_quote) is like Ratio(amount_quote / amount).
     pub(crate) fn lossy_mul<R>(self, rhs: &R) -> Self
+    where
+        R: Ratio<Amount>,
+    {
+        self.checked_lossy_mul(rhs)
+            .expect("price overflow during multiplication")
+    }
+
+    /// The same as [`Self::lossy_mul`] but returns `None` if the result does not fit in a price
+    fn checked_lossy_mul<R>(self, rhs: &R) -> Option<Self>
     where
         R: Ratio<Amount>,
     {
@@ -121,10 +135,9 @@ where
         let extra_bits =
             Self::bits_above_max(double_amount).max(Self::bits_above_max(double_amount_quote));
 
-        Price::new(
-            Self::trim_down(double_amount, extra_bits).into(),
-            Self::trim_down(double_amount_quote, extra_bits).into(),
-        )
+        Self::trim_down(double_amount, extra_bits)
+            .zip(Self::trim_down(double_amount_quote, extra_bits))
+            .map(|(amount, amount_quote)| Price::new(amount.into(), amount_quote.into()))
     }
 
     pub fn inv(self) -> Price<QuoteC, C> {
@@ -134,6 +147,15 @@ where
         }
     }
 
+    /// Checked counterpart of [`Price::inv`]
+    ///
+    /// Intended for prices obtained from non-validated input, for example deserialized
+    /// as is from the storage. Swapping the amounts cannot overflow, so the only failure
+    /// is a zero amount or a zero quote amount.
+    pub fn try_inv(self) -> Result<Price<QuoteC, C>> {
+        Price::try_new(self.amount_quote, self.amount)
+    }
+
     fn precondition_check(amount: Coin<C>, amount_quote: Coin<QuoteC>) -> Result<()> {
         Self::check(!amount.is_zero(), "The amount should not be zero").and(Self::check(
             !amount_quote.is_zero(),
@@ -189,9 +211,17 @@ where
     fn lossy_add(self, rhs: Self) -> Option<Self> {
         const FACTOR: Amount = 1_000_000_000_000_000_000; // 1*10^18
         let factored_amount = FACTOR.into();
-        let may_factored_total =
-            total(factored_amount, self).checked_add(total(factored_amount, rhs));
-        may_factored_total.map(|factored_total| total_of(factored_amount).is(factored_total))
+        let may_factored_total = checked_total(factored_amount, self)
+            .zip(checked_total(factored_amount, rhs))
+            .and_then(|(total, rhs_total)| total.checked_add(rhs_total));
+        may_factored_total
+            .filter(|factored_total| !factored_total.is_zero())
+            .map(|factored_total| total_of(factored_amount).is(factored_total))
+    }
+
+    /// Add two prices exactly, or with the precision of [`Self::lossy_add`] if that overflows
+    fn try_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs).or_else(|| self.lossy_add(rhs))
     }
 
     #[track_caller]
@@ -204,14 +234,13 @@ where
     }
 
     #[track_caller]
-    fn trim_down(double_amount: DoubleAmount, bits: u32) -> Amount {
+    fn trim_down(double_amount: DoubleAmount, bits: u32) -> Option<Amount> {
         debug_assert!(bits <= Amount::BITS);
         let amount: IntermediateAmount = (double_amount >> bits)
             .try_into()
             .expect("insufficient bits to trim");
-        let res = amount.into();
-        assert!(res > 0, "price overflow during multiplication");
-        res
+        let res: Amount = amount.into();
+        (res > 0).then_some(res)
     }
 }
 
@@ -285,9 +314,7 @@ where
     type Output = Price<C, QuoteC>;
 
     fn add(self, rhs: Price<C, QuoteC>) -> Self::Output {
-        self.checked_add(rhs)
-            .or_else(|| self.lossy_add(rhs))
-            .expect("should not observe huge prices")
+        self.try_add(rhs).expect("should not observe huge prices")
     }
 }
 
@@ -330,15 +357,128 @@ pub fn total<C, QuoteC>(of: Coin<C>, price: Price<C, QuoteC>) -> Coin<QuoteC> {
     Fraction::<Coin<C>>::of(&ratio_impl, price.amount_quote)
 }
 
+/// The same as [`total`] but returns `None` if the result does not fit in [`Amount`]
+fn checked_total<C, QuoteC>(of: Coin<C>, price: Price<C, QuoteC>) -> Option<Coin<QuoteC>> {
+    let total = DoubleAmount::from(of) * DoubleAmount::from(price.amount_quote)
+        / DoubleAmount::from(price.amount);
+    IntermediateAmount::try_from(total)
+        .ok()
+        .map(|total| Amount::from(total).into())
+}
+
+/// Calculates the amount of given coins in a quote currency through an intermediate one
+///
+/// The amount is multiplied by the combined ratio of both prices in one step, hence
+/// the result is rounded once, unlike two sequential [`total`] calls where each one
+/// rounds down on its own, or a [`total`] of the prices product that might be trimmed.
+///
+/// For example, total_via(10 EUR, 1.01 EURUSD, 150 USDJPY) = 1515 JPY
+pub fn total_via<C, IntermediateC, QuoteC>(
+    of: Coin<C>,
+    price: Price<C, IntermediateC>,
+    intermediate_price: Price<IntermediateC, QuoteC>,
+) -> Coin<QuoteC>
+where
+    C: 'static,
+    IntermediateC: 'static,
+    QuoteC: 'static,
+{
+    let nominator = Uint512::from(Amount::from(of))
+        * Uint512::from(Amount::from(price.amount_quote))
+        * Uint512::from(Amount::from(intermediate_price.amount_quote));
+    let denominator = Uint512::from(Amount::from(price.amount))
+        * Uint512::from(Amount::from(intermediate_price.amount));
+    Uint128::try_from(nominator / denominator)
+        .map(|total| Amount::from(total).into())
+        .expect("total overflow during conversion via an intermediate currency")
+}
+
+/// Calculates the mean of the prices weighted by their respective weights
+///
+/// The result is the sum of each price multiplied by the ratio of its weight to the total weight.
+/// Prices with a zero weight do not take part. The precision is the one provided by
+/// the price multiplication and addition, i.e. the amounts are trimmed down if they do not fit in [`Amount`],
+/// and if the sum overflows both terms are rounded to 1.10-18.
+///
+/// Returns `None` if there is no price with a non-zero weight, or if a weighted price or
+/// their sum does not fit in a price, which should not happen with real prices.
+pub fn weighted_average<C, QuoteC>(prices: &[(Price<C, QuoteC>, u32)]) -> Option<Price<C, QuoteC>>
+where
+    C: 'static,
+    QuoteC: 'static,
+{
+    let total_weight: Amount = prices
+        .iter()
+        .map(|&(_price, weight)| Amount::from(weight))
+        .sum();
+
+    prices
+        .iter()
+        .filter(|&&(_price, weight)| weight != 0)
+        .map(|&(price, weight)| {
+            price.checked_lossy_mul(&Rational::new(weight.into(), total_weight))
+        })
+        .reduce(|sum, price| sum.zip(price).and_then(|(sum, price)| sum.try_add(price)))
+        .flatten()
+}
+
+/// Calculates the annual percentage rate implied by a price growing from `old` at `old_at` to `new` at `new_at`
+///
+/// The growth is annualized linearly, i.e. without compounding, which matches how the interest accrues.
+/// A flat or decreasing price, as well as a non-positive period, yield [`Percent::ZERO`].
+/// A rate that does not fit in [`Percent`] saturates at its maximum.
+pub fn apr_from_prices<C, QuoteC>(
+    old: Price<C, QuoteC>,
+    old_at: Timestamp,
+    new: Price<C, QuoteC>,
+    new_at: Timestamp,
+) -> Percent
+where
+    C: 'static,
+    QuoteC: 'static,
+{
+    if new_at <= old_at {
+        return Percent::ZERO;
+    }
+
+    // growth = new / old = (new.amount_quote * old.amount) / (new.amount * old.amount_quote)
+    let growth_nominator = DoubleAmount::from(new.amount_quote) * DoubleAmount::from(old.amount);
+    let growth_denominator = DoubleAmount::from(new.amount) * DoubleAmount::from(old.amount_quote);
+
+    let period = Duration::between(&old_at, &new_at);
+
+    growth_nominator
+        .checked_sub(growth_denominator)
+        .map_or(Percent::ZERO, |gain| {
+            gain.checked_mul(DoubleAmount::from(Percent::HUNDRED.units()))
+                .and_then(|gain| gain.checked_mul(DoubleAmount::from(Duration::YEAR.nanos())))
+                .ok()
+                .and_then(|annual_gain_permille| {
+                    growth_denominator
+                        .checked_mul(DoubleAmount::from(period.nanos()))
+                        .ok()
+                        .map(|period_amount| annual_gain_permille / period_amount)
+                })
+                .and_then(|permille| IntermediateAmount::try_from(permille).ok())
+                .and_then(|permille| PercentUnits::try_from(permille.u128()).ok())
+                .map_or(
+                    Percent::from_permille(PercentUnits::MAX),
+                    Percent::from_permille,
+                )
+        })
+}
+
 #[cfg(test)]
 mod test {
     use std::ops::{Add, AddAssign, Mul};
 
     use currency::test::{SubGroupTestC10, SuperGroupTestC1, SuperGroupTestC2};
-    use sdk::cosmwasm_std::{Uint128, Uint256};
+    use sdk::cosmwasm_std::{from_json, Timestamp, Uint128, Uint256};
 
     use crate::{
         coin::{Amount, Coin as CoinT},
+        duration::Duration,
+        percent::{Percent, Units as PercentUnits},
         price::{self, Price},
         ratio::Rational,
     };
@@ -385,6 +525,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn try_inv() {
+        let price = Price::new(Coin::new(13), QuoteCoin::new(15));
+
+        assert_eq!(
+            Ok(Price::new(QuoteCoin::new(15), Coin::new(13))),
+            price.try_inv()
+        );
+        assert_eq!(Ok(price), price.try_inv().and_then(Price::try_inv));
+    }
+
+    #[test]
+    fn try_inv_zero() {
+        let zero_amount: Price<SuperGroupTestC2, SuperGroupTestC1> =
+            from_json(r#"{"amount":{"amount":"0"},"amount_quote":{"amount":"15"}}"#).unwrap();
+        assert!(zero_amount.try_inv().is_err());
+
+        let zero_quote: Price<SuperGroupTestC2, SuperGroupTestC1> =
+            from_json(r#"{"amount":{"amount":"13"},"amount_quote":{"amount":"0"}}"#).unwrap();
+        assert!(zero_quote.try_inv().is_err());
+    }
+
     #[test]
     fn ord() {
         ord_impl(13, 15);
@@ -440,6 +602,63 @@ mod test {
         super::total(2.into(), price);
     }
 
+    #[test]
+    fn total_via() {
+        let price = price::total_of(Coin::new(3)).is(QuoteCoin::new(1));
+        let quote_price = price::total_of(QuoteCoin::new(1)).is(QuoteQuoteCoin::new(3));
+        let coin = Coin::new(7);
+
+        // 7 * 1 / 3 -> 2.3333333333333335, 2 * 3 / 1 -> 6
+        assert_eq!(
+            QuoteQuoteCoin::new(6),
+            super::total(super::total(coin, price), quote_price)
+        );
+        assert_eq!(
+            QuoteQuoteCoin::new(7),
+            super::total_via(coin, price, quote_price)
+        );
+    }
+
+    #[test]
+    fn total_via_rounding() {
+        let price = price::total_of(Coin::new(48)).is(QuoteCoin::new(647));
+        let quote_price = price::total_of(QuoteCoin::new(13)).is(QuoteQuoteCoin::new(7));
+        let coin = Coin::new(47);
+
+        // 47 * 647 / 48 -> 633.5208333333334, 633 * 7 / 13 -> 340.84615384615387
+        assert_eq!(
+            QuoteQuoteCoin::new(340),
+            super::total(super::total(coin, price), quote_price)
+        );
+        // 47 * 647 * 7 / (48 * 13) -> 341.12660256410254
+        assert_eq!(
+            QuoteQuoteCoin::new(341),
+            super::total_via(coin, price, quote_price)
+        );
+        assert_eq!(
+            super::total(coin, price * quote_price),
+            super::total_via(coin, price, quote_price)
+        );
+    }
+
+    #[test]
+    fn total_via_no_trim() {
+        let price = price::total_of(Coin::new(Amount::MAX)).is(QuoteCoin::new(Amount::MAX - 1));
+        let quote_price = price::total_of(QuoteCoin::new(Amount::MAX - 2))
+            .is(QuoteQuoteCoin::new(Amount::MAX - 3));
+        let coin = Coin::new(Amount::MAX);
+
+        // MAX * (MAX - 1) * (MAX - 3) / (MAX * (MAX - 2)) -> MAX - 2 - 1 / (MAX - 2)
+        assert_eq!(
+            QuoteQuoteCoin::new(Amount::MAX - 3),
+            super::total_via(coin, price, quote_price)
+        );
+        assert_ne!(
+            super::total(coin, price * quote_price),
+            super::total_via(coin, price, quote_price)
+        );
+    }
+
     #[test]
     fn add_no_round() {
         add_impl(c(1), q(2), c(5), q(10), c(1), q(4));
@@ -488,12 +707,11 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn lossy_add_overflow() {
         // 2^128 / FACTOR (10^18) / 2^64 ~ 18.446744073709553
         let p1 = price::total_of(c(1)).is(q(u128::from(u64::MAX) * 19u128));
         let p2 = Price::identity();
-        p1.lossy_add(p2);
+        assert_eq!(None, p1.lossy_add(p2));
     }
 
     #[test]
@@ -662,6 +880,167 @@ mod test {
         let q_exp = shift_product(q1, q2, shifts);
         lossy_mul_impl(c(a1), q(q1), q(a2), qq(q2), c(a_exp), qq(q_exp));
     }
+
+    #[test]
+    fn weighted_average_equal_weights() {
+        let price1 = price::total_of(c(1)).is(q(2));
+        let price2 = price::total_of(c(1)).is(q(4));
+        assert_eq!(Some(price1), price::weighted_average(&[(price1, 5)]));
+        assert_eq!(
+            Some(price::total_of(c(1)).is(q(3))),
+            price::weighted_average(&[(price1, 1), (price2, 1)])
+        );
+        assert_eq!(
+            Some(price::total_of(c(1)).is(q(3))),
+            price::weighted_average(&[(price1, 7), (price2, 7)])
+        );
+        assert_eq!(
+            Some(price::total_of(c(6)).is(q(17))),
+            price::weighted_average(&[
+                (price1, 2),
+                (price2, 2),
+                (price::total_of(c(2)).is(q(5)), 2)
+            ])
+        );
+    }
+
+    #[test]
+    fn weighted_average_unequal_weights() {
+        let price1 = price::total_of(c(1)).is(q(2));
+        let price2 = price::total_of(c(1)).is(q(4));
+        // (2 * 3 + 4 * 1) / 4
+        assert_eq!(
+            Some(price::total_of(c(2)).is(q(5))),
+            price::weighted_average(&[(price1, 3), (price2, 1)])
+        );
+        // (1/3 * 1 + 1/2 * 2) / 3
+        assert_eq!(
+            Some(price::total_of(c(9)).is(q(4))),
+            price::weighted_average(&[
+                (price::total_of(c(3)).is(q(1)), 1),
+                (price::total_of(c(2)).is(q(1)), 2)
+            ])
+        );
+        assert_eq!(
+            Some(price2),
+            price::weighted_average(&[(price1, 0), (price2, 10)])
+        );
+    }
+
+    #[test]
+    fn weighted_average_round() {
+        // 3 / (MAX - 1) * 1 / 2 is trimmed down to 1 / (MAX - 1)
+        let price1 = price::total_of(c(Amount::MAX - 1)).is(q(3));
+        let price2 = price::total_of(c(1)).is(q(1));
+        assert_eq!(
+            Some(price::total_of(c(Amount::MAX - 1)).is(q((Amount::MAX - 1) / 2 + 1))),
+            price::weighted_average(&[(price1, 1), (price2, 1)])
+        );
+    }
+
+    #[test]
+    fn weighted_average_no_weights() {
+        let price1 = price::total_of(c(1)).is(q(2));
+        assert_eq!(None, price::weighted_average(&[(price1, 0)]));
+    }
+
+    #[test]
+    fn weighted_average_overflow() {
+        // MAX * 1 / 2^32 is trimmed down to 0
+        let price1 = price::total_of(c(Amount::MAX)).is(q(1));
+        let price2 = price::total_of(c(1)).is(q(1));
+        assert_eq!(
+            None,
+            price::weighted_average(&[(price1, 1), (price2, u32::MAX - 1)])
+        );
+
+        // MAX / 2 + MAX / 2 does not fit
+        let price3 = price::total_of(c(1)).is(q(Amount::MAX));
+        assert_eq!(None, price::weighted_average(&[(price3, 1), (price3, 1)]));
+    }
+
+    #[test]
+    fn apr_known_growth() {
+        let old = price::total_of(c(100)).is(q(200));
+        let at = Timestamp::from_seconds(1_000);
+
+        // 5% over half a year
+        assert_eq!(
+            Percent::from_percent(10),
+            price::apr_from_prices(
+                old,
+                at,
+                price::total_of(c(100)).is(q(210)),
+                at + Duration::from_nanos(Duration::YEAR.nanos() / 2)
+            )
+        );
+        // 20% over two years
+        assert_eq!(
+            Percent::from_percent(10),
+            price::apr_from_prices(
+                old,
+                at,
+                price::total_of(c(100)).is(q(240)),
+                at + Duration::YEAR.checked_mul(2).unwrap()
+            )
+        );
+        // 0.1% over a day
+        assert_eq!(
+            Percent::from_permille(365),
+            price::apr_from_prices(
+                old,
+                at,
+                price::total_of(c(1000)).is(q(2002)),
+                at + Duration::from_days(1)
+            )
+        );
+    }
+
+    #[test]
+    fn apr_flat_or_decreasing() {
+        let old = price::total_of(c(100)).is(q(200));
+        let at = Timestamp::from_seconds(1_000);
+        let later = at + Duration::YEAR;
+
+        assert_eq!(Percent::ZERO, price::apr_from_prices(old, at, old, later));
+        assert_eq!(
+            Percent::ZERO,
+            price::apr_from_prices(old, at, price::total_of(c(100)).is(q(199)), later)
+        );
+    }
+
+    #[test]
+    fn apr_no_period() {
+        let old = price::total_of(c(100)).is(q(200));
+        let new = price::total_of(c(100)).is(q(210));
+        let at = Timestamp::from_seconds(1_000);
+
+        assert_eq!(Percent::ZERO, price::apr_from_prices(old, at, new, at));
+        assert_eq!(
+            Percent::ZERO,
+            price::apr_from_prices(old, at, new, at - Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn apr_saturates() {
+        let old = price::total_of(c(1)).is(q(1));
+        let new = price::total_of(c(1)).is(q(Amount::MAX));
+        let at = Timestamp::from_seconds(1_000);
+
+        assert_eq!(
+            Percent::from_permille(PercentUnits::MAX),
+            price::apr_from_prices(old, at, new, at + Duration::from_nanos(1))
+        );
+    }
+
+    #[test]
+    fn weighted_average_empty() {
+        assert_eq!(
+            None,
+            price::weighted_average::<SuperGroupTestC2, SuperGroupTestC1>(&[])
+        );
+    }
 }
 
 #[cfg(test)]
@@ -31,7 +31,7 @@ pub trait BankAccount
 where
     Self: BankAccountView + Into<Batch>,
 {
-    fn send<C>(&mut self, amount: Coin<C>, to: Addr)
+    fn send<C>(&mut self, amount: Coin<C>, to: Addr) -> Result<()>
     where
         C: CurrencyDef;
 }
@@ -40,7 +40,7 @@ pub trait FixedAddressSender
 where
     Self: Into<Batch>,
 {
-    fn send<C>(&mut self, amount: Coin<C>)
+    fn send<C>(&mut self, amount: Coin<C>) -> Result<()>
     where
         C: CurrencyDef;
 }
@@ -60,7 +60,7 @@ where
 
 /// Run a command on the first coin of the specified group
 pub fn may_received<VisitedG, V>(
-    cw_amount: &Vec<CwCoin>,
+    cw_amount: &[CwCoin],
     mut cmd: V,
 ) -> Option<WithCoinResult<VisitedG, V>>
 where
@@ -134,12 +134,16 @@ impl BankAccountView for BankView<'_> {
     }
 }
 
+/// Bank account that schedules the sends in a batch
+///
+/// The amounts sent to the same recipient are coalesced into a single message.
+/// The amounts in the same currency are further summed up into a single coin.
 pub struct BankStub<View>
 where
     View: BankAccountView,
 {
     view: View,
-    batch: Batch,
+    sends: Vec<LazySenderStub>,
 }
 
 impl<View> BankStub<View>
@@ -149,7 +153,7 @@ where
     fn new(view: View) -> Self {
         Self {
             view,
-            batch: Batch::default(),
+            sends: Vec::new(),
         }
     }
 
@@ -197,8 +201,7 @@ where
             C::Group: MemberOf<G> + MemberOf<G::TopG>,
         {
             let mut sender = LazySenderStub::new(self.to);
-            sender.send(coin);
-            Ok(sender.into())
+            sender.send(coin).map(|()| sender.into())
         }
     }
 
@@ -253,12 +256,17 @@ where
     Self: BankAccountView + Into<Batch>,
     View: BankAccountView,
 {
-    fn send<C>(&mut self, amount: Coin<C>, to: Addr)
+    fn send<C>(&mut self, amount: Coin<C>, to: Addr) -> Result<()>
     where
         C: CurrencyDef,
     {
         debug_assert!(!amount.is_zero());
-        bank_send_impl(&mut self.batch, to, &[amount])
+        if let Some(sender) = self.sends.iter_mut().find(|sender| sender.receiver == to) {
+            sender.send(amount)
+        } else {
+            let mut sender = LazySenderStub::new(to);
+            sender.send(amount).map(|()| self.sends.push(sender))
+        }
     }
 }
 
@@ -267,7 +275,10 @@ where
     View: BankAccountView,
 {
     fn from(stub: BankStub<View>) -> Self {
-        stub.batch
+        stub.sends
+            .into_iter()
+            .map(Into::into)
+            .fold(Batch::default(), Batch::merge)
     }
 }
 
@@ -290,6 +301,7 @@ where
     }
 }
 
+#[cfg(feature = "testing")]
 fn bank_send_impl<C>(batch: &mut Batch, to: Addr, amount: &[Coin<C>])
 where
     C: CurrencyDef,
@@ -311,6 +323,9 @@ fn bank_send_cosmwasm(batch: &mut Batch, to: Addr, amount: Vec<CwCoin>) {
     });
 }
 
+/// Sender that schedules a single message to a fixed recipient
+///
+/// The amounts in the same currency are summed up into a single coin.
 pub struct LazySenderStub {
     receiver: Addr,
     amounts: Vec<CwCoin>,
@@ -329,13 +344,26 @@ impl FixedAddressSender for LazySenderStub
 where
     Self: Into<Batch>,
 {
-    fn send<C>(&mut self, amount: Coin<C>)
+    fn send<C>(&mut self, amount: Coin<C>) -> Result<()>
     where
         C: CurrencyDef,
     {
         if !amount.is_zero() {
-            self.amounts.push(to_cosmwasm_impl(amount));
+            let amount = to_cosmwasm_impl(amount);
+            if let Some(same_currency) = self
+                .amounts
+                .iter_mut()
+                .find(|coin| coin.denom == amount.denom)
+            {
+                same_currency.amount = same_currency
+                    .amount
+                    .checked_add(amount.amount)
+                    .map_err(|error| Error::SendAmountOverflow(amount.denom, error))?;
+            } else {
+                self.amounts.push(amount);
+            }
         }
+        Ok(())
     }
 }
 
@@ -425,14 +453,17 @@ mod test {
         test::coin::Expect,
     };
     use sdk::{
-        cosmwasm_std::{coin as cw_coin, Addr, Coin as CwCoin, QuerierWrapper},
+        cosmwasm_std::{coin as cw_coin, Addr, BankMsg, Coin as CwCoin, QuerierWrapper},
         cw_multi_test::BasicApp,
         testing,
     };
 
-    use crate::{coin_legacy, error::Error};
+    use crate::{batch::Batch, coin_legacy, error::Error};
 
-    use super::{may_received, BankAccountView as _, BankView, ReduceResults as _};
+    use super::{
+        may_received, BankAccount as _, BankAccountView as _, BankStub, BankView,
+        FixedAddressSender as _, LazySenderStub, ReduceResults as _,
+    };
 
     type TheCurrency = SubGroupTestC10;
     type ExtraCurrency = SuperGroupTestC1;
@@ -729,4 +760,111 @@ mod test {
         let msgs = super::bank_send_all::<G>(&from, to, querier).unwrap();
         assert_eq!(exp_coins_nb, msgs.len());
     }
+
+    #[test]
+    fn send_same_currency_coalesced() {
+        let owner = testing::user(USER);
+        let receiver = testing::user("receiver");
+        let app = BasicApp::default();
+        let mut account = BankStub::new(BankView::account(&owner, app.wrap()));
+
+        account
+            .send(Coin::<TheCurrency>::new(AMOUNT), receiver.clone())
+            .unwrap();
+        account
+            .send(Coin::<TheCurrency>::new(AMOUNT + 1), receiver.clone())
+            .unwrap();
+
+        assert_eq!(
+            bank_sends([(
+                receiver,
+                vec![cw_coin(AMOUNT + AMOUNT + 1, TheCurrency::bank())]
+            )]),
+            account.into()
+        );
+    }
+
+    #[test]
+    fn send_mixed_currencies() {
+        let receiver1 = testing::user("receiver1");
+        let receiver2 = testing::user("receiver2");
+        let owner = testing::user(USER);
+        let app = BasicApp::default();
+        let mut account = BankStub::new(BankView::account(&owner, app.wrap()));
+
+        account
+            .send(Coin::<TheCurrency>::new(AMOUNT), receiver1.clone())
+            .unwrap();
+        account
+            .send(Coin::<ExtraCurrency>::new(5), receiver1.clone())
+            .unwrap();
+        account
+            .send(Coin::<ExtraCurrency>::new(7), receiver2.clone())
+            .unwrap();
+        account
+            .send(Coin::<TheCurrency>::new(3), receiver1.clone())
+            .unwrap();
+        account
+            .send(Coin::<ExtraCurrency>::new(1), receiver1.clone())
+            .unwrap();
+
+        assert_eq!(
+            bank_sends([
+                (
+                    receiver1,
+                    vec![
+                        cw_coin(AMOUNT + 3, TheCurrency::bank()),
+                        cw_coin(6, ExtraCurrency::bank())
+                    ]
+                ),
+                (receiver2, vec![cw_coin(7, ExtraCurrency::bank())])
+            ]),
+            account.into()
+        );
+    }
+
+    #[test]
+    fn lazy_send_mixed_currencies() {
+        let receiver = testing::user("receiver");
+        let mut sender = LazySenderStub::new(receiver.clone());
+
+        sender.send(Coin::<ExtraCurrency>::new(AMOUNT)).unwrap();
+        sender.send(Coin::<TheCurrency>::new(AMOUNT)).unwrap();
+        sender.send(Coin::<ExtraCurrency>::new(2)).unwrap();
+        sender.send(Coin::<TheCurrency>::default()).unwrap();
+
+        assert_eq!(
+            bank_sends([(
+                receiver,
+                vec![
+                    cw_coin(AMOUNT + 2, ExtraCurrency::bank()),
+                    cw_coin(AMOUNT, TheCurrency::bank())
+                ]
+            )]),
+            sender.into()
+        );
+    }
+
+    #[test]
+    fn lazy_send_overflow() {
+        let mut sender = LazySenderStub::new(testing::user("receiver"));
+
+        sender.send(Coin::<TheCurrency>::new(Amount::MAX)).unwrap();
+        assert!(matches!(
+            sender.send(Coin::<TheCurrency>::new(1)),
+            Err(Error::SendAmountOverflow(..))
+        ));
+    }
+
+    fn bank_sends<const N: usize>(sends: [(Addr, Vec<CwCoin>); N]) -> Batch {
+        sends
+            .into_iter()
+            .fold(Batch::default(), |mut batch, (to, amount)| {
+                batch.schedule_execute_no_reply(BankMsg::Send {
+                    to_address: to.into(),
+                    amount,
+                });
+                batch
+            })
+    }
 }
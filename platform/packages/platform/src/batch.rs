@@ -1,12 +1,13 @@
 use std::vec;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use currency::CurrencyDef;
 use finance::coin::Coin;
 use sdk::{
     cosmwasm_ext::{CosmosMsg, SubMsg},
     cosmwasm_std::{to_json_binary, Addr, Coin as CoinCw, WasmMsg},
+    schemars::{self, JsonSchema},
 };
 
 pub use crate::emit::{Emit, Emitter};
@@ -14,6 +15,54 @@ use crate::{coin_legacy::to_cosmwasm_impl, contract::Code, error::Error, result:
 
 pub type ReplyId = u64;
 
+/// Build a wasm execute sub-message that replies on success with `reply_id`
+///
+/// The message is serialized and the funds, if any, are attached.
+fn wasm_execute_with_reply<M, C>(
+    addr: Addr,
+    msg: &M,
+    funds: Option<Coin<C>>,
+    reply_id: ReplyId,
+) -> Result<SubMsg>
+where
+    M: Serialize + ?Sized,
+    C: CurrencyDef,
+{
+    Batch::wasm_exec_msg(addr, msg, funds)
+        .map(|wasm_msg| SubMsg::reply_on_success(wasm_msg, reply_id))
+}
+
+/// The kind of a scheduled message
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum MsgKind {
+    BankSend,
+    WasmExecute,
+    WasmInstantiate,
+    WasmMigrate,
+    Stargate,
+    /// A message to the remote chain, for example, a transaction of the Dex account
+    InterChain,
+    Other,
+}
+
+impl From<&CosmosMsg> for MsgKind {
+    fn from(msg: &CosmosMsg) -> Self {
+        use sdk::cosmwasm_std::BankMsg;
+
+        match msg {
+            CosmosMsg::Bank(BankMsg::Send { .. }) => Self::BankSend,
+            CosmosMsg::Wasm(WasmMsg::Execute { .. }) => Self::WasmExecute,
+            CosmosMsg::Wasm(WasmMsg::Instantiate { .. }) => Self::WasmInstantiate,
+            CosmosMsg::Wasm(WasmMsg::Migrate { .. }) => Self::WasmMigrate,
+            #[expect(deprecated)]
+            CosmosMsg::Stargate { .. } => Self::Stargate,
+            CosmosMsg::Custom(_) => Self::InterChain,
+            _ => Self::Other,
+        }
+    }
+}
+
 #[derive(Default)]
 #[cfg_attr(
     any(debug_assertions, test, feature = "testing"),
@@ -38,6 +87,13 @@ impl Batch {
         self.schedule_reply_on_success(msg, reply_id)
     }
 
+    pub fn schedule_execute_reply_on_error<M>(&mut self, msg: M, reply_id: ReplyId)
+    where
+        M: Into<CosmosMsg>,
+    {
+        self.schedule_reply_on_error(msg, reply_id)
+    }
+
     pub fn schedule_execute_wasm_no_reply_no_funds<M>(&mut self, addr: Addr, msg: &M) -> Result<()>
     where
         M: Serialize + ?Sized,
@@ -83,8 +139,8 @@ impl Batch {
         M: Serialize + ?Sized,
         C: CurrencyDef,
     {
-        Self::wasm_exec_msg(addr, msg, funds)
-            .map(|wasm_msg| self.schedule_reply_on_success(wasm_msg, reply_id))
+        wasm_execute_with_reply(addr, msg, funds, reply_id)
+            .map(|sub_msg| self.schedule_msg(sub_msg))
     }
 
     pub fn schedule_execute_wasm_reply_always_no_funds<M>(
@@ -155,6 +211,33 @@ impl Batch {
         self.msgs.is_empty()
     }
 
+    /// Provide the kinds of the scheduled messages in the order of scheduling
+    pub fn message_kinds(&self) -> Vec<MsgKind> {
+        self.msgs
+            .iter()
+            .map(|msg| MsgKind::from(&msg.msg))
+            .collect()
+    }
+
+    /// Provide the targets of the scheduled messages in the order of scheduling
+    ///
+    /// A target is the receiver of a bank transfer, or the contract a message is sent to.
+    /// The rest of the messages do not have one.
+    pub fn message_targets(&self) -> Vec<Option<String>> {
+        use sdk::cosmwasm_std::BankMsg;
+
+        self.msgs
+            .iter()
+            .map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, .. }) => Some(to_address.clone()),
+                CosmosMsg::Wasm(
+                    WasmMsg::Execute { contract_addr, .. } | WasmMsg::Migrate { contract_addr, .. },
+                ) => Some(contract_addr.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn wasm_exec_msg_no_funds<M>(addr: Addr, msg: &M) -> Result<WasmMsg>
     where
         M: Serialize + ?Sized,
@@ -269,9 +352,48 @@ impl IntoIterator for Batch {
 
 #[cfg(test)]
 mod test {
-    use sdk::{cosmwasm_ext::CosmosMsg, cosmwasm_std::WasmMsg};
+    use currency::test::SuperGroupTestC1;
+    use finance::coin::Coin;
+    use sdk::{
+        cosmwasm_ext::{CosmosMsg, InterChainMsg},
+        cosmwasm_std::{to_json_binary, Addr, BankMsg, Binary, ReplyOn, WasmMsg},
+    };
+
+    use crate::{coin_legacy::to_cosmwasm_impl, contract::Code};
+
+    use super::{Batch, MsgKind, ReplyId};
+
+    #[test]
+    fn wasm_execute_with_reply() {
+        const REPLY_ID: ReplyId = 13;
+        let msg = "msg";
+        let funds = Coin::<SuperGroupTestC1>::new(10);
+
+        let sub_msg =
+            super::wasm_execute_with_reply(Addr::unchecked("contract"), msg, Some(funds), REPLY_ID)
+                .unwrap();
+
+        assert_eq!(REPLY_ID, sub_msg.id);
+        assert_eq!(ReplyOn::Success, sub_msg.reply_on);
+        assert_eq!(
+            CosmosMsg::from(WasmMsg::Execute {
+                contract_addr: "contract".into(),
+                msg: to_json_binary(msg).unwrap(),
+                funds: vec![to_cosmwasm_impl(funds)],
+            }),
+            sub_msg.msg
+        );
 
-    use super::Batch;
+        let mut b = Batch::default();
+        b.schedule_execute_wasm_reply_on_success(
+            Addr::unchecked("contract"),
+            msg,
+            Some(funds),
+            REPLY_ID,
+        )
+        .unwrap();
+        assert_eq!(vec![sub_msg], b.into_iter().collect::<Vec<_>>());
+    }
 
     #[test]
     fn no_events() {
@@ -302,4 +424,77 @@ mod test {
         assert_eq!(2, b.len());
         assert!(!b.is_empty());
     }
+
+    #[test]
+    fn message_kinds() {
+        let mut b = Batch::default();
+        assert_eq!(Vec::<MsgKind>::new(), b.message_kinds());
+
+        b.schedule_execute_no_reply(BankMsg::Send {
+            to_address: "receiver".into(),
+            amount: vec![],
+        });
+        b.schedule_execute_wasm_no_reply(
+            Addr::unchecked("contract"),
+            "msg",
+            Some(Coin::<SuperGroupTestC1>::new(10)),
+        )
+        .unwrap();
+        b.schedule_instantiate_wasm_reply_on_success(
+            Code::unchecked(1),
+            "init",
+            None,
+            "label".into(),
+            None,
+            1,
+        )
+        .unwrap();
+        b.schedule_migrate_wasm_no_reply(
+            Addr::unchecked("contract"),
+            "migrate",
+            Code::unchecked(2),
+        )
+        .unwrap();
+        #[expect(deprecated)]
+        b.schedule_execute_reply_on_error(
+            CosmosMsg::Stargate {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+                value: Binary::default(),
+            },
+            2,
+        );
+        b.schedule_execute_no_reply(CosmosMsg::Custom(
+            InterChainMsg::RegisterInterchainAccount {
+                connection_id: "connection-0".into(),
+                interchain_account_id: "0".into(),
+                register_fee: None,
+            },
+        ));
+        b.schedule_execute_no_reply(BankMsg::Burn { amount: vec![] });
+
+        assert_eq!(
+            vec![
+                MsgKind::BankSend,
+                MsgKind::WasmExecute,
+                MsgKind::WasmInstantiate,
+                MsgKind::WasmMigrate,
+                MsgKind::Stargate,
+                MsgKind::InterChain,
+                MsgKind::Other,
+            ],
+            b.message_kinds()
+        );
+        assert_eq!(
+            vec![
+                Some("receiver".into()),
+                Some("contract".into()),
+                None,
+                Some("contract".into()),
+                None,
+                None,
+                None,
+            ],
+            b.message_targets()
+        );
+    }
 }
@@ -1,10 +1,10 @@
 use std::{marker::PhantomData, result::Result as StdResult};
 
 use currency::{
-    AnyVisitor, AnyVisitorResult, BankSymbols, Currency, CurrencyDTO, CurrencyDef, CurrencyVisit,
-    Group, GroupVisit, MemberOf, SingleVisitor, Symbol,
+    error::Error as CurrencyError, never, AnyVisitor, AnyVisitorResult, BankSymbols, Currency,
+    CurrencyDTO, CurrencyDef, CurrencyVisit, Group, GroupVisit, MemberOf, SingleVisitor, Symbol,
 };
-use finance::coin::{Amount, Coin, CoinDTO, WithCoin, WithCoinResult};
+use finance::coin::{Amount, Coin, CoinDTO, IntoDTO, WithCoin, WithCoinResult};
 use sdk::cosmwasm_std::Coin as CosmWasmCoin;
 
 use crate::{error::Error, result::Result};
@@ -26,6 +26,21 @@ where
     BankSymbols::<CDef::Group>::visit::<CDef, _>(&coin.denom, CoinTransformer(coin, PhantomData))
 }
 
+/// Transform CW coin to a Nolus coin of the specified group
+///
+/// The CW denom is resolved against the bank symbols of the group members.
+/// An error is returned if it does not designate any of them.
+pub fn from_cosmwasm_checked<G>(coin: &CosmWasmCoin) -> Result<CoinDTO<G>>
+where
+    G: Group,
+{
+    from_cosmwasm_seek_any(coin, IntoDTO::<G>::new())
+        .map(never::safe_unwrap)
+        .map_err(|_into_dto| {
+            CurrencyError::not_in_currency_group::<_, BankSymbols<G>, G>(coin.denom.clone()).into()
+        })
+}
+
 /// Transform CW coin to Nolus coin and then return the [WithCoin] result
 ///
 /// If seeking for the corresponding Nolus coin is not successfull an `Err(v)` is returned.
@@ -165,10 +180,10 @@ where
 #[cfg(test)]
 mod test {
     use currency::{
-        test::{SuperGroup, SuperGroupTestC1, SuperGroupTestC2},
+        test::{SubGroup, SubGroupTestC6, SuperGroup, SuperGroupTestC1, SuperGroupTestC2},
         BankSymbols, CurrencyDef,
     };
-    use finance::test::coin;
+    use finance::{coin::CoinDTO, test::coin};
     use sdk::cosmwasm_std::Coin as CosmWasmCoin;
 
     use crate::error::Error;
@@ -266,6 +281,44 @@ mod test {
         );
     }
 
+    #[test]
+    fn from_cosmwasm_checked() {
+        let amount = 42;
+        assert_eq!(
+            Ok(CoinDTO::<SubGroup>::from(Coin::<SubGroupTestC6>::new(
+                amount
+            ))),
+            super::from_cosmwasm_checked::<SubGroup>(&CosmWasmCoin::new(
+                amount,
+                SubGroupTestC6::bank()
+            ))
+        );
+        assert_eq!(
+            Ok(CoinDTO::<SuperGroup>::from(Coin::<SubGroupTestC6>::new(
+                amount
+            ))),
+            super::from_cosmwasm_checked::<SuperGroup>(&CosmWasmCoin::new(
+                amount,
+                SubGroupTestC6::bank()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_cosmwasm_checked_not_in_group() {
+        assert_eq!(
+            Err(Error::Currency(
+                currency::error::Error::not_in_currency_group::<_, BankSymbols<SubGroup>, SubGroup>(
+                    SuperGroupTestC1::bank()
+                )
+            )),
+            super::from_cosmwasm_checked::<SubGroup>(&CosmWasmCoin::new(
+                42u8,
+                SuperGroupTestC1::bank()
+            ))
+        );
+    }
+
     #[test]
     fn to_cosmwasm() {
         let amount = 326;
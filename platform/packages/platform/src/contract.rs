@@ -43,6 +43,13 @@ pub fn validate_addr(querier: QuerierWrapper<'_>, contract_address: &Addr) -> Re
     query_info(querier, contract_address).map(mem::drop)
 }
 
+/// Query the admin of a contract, if any
+///
+/// The admin is the address permitted to migrate the contract.
+pub fn admin(querier: QuerierWrapper<'_>, contract_address: &Addr) -> Result<Option<Addr>> {
+    query_info(querier, contract_address).map(|info| info.admin)
+}
+
 pub fn validate_code_id(
     querier: QuerierWrapper<'_>,
     contract_address: &Addr,
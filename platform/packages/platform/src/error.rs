@@ -5,7 +5,7 @@ use thiserror::Error;
 use currency::{CurrencyDef, SymbolStatic};
 use sdk::{
     cosmos_sdk_proto::prost::DecodeError,
-    cosmwasm_std::{Addr, Api, StdError},
+    cosmwasm_std::{Addr, Api, OverflowError, StdError},
 };
 
 use crate::contract::CodeId;
@@ -49,6 +49,11 @@ pub enum Error {
     #[error("[Platform] [Std] An error occured while querying all balances: {0}")]
     CosmWasmQueryAllBalances(StdError),
 
+    #[error(
+        "[Platform] [Std] An overflow occured while summing up the amounts of {0} to send: {1}"
+    )]
+    SendAmountOverflow(String, OverflowError),
+
     #[error("[Platform] [Std] An error occured on data serialization: {0}")]
     Serialization(StdError),
 
@@ -22,6 +22,10 @@ impl Response {
         }
     }
 
+    pub fn messages(&self) -> &Batch {
+        &self.messages
+    }
+
     pub fn merge_with<R>(mut self, other: R) -> Self
     where
         R: Into<Self>,
@@ -31,6 +35,19 @@ impl Response {
         self.events.append(&mut other.events);
         self
     }
+
+    /// Merge the responses in the order they are provided
+    ///
+    /// The messages, and the events, of a response go after those of the preceding ones.
+    pub fn merge_all<I, R>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: Into<Self>,
+    {
+        responses
+            .into_iter()
+            .fold(Self::default(), |merged, next| merged.merge_with(next))
+    }
 }
 
 impl From<Batch> for Response {
@@ -44,3 +61,64 @@ impl From<Emitter> for Response {
         Self::messages_with_events(Default::default(), events)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use sdk::{
+        cosmwasm_ext::{CosmosMsg, Response as CwResponse},
+        cosmwasm_std::{Event, WasmMsg},
+    };
+
+    use crate::{
+        batch::{Batch, Emitter},
+        response,
+    };
+
+    use super::Response;
+
+    #[test]
+    fn merge_all_none() {
+        assert_eq!(
+            Response::default(),
+            Response::merge_all(Vec::<Response>::new())
+        );
+    }
+
+    #[test]
+    fn merge_all_order() {
+        let merged: CwResponse = response::response_only_messages(Response::merge_all([
+            response_of("first"),
+            response_of("second"),
+            Emitter::of_type("third").into(),
+        ]));
+
+        assert_eq!(
+            vec![&clear_admin("first"), &clear_admin("second")],
+            merged
+                .messages
+                .iter()
+                .map(|sub_msg| &sub_msg.msg)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                Event::new("first"),
+                Event::new("second"),
+                Event::new("third")
+            ],
+            merged.events
+        );
+    }
+
+    fn response_of(id: &str) -> Response {
+        let mut messages = Batch::default();
+        messages.schedule_execute_no_reply(clear_admin(id));
+        Response::messages_with_events(messages, Emitter::of_type(id))
+    }
+
+    fn clear_admin(contract_addr: &str) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::ClearAdmin {
+            contract_addr: contract_addr.into(),
+        })
+    }
+}
@@ -94,6 +94,20 @@ where
         self.add_internal(subscriber, as_seconds(time))
     }
 
+    /// Remove the scheduled alarm of the subscriber, if any
+    ///
+    /// Return whether an alarm has been removed. Alarms already out for delivery are not affected.
+    pub fn cancel(&mut self, subscriber: Addr) -> Result<bool, AlarmError> {
+        self.alarms
+            .may_load(self.storage.deref(), subscriber.clone())?
+            .map_or(Ok(false), |_time| {
+                self.alarms
+                    .remove(self.storage.deref_mut(), subscriber)
+                    .map(|()| true)
+                    .map_err(Into::into)
+            })
+    }
+
     pub fn ensure_no_in_delivery(&mut self) -> Result<&mut Self, AlarmError> {
         self.in_delivery
             .is_empty(self.storage.deref_mut())?
@@ -215,4 +229,45 @@ pub mod tests {
 
         assert_eq!(query_alarms(&alarms, t3_sec), vec![addr1, addr2, addr3]);
     }
+
+    #[test]
+    fn test_cancel() {
+        let mut storage = MockStorage::new();
+        let mut alarms = alarms(&mut storage);
+
+        let t1 = Timestamp::from_seconds(1);
+        let t2 = Timestamp::from_seconds(2);
+        let addr1 = Addr::unchecked("addr1");
+        let addr2 = Addr::unchecked("addr2");
+        let addr3 = Addr::unchecked("addr3");
+
+        alarms.add(addr1.clone(), t1).unwrap();
+        alarms.add(addr2.clone(), t2).unwrap();
+
+        assert_eq!(Ok(true), alarms.cancel(addr1.clone()));
+        assert_eq!(query_alarms(&alarms, 10), vec![addr2.clone()]);
+
+        assert_eq!(Ok(false), alarms.cancel(addr1.clone()));
+        assert_eq!(Ok(false), alarms.cancel(addr3));
+        assert_eq!(query_alarms(&alarms, 10), vec![addr2.clone()]);
+
+        alarms.add(addr1.clone(), t2).unwrap();
+        assert_eq!(query_alarms(&alarms, 10), vec![addr1, addr2]);
+    }
+
+    #[test]
+    fn test_cancel_fired() {
+        let mut storage = MockStorage::new();
+        let mut alarms = alarms(&mut storage);
+
+        let t1 = Timestamp::from_seconds(1);
+        let addr1 = Addr::unchecked("addr1");
+
+        alarms.add(addr1.clone(), t1).unwrap();
+        alarms.out_for_delivery(addr1.clone()).unwrap();
+
+        assert_eq!(Ok(false), alarms.cancel(addr1));
+        assert!(query_alarms(&alarms, 10).is_empty());
+        assert_eq!(Ok(()), alarms.last_delivered());
+    }
 }
@@ -2,7 +2,11 @@ use thiserror::Error;
 
 use sdk::cosmwasm_std::StdError;
 
-use crate::{protocol::Protocol, release::Id as ReleaseId, software::Package};
+use crate::{
+    protocol::Protocol,
+    release::Id as ReleaseId,
+    software::{Package, SemVer, VersionSegment},
+};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum Error {
@@ -22,12 +26,23 @@ pub enum Error {
     #[error("[Versioning] The package does not increase monotonically! The current package is \"{0}\", the new package is \"{1}\".")]
     OlderPackageCode(String, String),
 
+    #[error("[Versioning] The package is older than the minimum supported version! The new package is \"{0}\", the minimum version is \"{1}\".")]
+    PackageCodeBelowFloor(String, String),
+
     #[error("[Versioning] The package storage versions do not match! The current package is \"{0}\", the new package is \"{1}\".")]
     PackageStorageVersionMismatch(String, String),
 
     #[error("[Versioning] The new package storage version is not adjacent to the current one! The current package is \"{0}\", the new package is \"{1}\".")]
     PackageStorageVersionNotAdjacent(String, String),
 
+    #[error("[Versioning] The new package storage version is older than the current one! The current package is \"{0}\", the new package is \"{1}\".")]
+    OlderPackageStorage(String, String),
+
+    #[error(
+        "[Versioning] No storage migration provided from version {1}! The new package is \"{0}\"."
+    )]
+    MissingStorageMigration(String, VersionSegment),
+
     #[error("[Versioning] The protocols do not match! The current package's protocol is \"{0}\", the new package's one is \"{1}\".")]
     ProtocolMismatch(String, String),
 }
@@ -53,6 +68,10 @@ impl Error {
         Self::OlderPackageCode(current.to_string(), new.to_string())
     }
 
+    pub(crate) fn package_code_below_floor(new: &Package, floor: SemVer) -> Self {
+        Self::PackageCodeBelowFloor(new.to_string(), floor.to_string())
+    }
+
     pub(crate) fn package_storage_versions_mismatch(current: &Package, new: &Package) -> Self {
         Self::PackageStorageVersionMismatch(current.to_string(), new.to_string())
     }
@@ -61,6 +80,14 @@ impl Error {
         Self::PackageStorageVersionNotAdjacent(current.to_string(), new.to_string())
     }
 
+    pub(crate) fn older_package_storage(current: &Package, new: &Package) -> Self {
+        Self::OlderPackageStorage(current.to_string(), new.to_string())
+    }
+
+    pub(crate) fn missing_storage_migration(new: &Package, from: VersionSegment) -> Self {
+        Self::MissingStorageMigration(new.to_string(), from)
+    }
+
     pub(crate) fn protocol_mismatch(current: &Protocol, new: &Protocol) -> Self {
         Self::PackageNamesMismatch(current.to_string(), new.to_string())
     }
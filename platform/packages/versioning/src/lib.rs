@@ -1,5 +1,3 @@
-#[cfg(feature = "schema")]
-pub use crate::software::SemVer;
 pub use crate::{
     error::Error,
     migration::MigrationMessage,
@@ -8,7 +6,7 @@ pub use crate::{
         query, Id as ReleaseId, PlatformPackageRelease, ProtocolPackageRelease,
         ProtocolPackageReleaseId, UpdatablePackage,
     },
-    software::{PackageRelease as SoftwarePackageRelease, VersionSegment},
+    software::{PackageRelease as SoftwarePackageRelease, SemVer, VersionError, VersionSegment},
 };
 
 mod error;
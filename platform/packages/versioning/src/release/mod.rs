@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use sdk::schemars::{self, JsonSchema};
+use sdk::{
+    cosmwasm_std::Storage,
+    schemars::{self, JsonSchema},
+};
 
-use crate::{Error, ProtocolRelease, SoftwarePackageRelease};
+use crate::{Error, ProtocolRelease, SoftwarePackageRelease, VersionSegment};
 
 pub use self::id::Id;
 
@@ -24,10 +27,29 @@ where
         to: &Self,
         to_release: &Self::ReleaseId,
     ) -> Result<(), Error>;
+
+    /// Update the software and migrate the storage through all versions in between
+    ///
+    /// Each step is a storage version paired with the migration from it to the next one.
+    /// The steps must be ordered. Those before the current storage version are skipped,
+    /// and the remaining ones are applied one after another until the target storage
+    /// version is reached. If the storage is already at the target version, nothing is
+    /// migrated.
+    fn update_software_and_storage_stepwise<MigrateStorage, Err>(
+        &self,
+        to: &Self,
+        to_release: &Self::ReleaseId,
+        steps: &[(VersionSegment, MigrateStorage)],
+        storage: &mut dyn Storage,
+    ) -> Result<(), Err>
+    where
+        MigrateStorage: Fn(&mut dyn Storage) -> Result<(), Err>,
+        Err: From<Error>;
 }
 
 pub type PlatformPackageRelease = SoftwarePackageRelease;
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct ProtocolPackageRelease {
     software: SoftwarePackageRelease,
@@ -77,4 +99,28 @@ impl UpdatablePackage for ProtocolPackageRelease {
                     .update_software_and_storage(&to.software, &to_release.software)
             })
     }
+
+    fn update_software_and_storage_stepwise<MigrateStorage, Err>(
+        &self,
+        to: &Self,
+        to_release: &Self::ReleaseId,
+        steps: &[(VersionSegment, MigrateStorage)],
+        storage: &mut dyn Storage,
+    ) -> Result<(), Err>
+    where
+        MigrateStorage: Fn(&mut dyn Storage) -> Result<(), Err>,
+        Err: From<Error>,
+    {
+        self.protocol
+            .check_update_allowed(&to.protocol, &to_release.protocol)
+            .map_err(Into::into)
+            .and_then(|_| {
+                self.software.update_software_and_storage_stepwise(
+                    &to.software,
+                    &to_release.software,
+                    steps,
+                    storage,
+                )
+            })
+    }
 }
@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use sdk::cw_storage_plus::Item;
+use sdk::{cosmwasm_std::Storage, cw_storage_plus::Item};
 
 #[cfg(feature = "schema")]
 use sdk::schemars::{self, JsonSchema};
 
 pub use self::{
     package::Package,
-    version::{SemVer, VersionSegment},
+    version::{SemVer, VersionError, VersionSegment},
 };
 
 use crate::{
@@ -18,6 +18,15 @@ use crate::{
 mod package;
 mod version;
 
+/// The lowest software version a package may ever be migrated to
+///
+/// It is pinned at build time through the optional "SOFTWARE_VERSION_FLOOR"
+/// environment variable, and applies irrespective of the current version.
+const VERSION_FLOOR: Option<SemVer> = match option_env!("SOFTWARE_VERSION_FLOOR") {
+    Some(floor) => Some(SemVer::parse(floor)),
+    None => None,
+};
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[cfg_attr(test, derive(Debug))]
@@ -86,7 +95,8 @@ impl PackageRelease {
     where
         F: FnOnce(&Self, &Package) -> Result<(), Error>,
     {
-        self.check_name_match(&to.code)
+        Self::check_version_floor(&to.code, VERSION_FLOOR)
+            .and_then(|()| self.check_name_match(&to.code))
             .and_then(|()| self.check_code_same_or_newer(&to.code))
             .and_then(|()| storage_check(self, &to.code))
     }
@@ -102,6 +112,15 @@ impl PackageRelease {
         }
     }
 
+    fn check_version_floor(target: &Package, floor: Option<SemVer>) -> Result<(), Error> {
+        match floor {
+            Some(floor) if target.version() < floor => {
+                Err(Error::package_code_below_floor(target, floor))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn check_name_match(&self, other: &Package) -> Result<(), Error> {
         if self.code.same_name(other) {
             Ok(())
@@ -125,6 +144,31 @@ impl PackageRelease {
         }
     }
 
+    fn check_storage_same_or_newer(&self, other: &Package) -> Result<(), Error> {
+        if self.code.storage() <= other.storage() {
+            Ok(())
+        } else {
+            Err(Error::older_package_storage(&self.code, other))
+        }
+    }
+
+    fn storage_migrations<'steps, MigrateStorage>(
+        &self,
+        to: &Package,
+        steps: &'steps [(VersionSegment, MigrateStorage)],
+    ) -> Result<Vec<&'steps MigrateStorage>, Error> {
+        let mut pending = steps
+            .iter()
+            .skip_while(|&&(version, _)| version < self.code.storage());
+
+        (self.code.storage()..to.storage())
+            .map(|from| match pending.next() {
+                Some((version, migrate)) if *version == from => Ok(migrate),
+                _ => Err(Error::missing_storage_migration(to, from)),
+            })
+            .collect()
+    }
+
     fn check_storage_adjacent(&self, next: &Package) -> Result<(), Error> {
         if self.code.next_storage(next) {
             Ok(())
@@ -152,10 +196,36 @@ impl UpdatablePackage for PackageRelease {
         to.check_release_match(to_release)
             .and_then(|()| self.check_software_update_allowed(to, Self::check_storage_adjacent))
     }
+
+    fn update_software_and_storage_stepwise<MigrateStorage, Err>(
+        &self,
+        to: &Self,
+        to_release: &Self::ReleaseId,
+        steps: &[(VersionSegment, MigrateStorage)],
+        storage: &mut dyn Storage,
+    ) -> Result<(), Err>
+    where
+        MigrateStorage: Fn(&mut dyn Storage) -> Result<(), Err>,
+        Err: From<Error>,
+    {
+        to.check_release_match(to_release)
+            .and_then(|()| {
+                self.check_software_update_allowed(to, Self::check_storage_same_or_newer)
+            })
+            .and_then(|()| self.storage_migrations(&to.code, steps))
+            .map_err(Into::into)
+            .and_then(|migrations| {
+                migrations
+                    .into_iter()
+                    .try_for_each(|migrate| migrate(storage))
+            })
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use sdk::cosmwasm_std::{testing::MockStorage, Storage};
+
     use crate::{
         release::{Id, UpdatablePackage},
         Error,
@@ -247,6 +317,37 @@ mod test {
         ));
     }
 
+    #[test]
+    fn version_floor() {
+        let floor = CURRENT_VERSION;
+        let check = |version| {
+            PackageRelease::check_version_floor(
+                &Package::new(CURRENT_NAME, version, CURRENT_STORAGE),
+                Some(floor),
+            )
+        };
+
+        assert_eq!(Ok(()), check(CURRENT_VERSION));
+        assert_eq!(Ok(()), check(NEWER_VERSION));
+        assert_eq!(Ok(()), check(SemVer::parse("1.0.0")));
+        assert!(matches!(
+            check(SemVer::parse("0.3.3")),
+            Err(Error::PackageCodeBelowFloor(_, _))
+        ));
+        assert!(matches!(
+            check(SemVer::parse("0.2.9")),
+            Err(Error::PackageCodeBelowFloor(_, _))
+        ));
+
+        assert_eq!(
+            Ok(()),
+            PackageRelease::check_version_floor(
+                &Package::new(CURRENT_NAME, SemVer::parse("0.0.1"), CURRENT_STORAGE),
+                None,
+            )
+        );
+    }
+
     #[test]
     fn prod_software_and_storage() {
         let current_code = Package::new(CURRENT_NAME, CURRENT_VERSION, CURRENT_STORAGE);
@@ -308,4 +409,84 @@ mod test {
                 .update_software_and_storage(&next_release, &prod2_id())
         );
     }
+
+    #[test]
+    fn prod_software_and_storage_stepwise() {
+        const STORAGE_VERSION_KEY: &[u8] = b"storage_version";
+
+        type MigrateStorage = fn(&mut dyn Storage) -> Result<(), Error>;
+
+        fn migrate(storage: &mut dyn Storage, from: u8) -> Result<(), Error> {
+            assert_eq!(
+                storage.get(STORAGE_VERSION_KEY),
+                (from != 0).then(|| vec![from])
+            );
+            storage.set(STORAGE_VERSION_KEY, &[from + 1]);
+            Ok(())
+        }
+
+        let steps: [(VersionSegment, MigrateStorage); 2] = [
+            (0, |storage| migrate(storage, 0)),
+            (1, |storage| migrate(storage, 1)),
+        ];
+
+        let release = |storage| {
+            PackageRelease::instance(
+                prod2_id(),
+                Package::new(CURRENT_NAME, NEWER_VERSION, storage),
+            )
+        };
+        let current_release =
+            PackageRelease::instance(prod1_id(), Package::new(CURRENT_NAME, CURRENT_VERSION, 0));
+
+        let mut storage = MockStorage::new();
+        assert_eq!(
+            Ok(()),
+            current_release.update_software_and_storage_stepwise(
+                &release(2),
+                &prod2_id(),
+                &steps,
+                &mut storage
+            )
+        );
+        assert_eq!(Some(vec![2]), storage.get(STORAGE_VERSION_KEY));
+
+        let mut storage = MockStorage::new();
+        assert_eq!(
+            Ok(()),
+            release(2).update_software_and_storage_stepwise(
+                &release(2),
+                &prod2_id(),
+                &steps,
+                &mut storage
+            )
+        );
+        assert_eq!(None, storage.get(STORAGE_VERSION_KEY));
+
+        assert_eq!(
+            Err(Error::MissingStorageMigration(
+                release(3).code.to_string(),
+                2
+            )),
+            current_release.update_software_and_storage_stepwise(
+                &release(3),
+                &prod2_id(),
+                &steps,
+                &mut MockStorage::new()
+            )
+        );
+
+        assert!(matches!(
+            release(2).update_software_and_storage_stepwise(
+                &PackageRelease::instance(
+                    prod2_id(),
+                    Package::new(CURRENT_NAME, SemVer::parse("0.3.6"), 1),
+                ),
+                &prod2_id(),
+                &steps,
+                &mut MockStorage::new()
+            ),
+            Err(Error::OlderPackageStorage(_, _))
+        ));
+    }
 }
@@ -49,6 +49,10 @@ impl Package {
         self.version
     }
 
+    pub const fn storage(&self) -> VersionSegment {
+        self.storage
+    }
+
     pub fn same_name(&self, other: &Self) -> bool {
         self.name == other.name
     }
@@ -1,4 +1,8 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    cmp::Ordering,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -7,12 +11,28 @@ use sdk::schemars::{self, JsonSchema};
 
 pub type VersionSegment = u16;
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct SemVer {
     major: VersionSegment,
     minor: VersionSegment,
     patch: VersionSegment,
+    /// the pre-release identifier, if any
+    ///
+    /// A pre-release precedes the release with the same major, minor and patch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pre_release: Option<PreRelease>,
+}
+
+/// A pre-release identifier, e.g. the `rc1` in `1.2.3-rc1`
+///
+/// The variants are listed in their order of precedence.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PreRelease {
+    Beta(VersionSegment),
+    Rc(VersionSegment),
 }
 
 #[macro_export]
@@ -26,57 +46,229 @@ macro_rules! package_version {
 }
 
 impl SemVer {
+    /// Parse a version string known to be valid, e.g. at compile time
+    ///
+    /// Panics with the description of the [`VersionError`] on malformed input.
     pub const fn parse(version: &str) -> Self {
+        match Self::try_parse(version) {
+            Ok(version) => version,
+            Err(error) => panic!("{}", error.message()),
+        }
+    }
+
+    pub const fn try_parse(version: &str) -> Result<Self, VersionError> {
         let version: &[u8] = version.as_bytes();
         let mut version_index: usize = 0;
 
         let mut segments: [VersionSegment; 3] = [0; 3];
         let mut segment_index: usize = 0;
 
+        let mut pre_release: Option<PreRelease> = None;
+
         while version_index < version.len() {
             match version[version_index] {
                 digit @ b'0'..=b'9' => {
-                    segments[segment_index] *= 10;
-                    segments[segment_index] += (digit - b'0') as VersionSegment;
+                    segments[segment_index] = match append_digit(segments[segment_index], digit) {
+                        Some(segment) => segment,
+                        None => return Err(VersionError::NumberOverflow),
+                    };
                 }
                 b'.' => {
                     segment_index += 1;
 
-                    assert!(segment_index != segments.len(), "Unexpected segment!");
-                    assert!(
-                        version_index + 1 < version.len(),
-                        "Version can't end with a dot!"
-                    );
+                    if segment_index == segments.len() {
+                        return Err(VersionError::ExtraSegment);
+                    }
+                    if version_index + 1 == version.len() {
+                        return Err(VersionError::TrailingDot);
+                    }
                 }
-                _ => panic!(
-                    "Unexpected symbol encountered! Expected an ASCII number or an ASCII dot!"
-                ),
+                b'-' => {
+                    if segment_index + 1 != segments.len()
+                        || !version[version_index - 1].is_ascii_digit()
+                    {
+                        return Err(VersionError::MisplacedPreRelease);
+                    }
+
+                    pre_release = match PreRelease::try_parse(version.split_at(version_index + 1).1)
+                    {
+                        Ok(pre_release) => Some(pre_release),
+                        Err(error) => return Err(error),
+                    };
+
+                    break;
+                }
+                _ => return Err(VersionError::NonNumericSegment),
             }
 
             version_index += 1;
         }
 
-        assert!(segment_index + 1 == segments.len(), "Invalid version string! Expected three segments (major, minor and patch), but got less!");
+        if segment_index + 1 != segments.len() {
+            return Err(VersionError::MissingSegments);
+        }
 
         let [major, minor, patch]: [VersionSegment; 3] = segments;
 
-        Self {
+        Ok(Self {
             major,
             minor,
             patch,
-        }
+            pre_release,
+        })
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre_release, other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(this), Some(that)) => this.cmp(&that),
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Display for SemVer {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_fmt(format_args!("{}.{}.{}", self.major, self.minor, self.patch))
+            .and_then(|()| {
+                self.pre_release
+                    .map_or(Ok(()), |pre_release| write!(f, "-{pre_release}"))
+            })
+    }
+}
+
+impl PreRelease {
+    const BETA: &'static [u8] = b"beta";
+    const RC: &'static [u8] = b"rc";
+
+    const fn try_parse(suffix: &[u8]) -> Result<Self, VersionError> {
+        if starts_with(suffix, Self::BETA) {
+            match Self::try_parse_number(suffix.split_at(Self::BETA.len()).1) {
+                Ok(number) => Ok(Self::Beta(number)),
+                Err(error) => Err(error),
+            }
+        } else if starts_with(suffix, Self::RC) {
+            match Self::try_parse_number(suffix.split_at(Self::RC.len()).1) {
+                Ok(number) => Ok(Self::Rc(number)),
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(VersionError::UnknownPreRelease)
+        }
+    }
+
+    const fn try_parse_number(number: &[u8]) -> Result<VersionSegment, VersionError> {
+        if number.is_empty() {
+            return Err(VersionError::MissingPreReleaseNumber);
+        }
+
+        let mut index: usize = 0;
+        let mut result: VersionSegment = 0;
+
+        while index < number.len() {
+            match number[index] {
+                digit @ b'0'..=b'9' => {
+                    result = match append_digit(result, digit) {
+                        Some(result) => result,
+                        None => return Err(VersionError::NumberOverflow),
+                    };
+                }
+                _ => return Err(VersionError::NonNumericPreReleaseNumber),
+            }
+
+            index += 1;
+        }
+
+        Ok(result)
+    }
+}
+
+impl Display for PreRelease {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Beta(number) => write!(f, "beta{number}"),
+            Self::Rc(number) => write!(f, "rc{number}"),
+        }
+    }
+}
+
+/// The reason a version string is malformed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VersionError {
+    MissingSegments,
+    TrailingDot,
+    ExtraSegment,
+    NonNumericSegment,
+    MisplacedPreRelease,
+    UnknownPreRelease,
+    MissingPreReleaseNumber,
+    NonNumericPreReleaseNumber,
+    NumberOverflow,
+}
+
+impl VersionError {
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::MissingSegments => "Invalid version string! Expected three segments (major, minor and patch), but got less!",
+            Self::TrailingDot => "Version can't end with a dot!",
+            Self::ExtraSegment => "Unexpected segment!",
+            Self::NonNumericSegment => "Unexpected symbol encountered! Expected an ASCII number, an ASCII dot or an ASCII hyphen!",
+            Self::MisplacedPreRelease => "A pre-release suffix may only follow the patch segment!",
+            Self::UnknownPreRelease => "Unexpected pre-release suffix! Expected \"beta\" or \"rc\" followed by a number!",
+            Self::MissingPreReleaseNumber => "A pre-release suffix must end with a number!",
+            Self::NonNumericPreReleaseNumber => "Unexpected symbol encountered in the pre-release number! Expected an ASCII number!",
+            Self::NumberOverflow => "A version number exceeds the maximum supported value!",
+        }
+    }
+}
+
+impl Display for VersionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "[Versioning] {}", self.message())
     }
 }
 
+impl StdError for VersionError {}
+
+const fn append_digit(number: VersionSegment, digit: u8) -> Option<VersionSegment> {
+    match number.checked_mul(10) {
+        Some(number) => number.checked_add((digit - b'0') as VersionSegment),
+        None => None,
+    }
+}
+
+const fn starts_with(bytes: &[u8], prefix: &[u8]) -> bool {
+    if bytes.len() < prefix.len() {
+        return false;
+    }
+
+    let mut index: usize = 0;
+
+    while index < prefix.len() {
+        if bytes[index] != prefix[index] {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod tests {
-    use super::SemVer;
+    use super::{PreRelease, SemVer, VersionError};
 
     #[test]
     fn valid() {
@@ -87,6 +279,7 @@ mod tests {
                     major: 0,
                     minor: 0,
                     patch: 1,
+                    pre_release: None,
                 },
             ),
             (
@@ -95,6 +288,7 @@ mod tests {
                     major: 1,
                     minor: 3,
                     patch: 2,
+                    pre_release: None,
                 },
             ),
             (
@@ -103,6 +297,25 @@ mod tests {
                     major: 12,
                     minor: 34,
                     patch: 56,
+                    pre_release: None,
+                },
+            ),
+            (
+                "1.2.3-rc1",
+                SemVer {
+                    major: 1,
+                    minor: 2,
+                    patch: 3,
+                    pre_release: Some(PreRelease::Rc(1)),
+                },
+            ),
+            (
+                "1.2.3-beta12",
+                SemVer {
+                    major: 1,
+                    minor: 2,
+                    patch: 3,
+                    pre_release: Some(PreRelease::Beta(12)),
                 },
             ),
         ];
@@ -155,8 +368,95 @@ mod tests {
     }
 
     #[test]
-    #[should_panic = "Unexpected symbol encountered! Expected an ASCII number or an ASCII dot!"]
-    fn excluded_postfix() {
-        _ = SemVer::parse("1.2.3-rc1");
+    fn ordering() {
+        const ORDERED: &[&str] = &[
+            "1.2.2",
+            "1.2.3-beta1",
+            "1.2.3-beta2",
+            "1.2.3-rc1",
+            "1.2.3-rc2",
+            "1.2.3",
+            "1.2.4-beta1",
+        ];
+
+        ORDERED.windows(2).for_each(|pair| {
+            assert!(SemVer::parse(pair[0]) < SemVer::parse(pair[1]));
+        });
+    }
+
+    #[test]
+    fn display() {
+        ["0.0.1", "1.2.3-rc1", "12.34.56-beta7"]
+            .into_iter()
+            .for_each(|version| assert_eq!(version, SemVer::parse(version).to_string()));
+    }
+
+    #[test]
+    #[should_panic = "Unexpected symbol encountered! Expected an ASCII number, an ASCII dot or an ASCII hyphen!"]
+    fn invalid_symbol() {
+        _ = SemVer::parse("1.2.3+rc1");
+    }
+
+    #[test]
+    #[should_panic = "A pre-release suffix may only follow the patch segment!"]
+    fn pre_release_after_minor() {
+        _ = SemVer::parse("1.2-rc1");
+    }
+
+    #[test]
+    #[should_panic = "Unexpected pre-release suffix! Expected \"beta\" or \"rc\" followed by a number!"]
+    fn pre_release_unknown() {
+        _ = SemVer::parse("1.2.3-alpha1");
+    }
+
+    #[test]
+    #[should_panic = "A pre-release suffix must end with a number!"]
+    fn pre_release_no_number() {
+        _ = SemVer::parse("1.2.3-rc");
+    }
+
+    #[test]
+    #[should_panic = "Unexpected symbol encountered in the pre-release number! Expected an ASCII number!"]
+    fn pre_release_invalid_number() {
+        _ = SemVer::parse("1.2.3-rc1.1");
+    }
+
+    #[test]
+    fn max_number() {
+        assert_eq!(
+            "65535.0.65535-beta65535",
+            SemVer::parse("65535.0.65535-beta65535").to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic = "A version number exceeds the maximum supported value!"]
+    fn number_overflow() {
+        _ = SemVer::parse("70000.0.0");
+    }
+
+    #[test]
+    fn try_parse() {
+        assert_eq!(
+            Ok(SemVer::parse("1.2.3-rc1")),
+            SemVer::try_parse("1.2.3-rc1")
+        );
+
+        [
+            ("", VersionError::MissingSegments),
+            ("1.2", VersionError::MissingSegments),
+            ("1.2.", VersionError::TrailingDot),
+            ("1.2.3.4", VersionError::ExtraSegment),
+            ("1.x.3", VersionError::NonNumericSegment),
+            ("1.2-rc1", VersionError::MisplacedPreRelease),
+            ("1.2.3-alpha1", VersionError::UnknownPreRelease),
+            ("1.2.3-beta", VersionError::MissingPreReleaseNumber),
+            ("1.2.3-rc1a", VersionError::NonNumericPreReleaseNumber),
+            ("70000.0.0", VersionError::NumberOverflow),
+            ("1.65536.0", VersionError::NumberOverflow),
+            ("1.2.3-rc65536", VersionError::NumberOverflow),
+        ]
+        .into_iter()
+        .for_each(|(version, expected)| assert_eq!(Err(expected), SemVer::try_parse(version)));
     }
 }
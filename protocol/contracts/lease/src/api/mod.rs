@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use currencies::{LeaseGroup, PaymentGroup};
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::Percent};
 use position::ClosePolicyChange;
 use sdk::{
     cosmwasm_std::Addr,
@@ -38,6 +38,26 @@ pub enum ExecuteMsg {
     /// The funds should be sent attached to the message
     Repay(),
 
+    /// Top up the position collateral
+    ///
+    /// The funds should be sent attached to the message. If they are not in the lease currency,
+    /// they are swapped to it. The obtained amount is added to the position and the lease alarms
+    /// are re-registered against the improved LTV. The loan remains intact.
+    AddCollateral(),
+
+    /// Repay the loan down to a target LTV
+    ///
+    /// The repayment that takes the position LTV at or below `target_ltv` is computed
+    /// at the current market price. The funds should be sent attached to the message in
+    /// the loan currency. The excess over the repayment is returned to the customer.
+    ///
+    /// Return `error::ContractError::DeleverageTargetReached` if the position LTV is already
+    /// at or below the target, and `error::ContractError::InsufficientDeleveragePayment`
+    /// if the attached funds do not cover the repayment.
+    Deleverage {
+        target_ltv: Percent,
+    },
+
     /// Change the Lease automatic close policy
     ///
     /// The lease owner can set Stop Loss, SL, or/and TakeProfit, TP, triggers after the lease has been fully opened.
@@ -58,6 +78,14 @@ pub enum ExecuteMsg {
     /// the TP% is reset if a partial liquidation or a payment takes the position LTV below the TP%.
     ChangeClosePolicy(ClosePolicyChange),
 
+    /// Remove the Lease automatic close policy
+    ///
+    /// Both the Stop Loss and the Take Profit triggers are removed, and the lease price alarms
+    /// are re-registered against the liquidation zones only. It is a shorthand of
+    /// a `ChangeClosePolicy` resetting the two triggers in one transaction.
+    /// Removing a policy that has not been set up is a no-op.
+    RemoveClosePolicy(),
+
     /// Customer initiated position close
     ///
     /// Return `error::ContractError::PositionCloseAmountTooSmall` when a partial close is requested
@@ -101,16 +129,22 @@ pub enum ExecuteMsg {
 #[cfg_attr(any(test, feature = "testing"), derive(Debug, PartialEq, Eq))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum FinalizerExecuteMsg {
-    FinalizeLease { customer: Addr },
+    FinalizeLease {
+        customer: Addr,
+    },
+    /// A notification from a lease that it has just been opened
+    LeaseOpened {},
 }
 
 #[cfg(test)]
 mod test {
+    use currencies::testing::LeaseC1;
+    use finance::{coin::Coin, percent::Percent};
     use sdk::cosmwasm_std::{from_json, to_json_vec};
 
     use crate::api::{
         position::{FullClose, PositionClose},
-        ExecuteMsg,
+        ExecuteMsg, LeaseCoin, PaymentCoin,
     };
 
     #[test]
@@ -125,9 +159,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_deleverage_representation() {
+        let msg = ExecuteMsg::Deleverage {
+            target_ltv: Percent::from_percent(40),
+        };
+        let deleverage_bin = to_json_vec(&msg).expect("serialization failed");
+        assert_eq!(
+            msg,
+            from_json(&deleverage_bin).expect("deserialization failed"),
+        );
+
+        assert_eq!(
+            msg,
+            from_json("{\"deleverage\":{\"target_ltv\":400}}").expect("deserialization failed")
+        );
+    }
+
     #[test]
     fn test_close_position_representation() {
-        let msg = ExecuteMsg::ClosePosition(PositionClose::FullClose(FullClose {}));
+        let msg = ExecuteMsg::ClosePosition(PositionClose::FullClose(FullClose::default()));
         let close_bin = to_json_vec(&msg).expect("serialization failed");
         assert_eq!(msg, from_json(&close_bin).expect("deserialization failed"),);
 
@@ -136,4 +187,15 @@ mod test {
             from_json("{\"close_position\":{\"full_close\":{}}}").expect("deserialization failed"),
         );
     }
+
+    #[test]
+    fn lease_coin_into_payment_coin() {
+        let coin = Coin::<LeaseC1>::new(2_345);
+        let lease_coin: LeaseCoin = coin.into();
+
+        let payment_coin: PaymentCoin = lease_coin.into_super_group();
+        assert_eq!(PaymentCoin::from(coin), payment_coin);
+        assert_eq!(Ok(coin), payment_coin.try_into());
+        assert_eq!(Ok(lease_coin), payment_coin.try_narrow());
+    }
 }
@@ -2,9 +2,10 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use currencies::{Lpn, Lpns};
 use currency::CurrencyDTO;
-pub use dex::{ConnectionParams, Ics20Channel};
-use finance::{duration::Duration, liability::Liability, percent::Percent};
+pub use dex::{ConnectionParams, IcaTimeouts, Ics20Channel};
+use finance::{duration::Duration, liability::Liability, percent::Percent, price::base::BasePrice};
 use sdk::{
     cosmwasm_std::Addr,
     schemars::{self, JsonSchema},
@@ -57,6 +58,34 @@ pub struct NewLeaseForm {
     pub time_alarms: Addr,
     /// The oracle contract that sends market price alerts to the lease
     pub market_price_oracle: Addr,
+    /// Defer the opening until the market price reaches a target, optional.
+    ///
+    /// If not present, the lease is opened immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_order: Option<OpenOrder>,
+}
+
+pub type OrderPrice = BasePrice<LeaseAssetCurrencies, Lpn, Lpns>;
+
+#[derive(Serialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(feature = "skel", derive(Deserialize))]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+/// A limit order for opening a lease
+///
+/// The downpayment is held in escrow by the lease until the market price of the lease
+/// currency goes below `price`. Then the lease is opened as if the order was not present.
+/// The customer may cancel the order by closing the lease. The order expires `valid_for`
+/// past its placement. On cancel or expiration the downpayment is sent back to the customer.
+pub struct OpenOrder {
+    /// The lease currency price the lease is opened below
+    pub price: OrderPrice,
+    /// How long the order remains pending, up to [`OpenOrder::MAX_VALIDITY`]
+    pub valid_for: Duration,
+}
+
+impl OpenOrder {
+    pub const MAX_VALIDITY: Duration = Duration::from_days(30);
 }
 
 #[derive(Serialize, Clone, PartialEq, Eq, JsonSchema)]
@@ -75,6 +104,16 @@ pub struct LoanForm {
     pub annual_margin_interest: Percent,
     /// How long the accrued interest is due before getting overdue.
     pub due_period: Duration,
+    /// The percent of the borrowed amount charged as a fee on opening, optional.
+    ///
+    /// The amount is sent to the Profit contract and the position is reduced accordingly.
+    #[serde(default)]
+    pub origination_fee: Option<Percent>,
+    /// How long since the lease opening the margin interest does not accrue, optional.
+    ///
+    /// The loan interest is charged by the LPP regardless.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_period: Option<Duration>,
 }
 
 #[derive(Serialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
@@ -94,6 +133,28 @@ pub struct PositionSpecDTO {
     /// The minimum amount to liquidate or close. Any attempt to liquidate a smaller
     /// amount would be postponed until the amount goes above this limit
     pub min_transaction: LpnCoinDTO,
+    /// The minimum decrease of the LTV a partial liquidation should bring
+    ///
+    /// A smaller partial liquidation, due to a high LTV, is enlarged to achieve it.
+    /// If not possible, a full liquidation is performed. The liquidations of overdue
+    /// interest are not affected. By default, there is no such constraint.
+    #[serde(default, skip_serializing_if = "Percent::is_zero")]
+    pub min_liquidation_improvement: Percent,
+    /// The minimum time between the starts of two consecutive liquidations
+    ///
+    /// A liquidation triggered earlier, for example by a price alarm, is deferred
+    /// until the period elapses. It may not exceed
+    /// [`PositionSpecDTO::MAX_LIQUIDATION_COOLDOWN`]. By default, there is no such constraint.
+    #[serde(default, skip_serializing_if = "no_cooldown")]
+    pub liquidation_cooldown: Duration,
+}
+
+impl PositionSpecDTO {
+    pub const MAX_LIQUIDATION_COOLDOWN: Duration = Duration::from_days(1);
+}
+
+fn no_cooldown(cooldown: &Duration) -> bool {
+    cooldown == &Duration::default()
 }
 
 #[cfg(feature = "skel")]
@@ -111,6 +172,14 @@ impl PositionSpecDTO {
             self.min_asset.currency() == self.min_transaction.currency(),
             "The currency of min asset should be the same as the currency of min transaction",
         ))
+        .and(Self::check(
+            self.min_liquidation_improvement < Percent::HUNDRED,
+            "Min liquidation improvement should be less than 100%",
+        ))
+        .and(Self::check(
+            self.liquidation_cooldown <= Self::MAX_LIQUIDATION_COOLDOWN,
+            "Liquidation cooldown should not exceed a day",
+        ))
     }
 
     fn check(invariant: bool, msg: &str) -> Result<(), ErrorDe> {
@@ -125,13 +194,49 @@ impl PositionSpecDTO {
         liability: Liability,
         min_asset: LpnCoinDTO,
         min_transaction: LpnCoinDTO,
+        min_liquidation_improvement: Percent,
+        liquidation_cooldown: Duration,
     ) -> Self {
-        Self::new_unchecked(liability, min_asset, min_transaction)
+        Self::new_unchecked(
+            liability,
+            min_asset,
+            min_transaction,
+            min_liquidation_improvement,
+            liquidation_cooldown,
+        )
     }
 
     #[cfg(any(test, feature = "testing"))]
     pub fn new(liability: Liability, min_asset: LpnCoinDTO, min_transaction: LpnCoinDTO) -> Self {
-        let obj = Self::new_unchecked(liability, min_asset, min_transaction);
+        let obj = Self::new_unchecked(
+            liability,
+            min_asset,
+            min_transaction,
+            Percent::ZERO,
+            Duration::default(),
+        );
+        obj.invariant_held()
+            .expect("PositionSpecDTO invariant to be held");
+        obj
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_min_liquidation_improvement(self, min_liquidation_improvement: Percent) -> Self {
+        let obj = Self {
+            min_liquidation_improvement,
+            ..self
+        };
+        obj.invariant_held()
+            .expect("PositionSpecDTO invariant to be held");
+        obj
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_liquidation_cooldown(self, liquidation_cooldown: Duration) -> Self {
+        let obj = Self {
+            liquidation_cooldown,
+            ..self
+        };
         obj.invariant_held()
             .expect("PositionSpecDTO invariant to be held");
         obj
@@ -142,11 +247,15 @@ impl PositionSpecDTO {
         liability: Liability,
         min_asset: LpnCoinDTO,
         min_transaction: LpnCoinDTO,
+        min_liquidation_improvement: Percent,
+        liquidation_cooldown: Duration,
     ) -> Self {
         let obj = Self {
             liability,
             min_asset,
             min_transaction,
+            min_liquidation_improvement,
+            liquidation_cooldown,
         };
         debug_assert_eq!(Ok(()), obj.invariant_held());
         obj
@@ -193,6 +302,47 @@ mod test_position_spec {
         assert_err(r, "should be positive");
     }
 
+    #[test]
+    fn new_valid_min_liquidation_improvement() {
+        assert_load_ok(
+            spec_dto().with_min_liquidation_improvement(Percent::from_percent(5)),
+            format!(
+                r#"{{"liability":{{"initial":650,"healthy":700,"first_liq_warn":730,"second_liq_warn":750,"third_liq_warn":780,"max":800,"recalc_time":3600000000000}},"min_asset":{{"amount":"9000000","ticker":"{lpn}"}},"min_transaction":{{"amount":"5000","ticker":"{lpn}"}},"min_liquidation_improvement":50}}"#,
+                lpn = Lpn::ticker()
+            ),
+        );
+    }
+
+    #[test]
+    fn min_liquidation_improvement_hundred() {
+        let r = from_json(format!(
+            r#"{{"liability":{{"initial":650,"healthy":700,"first_liq_warn":730,"second_liq_warn":750,"third_liq_warn":780,"max":800,"recalc_time":3600000000000}},"min_asset":{{"amount":"9000000","ticker":"{lpn}"}},"min_transaction":{{"amount":"5000","ticker":"{lpn}"}},"min_liquidation_improvement":1000}}"#,
+            lpn = Lpn::ticker()
+        ));
+        assert_err(r, "should be less than 100%");
+    }
+
+    #[test]
+    fn new_valid_liquidation_cooldown() {
+        assert_load_ok(
+            spec_dto().with_liquidation_cooldown(Duration::from_minutes(10)),
+            format!(
+                r#"{{"liability":{{"initial":650,"healthy":700,"first_liq_warn":730,"second_liq_warn":750,"third_liq_warn":780,"max":800,"recalc_time":3600000000000}},"min_asset":{{"amount":"9000000","ticker":"{lpn}"}},"min_transaction":{{"amount":"5000","ticker":"{lpn}"}},"liquidation_cooldown":600000000000}}"#,
+                lpn = Lpn::ticker()
+            ),
+        );
+    }
+
+    #[test]
+    fn liquidation_cooldown_over_max() {
+        let r = from_json(format!(
+            r#"{{"liability":{{"initial":650,"healthy":700,"first_liq_warn":730,"second_liq_warn":750,"third_liq_warn":780,"max":800,"recalc_time":3600000000000}},"min_asset":{{"amount":"9000000","ticker":"{lpn}"}},"min_transaction":{{"amount":"5000","ticker":"{lpn}"}},"liquidation_cooldown":{cooldown}}}"#,
+            lpn = Lpn::ticker(),
+            cooldown = PositionSpecDTO::MAX_LIQUIDATION_COOLDOWN.nanos() + 1,
+        ));
+        assert_err(r, "should not exceed a day");
+    }
+
     #[test]
     fn invalid_ticker() {
         let r = from_json(format!(
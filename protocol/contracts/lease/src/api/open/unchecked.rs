@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use finance::liability::Liability;
+use finance::{duration::Duration, liability::Liability, percent::Percent};
 
 use crate::{error_de::ErrorDe, finance::LpnCoinDTO};
 
@@ -13,6 +13,10 @@ pub(super) struct PositionSpecDTO {
     liability: Liability,
     min_asset: LpnCoinDTO,
     min_transaction: LpnCoinDTO,
+    #[serde(default)]
+    min_liquidation_improvement: Percent,
+    #[serde(default)]
+    liquidation_cooldown: Duration,
 }
 
 impl TryFrom<PositionSpecDTO> for ValidatedPositionSpec {
@@ -23,6 +27,8 @@ impl TryFrom<PositionSpecDTO> for ValidatedPositionSpec {
             liability: value.liability,
             min_asset: value.min_asset,
             min_transaction: value.min_transaction,
+            min_liquidation_improvement: value.min_liquidation_improvement,
+            liquidation_cooldown: value.liquidation_cooldown,
         };
         res.invariant_held().map(|_| res)
     }
@@ -49,12 +55,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn read_no_origination_fee() {
+        assert_eq!(
+            loan_v5_0(),
+            from_json(format!(
+                r#"{{"lpp":"{LPP_ADDR}","profit":"{PROFIT_ADDR}","annual_margin_interest":40,"due_period":604800000000000}}"#
+            ))
+            .expect("deserialization passed")
+        );
+    }
+
     fn loan_v5_0() -> LoanForm {
         LoanForm {
             lpp: Addr::unchecked(LPP_ADDR),
             profit: Addr::unchecked(PROFIT_ADDR),
             annual_margin_interest: MARGIN_INTEREST,
             due_period: DUE_PERIOD,
+            origination_fee: None,
+            promotion_period: None,
         }
     }
 }
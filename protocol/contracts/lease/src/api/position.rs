@@ -1,9 +1,10 @@
 use finance::percent::Percent;
 use serde::{Deserialize, Serialize};
 
+use currency::CurrencyDTO;
 use sdk::schemars::{self, JsonSchema};
 
-use super::LeaseCoin;
+use super::{LeaseCoin, LeasePaymentCurrencies};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
@@ -13,16 +14,39 @@ pub enum PositionClose {
     PartialClose(PartialClose),
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct FullClose {
+    /// Pay the proceeds out in a currency other than the loan one
+    ///
+    /// By default, the customer receives the proceeds in the loan currency.
+    /// Either way, they are sent to the customer's address on this chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payout: Option<Payout>,
+}
+
+/// Swap of the full close proceeds into another payment currency
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
-pub struct FullClose {}
+pub struct Payout {
+    pub currency: CurrencyDTO<LeasePaymentCurrencies>,
+    /// The maximum shortfall of the swap output against the oracle price,
+    /// in the range [0%, 100%)
+    pub max_slippage: Percent,
+}
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct PartialClose {
     pub amount: LeaseCoin,
+    /// The share of the proceeds repaying the loan, in the range [0%, 100%]
+    ///
+    /// The rest is sent to the customer. By default, all proceeds repay the loan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repay_share: Option<Percent>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
@@ -43,10 +67,69 @@ pub struct ClosePolicyChange {
 
 #[cfg(test)]
 mod test {
-    use finance::percent::Percent;
+    use finance::{coin::Coin, percent::Percent};
     use sdk::cosmwasm_std;
 
-    use crate::api::position::{ChangeCmd, ClosePolicyChange};
+    use currencies::{testing::PaymentC3, PaymentGroup};
+
+    use crate::api::{
+        position::{ChangeCmd, ClosePolicyChange, FullClose, PartialClose, Payout},
+        LeaseCoin,
+    };
+
+    #[test]
+    fn full_close_default() {
+        const CLOSE_JSON: &str = "{}";
+        assert_eq!(
+            cosmwasm_std::from_json::<FullClose>(&CLOSE_JSON).expect("deserialization failed"),
+            FullClose::default()
+        );
+    }
+
+    #[test]
+    fn full_close_payout() {
+        let msg = FullClose {
+            payout: Some(Payout {
+                currency: currency::dto::<PaymentC3, PaymentGroup>(),
+                max_slippage: Percent::from_permille(15),
+            }),
+        };
+        assert_eq!(
+            cosmwasm_std::from_json::<FullClose>(
+                &cosmwasm_std::to_json_vec(&msg).expect("serialization failed")
+            )
+            .expect("deserialization failed"),
+            msg
+        );
+    }
+
+    #[test]
+    fn partial_close_repay_share() {
+        let amount: LeaseCoin = Coin::<PaymentC3>::new(1000).into();
+        let msg = PartialClose {
+            amount,
+            repay_share: Some(Percent::from_permille(500)),
+        };
+        assert_eq!(
+            cosmwasm_std::from_json::<PartialClose>(
+                &cosmwasm_std::to_json_vec(&msg).expect("serialization failed")
+            )
+            .expect("deserialization failed"),
+            msg
+        );
+        assert_eq!(
+            cosmwasm_std::from_json::<PartialClose>(
+                &cosmwasm_std::to_json_vec(&PartialClose {
+                    amount,
+                    repay_share: None
+                })
+                .expect("serialization failed")
+            )
+            .expect("deserialization failed")
+            .repay_share,
+            None
+        );
+    }
 
     #[test]
     fn sl_reset() {
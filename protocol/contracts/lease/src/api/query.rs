@@ -1,26 +1,28 @@
 use currency::CurrencyDTO;
 use serde::{Deserialize, Serialize};
 
+use dex::ConnectionParams;
 use finance::{
     duration::{Duration, Seconds},
     percent::Percent,
 };
+use platform::batch::MsgKind;
 use sdk::{
-    cosmwasm_std::Timestamp,
+    cosmwasm_std::{Addr, Timestamp},
     schemars::{self, JsonSchema},
 };
 
 use crate::finance::LpnCoinDTO;
 
-use super::{DownpaymentCoin, LeaseAssetCurrencies, LeaseCoin};
+use super::{
+    open::OrderPrice, position::PositionClose, DownpaymentCoin, LeaseAssetCurrencies, LeaseCoin,
+    LeasePaymentCurrencies,
+};
 
 pub use opened::ClosePolicy;
 
-#[derive(Deserialize, JsonSchema)]
-#[cfg_attr(
-    any(test, feature = "testing"),
-    derive(Clone, Debug, PartialEq, Serialize)
-)]
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug, PartialEq))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum QueryMsg {
     /// Ask for estimation of the due and overdue amounts and periods in that point of time
@@ -31,6 +33,67 @@ pub enum QueryMsg {
     State {
         #[serde(default, rename = "due_projection_secs")]
         due_projection: Seconds,
+        /// Absolute point of time the state is estimated at, optional
+        ///
+        /// If present, `due_projection` is ignored. It should not precede
+        /// the start of the current due period.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        at: Option<Timestamp>,
+    },
+    /// Ask for the amount that would fully pay the loan off
+    ///
+    /// Return a [PayoffResponse]
+    ///
+    /// The amount covers the overdue and due interest and margin, and the principal,
+    /// estimated at `due_projection` past the current time. Since the interest keeps
+    /// accruing, the amount is valid only at the returned point of time.
+    ///
+    /// The query is supported only if the lease is in Opened state.
+    Payoff {
+        #[serde(default, rename = "due_projection_secs")]
+        due_projection: Seconds,
+    },
+    /// Ask for the interest accrued by the loan split by its beneficiary
+    ///
+    /// Return an [InterestSplitResponse]
+    ///
+    /// The amounts cover the overdue and due interest and margin estimated at `at`, or at
+    /// the current time if absent. They are what a full payoff at that point of time would
+    /// pay to the lenders and to the protocol, respectively. `at` should not precede
+    /// the current time.
+    ///
+    /// The query is supported only if the lease is in Opened state.
+    InterestSplit {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        at: Option<Timestamp>,
+    },
+    /// Ask for a summary of the loan and its health at the current market price
+    ///
+    /// Return a [SummaryResponse]
+    ///
+    /// The query is supported only if the lease is in Opened state. The transaction
+    /// in progress, if any, is reported as well.
+    Summary {},
+    /// Ask for the price of the lease currency the position gets liquidated at
+    ///
+    /// Return a [LiquidationPriceResponse]
+    ///
+    /// The price is the one at which the position LTV reaches the liquidation threshold,
+    /// given the principal and the interest accrued by the current time.
+    ///
+    /// The query is supported only if the lease is in Opened state.
+    LiquidationPrice {},
+    /// Ask for the messages a close would emit if it were executed now
+    ///
+    /// Return a [ClosePreviewResponse]
+    ///
+    /// The close is run against the current state, which is left intact. It is
+    /// an [crate::api::ExecuteMsg::ClosePosition] with `position`, or
+    /// an [crate::api::ExecuteMsg::Close] if absent, sent by `sender` with no funds.
+    ClosePreview {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        position: Option<PositionClose>,
+        sender: Addr,
     },
     /// Implementation of [versioning::query::ProtocolPackage::Release]
     ProtocolPackageRelease {},
@@ -42,13 +105,133 @@ pub enum QueryMsg {
     derive(Clone, PartialEq, Eq, Debug, Deserialize)
 )]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PayoffResponse {
+    pub amount: LpnCoinDTO,
+    /// The point of time the `amount` is estimated at
+    pub at: Timestamp,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(
+    any(test, feature = "testing"),
+    derive(Clone, PartialEq, Eq, Debug, Deserialize)
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct InterestSplitResponse {
+    /// The overdue and due loan interest, repaid to the Liquidity Provider's Pool
+    pub lender_interest: LpnCoinDTO,
+    /// The overdue and due margin interest, paid to the Profit contract
+    pub protocol_margin: LpnCoinDTO,
+    /// The point of time the amounts are estimated at
+    pub at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct SummaryResponse {
+    pub amount: LeaseCoin,
+    pub principal_due: LpnCoinDTO,
+    pub overdue_margin: LpnCoinDTO,
+    pub overdue_interest: LpnCoinDTO,
+    pub due_margin: LpnCoinDTO,
+    pub due_interest: LpnCoinDTO,
+    /// The annual interest rate the customer pays, the loan plus the margin one
+    pub effective_annual_interest_rate: Percent,
+    /// The ratio of the total due amount to the lease amount at the current market price
+    pub ltv: Percent,
+    /// The liability zone the `ltv` falls in
+    ///
+    /// Not present if the position is subject of a liquidation or a close.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liability_zone: Option<LiabilityZone>,
+    /// The point of time the overdue interest becomes collectable at
+    pub grace_period_end: Timestamp,
+    /// The point of time the summary is calculated at
+    pub at: Timestamp,
+    /// The transaction in progress, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<opened::OngoingTrx>,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(
+    any(test, feature = "testing"),
+    derive(Clone, PartialEq, Eq, Debug, Deserialize)
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct LiquidationPriceResponse {
+    /// The price of the lease currency in LPN at which the position gets liquidated due to its LTV
+    ///
+    /// Not present if there is no such price, for example, the loan has been paid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<OrderPrice>,
+    /// The point of time the price is calculated at
+    pub at: Timestamp,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(
+    any(test, feature = "testing"),
+    derive(Clone, PartialEq, Eq, Debug, Deserialize)
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct ClosePreviewResponse {
+    /// The messages in the order they would be emitted
+    pub messages: Vec<MessagePreview>,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(
+    any(test, feature = "testing"),
+    derive(Clone, PartialEq, Eq, Debug, Deserialize)
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct MessagePreview {
+    pub kind: MsgKind,
+    /// The receiver of a bank transfer, or the contract a message is sent to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+/// A right-open range of LTVs between two liquidation warning levels
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct LiabilityZone {
+    /// The ordinal of the liquidation warning level the zone starts at, zero if none
+    pub level: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub low: Option<Percent>,
+    pub high: Percent,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum StateResponse {
+    /// The lease awaits the market price to reach the target of its open order
+    Pending {
+        currency: CurrencyDTO<LeaseAssetCurrencies>,
+        downpayment: DownpaymentCoin,
+        price: OrderPrice,
+        /// The point of time the order expires at
+        expiration: Timestamp,
+    },
     Opening {
         currency: CurrencyDTO<LeaseAssetCurrencies>,
         downpayment: DownpaymentCoin,
         loan: LpnCoinDTO,
         loan_interest_rate: Percent,
+        /// The part of the `loan` charged on opening, if any
+        ///
+        /// The position is built out of the `downpayment` and the `loan` net of this fee.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        origination_fee: Option<LpnCoinDTO>,
         in_progress: opening::OngoingTrx,
+        /// The account at the Dex, present once it gets open
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dex_account: Option<DexAccount>,
     },
     Opened {
         amount: LeaseCoin,
@@ -68,25 +251,44 @@ pub enum StateResponse {
         close_policy: ClosePolicy,
         validity: Timestamp,
         in_progress: Option<opened::OngoingTrx>,
+        /// Whether the last price alarm registration failed
+        ///
+        /// The registration is retried on the next time alarm or on `Heal`.
+        #[serde(default)]
+        needs_realarm: bool,
+        /// The account at the Dex holding the lease assets
+        dex_account: DexAccount,
     },
     Paid {
         amount: LeaseCoin,
         in_progress: Option<paid::ClosingTrx>,
     },
+    /// The lease is closed and its change is being swapped into the payout currency
+    PayingOut {
+        change: LpnCoinDTO,
+        payout_currency: CurrencyDTO<LeasePaymentCurrencies>,
+        in_progress: paying_out::OngoingTrx,
+    },
     Closed(),
     Liquidated(),
 }
 
+/// The interchain account a lease operates at the Dex network
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct DexAccount {
+    /// The address of the account at the Dex network
+    pub ica_account: String,
+    /// The connection the account is open over
+    pub connection: ConnectionParams,
+}
+
 pub(crate) mod opening {
-    #[cfg(any(test, feature = "testing"))]
-    use serde::Deserialize;
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Serialize)]
-    #[cfg_attr(
-        any(test, feature = "testing"),
-        derive(Clone, PartialEq, Eq, Deserialize, Debug)
-    )]
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub enum OngoingTrx {
         OpenIcaAccount,
@@ -97,19 +299,17 @@ pub(crate) mod opening {
 
 pub(crate) mod opened {
     use finance::percent::Percent;
-    #[cfg(any(test, feature = "testing"))]
-    use serde::Deserialize;
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
     use crate::api::{LeaseCoin, PaymentCoin};
 
     /// The data transport type of the configured Lease close policy
     ///
     /// Designed for use in query responses only!
-    #[derive(Serialize)]
+    #[derive(Serialize, Deserialize)]
     #[cfg_attr(
         any(test, feature = "testing"),
-        derive(Clone, Default, PartialEq, Eq, Debug, Deserialize)
+        derive(Clone, Default, PartialEq, Eq, Debug)
     )]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub struct ClosePolicy {
@@ -117,11 +317,8 @@ pub(crate) mod opened {
         stop_loss: Option<Percent>,
     }
 
-    #[derive(Serialize)]
-    #[cfg_attr(
-        any(test, feature = "testing"),
-        derive(Clone, PartialEq, Eq, Debug, Deserialize)
-    )]
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub enum OngoingTrx {
         Repayment {
@@ -136,13 +333,14 @@ pub(crate) mod opened {
             close: LeaseCoin,
             in_progress: PositionCloseTrx,
         },
+        AddCollateral {
+            collateral: PaymentCoin,
+            in_progress: CollateralTrx,
+        },
     }
 
-    #[derive(Serialize)]
-    #[cfg_attr(
-        any(test, feature = "testing"),
-        derive(Clone, PartialEq, Eq, Debug, Deserialize)
-    )]
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub enum RepayTrx {
         TransferOut,
@@ -151,11 +349,8 @@ pub(crate) mod opened {
         TransferInFinish,
     }
 
-    #[derive(Serialize)]
-    #[cfg_attr(
-        any(test, feature = "testing"),
-        derive(Clone, PartialEq, Eq, Debug, Deserialize)
-    )]
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub enum PositionCloseTrx {
         Swap,
@@ -163,6 +358,14 @@ pub(crate) mod opened {
         TransferInFinish,
     }
 
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+    #[serde(deny_unknown_fields, rename_all = "snake_case")]
+    pub enum CollateralTrx {
+        TransferOut,
+        Swap,
+    }
+
     #[cfg(feature = "contract")]
     impl ClosePolicy {
         pub fn new(tp: Option<Percent>, sl: Option<Percent>) -> Self {
@@ -175,15 +378,10 @@ pub(crate) mod opened {
 }
 
 pub(crate) mod paid {
-    #[cfg(any(test, feature = "testing"))]
-    use serde::Deserialize;
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Serialize)]
-    #[cfg_attr(
-        any(test, feature = "testing"),
-        derive(Clone, PartialEq, Eq, Debug, Deserialize)
-    )]
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
     #[serde(deny_unknown_fields, rename_all = "snake_case")]
     pub enum ClosingTrx {
         TransferInInit,
@@ -191,6 +389,20 @@ pub(crate) mod paid {
     }
 }
 
+pub(crate) mod paying_out {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, PartialEq, Eq, Debug))]
+    #[serde(deny_unknown_fields, rename_all = "snake_case")]
+    pub enum OngoingTrx {
+        TransferOut,
+        Swap,
+        TransferInInit,
+        TransferInFinish,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use platform::tests as platform_tests;
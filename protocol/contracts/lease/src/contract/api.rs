@@ -1,12 +1,12 @@
 use enum_dispatch::enum_dispatch;
 
-use finance::duration::Duration;
+use finance::{duration::Duration, percent::Percent};
 use sdk::cosmwasm_std::{Binary, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
 
 use crate::{
     api::{
         position::{ClosePolicyChange, PositionClose},
-        query::StateResponse,
+        query::{LiquidationPriceResponse, StateResponse, SummaryResponse},
     },
     error::{ContractError, ContractResult},
 };
@@ -90,6 +90,22 @@ where
         querier: QuerierWrapper<'_>,
     ) -> ContractResult<StateResponse>;
 
+    fn summary(
+        self,
+        _now: Timestamp,
+        _querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        err("summary")
+    }
+
+    fn liquidation_price(
+        self,
+        _now: Timestamp,
+        _querier: QuerierWrapper<'_>,
+    ) -> ContractResult<LiquidationPriceResponse> {
+        err("liquidation price")
+    }
+
     fn reply(
         self,
         _querier: QuerierWrapper<'_>,
@@ -108,6 +124,25 @@ where
         err("repay")
     }
 
+    fn add_collateral(
+        self,
+        _querier: QuerierWrapper<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("add collateral")
+    }
+
+    fn deleverage(
+        self,
+        _target_ltv: Percent,
+        _querier: QuerierWrapper<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("deleverage")
+    }
+
     fn change_close_policy(
         self,
         _change: ClosePolicyChange,
@@ -0,0 +1,76 @@
+use currency::{CurrencyDef, MemberOf};
+use finance::{coin::Coin, duration::Duration, zero::Zero};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle_platform::Oracle as OracleTrait;
+use platform::batch::{Emit, Emitter};
+use sdk::cosmwasm_std::{Addr, Timestamp};
+
+use crate::{
+    api::{LeaseAssetCurrencies, LeasePaymentCurrencies},
+    event::Type,
+    finance::{LpnCoin, LpnCurrencies, LpnCurrency},
+    lease::Lease,
+};
+
+/// The loan accruals of a lease at a point in time
+///
+/// Emitted past each change of the lease state to let indexers reconcile their books
+/// with an authoritative checkpoint. It is additive to the operation specific events.
+pub(crate) struct AccrualSnapshot {
+    at: Timestamp,
+    principal: LpnCoin,
+    overdue_margin: LpnCoin,
+    overdue_interest: LpnCoin,
+    due_margin: LpnCoin,
+    due_interest: LpnCoin,
+}
+
+impl AccrualSnapshot {
+    pub fn of<Asset, Lpp, Oracle>(lease: &Lease<Asset, Lpp, Oracle>, now: &Timestamp) -> Self
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
+        Lpp: LppLoanTrait<LpnCurrency, LpnCurrencies>,
+        Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+    {
+        let state = lease.state(*now, Duration::default());
+        Self {
+            at: *now,
+            principal: state.principal_due,
+            overdue_margin: state.overdue_margin,
+            overdue_interest: state.overdue_interest,
+            due_margin: state.due_margin,
+            due_interest: state.due_interest,
+        }
+    }
+
+    /// The snapshot of a fully repaid loan
+    pub fn paid(now: &Timestamp) -> Self {
+        Self {
+            at: *now,
+            principal: Coin::ZERO,
+            overdue_margin: Coin::ZERO,
+            overdue_interest: Coin::ZERO,
+            due_margin: Coin::ZERO,
+            due_interest: Coin::ZERO,
+        }
+    }
+
+    pub fn emit(&self, lease: &Addr) -> Emitter {
+        let overdue = self.overdue_margin + self.overdue_interest;
+        let margin_interest = self.overdue_margin + self.due_margin;
+        let loan_interest = self.overdue_interest + self.due_interest;
+
+        Emitter::of_type(Type::AccrualSnapshot)
+            .emit("to", lease)
+            .emit_timestamp("at", &self.at)
+            .emit_coin(
+                "total-due",
+                self.principal + margin_interest + loan_interest,
+            )
+            .emit_coin_amount("principal", self.principal)
+            .emit_coin_amount("overdue", overdue)
+            .emit_coin_amount("margin-interest", margin_interest)
+            .emit_coin_amount("loan-interest", loan_interest)
+    }
+}
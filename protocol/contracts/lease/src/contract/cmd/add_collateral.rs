@@ -0,0 +1,98 @@
+use currency::{CurrencyDef, MemberOf};
+use finance::coin::Coin;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle_platform::Oracle as OracleTrait;
+use platform::batch::Emitter;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::Timestamp;
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{LeaseAssetCurrencies, LeaseCoin, LeasePaymentCurrencies},
+    contract::SplitDTOOut,
+    error::ContractError,
+    finance::{LpnCurrencies, LpnCurrency, OracleRef, ReserveRef},
+    lease::{with_lease::WithLease, IntoDTOResult, Lease as LeaseDO, LeaseDTO},
+};
+
+use super::accrual_snapshot::AccrualSnapshot;
+
+/// Add collateral to the lease position leaving the loan intact
+pub(crate) struct AddCollateral {
+    collateral: LeaseCoin,
+    now: Timestamp,
+    // LeaseDTO attributes
+    profit: ProfitRef,
+    time_alarms: TimeAlarmsRef,
+    reserve: ReserveRef,
+}
+
+impl AddCollateral {
+    pub fn new(
+        collateral: LeaseCoin,
+        now: Timestamp,
+        // LeaseDTO attributes follow
+        profit: ProfitRef,
+        time_alarms: TimeAlarmsRef,
+        reserve: ReserveRef,
+    ) -> Self {
+        Self {
+            collateral,
+            now,
+            profit,
+            time_alarms,
+            reserve,
+        }
+    }
+}
+
+pub(crate) struct AddCollateralResult {
+    lease: LeaseDTO,
+    accrual_snapshot: Emitter,
+}
+
+impl SplitDTOOut for AddCollateralResult {
+    type Other = Emitter;
+
+    fn split_into(self) -> (LeaseDTO, Self::Other) {
+        (self.lease, self.accrual_snapshot)
+    }
+}
+
+impl WithLease for AddCollateral {
+    type Output = AddCollateralResult;
+
+    type Error = ContractError;
+
+    fn exec<Asset, Loan, Oracle>(
+        self,
+        mut lease: LeaseDO<Asset, Loan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
+        Loan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
+        Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>
+            + Into<OracleRef>,
+    {
+        self.collateral
+            .try_into()
+            .map_err(Into::into)
+            .and_then(|collateral: Coin<Asset>| {
+                // a zero swap output leaves the position intact
+                if !collateral.is_zero() {
+                    lease.add_collateral(collateral);
+                }
+                let accrual_snapshot = AccrualSnapshot::of(&lease, &self.now).emit(lease.addr());
+                lease
+                    .try_into_dto(self.profit, self.time_alarms, self.reserve)
+                    .inspect(|res| {
+                        debug_assert!(res.batch.is_empty());
+                    })
+                    .map(|IntoDTOResult { lease, batch: _ }| AddCollateralResult {
+                        lease,
+                        accrual_snapshot,
+                    })
+            })
+    }
+}
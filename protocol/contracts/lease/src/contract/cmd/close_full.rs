@@ -7,11 +7,11 @@ use sdk::cosmwasm_std::Timestamp;
 use crate::{
     api::{LeaseAssetCurrencies, LeasePaymentCurrencies},
     error::ContractError,
-    finance::{LpnCoinDTO, LpnCurrencies, LpnCurrency, OracleRef, ReserveRef},
+    finance::{LpnCoin, LpnCoinDTO, LpnCurrencies, LpnCurrency, OracleRef, ReserveRef},
     lease::{with_lease::WithLease, Lease},
 };
 
-use super::repayable::Emitter;
+use super::{accrual_snapshot::AccrualSnapshot, repayable::Emitter};
 
 pub(crate) struct Close<ProfitSender, ChangeSender, EmitterT> {
     payment: LpnCoinDTO,
@@ -42,13 +42,19 @@ impl<ProfitSender, ChangeSender, EmitterT> Close<ProfitSender, ChangeSender, Emi
     }
 }
 
+pub(crate) struct CloseResult {
+    pub response: MessageResponse,
+    /// The amount left over to the customer after the loan has been repaid
+    pub change: LpnCoin,
+}
+
 impl<ProfitSender, ChangeSender, EmitterT> WithLease for Close<ProfitSender, ChangeSender, EmitterT>
 where
     ProfitSender: FixedAddressSender,
     ChangeSender: FixedAddressSender,
     EmitterT: Emitter,
 {
-    type Output = MessageResponse;
+    type Output = CloseResult;
 
     type Error = ContractError;
 
@@ -79,10 +85,14 @@ where
             })
             .map(|result| {
                 let (receipt, messages) = result.decompose();
-                MessageResponse::messages_with_events(
-                    messages,
-                    self.emitter_fn.emit(&lease_addr, &receipt),
-                )
+                CloseResult {
+                    change: receipt.change(),
+                    response: MessageResponse::messages_with_events(
+                        messages,
+                        self.emitter_fn.emit(&lease_addr, &receipt),
+                    )
+                    .merge_with(AccrualSnapshot::paid(&self.now).emit(&lease_addr)),
+                }
             })
     }
 }
@@ -0,0 +1,63 @@
+use currency::{CurrencyDef, MemberOf};
+use finance::{percent::Percent, zero::Zero};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle_platform::Oracle as OracleTrait;
+use sdk::cosmwasm_std::Timestamp;
+
+use crate::{
+    api::{LeaseAssetCurrencies, LeasePaymentCurrencies},
+    error::ContractError,
+    finance::{LpnCoin, LpnCurrencies, LpnCurrency},
+    lease::{with_lease::WithLease, Lease},
+};
+
+/// Compute the repayment that takes the lease LTV down to a target
+///
+/// Fail if the position is already at or below the target, or if the payment is less
+/// than the required repayment.
+pub(in crate::contract) struct Cmd<'now> {
+    target_ltv: Percent,
+    payment: LpnCoin,
+    now: &'now Timestamp,
+}
+
+impl<'now> Cmd<'now> {
+    pub fn new(target_ltv: Percent, payment: LpnCoin, now: &'now Timestamp) -> Self {
+        Self {
+            target_ltv,
+            payment,
+            now,
+        }
+    }
+}
+
+impl WithLease for Cmd<'_> {
+    type Output = LpnCoin;
+
+    type Error = ContractError;
+
+    fn exec<Asset, LppLoan, Oracle>(
+        self,
+        lease: Lease<Asset, LppLoan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
+        LppLoan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
+        Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+    {
+        lease
+            .deleverage_amount(self.target_ltv, self.now)
+            .and_then(|repayment| {
+                if repayment == LpnCoin::ZERO {
+                    Err(ContractError::DeleverageTargetReached(self.target_ltv))
+                } else if self.payment < repayment {
+                    Err(ContractError::InsufficientDeleveragePayment(
+                        repayment.into(),
+                    ))
+                } else {
+                    Ok(repayment)
+                }
+            })
+    }
+}
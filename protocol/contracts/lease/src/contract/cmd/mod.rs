@@ -1,22 +1,27 @@
-pub(super) use close_full::Close as FullClose;
+pub(super) use add_collateral::AddCollateral;
+pub(super) use close_full::{Close as FullClose, CloseResult as FullCloseResult};
 pub(super) use close_paid::Close;
 pub(super) use close_partial::CloseFn as PartialCloseFn;
 pub(super) use close_policy::{
     change::ChangeCmd as ChangeClosePolicy, check::CheckCmd as CloseStatusCmd, CloseStatusDTO,
     FullLiquidationDTO, LiquidationDTO, PartialLiquidationDTO,
 };
+pub(super) use deleverage::Cmd as Deleverage;
 pub(super) use obtain_payment::ObtainPayment;
 pub(super) use open::{LeaseFactory, OpenLeaseResult};
 pub(super) use open_loan::{OpenLoanReq, OpenLoanReqResult, OpenLoanResp, OpenLoanRespResult};
 pub(super) use repay::RepayLeaseFn;
 pub(super) use repayable::{Emitter as RepayEmitter, Repay, RepayFn, RepayResult};
-pub(super) use state::LeaseState;
+pub(super) use state::{LeaseLiquidationPrice, LeaseState, LeaseSummary};
 pub(super) use validate_close_position::Cmd as ValidateClosePosition;
 
+mod accrual_snapshot;
+mod add_collateral;
 mod close_full;
 mod close_paid;
 mod close_partial;
 mod close_policy;
+mod deleverage;
 mod obtain_payment;
 mod open;
 mod open_loan;
@@ -1,4 +1,4 @@
-use currency::{Currency, CurrencyDef, MemberOf};
+use currency::{Currency, CurrencyDef, Group as _, MemberOf};
 use finance::coin::{Coin, WithCoin, WithCoinResult};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle_platform::Oracle as OracleTrait;
@@ -37,10 +37,22 @@ impl WithLease for ObtainPayment {
         Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
     {
         bank::may_received(&self.cw_amount, RepaymentHandler::<_, _, _> { lease })
-            .ok_or_else(ContractError::NoPaymentError)?
+            .unwrap_or_else(|| Err(no_payment(&self.cw_amount)))
     }
 }
 
+/// The error on none of the received coins being a payment currency
+fn no_payment(cw_amount: &[CwCoin]) -> ContractError {
+    cw_amount
+        .first()
+        .map_or_else(ContractError::NoPaymentError, |cw_coin| {
+            ContractError::UnsupportedPaymentCurrency(
+                cw_coin.denom.clone(),
+                LeasePaymentCurrencies::DESCR,
+            )
+        })
+}
+
 struct RepaymentHandler<Asset, LppLoan, Oracle> {
     lease: Lease<Asset, LppLoan, Oracle>,
 }
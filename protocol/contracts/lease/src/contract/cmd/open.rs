@@ -31,6 +31,8 @@ pub struct LeaseFactory<'a> {
 pub struct OpenLeaseResult {
     pub lease: LeaseDTO,
     pub status: CloseStatusDTO,
+    /// Whether the position is worth less than the minimum asset amount
+    pub undersized: bool,
 }
 
 impl SplitDTOOut for OpenLeaseResult {
@@ -87,20 +89,24 @@ impl WithLeaseDeps for LeaseFactory<'_> {
                 self.start_at,
                 self.form.loan.annual_margin_interest,
                 self.form.loan.due_period,
+                self.form.loan.promotion_period,
             );
             Lease::new(self.lease_addr, self.form.customer, position, loan, oracle)
         };
 
-        check::check(&lease, self.now, &self.time_alarms, &self.price_alarms).and_then(|status| {
-            lease
-                .try_into_dto(self.profit, self.time_alarms, self.reserve)
-                .inspect(|res| {
-                    debug_assert!(res.batch.is_empty());
-                })
-                .map(|res| OpenLeaseResult {
-                    lease: res.lease,
-                    status,
-                })
-        })
+        check::check(&lease, self.now, &self.time_alarms, &self.price_alarms)
+            .and_then(|status| lease.undersized().map(|undersized| (status, undersized)))
+            .and_then(|(status, undersized)| {
+                lease
+                    .try_into_dto(self.profit, self.time_alarms, self.reserve)
+                    .inspect(|res| {
+                        debug_assert!(res.batch.is_empty());
+                    })
+                    .map(|res| OpenLeaseResult {
+                        lease: res.lease,
+                        status,
+                        undersized,
+                    })
+            })
     }
 }
@@ -1,23 +1,28 @@
 use serde::{Deserialize, Serialize};
 
-use currency::{CurrencyDef, MemberOf};
+use currency::{CurrencyDTO, CurrencyDef, Group, MemberOf};
 use finance::{
     coin::{Coin, WithCoin, WithCoinResult},
+    fraction::Fraction,
     percent::Percent,
 };
-use lpp::stub::lender::{LppLender as LppLenderTrait, WithLppLender};
-use oracle::stub::convert;
+use lpp::{
+    lpn::LpnCoin as ConfiguredLpnCoin,
+    stub::lender::{LppLender as LppLenderTrait, WithLppLender},
+};
+use oracle::stub::{convert, SwapPath};
 use platform::{bank, batch::Batch};
 use sdk::cosmwasm_std::{Coin as CwCoin, QuerierWrapper, Reply};
 
 use crate::{
-    api::{open::PositionSpecDTO, DownpaymentCoin, LeasePaymentCurrencies},
+    api::{open::PositionSpecDTO, DownpaymentCoin, LeaseAssetCurrencies, LeasePaymentCurrencies},
     error::ContractError,
     finance::{LpnCoin, LpnCoinDTO, LpnCurrencies, LpnCurrency, OracleRef},
     position::Spec as PositionSpec,
 };
 
 pub struct OpenLoanReq<'a> {
+    lease_currency: CurrencyDTO<LeaseAssetCurrencies>,
     position_spec: PositionSpecDTO,
     funds_in: Vec<CwCoin>,
     max_ltd: Option<Percent>,
@@ -27,6 +32,7 @@ pub struct OpenLoanReq<'a> {
 
 impl<'a> OpenLoanReq<'a> {
     pub fn new(
+        lease_currency: CurrencyDTO<LeaseAssetCurrencies>,
         position_spec: PositionSpecDTO,
         funds_in: Vec<CwCoin>,
         max_ltd: Option<Percent>,
@@ -34,6 +40,7 @@ impl<'a> OpenLoanReq<'a> {
         querier: QuerierWrapper<'a>,
     ) -> Self {
         Self {
+            lease_currency,
             position_spec,
             funds_in,
             max_ltd,
@@ -52,9 +59,13 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for OpenLoanReq<'_> {
     where
         LppLender: LppLenderTrait<LpnCurrency, LpnCurrencies>,
     {
+        check_not_lpn(&self.lease_currency)?;
+        check_lpn(&self.position_spec)?;
+
         let (downpayment, downpayment_lpn) = bank::may_received(
             &self.funds_in,
             DownpaymentHandler {
+                lease_currency: self.lease_currency,
                 oracle: self.oracle,
                 querier: self.querier,
             },
@@ -76,6 +87,7 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for OpenLoanReq<'_> {
 }
 
 struct DownpaymentHandler<'a> {
+    lease_currency: CurrencyDTO<LeaseAssetCurrencies>,
     oracle: OracleRef,
     querier: QuerierWrapper<'a>,
 }
@@ -89,6 +101,13 @@ impl WithCoin<LeasePaymentCurrencies> for DownpaymentHandler<'_> {
         C: CurrencyDef,
         C::Group: MemberOf<LeasePaymentCurrencies>,
     {
+        check_swappable(
+            &self.oracle,
+            currency::dto::<C, LeasePaymentCurrencies>(),
+            self.lease_currency,
+            self.querier,
+        )?;
+
         let downpayment_lpn = convert::to_quote::<
             C,
             LeasePaymentCurrencies,
@@ -102,6 +121,48 @@ impl WithCoin<LeasePaymentCurrencies> for DownpaymentHandler<'_> {
     }
 }
 
+/// Reject a lease in the Lpn since it would bear no price exposure
+fn check_not_lpn<G>(lease: &CurrencyDTO<G>) -> Result<(), ContractError>
+where
+    G: Group,
+{
+    let lpn = currency::dto::<LpnCurrency, LpnCurrencies>();
+
+    if lease.same_as(&lpn) {
+        Err(ContractError::LeaseCurrencyIsLpn(lpn))
+    } else {
+        Ok(())
+    }
+}
+
+/// Reject a position specification with amounts not in the configured Lpn
+fn check_lpn(spec: &PositionSpecDTO) -> Result<(), ContractError> {
+    ConfiguredLpnCoin::<LpnCurrency>::try_new(spec.min_asset)
+        .and_then(|_| ConfiguredLpnCoin::<LpnCurrency>::try_new(spec.min_transaction))
+        .map(|_| ())
+        .map_err(Into::into)
+}
+
+/// Fail fast if the downpayment could not be swapped to the lease currency
+fn check_swappable<Oracle>(
+    oracle: &Oracle,
+    downpayment: CurrencyDTO<LeasePaymentCurrencies>,
+    lease: CurrencyDTO<LeaseAssetCurrencies>,
+    querier: QuerierWrapper<'_>,
+) -> Result<(), ContractError>
+where
+    Oracle: SwapPath<LeasePaymentCurrencies>,
+{
+    oracle
+        .swap_path(downpayment, lease, querier)
+        .map(|_path| ())
+        .map_err(|cause| ContractError::NonSwappableDownpayment {
+            downpayment,
+            lease,
+            cause,
+        })
+}
+
 pub struct OpenLoanReqResult {
     pub(in crate::contract) batch: Batch,
     pub(in crate::contract) downpayment: DownpaymentCoin,
@@ -109,11 +170,15 @@ pub struct OpenLoanReqResult {
 
 pub struct OpenLoanResp {
     reply: Reply,
+    origination_fee: Option<Percent>,
 }
 
 impl OpenLoanResp {
-    pub fn new(reply: Reply) -> Self {
-        Self { reply }
+    pub fn new(reply: Reply, origination_fee: Option<Percent>) -> Self {
+        Self {
+            reply,
+            origination_fee,
+        }
     }
 }
 
@@ -140,6 +205,9 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for OpenLoanResp {
         Ok(OpenLoanRespResult {
             principal: loan_resp.principal_due.into(),
             annual_interest_rate: loan_resp.annual_interest_rate,
+            origination_fee: self
+                .origination_fee
+                .map(|fee| fee.of(loan_resp.principal_due).into()),
         })
     }
 }
@@ -148,4 +216,74 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for OpenLoanResp {
 pub struct OpenLoanRespResult {
     pub(in crate::contract) principal: LpnCoinDTO,
     pub(in crate::contract) annual_interest_rate: Percent,
+    /// The part of the principal charged on opening, if any
+    #[serde(default)]
+    pub(in crate::contract) origination_fee: Option<LpnCoinDTO>,
+}
+
+impl OpenLoanRespResult {
+    /// The part of the principal that goes into the position
+    pub(in crate::contract) fn principal_net_of_fee(&self) -> LpnCoinDTO {
+        self.origination_fee().map_or(self.principal, |fee| {
+            (self
+                .principal
+                .as_specific::<LpnCurrency, _>(LpnCurrency::dto())
+                - fee)
+                .into()
+        })
+    }
+
+    pub(in crate::contract) fn origination_fee(&self) -> Option<LpnCoin> {
+        self.origination_fee
+            .map(|fee| fee.as_specific(LpnCurrency::dto()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::testing::LeaseC1;
+    use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent};
+
+    use crate::{
+        api::{open::PositionSpecDTO, LeaseAssetCurrencies, LeasePaymentCurrencies},
+        error::ContractError,
+        finance::{LpnCurrencies, LpnCurrency},
+    };
+
+    #[test]
+    fn lease_currency_not_lpn() {
+        assert_eq!(
+            Ok(()),
+            super::check_not_lpn(&currency::dto::<LeaseC1, LeaseAssetCurrencies>())
+        );
+    }
+
+    #[test]
+    fn lease_currency_lpn() {
+        assert_eq!(
+            Err(ContractError::LeaseCurrencyIsLpn(currency::dto::<
+                LpnCurrency,
+                LpnCurrencies,
+            >())),
+            super::check_not_lpn(&currency::dto::<LpnCurrency, LeasePaymentCurrencies>())
+        );
+    }
+
+    #[test]
+    fn position_spec_lpn() {
+        let spec = PositionSpecDTO::new(
+            Liability::new(
+                Percent::from_percent(65),
+                Percent::from_percent(70),
+                Percent::from_percent(73),
+                Percent::from_percent(75),
+                Percent::from_percent(78),
+                Percent::from_percent(80),
+                Duration::from_hours(1),
+            ),
+            Coin::<LpnCurrency>::new(1000).into(),
+            Coin::<LpnCurrency>::new(10).into(),
+        );
+        assert_eq!(Ok(()), super::check_lpn(&spec));
+    }
 }
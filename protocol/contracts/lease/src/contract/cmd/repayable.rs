@@ -22,7 +22,7 @@ use crate::{
     position::CloseStrategy,
 };
 
-use super::{close_policy::check, CloseStatusDTO};
+use super::{accrual_snapshot::AccrualSnapshot, close_policy::check, CloseStatusDTO};
 
 pub(crate) trait RepayFn {
     fn do_repay<Asset, Lpp, Oracle, Profit>(
@@ -175,6 +175,7 @@ where
             self.alarms.1,
         )
         .and_then(|close_status| {
+            let snapshot = AccrualSnapshot::of(&lease, self.now).emit(lease.addr());
             lease
                 .try_into_dto(self.profit, self.alarms.0, self.reserve)
                 .map(
@@ -188,7 +189,8 @@ where
                                 response: MessageResponse::messages_with_events(
                                     messages.merge(profit_sender.into()),
                                     events,
-                                ),
+                                )
+                                .merge_with(snapshot),
                                 close_status,
                             },
                         }
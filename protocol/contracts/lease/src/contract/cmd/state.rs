@@ -6,7 +6,10 @@ use sdk::cosmwasm_std::Timestamp;
 
 use crate::{
     api::{
-        query::{opened::OngoingTrx, StateResponse},
+        query::{
+            opened::OngoingTrx, DexAccount, LiquidationPriceResponse, StateResponse,
+            SummaryResponse,
+        },
         LeaseAssetCurrencies, LeasePaymentCurrencies,
     },
     error::ContractError,
@@ -18,14 +21,24 @@ pub struct LeaseState {
     now: Timestamp,
     due_projection: Duration,
     in_progress: Option<OngoingTrx>,
+    needs_realarm: bool,
+    dex_account: DexAccount,
 }
 
 impl LeaseState {
-    pub fn new(now: Timestamp, due_projection: Duration, in_progress: Option<OngoingTrx>) -> Self {
+    pub fn new(
+        now: Timestamp,
+        due_projection: Duration,
+        in_progress: Option<OngoingTrx>,
+        needs_realarm: bool,
+        dex_account: DexAccount,
+    ) -> Self {
         Self {
             now,
             due_projection,
             in_progress,
+            needs_realarm,
+            dex_account,
         }
     }
 }
@@ -45,9 +58,87 @@ impl WithLease for LeaseState {
         LppLoan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
         Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
     {
-        Ok(StateResponse::opened_from(
-            lease.state(self.now, self.due_projection),
-            self.in_progress,
-        ))
+        lease.check_state_at(&self.now).map(|()| {
+            StateResponse::opened_from(
+                lease.state(self.now, self.due_projection),
+                self.in_progress,
+                self.needs_realarm,
+                self.dex_account,
+            )
+        })
+    }
+}
+
+pub struct LeaseSummary {
+    now: Timestamp,
+    in_progress: Option<OngoingTrx>,
+}
+
+impl LeaseSummary {
+    pub fn new(now: Timestamp, in_progress: Option<OngoingTrx>) -> Self {
+        Self { now, in_progress }
+    }
+}
+
+impl WithLease for LeaseSummary {
+    type Output = SummaryResponse;
+
+    type Error = ContractError;
+
+    fn exec<Asset, LppLoan, Oracle>(
+        self,
+        lease: Lease<Asset, LppLoan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
+        LppLoan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
+        Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+    {
+        lease
+            .check_state_at(&self.now)
+            .and_then(|()| lease.health(&self.now))
+            .and_then(|health| {
+                SummaryResponse::from_state(
+                    lease.state(self.now, Duration::default()),
+                    health,
+                    self.in_progress,
+                )
+                .map_err(Into::into)
+            })
+    }
+}
+
+pub struct LeaseLiquidationPrice {
+    now: Timestamp,
+}
+
+impl LeaseLiquidationPrice {
+    pub fn new(now: Timestamp) -> Self {
+        Self { now }
+    }
+}
+
+impl WithLease for LeaseLiquidationPrice {
+    type Output = LiquidationPriceResponse;
+
+    type Error = ContractError;
+
+    fn exec<Asset, LppLoan, Oracle>(
+        self,
+        lease: Lease<Asset, LppLoan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
+        LppLoan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
+        Oracle: OracleTrait<LeasePaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+    {
+        lease
+            .check_state_at(&self.now)
+            .map(|()| LiquidationPriceResponse {
+                price: lease.liquidation_price(&self.now).map(Into::into),
+                at: self.now,
+            })
     }
 }
@@ -2,6 +2,7 @@ use currency::{CurrencyDef, MemberOf};
 use finance::coin::Coin;
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle_platform::Oracle as OracleTrait;
+use sdk::cosmwasm_std::Timestamp;
 
 use crate::{
     api::{position::PartialClose, LeaseAssetCurrencies, LeasePaymentCurrencies},
@@ -10,17 +11,18 @@ use crate::{
     lease::{with_lease::WithLease, Lease},
 };
 
-pub(in crate::contract) struct Cmd<'spec> {
+pub(in crate::contract) struct Cmd<'spec, 'now> {
     spec: &'spec PartialClose,
+    now: &'now Timestamp,
 }
 
-impl<'spec> Cmd<'spec> {
-    pub fn new(spec: &'spec PartialClose) -> Self {
-        Self { spec }
+impl<'spec, 'now> Cmd<'spec, 'now> {
+    pub fn new(spec: &'spec PartialClose, now: &'now Timestamp) -> Self {
+        Self { spec, now }
     }
 }
 
-impl WithLease for Cmd<'_> {
+impl WithLease for Cmd<'_, '_> {
     type Output = ();
 
     type Error = ContractError;
@@ -38,6 +40,6 @@ impl WithLease for Cmd<'_> {
     {
         Coin::<Asset>::try_from(self.spec.amount)
             .map_err(Into::into)
-            .and_then(|amount| lease.validate_close(amount))
+            .and_then(|amount| lease.validate_close(amount, self.spec.repay_share, self.now))
     }
 }
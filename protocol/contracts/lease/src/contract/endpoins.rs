@@ -4,7 +4,7 @@ use sdk::{
     cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
         entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
-        Reply, Storage,
+        Reply, Storage, Timestamp,
     },
     neutron_sdk::sudo::msg::SudoMsg,
 };
@@ -14,7 +14,15 @@ use versioning::{
 };
 
 use crate::{
-    api::{open::NewLeaseContract, query::QueryMsg, ExecuteMsg, MigrateMsg},
+    api::{
+        open::NewLeaseContract,
+        position::{ChangeCmd, ClosePolicyChange},
+        query::{
+            ClosePreviewResponse, InterestSplitResponse, MessagePreview, PayoffResponse, QueryMsg,
+            StateResponse,
+        },
+        ExecuteMsg, LpnCoinDTO, MigrateMsg,
+    },
     contract::api::Contract,
     error::{ContractError, ContractResult},
 };
@@ -31,7 +39,7 @@ const CURRENT_RELEASE: ProtocolPackageRelease = ProtocolPackageRelease::current(
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut<'_>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     new_lease: NewLeaseContract,
 ) -> ContractResult<CwResponse> {
@@ -44,8 +52,10 @@ pub fn instantiate(
     platform::contract::validate_addr(deps.querier, &new_lease.form.loan.lpp)?;
     platform::contract::validate_addr(deps.querier, &new_lease.form.loan.profit)?;
 
-    state::new_lease(deps.querier, info, new_lease)
-        .and_then(|(batch, next_state)| state::save(deps.storage, &next_state).map(|()| batch))
+    state::new_lease(deps.querier, &env, info, new_lease)
+        .and_then(|(response, next_state)| {
+            state::save(deps.storage, &next_state).map(|()| response)
+        })
         .map(response::response_only_messages)
         .inspect_err(platform_error::log(deps.api))
 }
@@ -99,7 +109,16 @@ pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> ContractResult<CwRespo
 #[entry_point]
 pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
-        QueryMsg::State { due_projection } => state::load(deps.storage)
+        QueryMsg::State { due_projection, at } => state::load(deps.storage)
+            .and_then(|state| {
+                let (now, due_projection) = at.map_or_else(
+                    || (env.block.time, Duration::from_secs(due_projection)),
+                    |at| (at, Duration::default()),
+                );
+                state.state(now, due_projection, deps.querier)
+            })
+            .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
+        QueryMsg::Payoff { due_projection } => state::load(deps.storage)
             .and_then(|state| {
                 state.state(
                     env.block.time,
@@ -107,12 +126,113 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary>
                     deps.querier,
                 )
             })
+            .and_then(payoff)
+            .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
+        QueryMsg::InterestSplit { at } => interest_split_at(at, env.block.time)
+            .and_then(|at| {
+                state::load(deps.storage)
+                    .and_then(|state| state.state(at, Duration::default(), deps.querier))
+            })
+            .and_then(interest_split)
+            .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
+        QueryMsg::Summary {} => state::load(deps.storage)
+            .and_then(|state| state.summary(env.block.time, deps.querier))
+            .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
+        QueryMsg::LiquidationPrice {} => state::load(deps.storage)
+            .and_then(|state| state.liquidation_price(env.block.time, deps.querier))
+            .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
+        QueryMsg::ClosePreview { position, sender } => state::load(deps.storage)
+            .and_then(|state| {
+                let info = MessageInfo {
+                    sender,
+                    funds: vec![],
+                };
+                match position {
+                    Some(spec) => state.close_position(spec, deps.querier, env, info),
+                    None => state.close(deps.querier, env, info),
+                }
+            })
+            .map(|Response { response, .. }| close_preview(&response))
             .and_then(|resp| to_json_binary(&resp).map_err(Into::into)),
         QueryMsg::ProtocolPackageRelease {} => to_json_binary(&CURRENT_RELEASE).map_err(Into::into),
     }
     .inspect_err(platform_error::log(deps.api))
 }
 
+fn payoff(state: StateResponse) -> ContractResult<PayoffResponse> {
+    if let StateResponse::Opened {
+        principal_due,
+        overdue_margin,
+        overdue_interest,
+        due_margin,
+        due_interest,
+        due_projection,
+        validity,
+        ..
+    } = state
+    {
+        [overdue_margin, overdue_interest, due_margin, due_interest]
+            .into_iter()
+            .try_fold(principal_due, LpnCoinDTO::checked_add)
+            .map(|amount| PayoffResponse {
+                amount,
+                at: validity + due_projection,
+            })
+            .map_err(Into::into)
+    } else {
+        Err(ContractError::unsupported_operation("payoff"))
+    }
+}
+
+fn interest_split_at(at: Option<Timestamp>, now: Timestamp) -> ContractResult<Timestamp> {
+    at.map_or(Ok(now), |at| {
+        if at < now {
+            Err(ContractError::InterestSplitInPast { at, now })
+        } else {
+            Ok(at)
+        }
+    })
+}
+
+fn interest_split(state: StateResponse) -> ContractResult<InterestSplitResponse> {
+    if let StateResponse::Opened {
+        overdue_margin,
+        overdue_interest,
+        due_margin,
+        due_interest,
+        validity,
+        ..
+    } = state
+    {
+        overdue_interest
+            .checked_add(due_interest)
+            .and_then(|lender_interest| {
+                overdue_margin
+                    .checked_add(due_margin)
+                    .map(|protocol_margin| InterestSplitResponse {
+                        lender_interest,
+                        protocol_margin,
+                        at: validity,
+                    })
+            })
+            .map_err(Into::into)
+    } else {
+        Err(ContractError::unsupported_operation("interest split"))
+    }
+}
+
+fn close_preview(response: &MessageResponse) -> ClosePreviewResponse {
+    let messages = response.messages();
+    ClosePreviewResponse {
+        messages: messages
+            .message_kinds()
+            .into_iter()
+            .zip(messages.message_targets())
+            .map(|(kind, target)| MessagePreview { kind, target })
+            .collect(),
+    }
+}
+
 fn process_lease<ProcFn>(
     storage: &mut dyn Storage,
     process_fn: ProcFn,
@@ -137,9 +257,20 @@ fn process_execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Repay() => state.repay(querier, env, info),
+        ExecuteMsg::AddCollateral() => state.add_collateral(querier, env, info),
+        ExecuteMsg::Deleverage { target_ltv } => state.deleverage(target_ltv, querier, env, info),
         ExecuteMsg::ChangeClosePolicy(change) => {
             state.change_close_policy(change, querier, env, info)
         }
+        ExecuteMsg::RemoveClosePolicy() => state.change_close_policy(
+            ClosePolicyChange {
+                stop_loss: Some(ChangeCmd::Reset),
+                take_profit: Some(ChangeCmd::Reset),
+            },
+            querier,
+            env,
+            info,
+        ),
         ExecuteMsg::ClosePosition(spec) => state.close_position(spec, querier, env, info),
         ExecuteMsg::Close() => state.close(querier, env, info),
         ExecuteMsg::TimeAlarm {} => state.on_time_alarm(querier, env, info),
@@ -178,3 +309,56 @@ fn process_sudo(
         _ => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use platform::{
+        batch::{Batch, MsgKind},
+        message::Response as MessageResponse,
+    };
+    use sdk::{
+        cosmwasm_ext::{CosmosMsg, InterChainMsg},
+        cosmwasm_std::{Addr, BankMsg},
+    };
+
+    use crate::api::query::{ClosePreviewResponse, MessagePreview};
+
+    #[test]
+    fn close_preview() {
+        let mut messages = Batch::default();
+        messages.schedule_execute_no_reply(CosmosMsg::Custom(
+            InterChainMsg::RegisterInterchainAccount {
+                connection_id: "connection-0".into(),
+                interchain_account_id: "0".into(),
+                register_fee: None,
+            },
+        ));
+        messages
+            .schedule_execute_wasm_no_reply_no_funds(Addr::unchecked("lpp"), "repay")
+            .unwrap();
+        messages.schedule_execute_no_reply(BankMsg::Send {
+            to_address: "customer".into(),
+            amount: vec![],
+        });
+
+        assert_eq!(
+            ClosePreviewResponse {
+                messages: vec![
+                    MessagePreview {
+                        kind: MsgKind::InterChain,
+                        target: None,
+                    },
+                    MessagePreview {
+                        kind: MsgKind::WasmExecute,
+                        target: Some("lpp".into()),
+                    },
+                    MessagePreview {
+                        kind: MsgKind::BankSend,
+                        target: Some("customer".into()),
+                    },
+                ],
+            },
+            super::close_preview(&MessageResponse::messages_only(messages))
+        );
+    }
+}
@@ -20,6 +20,11 @@ impl FinalizerRef {
             .map_err(Into::into)
     }
 
+    #[cfg(test)]
+    pub(super) fn unchecked(addr: Addr) -> Self {
+        Self { addr }
+    }
+
     pub(super) fn notify(&self, customer: Addr) -> ContractResult<Batch> {
         let mut msgs = Batch::default();
         msgs.schedule_execute_wasm_no_reply_no_funds(
@@ -29,4 +34,14 @@ impl FinalizerRef {
         .map(|()| msgs)
         .map_err(Into::into)
     }
+
+    pub(super) fn notify_opened(&self) -> ContractResult<Batch> {
+        let mut msgs = Batch::default();
+        msgs.schedule_execute_wasm_no_reply_no_funds(
+            self.addr.clone(),
+            &FinalizerExecuteMsg::LeaseOpened {},
+        )
+        .map(|()| msgs)
+        .map_err(Into::into)
+    }
 }
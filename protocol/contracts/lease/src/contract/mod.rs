@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use dex::{Account, ConnectionParams, DexConnectable};
-use sdk::cosmwasm_std::QuerierWrapper;
+use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
 
 use crate::{
     lease::{with_lease::WithLease, LeaseDTO},
@@ -24,6 +24,9 @@ pub(crate) struct Lease {
     lease: LeaseDTO,
     dex: Account,
     finalizer: FinalizerRef,
+    /// When the last liquidation has started, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_liquidation: Option<Timestamp>,
 }
 
 pub(crate) trait SplitDTOOut {
@@ -38,6 +41,27 @@ impl Lease {
             lease,
             dex,
             finalizer,
+            last_liquidation: None,
+        }
+    }
+
+    /// The earliest time a next liquidation may start at
+    ///
+    /// A liquidation may start any time if none has been started so far.
+    fn liquidation_allowed_from(&self) -> Option<Timestamp> {
+        self.last_liquidation.map(|last_liquidation| {
+            Timestamp::from_nanos(
+                last_liquidation
+                    .nanos()
+                    .saturating_add(self.lease.position.liquidation_cooldown().nanos()),
+            )
+        })
+    }
+
+    fn start_liquidation(self, now: Timestamp) -> Self {
+        Self {
+            last_liquidation: Some(now),
+            ..self
         }
     }
 
@@ -56,7 +80,7 @@ impl Lease {
     {
         self.lease.execute(cmd, querier).map(|result| {
             let (lease, other) = result.split_into();
-            (Self::new(lease, self.dex, self.finalizer), other)
+            (Self { lease, ..self }, other)
         })
     }
 }
@@ -1,17 +1,26 @@
 use currency::{CurrencyDef, MemberOf};
+use dex::{Account, DexConnectable as _};
+use finance::{
+    error::Result as FinanceResult,
+    interest,
+    liability::{Level, Zone},
+};
+use platform::ica::HostAccount;
 
 use crate::{
     api::{
-        query::{opened, paid, StateResponse},
+        query::{opened, paid, DexAccount, LiabilityZone, StateResponse, SummaryResponse},
         LeaseAssetCurrencies,
     },
-    lease::{LeaseDTO, State},
+    lease::{Health, LeaseDTO, State},
 };
 
 impl StateResponse {
     pub fn opened_from<Asset>(
         open_lease: State<Asset>,
         in_progress: Option<opened::OngoingTrx>,
+        needs_realarm: bool,
+        dex_account: DexAccount,
     ) -> Self
     where
         Asset: CurrencyDef,
@@ -31,6 +40,8 @@ impl StateResponse {
             close_policy: open_lease.close_policy,
             validity: open_lease.validity,
             in_progress,
+            needs_realarm,
+            dex_account,
         }
     }
 
@@ -41,3 +52,53 @@ impl StateResponse {
         }
     }
 }
+
+impl SummaryResponse {
+    pub(crate) fn from_state<Asset>(
+        open_lease: State<Asset>,
+        health: Health,
+        in_progress: Option<opened::OngoingTrx>,
+    ) -> FinanceResult<Self>
+    where
+        Asset: CurrencyDef,
+        Asset::Group: MemberOf<LeaseAssetCurrencies>,
+    {
+        interest::effective_apr(open_lease.interest_rate, open_lease.interest_rate_margin).map(
+            |effective_annual_interest_rate| Self {
+                amount: open_lease.amount.into(),
+                principal_due: open_lease.principal_due.into(),
+                overdue_margin: open_lease.overdue_margin.into(),
+                overdue_interest: open_lease.overdue_interest.into(),
+                due_margin: open_lease.due_margin.into(),
+                due_interest: open_lease.due_interest.into(),
+                effective_annual_interest_rate,
+                ltv: health.ltv,
+                liability_zone: health.zone.map(Into::into),
+                grace_period_end: open_lease.validity + open_lease.overdue_collect_in,
+                at: open_lease.validity,
+                in_progress,
+            },
+        )
+    }
+}
+
+impl From<Zone> for LiabilityZone {
+    fn from(zone: Zone) -> Self {
+        Self {
+            level: zone.low().map_or(0, Level::ordinal),
+            low: zone.low().map(Into::into),
+            high: zone.high().into(),
+        }
+    }
+}
+
+impl From<Account> for DexAccount {
+    fn from(account: Account) -> Self {
+        let connection = account.dex().clone();
+
+        Self {
+            ica_account: HostAccount::from(account).into(),
+            connection,
+        }
+    }
+}
@@ -6,9 +6,9 @@ use platform::state_machine;
 use sdk::cosmwasm_std::{Binary, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
 
 use crate::{
-    api::query::StateResponse as QueryStateResponse,
+    api::query::{StateResponse as QueryStateResponse, SummaryResponse},
     contract::{api::Contract, state::StateResponse as ContractStateResponse},
-    error::ContractResult,
+    error::{ContractError, ContractResult},
 };
 
 use super::{Response, State as ContractState};
@@ -19,6 +19,22 @@ pub(crate) struct State<H> {
     handler: H,
 }
 
+/// Summary of the lease in a dex state
+///
+/// Supported only by the states of a transaction in progress on an opened lease.
+pub(crate) trait Summary
+where
+    Self: Sized,
+{
+    fn summary(
+        self,
+        _now: Timestamp,
+        _querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        Err(ContractError::unsupported_operation("summary"))
+    }
+}
+
 impl<H> State<H> {
     pub fn new(handler: H) -> Self {
         Self { handler }
@@ -29,6 +45,7 @@ impl<H> Contract for State<H>
 where
     H: DexHandler<SwapResult = ContractResult<Response>>,
     H: DexContract<StateResponse = ContractResult<QueryStateResponse>>,
+    H: Summary,
     H::Response: Into<ContractState>,
     Self: Into<ContractState>,
 {
@@ -100,6 +117,14 @@ where
         self.handler.state(now, due_projection, querier)
     }
 
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        self.handler.summary(now, querier)
+    }
+
     fn reply(self, querier: QuerierWrapper<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
         self.handler
             .reply(querier, env, msg)
@@ -8,19 +8,36 @@ use crate::{
 
 pub(crate) struct LiquidationEmitter<'liq, 'env> {
     cause: &'liq Cause,
+    full: bool,
     amount: LeaseCoin,
     env: &'env Env,
 }
 
 impl<'liq, 'env> LiquidationEmitter<'liq, 'env> {
-    pub fn new(cause: &'liq Cause, amount: LeaseCoin, env: &'env Env) -> Self {
-        Self { cause, amount, env }
+    pub fn partial(cause: &'liq Cause, amount: LeaseCoin, env: &'env Env) -> Self {
+        Self {
+            cause,
+            full: false,
+            amount,
+            env,
+        }
+    }
+
+    pub fn full(cause: &'liq Cause, amount: LeaseCoin, env: &'env Env) -> Self {
+        Self {
+            cause,
+            full: true,
+            amount,
+            env,
+        }
     }
 }
 impl RepayEmitter for LiquidationEmitter<'_, '_> {
     fn emit(self, lease: &Addr, receipt: &RepayReceipt) -> Emitter {
         let emitter = emit_payment_int(Type::Liquidation, self.env, lease, receipt);
-        emit_liquidation_cause(emitter, self.cause).emit_coin_dto("amount", &self.amount)
+        emit_liquidation_cause(emitter, self.cause)
+            .emit("kind", if self.full { "full" } else { "partial" })
+            .emit_coin_dto("amount", &self.amount)
     }
 }
 
@@ -71,6 +88,12 @@ pub(super) fn emit_auto_close(strategy: CloseStrategy, env: &Env, lease: &Addr)
     }
 }
 
+pub(super) fn emit_undersized_close(env: &Env, lease: &Addr) -> Emitter {
+    Emitter::of_type(Type::UndersizedClosePosition)
+        .emit_tx_info(env)
+        .emit("to", lease)
+}
+
 fn emit_liquidation_cause(emitter: Emitter, cause: &Cause) -> Emitter {
     match cause {
         Cause::Liability { ltv, healthy_ltv } => emitter
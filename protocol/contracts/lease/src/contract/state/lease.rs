@@ -1,4 +1,4 @@
-use finance::duration::Duration;
+use finance::{duration::Duration, percent::Percent};
 use serde::{Deserialize, Serialize};
 
 use sdk::cosmwasm_std::{Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
@@ -6,7 +6,7 @@ use sdk::cosmwasm_std::{Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
 use crate::{
     api::{
         position::{ClosePolicyChange, PositionClose},
-        query::StateResponse,
+        query::{LiquidationPriceResponse, StateResponse, SummaryResponse},
     },
     error::ContractResult,
 };
@@ -38,6 +38,22 @@ where
         self.handler.state(now, due_projection, querier)
     }
 
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        self.handler.summary(now, querier)
+    }
+
+    fn liquidation_price(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<LiquidationPriceResponse> {
+        self.handler.liquidation_price(now, querier)
+    }
+
     fn reply(self, querier: QuerierWrapper<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
         self.handler.reply(querier, env, msg)
     }
@@ -51,6 +67,25 @@ where
         self.handler.repay(querier, env, info)
     }
 
+    fn add_collateral(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.handler.add_collateral(querier, env, info)
+    }
+
+    fn deleverage(
+        self,
+        target_ltv: Percent,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.handler.deleverage(target_ltv, querier, env, info)
+    }
+
     fn change_close_policy(
         self,
         change: ClosePolicyChange,
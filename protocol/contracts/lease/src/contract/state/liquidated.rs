@@ -3,12 +3,24 @@ use serde::{Deserialize, Serialize};
 
 use sdk::cosmwasm_std::{Env, MessageInfo, QuerierWrapper, Timestamp};
 
-use crate::{api::query::StateResponse, error::ContractResult};
+use crate::{api::query::StateResponse, error::ContractResult, position::Cause};
 
 use super::{drain::DrainAll, Handler, Response};
 
-#[derive(Serialize, Deserialize, Default)]
-pub struct Liquidated {}
+#[derive(Serialize, Deserialize)]
+pub struct Liquidated {
+    /// What has triggered the liquidation, a price drop or an interest overdue
+    ///
+    /// Not present on leases liquidated before the cause has been recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cause: Option<Cause>,
+}
+
+impl Liquidated {
+    pub fn new(cause: Cause) -> Self {
+        Self { cause: Some(cause) }
+    }
+}
 
 impl Handler for Liquidated {
     fn state(
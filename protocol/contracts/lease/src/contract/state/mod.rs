@@ -1,10 +1,10 @@
 use std::str;
 
 use enum_dispatch::enum_dispatch;
-use finance::duration::Duration;
+use finance::{duration::Duration, percent::Percent};
 use serde::{Deserialize, Serialize};
 
-use platform::{batch::Batch, message::Response as MessageResponse};
+use platform::message::Response as MessageResponse;
 use sdk::{
     cosmwasm_std::{Binary, Env, MessageInfo, QuerierWrapper, Reply, Storage, Timestamp},
     cw_storage_plus::Item,
@@ -15,14 +15,17 @@ use crate::{
     api::{
         open::NewLeaseContract,
         position::{ClosePolicyChange, PositionClose},
-        query::StateResponse,
+        query::{LiquidationPriceResponse, StateResponse, SummaryResponse},
     },
     contract::api::Contract,
     error::ContractResult,
 };
 
 pub(crate) use self::handler::{Handler, Response};
-use self::{dex::State as DexState, lease::State as LeaseState};
+use self::{
+    dex::{State as DexState, Summary as DexSummary},
+    lease::State as LeaseState,
+};
 
 mod closed;
 mod dex;
@@ -35,6 +38,10 @@ mod opened;
 mod opening;
 mod paid;
 mod resp_delivery;
+#[cfg(test)]
+mod testing;
+
+type PendingOrder = LeaseState<opening::order::PendingOrder>;
 
 type RequestLoan = LeaseState<opening::request_loan::RequestLoan>;
 
@@ -44,6 +51,8 @@ type OpenedActive = LeaseState<opened::active::Active>;
 
 type BuyLpn = DexState<opened::repay::buy_lpn::DexState>;
 
+type AddCollateral = DexState<opened::collateral::DexState>;
+
 type PartialLiquidation = DexState<opened::close::liquidation::partial::DexState>;
 
 type FullLiquidation = DexState<opened::close::liquidation::full::DexState>;
@@ -52,6 +61,8 @@ type PartialClose = DexState<opened::close::customer_close::partial::DexState>;
 
 type FullClose = DexState<opened::close::customer_close::full::DexState>;
 
+type PayoutSwap = DexState<opened::close::customer_close::payout::DexState>;
+
 type PaidActive = LeaseState<paid::Active>;
 
 type ClosingTransferIn = DexState<paid::transfer_in::DexState>;
@@ -79,6 +90,9 @@ pub(crate) enum State {
     ClosingTransferIn,
     Closed,
     Liquidated,
+    AddCollateral,
+    PendingOrder,
+    PayoutSwap,
 }
 
 const STATE_DB_ITEM: Item<State> = Item::new("state");
@@ -93,11 +107,16 @@ pub(super) fn save(storage: &mut dyn Storage, next_state: &State) -> ContractRes
 
 pub(super) fn new_lease(
     querier: QuerierWrapper<'_>,
+    env: &Env,
     info: MessageInfo,
-    spec: NewLeaseContract,
-) -> ContractResult<(Batch, State)> {
-    opening::request_loan::RequestLoan::new(querier, info, spec)
-        .map(|(batch, start_state)| (batch, start_state.into()))
+    mut spec: NewLeaseContract,
+) -> ContractResult<(MessageResponse, State)> {
+    match spec.form.open_order.take() {
+        Some(order) => opening::order::PendingOrder::new(querier, env, info.funds, spec, order)
+            .map(|(response, start_state)| (response, start_state.into())),
+        None => opening::request_loan::RequestLoan::new(querier, info.funds, spec)
+            .map(|(batch, start_state)| (batch.into(), start_state.into())),
+    }
 }
 
 fn ignore_msg<S>(state: S) -> ContractResult<Response>
@@ -109,10 +128,17 @@ where
 
 mod impl_from {
     use super::{
-        BuyAsset, BuyLpn, Closed, ClosingTransferIn, FullClose, FullLiquidation, Liquidated,
-        OpenedActive, PaidActive, PartialClose, PartialLiquidation, RequestLoan, State,
+        AddCollateral, BuyAsset, BuyLpn, Closed, ClosingTransferIn, FullClose, FullLiquidation,
+        Liquidated, OpenedActive, PaidActive, PartialClose, PartialLiquidation, PayoutSwap,
+        PendingOrder, RequestLoan, State,
     };
 
+    impl From<super::opening::order::PendingOrder> for State {
+        fn from(value: super::opening::order::PendingOrder) -> Self {
+            PendingOrder::new(value).into()
+        }
+    }
+
     impl From<super::opening::request_loan::RequestLoan> for State {
         fn from(value: super::opening::request_loan::RequestLoan) -> Self {
             RequestLoan::new(value).into()
@@ -137,6 +163,12 @@ mod impl_from {
         }
     }
 
+    impl From<super::opened::collateral::DexState> for State {
+        fn from(value: super::opened::collateral::DexState) -> Self {
+            AddCollateral::new(value).into()
+        }
+    }
+
     impl From<super::opened::close::liquidation::partial::DexState> for State {
         fn from(value: super::opened::close::liquidation::partial::DexState) -> Self {
             PartialLiquidation::new(value).into()
@@ -161,6 +193,12 @@ mod impl_from {
         }
     }
 
+    impl From<super::opened::close::customer_close::payout::DexState> for State {
+        fn from(value: super::opened::close::customer_close::payout::DexState) -> Self {
+            PayoutSwap::new(value).into()
+        }
+    }
+
     impl From<super::paid::Active> for State {
         fn from(value: super::paid::Active) -> Self {
             PaidActive::new(value).into()
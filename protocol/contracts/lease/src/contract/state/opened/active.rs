@@ -1,31 +1,36 @@
-use currency::never;
+use currency::{never, Group as _};
 use serde::{Deserialize, Serialize};
 
 use dex::Enterable;
-use finance::{coin::IntoDTO, duration::Duration};
-use platform::{bank, batch::Emitter, message::Response as MessageResponse};
-use sdk::cosmwasm_std::{Coin as CwCoin, Env, MessageInfo, QuerierWrapper, Timestamp};
+use finance::{coin::IntoDTO, duration::Duration, percent::Percent};
+use platform::{bank, batch::Emitter, coin_legacy, message::Response as MessageResponse};
+use sdk::cosmwasm_std::{
+    Coin as CwCoin, Env, MessageInfo, QuerierWrapper, Reply, SubMsgResult, Timestamp,
+};
 
 use crate::{
     api::{
         position::{ClosePolicyChange, PositionClose},
-        query::StateResponse,
+        query::{LiquidationPriceResponse, StateResponse, SummaryResponse},
         DownpaymentCoin,
     },
     contract::{
         cmd::{
-            ChangeClosePolicy, CloseStatusCmd, CloseStatusDTO, ObtainPayment, OpenLoanRespResult,
+            ChangeClosePolicy, CloseStatusCmd, CloseStatusDTO, Deleverage, ObtainPayment,
+            OpenLoanRespResult,
         },
         state::{Handler, Response},
         Lease,
     },
     error::{ContractError, ContractResult},
-    finance::LpnCurrencies,
+    finance::{LpnCoin, LpnCurrencies},
+    position::PRICE_ALARM_REPLY_ID,
 };
 
 use super::{
     alarm, balance,
     close::{customer_close, liquidation},
+    collateral::{self, DexState as CollateralState},
     event,
     repay::{
         self,
@@ -36,11 +41,17 @@ use super::{
 #[derive(Serialize, Deserialize)]
 pub struct Active {
     lease: Lease,
+    /// The last price alarm registration failed and should be retried
+    #[serde(default)]
+    needs_realarm: bool,
 }
 
 impl Active {
     pub(in super::super) fn new(lease: Lease) -> Self {
-        Self { lease }
+        Self {
+            lease,
+            needs_realarm: false,
+        }
     }
 
     pub(in super::super) fn emit_opened(
@@ -102,7 +113,11 @@ impl Active {
         self.try_on_alarm(querier, env)
     }
 
-    fn try_on_alarm(self, querier: QuerierWrapper<'_>, env: &Env) -> ContractResult<Response> {
+    pub(super) fn try_on_alarm(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
         let time_alarms_ref = self.lease.lease.time_alarms.clone();
         let oracle_ref = self.lease.lease.oracle.clone();
         let close_status = self.lease.lease.clone().execute(
@@ -119,7 +134,7 @@ impl Active {
                 alarms,
             } => Ok(Response::from(
                 alarm::build_resp(&self.lease, current_liability, alarms),
-                self,
+                Self::new(self.lease),
             )),
             CloseStatusDTO::NeedLiquidation(liquidation) => liquidation::start(
                 self.lease,
@@ -134,6 +149,53 @@ impl Active {
         }
     }
 
+    fn try_add_collateral(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+
+        self.lease
+            .lease
+            .clone()
+            .execute(ObtainPayment::new(info.funds), querier)
+            .and_then(|collateral| {
+                let add_collateral = collateral::start(self.lease, collateral);
+                add_collateral
+                    .enter(env.block.time, querier)
+                    .map(|batch| Response::from(batch, CollateralState::from(add_collateral)))
+                    .map_err(Into::into)
+            })
+    }
+
+    fn try_deleverage(
+        self,
+        target_ltv: Percent,
+        querier: QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+
+        let payment = match info.funds.as_slice() {
+            [cw_payment] => coin_legacy::from_cosmwasm_checked::<LpnCurrencies>(cw_payment)
+                .map_err(ContractError::from),
+            cw_payments => Err(no_lpn_payment(cw_payments)),
+        }?;
+        let payment_lpn = LpnCoin::try_from(payment)?;
+
+        self.lease
+            .lease
+            .clone()
+            .execute(
+                Deleverage::new(target_ltv, payment_lpn, &env.block.time),
+                querier,
+            )
+            .and_then(|repayment| repay::deleverage(self.lease, repayment, payment, env, querier))
+    }
+
     fn start_swap(
         self,
         cw_amount: Vec<CwCoin>,
@@ -154,6 +216,15 @@ impl Active {
     }
 }
 
+/// The error on none of the received coins being in the loan currency
+fn no_lpn_payment(cw_amount: &[CwCoin]) -> ContractError {
+    cw_amount
+        .first()
+        .map_or_else(ContractError::NoPaymentError, |cw_coin| {
+            ContractError::UnsupportedPaymentCurrency(cw_coin.denom.clone(), LpnCurrencies::DESCR)
+        })
+}
+
 impl From<Active> for Lease {
     fn from(value: Active) -> Self {
         value.lease
@@ -167,7 +238,49 @@ impl Handler for Active {
         due_projection: Duration,
         querier: QuerierWrapper<'_>,
     ) -> ContractResult<StateResponse> {
-        super::lease_state(self.lease, None, now, due_projection, querier)
+        super::lease_state(
+            self.lease,
+            None,
+            self.needs_realarm,
+            now,
+            due_projection,
+            querier,
+        )
+    }
+
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        super::lease_summary(self.lease, now, querier)
+    }
+
+    fn liquidation_price(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<LiquidationPriceResponse> {
+        super::lease_liquidation_price(self.lease, now, querier)
+    }
+
+    fn reply(
+        self,
+        _querier: QuerierWrapper<'_>,
+        _env: Env,
+        msg: Reply,
+    ) -> ContractResult<Response> {
+        // the price alarms are registered with a reply on error only
+        match msg.result {
+            SubMsgResult::Err(_) if msg.id == PRICE_ALARM_REPLY_ID => Ok(Response::from(
+                MessageResponse::default(),
+                Self {
+                    needs_realarm: true,
+                    ..self
+                },
+            )),
+            _ => Err(ContractError::unsupported_operation("reply")),
+        }
     }
 
     fn repay(
@@ -179,6 +292,25 @@ impl Handler for Active {
         self.try_repay(querier, &env, info)
     }
 
+    fn add_collateral(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_add_collateral(querier, &env, info)
+    }
+
+    fn deleverage(
+        self,
+        target_ltv: Percent,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_deleverage(target_ltv, querier, &env, info)
+    }
+
     fn change_close_policy(
         self,
         change: ClosePolicyChange,
@@ -247,10 +379,12 @@ impl Handler for Active {
     ) -> ContractResult<Response> {
         let lease_addr = self.lease.lease.addr.clone();
         balance::lpn_balance(&lease_addr, querier).and_then(|balance| {
-            if balance.is_zero() {
-                Err(ContractError::InconsistencyNotDetected())
-            } else {
+            if !balance.is_zero() {
                 repay::repay(self.lease, balance, &env, querier)
+            } else if self.needs_realarm {
+                self.try_on_alarm(querier, &env)
+            } else {
+                Err(ContractError::InconsistencyNotDetected())
             }
         })
     }
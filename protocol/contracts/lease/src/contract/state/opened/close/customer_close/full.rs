@@ -1,9 +1,17 @@
+use currency::CurrencyDef;
+use finance::{coin::Coin, percent::Percent};
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+    message::Response as MessageResponse,
+    result::Result as PlatformResult,
+};
 use profit::stub::ProfitStub;
-use sdk::cosmwasm_std::Env;
+use sdk::cosmwasm_std::{Env, QuerierWrapper};
 
 use crate::{
     api::{
-        position::FullClose,
+        position::{FullClose, Payout},
         query::opened::{OngoingTrx, PositionCloseTrx},
         LeaseCoin,
     },
@@ -15,16 +23,39 @@ use crate::{
                 close::{self, Closable, IntoRepayable},
                 payment::{Close, CloseAlgo},
             },
+            Response,
         },
         Lease,
     },
+    error::{ContractError, ContractResult},
     event::Type,
+    finance::LpnCoin,
 };
 
+use super::payout;
+
 type Spec = FullClose;
 pub(in super::super) type RepayableImpl = Close<Spec>;
 pub(crate) type DexState = close::DexState<RepayableImpl>;
 
+pub(super) fn validate(spec: &Spec) -> ContractResult<()> {
+    spec.payout.as_ref().map_or(Ok(()), |payout| {
+        if payout.max_slippage < Percent::HUNDRED {
+            Ok(())
+        } else {
+            Err(ContractError::InvalidPayoutSlippage(payout.max_slippage))
+        }
+    })
+}
+
+/// The payout into a currency other than the loan currency, if such is requested
+fn effective_payout(spec: &Spec, lease: &Lease) -> Option<Payout> {
+    spec.payout
+        .as_ref()
+        .filter(|payout| payout.currency != lease.lease.loan.lpp().lpn())
+        .cloned()
+}
+
 impl IntoRepayable for Spec {
     type Repayable = RepayableImpl;
 
@@ -55,7 +86,7 @@ impl CloseAlgo for Spec {
 
     type ProfitSender = ProfitStub;
 
-    type ChangeSender = Self::ProfitSender;
+    type ChangeSender = ChangeSender;
 
     type PaymentEmitter<'this, 'env>
         = PositionCloseEmitter<'env>
@@ -63,12 +94,36 @@ impl CloseAlgo for Spec {
         Self: 'this,
         'env: 'this;
 
+    fn out_state(&self) -> Self::OutState {
+        Closed::default()
+    }
+
+    fn on_closed(
+        &self,
+        lease: Lease,
+        change: LpnCoin,
+        response: MessageResponse,
+        env: &Env,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<Response> {
+        match effective_payout(self, &lease) {
+            Some(payout) if !change.is_zero() => {
+                payout::start(lease, change, payout, response, env, querier)
+            }
+            _ => Ok(Response::from(response, self.out_state())),
+        }
+    }
+
     fn profit_sender(&self, lease: &Lease) -> Self::ProfitSender {
         lease.lease.loan.profit().clone().into_stub()
     }
 
     fn change_sender(&self, lease: &Lease) -> Self::ChangeSender {
-        Self::ChangeSender::new(lease.lease.customer.clone())
+        ChangeSender(
+            effective_payout(self, lease)
+                .is_none()
+                .then(|| LazySenderStub::new(lease.lease.customer.clone())),
+        )
     }
 
     fn emitter_fn<'this, 'lease, 'env>(
@@ -83,3 +138,21 @@ impl CloseAlgo for Spec {
         Self::PaymentEmitter::new(*self.amount(lease), env)
     }
 }
+
+/// Sends the change to the customer unless it is to be paid out in another currency
+pub(crate) struct ChangeSender(Option<LazySenderStub>);
+
+impl FixedAddressSender for ChangeSender {
+    fn send<C>(&mut self, amount: Coin<C>) -> PlatformResult<()>
+    where
+        C: CurrencyDef,
+    {
+        self.0.as_mut().map_or(Ok(()), |sender| sender.send(amount))
+    }
+}
+
+impl From<ChangeSender> for Batch {
+    fn from(sender: ChangeSender) -> Self {
+        sender.0.map(Into::into).unwrap_or_default()
+    }
+}
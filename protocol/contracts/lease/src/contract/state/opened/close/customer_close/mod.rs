@@ -16,6 +16,7 @@ use super::ClosePositionTask;
 
 pub mod full;
 pub mod partial;
+pub mod payout;
 
 pub(in super::super) fn start(
     close: PositionClose,
@@ -24,14 +25,16 @@ pub(in super::super) fn start(
     querier: QuerierWrapper<'_>,
 ) -> ContractResult<Response> {
     match close {
-        PositionClose::PartialClose(spec) => lease
-            .lease
-            .clone()
-            .execute(ValidateClosePosition::new(&spec), querier)
+        PositionClose::PartialClose(spec) => partial::validate(&spec)
+            .and_then(|()| {
+                lease
+                    .lease
+                    .clone()
+                    .execute(ValidateClosePosition::new(&spec, &env.block.time), querier)
+            })
+            .and_then(|()| spec.start(lease, MessageResponse::default(), env, querier)),
+        PositionClose::FullClose(spec) => full::validate(&spec)
             .and_then(|()| spec.start(lease, MessageResponse::default(), env, querier)),
-        PositionClose::FullClose(spec) => {
-            spec.start(lease, MessageResponse::default(), env, querier)
-        }
     }
 }
 
@@ -42,5 +45,21 @@ pub(in super::super) fn auto_start(
     querier: QuerierWrapper<'_>,
 ) -> ContractResult<Response> {
     let events = event::emit_auto_close(strategy, env, &lease.lease.addr);
-    FullClose {}.start(lease, events.into(), env, querier)
+    FullClose::default().start(lease, events.into(), env, querier)
+}
+
+/// Close fully a freshly opened position worth less than the minimum asset amount
+pub(in crate::contract::state) fn undersized_start(
+    lease: Lease,
+    curr_request_response: MessageResponse,
+    env: &Env,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let events = event::emit_undersized_close(env, &lease.lease.addr);
+    FullClose::default().start(
+        lease,
+        curr_request_response.merge_with(events),
+        env,
+        querier,
+    )
 }
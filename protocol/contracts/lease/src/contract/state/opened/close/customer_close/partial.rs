@@ -1,3 +1,4 @@
+use finance::{fraction::Fraction, percent::Percent, zero::Zero};
 use sdk::cosmwasm_std::Env;
 
 use crate::{
@@ -17,13 +18,25 @@ use crate::{
         },
         Lease,
     },
+    error::{ContractError, ContractResult},
     event::Type,
+    finance::LpnCoin,
 };
 
 type Spec = PartialClose;
 pub(in super::super) type RepayableImpl = Repay<Spec>;
 pub(crate) type DexState = close::DexState<RepayableImpl>;
 
+pub(super) fn validate(spec: &Spec) -> ContractResult<()> {
+    spec.repay_share.map_or(Ok(()), |repay_share| {
+        if repay_share <= Percent::HUNDRED {
+            Ok(())
+        } else {
+            Err(ContractError::InvalidRepayShare(repay_share))
+        }
+    })
+}
+
 impl IntoRepayable for Spec {
     type Repayable = RepayableImpl;
 
@@ -61,4 +74,13 @@ impl RepayAlgo for Spec {
     fn emitter_fn<'this, 'env>(&'this self, env: &'env Env) -> Self::PaymentEmitter<'this, 'env> {
         Self::PaymentEmitter::new(self.amount, env)
     }
+
+    fn split_proceeds(&self, proceeds: LpnCoin) -> (LpnCoin, LpnCoin) {
+        self.repay_share
+            .map_or((proceeds, LpnCoin::ZERO), |repay_share| {
+                // the remainder of the rounding goes to the repayment
+                let payout = (Percent::HUNDRED - repay_share).of(proceeds);
+                (proceeds - payout, payout)
+            })
+    }
 }
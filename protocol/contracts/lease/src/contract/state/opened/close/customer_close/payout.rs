@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+
+use currency::{AnyVisitor, AnyVisitorResult, CurrencyDTO, CurrencyDef, Group, MemberOf};
+use dex::{
+    Account, CoinVisitor, ContractInSwap, DexResult, Enterable, IterNext, IterState,
+    StartLocalLocalState, SwapState, SwapTask, TransferInFinishState, TransferInInitState,
+    TransferOutState,
+};
+use finance::{
+    coin::{Amount, Coin, CoinDTO, WithCoin, WithCoinResult},
+    duration::Duration,
+    fraction::Fraction,
+    percent::Percent,
+};
+use oracle::stub::{convert, SwapPath};
+use oracle_platform::error::Error as OracleError;
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::{Batch, Emit, Emitter},
+    message::Response as MessageResponse,
+};
+use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{
+        position::Payout,
+        query::{paying_out::OngoingTrx, StateResponse as QueryStateResponse},
+        LeasePaymentCurrencies, PaymentCoin,
+    },
+    contract::{
+        state::{
+            closed::Closed, resp_delivery::ForwardToDexEntry, DexSummary, Response, SwapClient,
+            SwapResult,
+        },
+        Lease,
+    },
+    error::{ContractError, ContractResult},
+    event::Type,
+    finance::{LpnCoin, LpnCoinDTO, LpnCurrencies, LpnCurrency, OracleRef},
+};
+
+type StartState =
+    StartLocalLocalState<BuyPayout, LeasePaymentCurrencies, SwapClient, ForwardToDexEntry>;
+pub(crate) type DexState =
+    dex::StateLocalOut<BuyPayout, LeasePaymentCurrencies, SwapClient, ForwardToDexEntry>;
+
+impl DexSummary for DexState {}
+
+/// Start swapping the change of a closed lease into the payout currency
+pub(super) fn start(
+    lease: Lease,
+    change: LpnCoin,
+    payout: Payout,
+    response: MessageResponse,
+    env: &Env,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let buy_payout: StartState = dex::start_local_local(BuyPayout::new(
+        lease,
+        change.into(),
+        payout.currency,
+        payout.max_slippage,
+    ));
+    buy_payout
+        .enter(env.block.time, querier)
+        .map(|batch| Response::from(response.merge_with(batch), DexState::from(buy_payout)))
+        .map_err(Into::into)
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct BuyPayout {
+    lease: Lease,
+    change: LpnCoinDTO,
+    currency: CurrencyDTO<LeasePaymentCurrencies>,
+    /// The maximum acceptable slippage against the market price at the swap time
+    max_slippage: Percent,
+}
+
+impl BuyPayout {
+    fn new(
+        lease: Lease,
+        change: LpnCoinDTO,
+        currency: CurrencyDTO<LeasePaymentCurrencies>,
+        max_slippage: Percent,
+    ) -> Self {
+        Self {
+            lease,
+            change,
+            currency,
+            max_slippage,
+        }
+    }
+
+    fn emit_ok(&self, env: &Env, payout: &PaymentCoin) -> Emitter {
+        Emitter::of_type(Type::Payout)
+            .emit_tx_info(env)
+            .emit("id", self.lease.lease.addr.clone())
+            .emit("customer", self.lease.lease.customer.clone())
+            .emit_coin_dto("change", &self.change)
+            .emit_coin_dto("payout", payout)
+    }
+}
+
+impl SwapTask for BuyPayout {
+    type InG = LpnCurrencies;
+    type OutG = LeasePaymentCurrencies;
+    type InOutG = LeasePaymentCurrencies;
+    type Label = Type;
+    type StateResponse = ContractResult<QueryStateResponse>;
+    type Result = SwapResult;
+
+    fn label(&self) -> Self::Label {
+        Type::PayoutSwap
+    }
+
+    fn dex_account(&self) -> &Account {
+        &self.lease.dex
+    }
+
+    fn oracle(&self) -> &impl SwapPath<Self::InOutG> {
+        &self.lease.lease.oracle
+    }
+
+    fn time_alarm(&self) -> &TimeAlarmsRef {
+        &self.lease.lease.time_alarms
+    }
+
+    fn out_currency(&self) -> CurrencyDTO<Self::OutG> {
+        self.currency
+    }
+
+    fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
+    where
+        Visitor: CoinVisitor<GIn = Self::InG, Result = IterNext>,
+    {
+        dex::on_coin(&self.change, visitor)
+    }
+
+    fn min_output<G>(&self, _coin_in: &CoinDTO<G>, querier: QuerierWrapper<'_>) -> DexResult<Amount>
+    where
+        G: Group + MemberOf<Self::InG>,
+    {
+        self.currency
+            .into_currency_type(MinPayout {
+                oracle: self.lease.lease.oracle.clone(),
+                change: self.change.as_specific(LpnCurrency::dto()),
+                max_slippage: self.max_slippage,
+                querier,
+            })
+            .map_err(Into::into)
+    }
+
+    fn finish(
+        self,
+        amount_out: CoinDTO<Self::OutG>,
+        env: &Env,
+        _querier: QuerierWrapper<'_>,
+    ) -> Self::Result {
+        let emitter = self.emit_ok(env, &amount_out);
+
+        amount_out
+            .with_coin(SendPayout(LazySenderStub::new(
+                self.lease.lease.customer.clone(),
+            )))
+            .map(|msgs| MessageResponse::messages_with_events(msgs, emitter))
+            .map(|response| Response::from(response, Closed::default()))
+    }
+}
+
+impl<DexState> ContractInSwap<DexState> for BuyPayout
+where
+    DexState: InProgressTrx,
+{
+    type StateResponse = <Self as SwapTask>::StateResponse;
+
+    fn state(
+        self,
+        _now: Timestamp,
+        _due_projection: Duration,
+        _querier: QuerierWrapper<'_>,
+    ) -> Self::StateResponse {
+        Ok(QueryStateResponse::PayingOut {
+            change: self.change,
+            payout_currency: self.currency,
+            in_progress: DexState::trx_in_progress(),
+        })
+    }
+}
+
+trait InProgressTrx {
+    fn trx_in_progress() -> OngoingTrx;
+}
+
+impl InProgressTrx for TransferOutState {
+    fn trx_in_progress() -> OngoingTrx {
+        OngoingTrx::TransferOut
+    }
+}
+
+impl InProgressTrx for SwapState {
+    fn trx_in_progress() -> OngoingTrx {
+        OngoingTrx::Swap
+    }
+}
+
+impl InProgressTrx for TransferInInitState {
+    fn trx_in_progress() -> OngoingTrx {
+        OngoingTrx::TransferInInit
+    }
+}
+
+impl InProgressTrx for TransferInFinishState {
+    fn trx_in_progress() -> OngoingTrx {
+        OngoingTrx::TransferInFinish
+    }
+}
+
+struct MinPayout<'q> {
+    oracle: OracleRef,
+    change: LpnCoin,
+    max_slippage: Percent,
+    querier: QuerierWrapper<'q>,
+}
+
+impl AnyVisitor<LeasePaymentCurrencies> for MinPayout<'_> {
+    type Output = Amount;
+    type Error = OracleError;
+
+    fn on<C>(self, _def: &CurrencyDTO<C::Group>) -> AnyVisitorResult<LeasePaymentCurrencies, Self>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<LeasePaymentCurrencies>,
+    {
+        convert::from_quote::<LpnCurrency, LpnCurrencies, C, LeasePaymentCurrencies>(
+            self.oracle,
+            self.change,
+            self.querier,
+        )
+        .map(|payout| (Percent::HUNDRED - self.max_slippage).of(payout).into())
+    }
+}
+
+struct SendPayout(LazySenderStub);
+
+impl WithCoin<LeasePaymentCurrencies> for SendPayout {
+    type Output = Batch;
+    type Error = ContractError;
+
+    fn on<C>(mut self, coin: Coin<C>) -> WithCoinResult<LeasePaymentCurrencies, Self>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<LeasePaymentCurrencies>,
+    {
+        self.0
+            .send(coin)
+            .map(|()| self.0.into())
+            .map_err(Into::into)
+    }
+}
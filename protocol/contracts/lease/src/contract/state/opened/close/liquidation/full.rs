@@ -63,6 +63,10 @@ impl CloseAlgo for Spec {
         Self: 'this,
         'env: 'this;
 
+    fn out_state(&self) -> Self::OutState {
+        Liquidated::new(self.cause)
+    }
+
     fn profit_sender(&self, lease: &Lease) -> Self::ProfitSender {
         lease.lease.loan.profit().clone().into_stub()
     }
@@ -80,6 +84,6 @@ impl CloseAlgo for Spec {
         'env: 'this,
         'this: 'lease,
     {
-        Self::PaymentEmitter::new(&self.cause, *self.amount(lease), env)
+        Self::PaymentEmitter::full(&self.cause, *self.amount(lease), env)
     }
 }
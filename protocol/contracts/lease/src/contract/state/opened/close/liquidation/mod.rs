@@ -1,8 +1,12 @@
 use platform::message::Response as MessageResponse;
-use sdk::cosmwasm_std::{Env, QuerierWrapper};
+use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
 
 use crate::{
-    contract::{cmd::LiquidationDTO, state::Response, Lease},
+    contract::{
+        cmd::LiquidationDTO,
+        state::{opened::active::Active, Response},
+        Lease,
+    },
     error::ContractResult,
 };
 
@@ -11,6 +15,14 @@ use super::ClosePositionTask;
 pub mod full;
 pub mod partial;
 
+/// Start a liquidation
+///
+/// A partial liquidation is deferred if the previous one has started less than the liquidation
+/// cooldown ago. A full liquidation is started immediately not to let the debt go bad.
+///
+/// A deferred liquidation leaves the lease active with a time alarm set at the cooldown end.
+/// The price alarms delivered meanwhile are not re-registered. They get restored,
+/// or the liquidation gets started, when the time alarm comes and the position is re-evaluated.
 pub(in crate::contract::state) fn start(
     lease: Lease,
     liquidation: LiquidationDTO,
@@ -18,9 +30,37 @@ pub(in crate::contract::state) fn start(
     env: &Env,
     querier: QuerierWrapper<'_>,
 ) -> ContractResult<Response> {
+    let now = env.block.time;
     match liquidation {
-        LiquidationDTO::Partial(spec) => spec.start(lease, curr_request_response, env, querier),
-        LiquidationDTO::Full(spec) => spec.start(lease, curr_request_response, env, querier),
+        LiquidationDTO::Partial(spec) => match lease.liquidation_allowed_from() {
+            Some(allowed_from) if now < allowed_from => {
+                defer(lease, allowed_from, curr_request_response)
+            }
+            _ => spec.start(
+                lease.start_liquidation(now),
+                curr_request_response,
+                env,
+                querier,
+            ),
+        },
+        LiquidationDTO::Full(spec) => spec.start(
+            lease.start_liquidation(now),
+            curr_request_response,
+            env,
+            querier,
+        ),
     }
-    .map_err(Into::into)
+}
+
+fn defer(
+    lease: Lease,
+    until: Timestamp,
+    curr_request_response: MessageResponse,
+) -> ContractResult<Response> {
+    lease
+        .lease
+        .time_alarms
+        .setup_alarm(until)
+        .map(|alarm| Response::from(curr_request_response.merge_with(alarm), Active::new(lease)))
+        .map_err(Into::into)
 }
@@ -12,13 +12,13 @@ use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
     api::{
-        query::{opened::PositionCloseTrx, StateResponse as QueryStateResponse},
+        query::{opened::PositionCloseTrx, StateResponse as QueryStateResponse, SummaryResponse},
         LeaseAssetCurrencies, LeasePaymentCurrencies,
     },
     contract::{
         state::{
             opened::{self, payment::Repayable},
-            SwapResult,
+            DexSummary, SwapResult,
         },
         Lease,
     },
@@ -27,7 +27,7 @@ use crate::{
     finance::LpnCurrencies,
 };
 
-use super::Closable;
+use super::{Closable, DexState};
 
 #[derive(Serialize, Deserialize)]
 pub(crate) struct SellAsset<RepayableT> {
@@ -41,6 +41,24 @@ impl<RepayableT> SellAsset<RepayableT> {
     }
 }
 
+impl<RepayableT> DexSummary for DexState<RepayableT>
+where
+    RepayableT: Closable + Repayable,
+{
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        opened::lease_summary_in_swap(
+            self,
+            |sell_asset: &SellAsset<RepayableT>| sell_asset.lease.lease.clone(),
+            now,
+            querier,
+        )
+    }
+}
+
 impl<RepayableT> SellAsset<RepayableT>
 where
     RepayableT: Closable,
@@ -53,7 +71,7 @@ where
         querier: QuerierWrapper<'_>,
     ) -> ContractResult<QueryStateResponse> {
         let trx = self.repayable.transaction(&self.lease, in_progress);
-        opened::lease_state(self.lease, Some(trx), now, due_projection, querier)
+        opened::lease_state(self.lease, Some(trx), false, now, due_projection, querier)
     }
 }
 
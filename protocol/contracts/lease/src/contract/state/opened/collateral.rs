@@ -0,0 +1,265 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use oracle::stub::SwapPath;
+use serde::{Deserialize, Serialize};
+
+use currency::CurrencyDTO;
+use dex::{
+    Account, CoinVisitor, ConnectionParams, Contract as DexContract, ContractInSwap,
+    DexConnectable, DexResult, IcaConnectee, InspectSpec, IterNext, IterState, SwapState, SwapTask,
+    TimeAlarm, TransferOut, TransferOutState,
+};
+use finance::{coin::CoinDTO, duration::Duration};
+use platform::{batch::Batch, message::Response as MessageResponse};
+use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{
+        query::{
+            opened::{CollateralTrx, OngoingTrx},
+            StateResponse as QueryStateResponse, SummaryResponse,
+        },
+        LeaseAssetCurrencies, LeasePaymentCurrencies, PaymentCoin,
+    },
+    contract::{
+        cmd::AddCollateral,
+        state::{
+            opened,
+            resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
+            DexSummary, Response, StateResponse as ContractStateResponse, SwapClient, SwapResult,
+        },
+        Lease,
+    },
+    error::ContractResult,
+    event::Type,
+    lease::LeaseDTO,
+};
+
+use super::{active::Active, event};
+
+pub(super) type StartState =
+    TransferOut<BuyCollateral, DexState, LeasePaymentCurrencies, SwapClient>;
+pub(crate) type DexState = dex::StateRemoteOut<
+    IcaOpened,
+    BuyCollateral,
+    LeasePaymentCurrencies,
+    SwapClient,
+    ForwardToDexEntry,
+    ForwardToDexEntryContinue,
+>;
+
+impl DexSummary for DexState {
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        opened::lease_summary_in_swap(
+            self,
+            |buy_collateral: &BuyCollateral| buy_collateral.lease.lease.clone(),
+            now,
+            querier,
+        )
+    }
+}
+
+/// Start transferring the collateral to the lease DEX account
+///
+/// The collateral is swapped to the lease currency there, unless it is already in it.
+pub(super) fn start(lease: Lease, collateral: PaymentCoin) -> StartState {
+    TransferOut::new(BuyCollateral::new(lease, collateral))
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct BuyCollateral {
+    lease: Lease,
+    collateral: PaymentCoin,
+}
+
+impl BuyCollateral {
+    fn new(lease: Lease, collateral: PaymentCoin) -> Self {
+        Self { lease, collateral }
+    }
+
+    fn query(
+        self,
+        in_progress: CollateralTrx,
+        now: Timestamp,
+        due_projection: Duration,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<ContractStateResponse> {
+        let in_progress = OngoingTrx::AddCollateral {
+            collateral: self.collateral,
+            in_progress,
+        };
+
+        opened::lease_state(
+            self.lease,
+            Some(in_progress),
+            false,
+            now,
+            due_projection,
+            querier,
+        )
+    }
+}
+
+impl SwapTask for BuyCollateral {
+    type InG = LeasePaymentCurrencies;
+    type OutG = LeaseAssetCurrencies;
+    type InOutG = LeasePaymentCurrencies;
+    type Label = Type;
+    type StateResponse = ContractResult<QueryStateResponse>;
+    type Result = SwapResult;
+
+    fn label(&self) -> Self::Label {
+        Type::CollateralSwap
+    }
+
+    fn dex_account(&self) -> &Account {
+        &self.lease.dex
+    }
+
+    fn oracle(&self) -> &impl SwapPath<Self::InOutG> {
+        &self.lease.lease.oracle
+    }
+
+    fn time_alarm(&self) -> &TimeAlarmsRef {
+        &self.lease.lease.time_alarms
+    }
+
+    fn out_currency(&self) -> CurrencyDTO<Self::OutG> {
+        self.lease.lease.position.amount().currency()
+    }
+
+    fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
+    where
+        Visitor: CoinVisitor<GIn = Self::InG, Result = IterNext>,
+    {
+        dex::on_coin(&self.collateral, visitor)
+    }
+
+    fn finish(
+        self,
+        amount_out: CoinDTO<Self::OutG>,
+        env: &Env,
+        querier: QuerierWrapper<'_>,
+    ) -> Self::Result {
+        debug_assert_eq!(amount_out.currency(), self.out_currency());
+
+        let emitter =
+            event::emit_collateral_added(env, &self.lease.lease, &self.collateral, &amount_out);
+        let profit = self.lease.lease.loan.profit().clone();
+        let time_alarms = self.lease.lease.time_alarms.clone();
+        let reserve = self.lease.lease.reserve.clone();
+        self.lease
+            .update(
+                AddCollateral::new(amount_out, env.block.time, profit, time_alarms, reserve),
+                querier,
+            )
+            .and_then(|(lease, accrual_snapshot)| {
+                Active::new(lease)
+                    .try_on_alarm(querier, env)
+                    .map(|resp| (accrual_snapshot, resp))
+            })
+            .map(|(accrual_snapshot, resp)| {
+                Response::from(
+                    MessageResponse::merge_all([
+                        MessageResponse::from(emitter),
+                        MessageResponse::from(accrual_snapshot),
+                        resp.response,
+                    ]),
+                    resp.next_state,
+                )
+            })
+    }
+}
+
+impl<DexState> ContractInSwap<DexState> for BuyCollateral
+where
+    DexState: InProgressTrx,
+{
+    type StateResponse = <Self as SwapTask>::StateResponse;
+
+    fn state(
+        self,
+        now: Timestamp,
+        due_projection: Duration,
+        querier: QuerierWrapper<'_>,
+    ) -> Self::StateResponse {
+        self.query(DexState::trx_in_progress(), now, due_projection, querier)
+    }
+}
+
+trait InProgressTrx {
+    fn trx_in_progress() -> CollateralTrx;
+}
+
+impl InProgressTrx for TransferOutState {
+    fn trx_in_progress() -> CollateralTrx {
+        CollateralTrx::TransferOut
+    }
+}
+
+impl InProgressTrx for SwapState {
+    fn trx_in_progress() -> CollateralTrx {
+        CollateralTrx::Swap
+    }
+}
+
+/// The DEX account of an opened lease exists already
+///
+/// It is the ICA connectee of the remote DEX flow. Since there is no account to open,
+/// the flow starts with the collateral transfer and this type cannot be instantiated.
+#[derive(Serialize, Deserialize)]
+pub(crate) enum IcaOpened {}
+
+impl IcaConnectee for IcaOpened {
+    type State = DexState;
+    type NextState = StartState;
+
+    fn connected(self, _ica_account: Account) -> Self::NextState {
+        match self {}
+    }
+}
+
+impl DexConnectable for IcaOpened {
+    fn dex(&self) -> &ConnectionParams {
+        match *self {}
+    }
+}
+
+impl TimeAlarm for IcaOpened {
+    fn setup_alarm(&self, _forr: Timestamp) -> DexResult<Batch> {
+        match *self {}
+    }
+}
+
+impl DexContract for IcaOpened {
+    type StateResponse = ContractResult<QueryStateResponse>;
+
+    fn state(
+        self,
+        _now: Timestamp,
+        _due_projection: Duration,
+        _querier: QuerierWrapper<'_>,
+    ) -> Self::StateResponse {
+        match self {}
+    }
+}
+
+impl InspectSpec<BuyCollateral, LeaseDTO> for IcaOpened {
+    fn inspect_spec<InspectFn>(&self, _inspect_fn: InspectFn) -> LeaseDTO
+    where
+        InspectFn: FnOnce(&BuyCollateral) -> LeaseDTO,
+    {
+        match *self {}
+    }
+}
+
+impl Display for IcaOpened {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> FmtResult {
+        match *self {}
+    }
+}
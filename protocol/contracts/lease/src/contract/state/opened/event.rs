@@ -3,7 +3,7 @@ use platform::batch::{Emit, Emitter};
 use sdk::cosmwasm_std::{Addr, Env};
 
 use crate::{
-    api::DownpaymentCoin,
+    api::{DownpaymentCoin, LeaseCoin, PaymentCoin},
     contract::{
         cmd::{OpenLoanRespResult, RepayEmitter},
         state::event as state_event,
@@ -45,6 +45,19 @@ impl RepayEmitter for PaymentEmitter<'_> {
     }
 }
 
+pub(super) fn emit_collateral_added(
+    env: &Env,
+    lease: &LeaseDTO,
+    collateral: &PaymentCoin,
+    amount: &LeaseCoin,
+) -> Emitter {
+    Emitter::of_type(Type::CollateralAdded)
+        .emit_tx_info(env)
+        .emit("to", lease.addr.clone())
+        .emit_coin_dto("collateral", collateral)
+        .emit_coin_dto("amount", amount)
+}
+
 pub(super) fn emit_liquidation_warning(lease: &LeaseDTO, level: &Level) -> Emitter {
     emit_lease(Emitter::of_type(Type::LiquidationWarning), lease)
         .emit_percent_amount("ltv", level.ltv())
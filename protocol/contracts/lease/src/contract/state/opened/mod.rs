@@ -1,16 +1,22 @@
+use dex::{Contract as DexContract, InspectSpec};
 use finance::duration::Duration;
 use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
 
 use crate::{
-    api::query::{opened::OngoingTrx, StateResponse},
-    contract::{cmd::LeaseState, Lease},
-    error::ContractResult,
+    api::query::{opened::OngoingTrx, LiquidationPriceResponse, StateResponse, SummaryResponse},
+    contract::{
+        cmd::{LeaseLiquidationPrice, LeaseState, LeaseSummary},
+        Lease,
+    },
+    error::{ContractError, ContractResult},
+    lease::LeaseDTO,
 };
 
 pub mod active;
 mod alarm;
 mod balance;
 pub mod close;
+pub mod collateral;
 mod event;
 mod payment;
 pub mod repay;
@@ -18,11 +24,58 @@ pub mod repay;
 fn lease_state(
     lease: Lease,
     in_progress: Option<OngoingTrx>,
+    needs_realarm: bool,
     now: Timestamp,
     due_projection: Duration,
     querier: QuerierWrapper<'_>,
 ) -> ContractResult<StateResponse> {
+    let Lease { lease, dex, .. } = lease;
+
+    lease.execute(
+        LeaseState::new(now, due_projection, in_progress, needs_realarm, dex.into()),
+        querier,
+    )
+}
+
+fn lease_summary(
+    lease: Lease,
+    now: Timestamp,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<SummaryResponse> {
+    lease.lease.execute(LeaseSummary::new(now, None), querier)
+}
+
+/// Summarize a lease with a swap in progress
+///
+/// The transaction in progress is reported as the state query does.
+fn lease_summary_in_swap<DexState, Task>(
+    dex_state: DexState,
+    lease_of: impl FnOnce(&Task) -> LeaseDTO,
+    now: Timestamp,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<SummaryResponse>
+where
+    DexState:
+        InspectSpec<Task, LeaseDTO> + DexContract<StateResponse = ContractResult<StateResponse>>,
+{
+    let lease = dex_state.inspect_spec(lease_of);
+
+    dex_state
+        .state(now, Duration::default(), querier)
+        .and_then(|state| match state {
+            StateResponse::Opened { in_progress, .. } => {
+                lease.execute(LeaseSummary::new(now, in_progress), querier)
+            }
+            _ => Err(ContractError::unsupported_operation("summary")),
+        })
+}
+
+fn lease_liquidation_price(
+    lease: Lease,
+    now: Timestamp,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<LiquidationPriceResponse> {
     lease
         .lease
-        .execute(LeaseState::new(now, due_projection, in_progress), querier)
+        .execute(LeaseLiquidationPrice::new(now), querier)
 }
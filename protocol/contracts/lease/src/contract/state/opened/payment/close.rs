@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use platform::bank::FixedAddressSender;
+use platform::{bank::FixedAddressSender, message::Response as MessageResponse};
 use sdk::cosmwasm_std::{Env, QuerierWrapper};
 
 use crate::{
@@ -9,19 +9,19 @@ use crate::{
         LeaseCoin,
     },
     contract::{
-        cmd::{FullClose as FullCloseCmd, RepayEmitter},
+        cmd::{FullClose as FullCloseCmd, FullCloseResult, RepayEmitter},
         state::{opened::close::Closable, Response, State},
         Lease,
     },
     error::ContractResult,
     event::Type,
-    finance::LpnCoinDTO,
+    finance::{LpnCoin, LpnCoinDTO},
 };
 
 use super::Repayable;
 
 pub(crate) trait CloseAlgo {
-    type OutState: Default + Into<State>;
+    type OutState: Into<State>;
 
     type ProfitSender: FixedAddressSender;
 
@@ -32,6 +32,23 @@ pub(crate) trait CloseAlgo {
         Self: 'this,
         'env: 'this;
 
+    fn out_state(&self) -> Self::OutState;
+
+    /// Proceed once the position has been closed
+    ///
+    /// The `change` is what has been left over to the customer. By default,
+    /// the close ends in [`Self::out_state`].
+    fn on_closed(
+        &self,
+        _lease: Lease,
+        _change: LpnCoin,
+        response: MessageResponse,
+        _env: &Env,
+        _querier: QuerierWrapper<'_>,
+    ) -> ContractResult<Response> {
+        Ok(Response::from(response, self.out_state()))
+    }
+
     fn profit_sender(&self, lease: &Lease) -> Self::ProfitSender;
     fn change_sender(&self, lease: &Lease) -> Self::ChangeSender;
     fn emitter_fn<'this, 'lease, 'env>(
@@ -96,13 +113,19 @@ where
             let emitter_fn = self.0.emitter_fn(&lease, env);
             lease
                 .lease
+                .clone()
                 .execute(
                     FullCloseCmd::new(amount, env.block.time, profit, reserve, change, emitter_fn),
                     querier,
                 )
-                .map(|liquidation_response| liquidation_response.merge_with(finalizer_msgs))
+                .and_then(|FullCloseResult { response, change }| {
+                    self.0.on_closed(lease, change, response, env, querier)
+                })
                 //make sure the finalizer messages go out last
-                .map(|response| Response::from(response, CloseAlgoT::OutState::default()))
+                .map(|mut close_resp| {
+                    close_resp.response = close_resp.response.merge_with(finalizer_msgs);
+                    close_resp
+                })
         })
     }
 }
@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use finance::zero::Zero;
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+    message::Response as MessageResponse,
+};
 use sdk::cosmwasm_std::{Env, QuerierWrapper};
 
 use crate::{
@@ -20,7 +26,7 @@ use crate::{
     },
     error::ContractResult,
     event::Type,
-    finance::LpnCoinDTO,
+    finance::{LpnCoin, LpnCoinDTO},
     position::CloseStrategy,
 };
 
@@ -34,6 +40,13 @@ pub(crate) trait RepayAlgo {
 
     fn repay_fn(&self) -> Self::RepayFn;
     fn emitter_fn<'this, 'env>(&'this self, env: &'env Env) -> Self::PaymentEmitter<'this, 'env>;
+
+    /// Split the proceeds into a repayment, return.0, and a payout to the customer, return.1
+    ///
+    /// By default, all proceeds go to the repayment.
+    fn split_proceeds(&self, proceeds: LpnCoin) -> (LpnCoin, LpnCoin) {
+        (proceeds, LpnCoin::ZERO)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -81,6 +94,9 @@ where
         let price_alarms = lease.lease.oracle.clone();
         let time_alarms = lease.lease.time_alarms.clone();
         let reserve = lease.lease.reserve.clone();
+        let (repayment, payout) = self.0.split_proceeds(amount.try_into()?);
+        let mut payout_sender = LazySenderStub::new(lease.lease.customer.clone());
+        payout_sender.send(payout)?;
         let (
             lease,
             RepayResult {
@@ -90,7 +106,7 @@ where
         ) = lease.update(
             RepayCmd::new(
                 self.0.repay_fn(),
-                amount,
+                repayment.into(),
                 &env.block.time,
                 self.0.emitter_fn(env),
                 profit,
@@ -99,6 +115,7 @@ where
             ),
             querier,
         )?;
+        let response = response.merge_with(Batch::from(payout_sender));
 
         match close_status {
             CloseStatusDTO::Paid => Ok(Response::from(response, paid::Active::new(lease))),
@@ -106,8 +123,10 @@ where
                 current_liability,
                 alarms,
             } => {
-                let response =
-                    alarm::build_resp(&lease, current_liability, alarms).merge_with(response);
+                let response = MessageResponse::merge_all([
+                    alarm::build_resp(&lease, current_liability, alarms),
+                    response,
+                ]);
                 Ok(Response::from(response, active::Active::new(lease)))
             }
             CloseStatusDTO::NeedLiquidation(liquidation) => {
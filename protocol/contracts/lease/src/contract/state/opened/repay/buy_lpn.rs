@@ -14,7 +14,7 @@ use crate::{
     api::{
         query::{
             opened::{OngoingTrx, RepayTrx},
-            StateResponse as QueryStateResponse,
+            StateResponse as QueryStateResponse, SummaryResponse,
         },
         LeasePaymentCurrencies, PaymentCoin,
     },
@@ -22,7 +22,7 @@ use crate::{
         state::{
             opened::{self, repay},
             resp_delivery::ForwardToDexEntry,
-            StateResponse as ContractStateResponse, SwapClient, SwapResult,
+            DexSummary, StateResponse as ContractStateResponse, SwapClient, SwapResult,
         },
         Lease,
     },
@@ -36,6 +36,21 @@ pub(super) type StartState =
 pub(crate) type DexState =
     dex::StateLocalOut<BuyLpn, LeasePaymentCurrencies, SwapClient, ForwardToDexEntry>;
 
+impl DexSummary for DexState {
+    fn summary(
+        self,
+        now: Timestamp,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<SummaryResponse> {
+        opened::lease_summary_in_swap(
+            self,
+            |buy_lpn: &BuyLpn| buy_lpn.lease.lease.clone(),
+            now,
+            querier,
+        )
+    }
+}
+
 pub(in super::super) fn start(lease: Lease, payment: PaymentCoin) -> StartState {
     dex::start_local_local(BuyLpn::new(lease, payment))
 }
@@ -63,7 +78,14 @@ impl BuyLpn {
             in_progress,
         };
 
-        opened::lease_state(self.lease, Some(in_progress), now, due_projection, querier)
+        opened::lease_state(
+            self.lease,
+            Some(in_progress),
+            false,
+            now,
+            due_projection,
+            querier,
+        )
     }
 }
 
@@ -3,7 +3,7 @@ use sdk::cosmwasm_std::{Env, QuerierWrapper};
 use crate::{
     contract::{cmd::RepayLeaseFn, state::Response, Lease},
     error::ContractResult,
-    finance::LpnCoinDTO,
+    finance::{LpnCoin, LpnCoinDTO},
 };
 
 use super::{
@@ -22,6 +22,17 @@ pub(super) fn repay(
     Repay::from(CustomerRepay {}).try_repay(lease, amount, env, querier)
 }
 
+/// Repay `repayment` out of `amount` and send the excess back to the customer
+pub(super) fn deleverage(
+    lease: Lease,
+    repayment: LpnCoin,
+    amount: LpnCoinDTO,
+    env: &Env,
+    querier: QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    Repay::from(DeleverageRepay { repayment }).try_repay(lease, amount, env, querier)
+}
+
 pub(super) struct CustomerRepay {}
 
 impl RepayAlgo for CustomerRepay {
@@ -37,3 +48,26 @@ impl RepayAlgo for CustomerRepay {
         Self::PaymentEmitter::new(env)
     }
 }
+
+pub(super) struct DeleverageRepay {
+    repayment: LpnCoin,
+}
+
+impl RepayAlgo for DeleverageRepay {
+    type RepayFn = RepayLeaseFn;
+
+    type PaymentEmitter<'liq, 'env> = PaymentEmitter<'env>;
+
+    fn repay_fn(&self) -> Self::RepayFn {
+        Self::RepayFn {}
+    }
+
+    fn emitter_fn<'liq, 'env>(&'liq self, env: &'env Env) -> Self::PaymentEmitter<'liq, 'env> {
+        Self::PaymentEmitter::new(env)
+    }
+
+    fn split_proceeds(&self, proceeds: LpnCoin) -> (LpnCoin, LpnCoin) {
+        debug_assert!(self.repayment <= proceeds);
+        (self.repayment, proceeds - self.repayment)
+    }
+}
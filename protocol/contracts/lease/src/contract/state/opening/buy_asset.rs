@@ -9,8 +9,7 @@ use dex::{
 };
 use finance::{coin::CoinDTO, duration::Duration};
 use platform::{
-    ica::HostAccount, message::Response as MessageResponse,
-    state_machine::Response as StateMachineResponse,
+    message::Response as MessageResponse, state_machine::Response as StateMachineResponse,
 };
 use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
@@ -18,16 +17,19 @@ use timealarms::stub::TimeAlarmsRef;
 use crate::{
     api::{
         open::{NewLeaseContract, NewLeaseForm},
-        query::{opening::OngoingTrx, StateResponse as QueryStateResponse},
+        query::{opening::OngoingTrx, DexAccount, StateResponse as QueryStateResponse},
         DownpaymentCoin, LeaseAssetCurrencies, LeasePaymentCurrencies,
     },
     contract::{
         cmd::{CloseStatusDTO, LeaseFactory, OpenLeaseResult, OpenLoanRespResult},
         finalize::FinalizerRef,
         state::{
-            opened::{active::Active, close::liquidation},
+            opened::{
+                active::Active,
+                close::{customer_close, liquidation},
+            },
             resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
-            SwapClient, SwapResult,
+            DexSummary, SwapClient, SwapResult,
         },
         Lease,
     },
@@ -51,6 +53,8 @@ pub(in super::super) type DexState = dex::StateRemoteOut<
     ForwardToDexEntryContinue,
 >;
 
+impl DexSummary for DexState {}
+
 pub(super) fn start(
     new_lease: NewLeaseContract,
     downpayment: DownpaymentCoin,
@@ -102,12 +106,16 @@ impl BuyAsset {
     where
         InP: FnOnce(String) -> OngoingTrx,
     {
+        let dex_account = DexAccount::from(self.dex_account);
+
         Ok(QueryStateResponse::Opening {
             currency: self.form.currency,
             downpayment: self.downpayment,
             loan: self.loan.principal,
             loan_interest_rate: self.loan.annual_interest_rate,
-            in_progress: in_progress_fn(HostAccount::from(self.dex_account).into()),
+            origination_fee: self.loan.origination_fee,
+            in_progress: in_progress_fn(dex_account.ica_account.clone()),
+            dex_account: Some(dex_account),
         })
     }
 }
@@ -144,7 +152,11 @@ impl SwapTask for BuyAsset {
     where
         Visitor: CoinVisitor<GIn = Self::InG, Result = IterNext>,
     {
-        dex::on_coins(&self.downpayment, &self.loan.principal, visitor)
+        dex::on_coins(
+            &self.downpayment,
+            &self.loan.principal_net_of_fee(),
+            visitor,
+        )
     }
 
     fn finish(
@@ -169,13 +181,27 @@ impl SwapTask for BuyAsset {
             self.start_opening_at,
             &env.block.time,
         );
-        let OpenLeaseResult { lease, status } =
-            with_lease_deps::execute(cmd, lease_addr, position, self.deps.0, self.deps.1, querier)?;
+        let OpenLeaseResult {
+            lease,
+            status,
+            undersized,
+        } = with_lease_deps::execute(cmd, lease_addr, position, self.deps.0, self.deps.1, querier)?;
 
+        let opened_msgs = self.deps.3.notify_opened()?;
         let lease = Lease::new(lease, self.dex_account, self.deps.3);
         let active = Active::new(lease);
         let emitter = active.emit_opened(env, self.downpayment, self.loan);
 
+        if undersized {
+            // the swap slippage has left the position below the minimum asset amount
+            return customer_close::undersized_start(
+                active.into(),
+                MessageResponse::messages_with_events(opened_msgs, emitter),
+                env,
+                querier,
+            );
+        }
+
         match status {
             CloseStatusDTO::Paid => {
                 unimplemented!("a freshly open lease should have some due amount")
@@ -184,12 +210,16 @@ impl SwapTask for BuyAsset {
                 current_liability: _, // TODO shouldn't we add warning zone events?
                 alarms,
             } => Ok(StateMachineResponse::from(
-                MessageResponse::messages_with_events(alarms, emitter),
+                MessageResponse::messages_with_events(alarms.merge(opened_msgs), emitter),
                 active,
             )),
-            CloseStatusDTO::NeedLiquidation(liquidation) => {
-                liquidation::start(active.into(), liquidation, emitter.into(), env, querier)
-            }
+            CloseStatusDTO::NeedLiquidation(liquidation) => liquidation::start(
+                active.into(),
+                liquidation,
+                MessageResponse::messages_with_events(opened_msgs, emitter),
+                env,
+                querier,
+            ),
             CloseStatusDTO::CloseAsked(_) => unimplemented!("no triggers have been set"),
         }
     }
@@ -1,3 +1,4 @@
 pub mod buy_asset;
 pub mod open_ica;
+pub mod order;
 pub mod request_loan;
@@ -86,7 +86,9 @@ impl DexContract for OpenIcaAccount {
             downpayment: self.downpayment,
             loan: self.loan.principal,
             loan_interest_rate: self.loan.annual_interest_rate,
+            origination_fee: self.loan.origination_fee,
             in_progress: OngoingTrx::OpenIcaAccount {},
+            dex_account: None,
         })
     }
 }
@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+
+use currency::never;
+use finance::{coin::IntoDTO, duration::Duration};
+use oracle::{
+    api::alarms::Alarm,
+    stub::{AsAlarms, PriceAlarms},
+};
+use platform::{
+    bank,
+    batch::{Batch, Emit, Emitter},
+    message::Response as MessageResponse,
+    state_machine::Response as StateMachineResponse,
+};
+use sdk::cosmwasm_std::{Coin as CwCoin, Env, MessageInfo, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{
+        open::{NewLeaseContract, OpenOrder, OrderPrice},
+        query::StateResponse as QueryStateResponse,
+        DownpaymentCoin, LeaseAssetCurrencies, LeasePaymentCurrencies,
+    },
+    contract::{
+        finalize::FinalizerRef,
+        state::{closed::Closed, Handler, Response},
+    },
+    error::{ContractError, ContractResult},
+    event::Type,
+    finance::OracleRef,
+};
+
+use super::request_loan::RequestLoan;
+
+/// A lease awaiting the market price to reach the target of its open order
+///
+/// The downpayment is held by the lease until the order is triggered by a price alarm,
+/// cancelled by the customer, or expired on a time alarm.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PendingOrder {
+    new_lease: NewLeaseContract,
+    funds: Vec<CwCoin>,
+    price: OrderPrice,
+    expiration: Timestamp,
+    deps: (OracleRef, TimeAlarmsRef, FinalizerRef),
+}
+
+impl PendingOrder {
+    pub fn new(
+        querier: QuerierWrapper<'_>,
+        env: &Env,
+        funds: Vec<CwCoin>,
+        spec: NewLeaseContract,
+        order: OpenOrder,
+    ) -> ContractResult<(MessageResponse, Self)> {
+        check_order(&order, &spec)?;
+
+        let oracle = OracleRef::try_from_base(spec.form.market_price_oracle.clone(), querier)?;
+
+        let timealarms = TimeAlarmsRef::new(spec.form.time_alarms.clone(), querier)?;
+
+        let finalizer = FinalizerRef::try_new(spec.finalizer.clone(), querier)?;
+
+        let res = Self {
+            new_lease: spec,
+            funds,
+            price: order.price,
+            expiration: env.block.time + order.valid_for,
+            deps: (oracle, timealarms, finalizer),
+        };
+        let downpayment = res.downpayment()?;
+
+        res.setup_alarms().map(|alarms| {
+            let emitter = res.emit_placed(env, &downpayment);
+            (MessageResponse::messages_with_events(alarms, emitter), res)
+        })
+    }
+
+    fn downpayment(&self) -> ContractResult<DownpaymentCoin> {
+        bank::may_received(&self.funds, IntoDTO::<LeasePaymentCurrencies>::new())
+            .map(never::safe_unwrap)
+            .ok_or_else(ContractError::NoPaymentError)
+    }
+
+    fn setup_alarms(&self) -> ContractResult<Batch> {
+        self.deps
+            .1
+            .setup_alarm(self.expiration)
+            .map_err(Into::into)
+            .and_then(|expiration_alarm| {
+                let mut price_alarms = self.deps.0.as_alarms::<LeaseAssetCurrencies>();
+                price_alarms
+                    .add_alarm(Alarm::new(self.price, None::<OrderPrice>))
+                    .map(|()| expiration_alarm.merge(price_alarms.into()))
+                    .map_err(Into::into)
+            })
+    }
+
+    fn trigger(self, querier: QuerierWrapper<'_>, env: &Env) -> ContractResult<Response> {
+        let emitter = self.emit_closed(env, "trigger");
+        RequestLoan::new(querier, self.funds, self.new_lease).map(|(batch, next_state)| {
+            StateMachineResponse::from(
+                MessageResponse::messages_with_events(batch, emitter),
+                next_state,
+            )
+        })
+    }
+
+    fn refund(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: &Env,
+        reason: &str,
+    ) -> ContractResult<Response> {
+        let customer = self.new_lease.form.customer.clone();
+        let emitter = self.emit_closed(env, reason);
+
+        bank::bank_send_all::<LeasePaymentCurrencies>(
+            &env.contract.address,
+            customer.clone(),
+            querier,
+        )
+        .map_err(ContractError::from)
+        .and_then(|refund_msgs| {
+            self.deps
+                .2
+                .notify(customer)
+                .map(|finalizer_msgs| refund_msgs.merge(finalizer_msgs)) //make sure the finalizer messages go out last
+        })
+        .map(|all_messages| MessageResponse::messages_with_events(all_messages, emitter))
+        .map(|response| StateMachineResponse::from(response, Closed::default()))
+    }
+
+    fn expired(&self, now: &Timestamp) -> bool {
+        &self.expiration <= now
+    }
+
+    fn emit_placed(&self, env: &Env, downpayment: &DownpaymentCoin) -> Emitter {
+        Emitter::of_type(Type::OpenOrder)
+            .emit_tx_info(env)
+            .emit("id", &env.contract.address)
+            .emit("customer", self.new_lease.form.customer.clone())
+            .emit_currency_dto("currency", &self.new_lease.form.currency)
+            .emit_coin_dto("downpayment", downpayment)
+            .emit_timestamp("expiration", &self.expiration)
+    }
+
+    fn emit_closed(&self, env: &Env, reason: &str) -> Emitter {
+        Emitter::of_type(Type::OpenOrderClose)
+            .emit_tx_info(env)
+            .emit("id", &env.contract.address)
+            .emit("reason", reason)
+    }
+}
+
+impl Handler for PendingOrder {
+    fn state(
+        self,
+        _now: Timestamp,
+        _due_projection: Duration,
+        _querier: QuerierWrapper<'_>,
+    ) -> ContractResult<QueryStateResponse> {
+        self.downpayment()
+            .map(|downpayment| QueryStateResponse::Pending {
+                currency: self.new_lease.form.currency,
+                downpayment,
+                price: self.price,
+                expiration: self.expiration,
+            })
+    }
+
+    fn close(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.new_lease.form.customer, &info.sender)?;
+
+        self.refund(querier, &env, "cancel")
+    }
+
+    fn on_time_alarm(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        if !self.deps.1.owned_by(&info.sender) {
+            return Err(ContractError::Unauthorized(
+                access_control::error::Error::Unauthorized {},
+            ));
+        }
+
+        if self.expired(&env.block.time) {
+            self.refund(querier, &env, "expiry")
+        } else {
+            super::super::ignore_msg(self)
+        }
+    }
+
+    fn on_price_alarm(
+        self,
+        querier: QuerierWrapper<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        if !self.deps.0.owned_by(&info.sender) {
+            return Err(ContractError::Unauthorized(
+                access_control::error::Error::Unauthorized {},
+            ));
+        }
+
+        if self.expired(&env.block.time) {
+            self.refund(querier, &env, "expiry")
+        } else {
+            self.trigger(querier, &env)
+        }
+    }
+}
+
+fn check_order(order: &OpenOrder, spec: &NewLeaseContract) -> ContractResult<()> {
+    if order.valid_for == Duration::default() || order.valid_for > OpenOrder::MAX_VALIDITY {
+        Err(ContractError::InvalidOrderValidity {
+            valid_for: order.valid_for,
+            max: OpenOrder::MAX_VALIDITY,
+        })
+    } else if order.price.currency() != spec.form.currency {
+        Err(ContractError::InvalidOrderPrice {
+            lease: spec.form.currency,
+            found: order.price.currency(),
+        })
+    } else {
+        Ok(())
+    }
+}
@@ -2,21 +2,23 @@ use finance::duration::Duration;
 use serde::{Deserialize, Serialize};
 
 use platform::{
+    bank::FixedAddressSender,
     batch::{Batch, Emit, Emitter},
     message::Response as MessageResponse,
     state_machine::Response as StateMachineResponse,
 };
-use sdk::cosmwasm_std::{Addr, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::{Addr, Coin as CwCoin, Env, QuerierWrapper, Reply, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
     api::{open::NewLeaseContract, query::StateResponse as QueryStateResponse, DownpaymentCoin},
     contract::{
-        cmd::{OpenLoanReq, OpenLoanReqResult, OpenLoanResp},
+        cmd::{OpenLoanReq, OpenLoanReqResult, OpenLoanResp, OpenLoanRespResult},
         finalize::FinalizerRef,
         state::{Handler, Response},
     },
-    error::ContractResult,
+    error::{ContractError, ContractResult},
     event::Type,
     finance::{LppRef, OracleRef},
 };
@@ -33,7 +35,7 @@ pub(crate) struct RequestLoan {
 impl RequestLoan {
     pub fn new(
         querier: QuerierWrapper<'_>,
-        info: MessageInfo,
+        funds: Vec<CwCoin>,
         spec: NewLeaseContract,
     ) -> ContractResult<(Batch, Self)> {
         let lpp = LppRef::try_new(spec.form.loan.lpp.clone(), querier)?;
@@ -46,8 +48,9 @@ impl RequestLoan {
 
         let OpenLoanReqResult { batch, downpayment } = lpp.clone().execute_lender(
             OpenLoanReq::new(
+                spec.form.currency,
                 spec.form.position_spec,
-                info.funds,
+                funds,
                 spec.form.max_ltd,
                 oracle.clone(),
                 querier,
@@ -63,17 +66,35 @@ impl RequestLoan {
         }))
     }
 
+    #[cfg(test)]
+    pub(in super::super) fn unchecked(
+        new_lease: NewLeaseContract,
+        downpayment: DownpaymentCoin,
+    ) -> Self {
+        let lpp = LppRef::unchecked(new_lease.form.loan.lpp.clone());
+        let oracle = OracleRef::unchecked(new_lease.form.market_price_oracle.clone());
+        let timealarms = TimeAlarmsRef::unchecked(new_lease.form.time_alarms.clone());
+        let finalizer = FinalizerRef::unchecked(new_lease.finalizer.clone());
+
+        Self {
+            new_lease,
+            downpayment,
+            deps: (lpp, oracle, timealarms, finalizer),
+        }
+    }
+
     fn on_response(
         self,
         querier: QuerierWrapper<'_>,
         env: Env,
         msg: Reply,
     ) -> ContractResult<Response> {
-        let loan = self
-            .deps
-            .0
-            .clone()
-            .execute_lender(OpenLoanResp::new(msg), querier)?;
+        let loan = self.deps.0.clone().execute_lender(
+            OpenLoanResp::new(msg, self.new_lease.form.loan.origination_fee),
+            querier,
+        )?;
+
+        let charge_fee = self.charge_origination_fee(&loan, querier)?;
 
         let emitter = self.emit_ok(env.contract.address);
 
@@ -85,11 +106,29 @@ impl RequestLoan {
             env.block.time,
         );
         Ok(StateMachineResponse::from(
-            MessageResponse::messages_with_events(open_ica.enter(), emitter),
+            MessageResponse::messages_with_events(charge_fee.merge(open_ica.enter()), emitter),
             Into::<DexState>::into(open_ica),
         ))
     }
 
+    fn charge_origination_fee(
+        &self,
+        loan: &OpenLoanRespResult,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<Batch> {
+        loan.origination_fee().map_or_else(
+            || Ok(Batch::default()),
+            |fee| {
+                ProfitRef::new(self.new_lease.form.loan.profit.clone(), &querier)
+                    .map(ProfitRef::into_stub)
+                    .map_err(ContractError::from)
+                    .and_then(|mut profit| {
+                        profit.send(fee).map(|()| profit.into()).map_err(Into::into)
+                    })
+            },
+        )
+    }
+
     fn emit_ok(&self, contract: Addr) -> Emitter {
         Emitter::of_type(Type::RequestLoan).emit("id", contract)
     }
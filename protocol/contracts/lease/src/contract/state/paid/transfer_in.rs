@@ -23,7 +23,9 @@ use crate::{
     },
     contract::{
         cmd::Close,
-        state::{closed::Closed, resp_delivery::ForwardToDexEntry, SwapClient, SwapResult},
+        state::{
+            closed::Closed, resp_delivery::ForwardToDexEntry, DexSummary, SwapClient, SwapResult,
+        },
         Lease,
     },
     error::ContractResult,
@@ -37,6 +39,8 @@ pub(super) type StartState =
 pub(in super::super) type DexState =
     dex::StateLocalOut<TransferIn, LeasePaymentCurrencies, SwapClient, ForwardToDexEntry>;
 
+impl DexSummary for DexState {}
+
 pub(in super::super) fn start(lease: Lease) -> StartState {
     let transfer = TransferIn::new(lease);
     let amount_in = *transfer.amount();
@@ -0,0 +1,243 @@
+//! A builder of lease `State`-s for tests
+//!
+//! The defaults reproduce the golden fixtures. Override any of them before building
+//! a lease in the state of interest.
+use currencies::testing::LeaseC1;
+use dex::{Account, ConnectionParams, IcaTimeouts, Ics20Channel};
+use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent};
+use lpp::stub::LppRef;
+use platform::ica::HostAccount;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::{Addr, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{
+        open::{LoanForm, NewLeaseContract, NewLeaseForm, PositionSpecDTO},
+        DownpaymentCoin, LeaseCoin,
+    },
+    contract::{finalize::FinalizerRef, Lease},
+    finance::{LpnCoin, OracleRef, ReserveRef},
+    lease::LeaseDTO,
+    loan::LoanDTO,
+    position::PositionDTO,
+};
+
+use super::super::{opened, opening, paid, State};
+
+const LEASE: &str = "nolus1yhcph5r2x9rss6tluptttma736rknasjwn3659620ysu5fhmx2wq47gmch";
+const CUSTOMER: &str = "nolus17rjgmry3w2xcc8yer4h4m8vuypkhkh8he3u8xv";
+const LPP: &str = "nolus1qqcr7exupnymvg6m63eqwu8pd4n5x6r5t3pyyxdy7r97rcgajmhqy3gn94";
+const PROFIT: &str = "nolus1udkxyfeh7kxjnzm0exfaq9hncqzm3rj59gut4qnll0gq2z4yff0sda5aw2";
+const TIME_ALARMS: &str = "nolus1zwv6feuzhy6a9wekh96cd57lsarmqlwxdypdsplw6zhfncqw6ftqmx7chl";
+const ORACLE: &str = "nolus1jew4l5nq7m3xhkqzy8j7cc99083m5j8d9w004ayyv8xl3yv4h0dql2dd4e";
+const RESERVE: &str = "nolus10hzky830fafe5ffzt6vqprmpxjsy0fk8gcq5wvnvgr6lt4s6he3s045c4n";
+const FINALIZER: &str = "nolus1et45v5gepxs44jxewfxah0hk4wqmw34m8pm4alf44ucxvj895kas5yrxd8";
+const ICA_HOST: &str = "neutron1kdfwfa2pxf7jfth0pej3ds8v4fqa5nhc4nxdm6lr3ctqzvqjfg0shuxdcy";
+
+pub(crate) struct StateBuilder {
+    customer: Addr,
+    amount: LeaseCoin,
+    position_spec: PositionSpecDTO,
+    downpayment: DownpaymentCoin,
+    annual_margin_interest: Percent,
+    due_period: Duration,
+    margin_paid_by: Timestamp,
+    dex: ConnectionParams,
+}
+
+impl Default for StateBuilder {
+    fn default() -> Self {
+        Self {
+            customer: Addr::unchecked(CUSTOMER),
+            amount: Coin::<LeaseC1>::new(50_000_000).into(),
+            position_spec: PositionSpecDTO::new(
+                Liability::new(
+                    Percent::from_permille(600),
+                    Percent::from_permille(830),
+                    Percent::from_permille(850),
+                    Percent::from_permille(865),
+                    Percent::from_permille(880),
+                    Percent::from_permille(900),
+                    Duration::from_hours(2),
+                ),
+                LpnCoin::new(15_000_000).into(),
+                LpnCoin::new(10_000).into(),
+            ),
+            downpayment: LpnCoin::new(40_000_000).into(),
+            annual_margin_interest: Percent::from_permille(40),
+            due_period: Duration::from_days(14),
+            margin_paid_by: Timestamp::from_nanos(1705072797559458289),
+            dex: ConnectionParams {
+                connection_id: "connection-11".into(),
+                transfer_channel: Ics20Channel {
+                    local_endpoint: "channel-3839".into(),
+                    remote_endpoint: "channel-44".into(),
+                },
+                timeouts: IcaTimeouts::new(Duration::HOUR, Duration::HOUR).expect("valid timeouts"),
+            },
+        }
+    }
+}
+
+impl StateBuilder {
+    pub fn customer(self, customer: Addr) -> Self {
+        Self { customer, ..self }
+    }
+
+    /// Override the lease asset amount, and currency, of the position
+    pub fn amount(self, amount: LeaseCoin) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Override the loan terms, the margin interest is paid by `margin_paid_by`
+    pub fn loan(
+        self,
+        annual_margin_interest: Percent,
+        due_period: Duration,
+        margin_paid_by: Timestamp,
+    ) -> Self {
+        Self {
+            annual_margin_interest,
+            due_period,
+            margin_paid_by,
+            ..self
+        }
+    }
+
+    /// A lease waiting for the loan from the LPP
+    pub fn request_loan(self) -> State {
+        let new_lease = NewLeaseContract {
+            form: NewLeaseForm {
+                customer: self.customer,
+                currency: self.amount.currency(),
+                max_ltd: None,
+                position_spec: self.position_spec,
+                loan: LoanForm {
+                    lpp: Addr::unchecked(LPP),
+                    profit: Addr::unchecked(PROFIT),
+                    annual_margin_interest: self.annual_margin_interest,
+                    due_period: self.due_period,
+                    origination_fee: None,
+                    promotion_period: None,
+                },
+                reserve: Addr::unchecked(RESERVE),
+                time_alarms: Addr::unchecked(TIME_ALARMS),
+                market_price_oracle: Addr::unchecked(ORACLE),
+                open_order: None,
+            },
+            dex: self.dex,
+            finalizer: Addr::unchecked(FINALIZER),
+        };
+        opening::request_loan::RequestLoan::unchecked(new_lease, self.downpayment).into()
+    }
+
+    /// An open lease with an outstanding loan
+    pub fn opened_active(self) -> State {
+        opened::active::Active::new(self.into_lease()).into()
+    }
+
+    /// A lease whose loan has been fully repaid
+    pub fn paid_active(self) -> State {
+        paid::Active::new(self.into_lease()).into()
+    }
+
+    /// A paid lease on closing, i.e. transferring its assets in from the DEX
+    pub fn closing_transfer_in(self) -> State {
+        paid::transfer_in::DexState::from(paid::transfer_in::start(self.into_lease())).into()
+    }
+
+    fn into_lease(self) -> Lease {
+        let lease = Addr::unchecked(LEASE);
+        let loan = LoanDTO::new(
+            LppRef::unchecked(LPP),
+            ProfitRef::unchecked(PROFIT),
+            self.due_period,
+            self.annual_margin_interest,
+            self.margin_paid_by,
+        );
+        Lease::new(
+            LeaseDTO::new(
+                lease.clone(),
+                self.customer,
+                PositionDTO::new(self.amount, self.position_spec.into()),
+                loan,
+                TimeAlarmsRef::unchecked(TIME_ALARMS),
+                OracleRef::unchecked(Addr::unchecked(ORACLE)),
+                ReserveRef::unchecked(Addr::unchecked(RESERVE)),
+            ),
+            Account::unchecked(
+                lease,
+                HostAccount::try_from(String::from(ICA_HOST)).expect("valid ICA host"),
+                self.dex,
+            ),
+            FinalizerRef::unchecked(Addr::unchecked(FINALIZER)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::testing::LeaseC2;
+    use finance::{coin::Coin, duration::Duration, percent::Percent};
+    use sdk::cosmwasm_std::{self, Addr, Timestamp};
+
+    use super::{super::variant, StateBuilder};
+
+    #[test]
+    fn defaults_as_golden() {
+        assert_eq!(
+            include_str!("golden/request_loan.json").trim_end(),
+            to_json(StateBuilder::default().request_loan())
+        );
+        assert_eq!(
+            include_str!("golden/opened_active.json").trim_end(),
+            to_json(StateBuilder::default().opened_active())
+        );
+        assert_eq!(
+            include_str!("golden/paid_active.json").trim_end(),
+            to_json(StateBuilder::default().paid_active())
+        );
+        assert_eq!(
+            include_str!("golden/closing_transfer_in.json").trim_end(),
+            to_json(StateBuilder::default().closing_transfer_in())
+        );
+    }
+
+    #[test]
+    fn opened_with_loan() {
+        let margin_paid_by = Timestamp::from_seconds(1_700_000_000);
+        let state = StateBuilder::default()
+            .loan(
+                Percent::from_percent(7),
+                Duration::from_days(30),
+                margin_paid_by,
+            )
+            .opened_active();
+        assert_eq!("OpenedActive", variant(&state));
+
+        let json = to_json(state);
+        assert!(json.contains(&format!(
+            r#""due_period":{},"margin_interest":70,"margin_paid_by":"{}""#,
+            Duration::from_days(30).nanos(),
+            margin_paid_by.nanos()
+        )));
+    }
+
+    #[test]
+    fn paid_of_another_customer() {
+        let state = StateBuilder::default()
+            .customer(Addr::unchecked("customer"))
+            .amount(Coin::<LeaseC2>::new(1_000).into())
+            .paid_active();
+        assert_eq!("PaidActive", variant(&state));
+
+        let json = to_json(state);
+        assert!(json.contains(r#""customer":"customer""#));
+        assert!(json.contains(r#""amount":{"amount":"1000","ticker":"LC2"}"#));
+    }
+
+    fn to_json(state: super::State) -> String {
+        cosmwasm_std::to_json_string(&state).expect("serialization passed")
+    }
+}
@@ -2,18 +2,23 @@ use std::any::type_name;
 
 use thiserror::Error;
 
-use currency::error::Error as CurrencyError;
+use currency::{error::Error as CurrencyError, CurrencyDTO};
 use dex::Error as DexError;
-use finance::error::Error as FinanceError;
-use lpp::error::Error as LppError;
-use oracle::api::alarms::Error as OracleAlarmError;
+use finance::{duration::Duration, error::Error as FinanceError, percent::Percent};
+use lpp::{error::Error as LppError, lpn::UnexpectedLpn};
+use oracle::api::{alarms::Error as OracleAlarmError, swap::Error as OracleSwapError};
 use oracle_platform::error::Error as OracleError;
 use platform::error::Error as PlatformError;
 use profit::stub::Error as ProfitError;
 use reserve::stub::Error as ReserveError;
-use sdk::cosmwasm_std::StdError;
+use sdk::cosmwasm_std::{StdError, Timestamp};
 use timealarms::stub::Error as TimeAlarmsError;
 
+use crate::{
+    api::{LeaseAssetCurrencies, LeasePaymentCurrencies},
+    finance::{LpnCoinDTO, LpnCurrencies},
+};
+
 pub use crate::position::PositionError;
 
 #[derive(Error, Debug, PartialEq)]
@@ -39,6 +44,9 @@ pub enum ContractError {
     #[error("[Lease] {0}")]
     LppError(#[from] LppError),
 
+    #[error("[Lease] {0}")]
+    UnexpectedLpn(#[from] UnexpectedLpn),
+
     #[error("[Lease] {0}")]
     TimeAlarmsError(#[from] TimeAlarmsError),
 
@@ -60,9 +68,49 @@ pub enum ContractError {
     #[error("[Lease] {0}")]
     PositionError(#[from] PositionError),
 
+    #[error("[Lease] The downpayment currency '{downpayment}' cannot be swapped to the lease currency '{lease}'! Cause: {cause}")]
+    NonSwappableDownpayment {
+        downpayment: CurrencyDTO<LeasePaymentCurrencies>,
+        lease: CurrencyDTO<LeaseAssetCurrencies>,
+        cause: OracleSwapError,
+    },
+
+    #[error("[Lease] The lease currency should differ from the loan currency '{0}'")]
+    LeaseCurrencyIsLpn(CurrencyDTO<LpnCurrencies>),
+
     #[error("[Lease] No payment sent")]
     NoPaymentError(),
 
+    #[error("[Lease] Unsupported payment currency '{0}'! Expected a currency of the '{1}' group")]
+    UnsupportedPaymentCurrency(String, &'static str),
+
+    #[error(
+        "[Lease] The open order validity '{valid_for}' should be positive and not exceed '{max}'"
+    )]
+    InvalidOrderValidity { valid_for: Duration, max: Duration },
+
+    #[error(
+        "[Lease] The open order price should be of the lease currency '{lease}', found '{found}'"
+    )]
+    InvalidOrderPrice {
+        lease: CurrencyDTO<LeaseAssetCurrencies>,
+        found: CurrencyDTO<LeaseAssetCurrencies>,
+    },
+
+    #[error("[Lease] The payout max slippage '{0}' should be less than 100%")]
+    InvalidPayoutSlippage(Percent),
+
+    #[error("[Lease] The partial close repay share '{0}' should not exceed 100%")]
+    InvalidRepayShare(Percent),
+
+    #[error("[Lease] The position LTV is already at or below the deleverage target '{0}'")]
+    DeleverageTargetReached(Percent),
+
+    #[error(
+        "[Lease] Insufficient deleverage payment! The target LTV requires a repayment of '{0}'"
+    )]
+    InsufficientDeleveragePayment(LpnCoinDTO),
+
     #[error("[Lease] The operation '{0}' is not supported in the current state")]
     UnsupportedOperation(String),
 
@@ -71,6 +119,17 @@ pub enum ContractError {
 
     #[error("[Lease] Inconsistency not detected")]
     InconsistencyNotDetected(),
+
+    #[error("[Lease] The state cannot be estimated at {at}, before the current due period start {due_period_start}")]
+    StateBeforeDuePeriod {
+        at: Timestamp,
+        due_period_start: Timestamp,
+    },
+
+    #[error(
+        "[Lease] The interest split cannot be estimated at {at}, before the current time {now}"
+    )]
+    InterestSplitInPast { at: Timestamp, now: Timestamp },
 }
 
 impl ContractError {
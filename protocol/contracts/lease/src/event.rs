@@ -1,9 +1,13 @@
 pub enum Type {
+    OpenOrder,
+    OpenOrderClose,
     RequestLoan,
     OpenIcaAccount,
     OpeningSwap,
     OpenedActive,
     RepaymentSwap,
+    CollateralSwap,
+    CollateralAdded,
     PaidActive,
     ClosingTransferIn,
     Closed,
@@ -12,17 +16,25 @@ pub enum Type {
     Liquidation,
     ClosePosition,
     AutoClosePosition,
+    UndersizedClosePosition,
+    PayoutSwap,
+    Payout,
+    AccrualSnapshot,
 }
 
 impl Type {
     /// 'wasm-' is always prepended by the runtime
     pub const fn as_str(&self) -> &'static str {
         match self {
+            Self::OpenOrder => "ls-open-order",
+            Self::OpenOrderClose => "ls-open-order-close",
             Self::RequestLoan => "ls-request-loan",
             Self::OpenIcaAccount => "ls-open-dex-account",
             Self::OpeningSwap => "ls-open-swap",
             Self::OpenedActive => "ls-open",
             Self::RepaymentSwap => "ls-repay-swap",
+            Self::CollateralSwap => "ls-collateral-swap",
+            Self::CollateralAdded => "ls-add-collateral",
             Self::PaidActive => "ls-repay",
             Self::ClosingTransferIn => "ls-close-transfer-in",
             Self::Closed => "ls-close",
@@ -31,6 +43,10 @@ impl Type {
             Self::Liquidation => "ls-liquidation",
             Self::ClosePosition => "ls-close-position",
             Self::AutoClosePosition => "ls-auto-close-position",
+            Self::UndersizedClosePosition => "ls-undersized-close-position",
+            Self::PayoutSwap => "ls-payout-swap",
+            Self::Payout => "ls-payout",
+            Self::AccrualSnapshot => "ls-accrual-snapshot",
         }
     }
 }
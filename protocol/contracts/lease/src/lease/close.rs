@@ -1,7 +1,7 @@
 use platform::{bank::FixedAddressSender, batch::Batch};
 
 use currency::{CurrencyDef, MemberOf};
-use finance::{coin::Coin, duration::Duration};
+use finance::{coin::Coin, duration::Duration, fraction::Fraction, percent::Percent, price};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle_platform::Oracle as OracleTrait;
 use reserve::stub::Reserve as ReserveTrait;
@@ -39,10 +39,30 @@ where
     Asset: CurrencyDef,
     Asset::Group: MemberOf<LeaseAssetCurrencies> + MemberOf<LeasePaymentCurrencies>,
 {
-    pub(crate) fn validate_close(&self, amount: Coin<Asset>) -> ContractResult<()> {
+    /// Check if a partial close of `amount` is valid
+    ///
+    /// The `repay_share` of the proceeds, or all if absent, are expected to repay the loan.
+    /// The proceeds are estimated at the current market price.
+    pub(crate) fn validate_close(
+        &self,
+        amount: Coin<Asset>,
+        repay_share: Option<Percent>,
+        now: &Timestamp,
+    ) -> ContractResult<()> {
         self.price_of_lease_currency().and_then(|asset_in_lpns| {
+            let repayment = repay_share
+                .unwrap_or(Percent::HUNDRED)
+                .of(price::total(amount, asset_in_lpns));
             self.position
                 .validate_close_amount(amount, asset_in_lpns)
+                .and_then(|()| {
+                    self.position.validate_close_ltv(
+                        amount,
+                        repayment,
+                        &self.loan.state(now),
+                        asset_in_lpns,
+                    )
+                })
                 .map_err(Into::into)
         })
     }
@@ -85,7 +105,7 @@ where
         let receipt = self.repay(payment, &now, &mut profit)?;
         debug_assert!(receipt.close());
 
-        change_recipient.send(receipt.change());
+        change_recipient.send(receipt.change())?;
 
         reserve
             .try_into()
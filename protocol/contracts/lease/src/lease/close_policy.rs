@@ -1,5 +1,5 @@
 use currency::{Currency, CurrencyDef, MemberOf};
-use finance::liability::Zone;
+use finance::{fraction::Fraction, liability::Zone, percent::Percent, price};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle_platform::Oracle as OracleTrait;
 use sdk::cosmwasm_std::Timestamp;
@@ -7,8 +7,9 @@ use sdk::cosmwasm_std::Timestamp;
 use crate::{
     api::{position::ClosePolicyChange, LeaseAssetCurrencies, LeasePaymentCurrencies},
     error::ContractResult,
-    finance::{LpnCurrencies, LpnCurrency, Price},
-    position::{CloseStrategy, Debt, Liquidation, Steadiness},
+    finance::{LpnCoin, LpnCurrencies, LpnCurrency, Price},
+    loan::State as LoanState,
+    position::{CloseStrategy, Debt, DueTrait as _, Liquidation, Steadiness},
 };
 
 use super::Lease;
@@ -26,18 +27,49 @@ where
     pub(crate) fn check_close_policy(&self, now: &Timestamp) -> ContractResult<CloseStatus<Asset>> {
         let due = self.loan.state(now);
 
+        self.price_of_lease_currency()
+            .map(|asset_in_lpns| self.close_status(&due, asset_in_lpns))
+    }
+
+    /// Compute the health of the position at the current market price
+    pub(crate) fn health(&self, now: &Timestamp) -> ContractResult<Health> {
+        let due = self.loan.state(now);
+
+        self.price_of_lease_currency().map(|asset_in_lpns| Health {
+            ltv: Percent::from_ratio(
+                due.total_due(),
+                price::total(self.position.amount(), asset_in_lpns),
+            ),
+            zone: match self.close_status(&due, asset_in_lpns) {
+                CloseStatus::None {
+                    current_liability, ..
+                } => Some(current_liability),
+                _ => None,
+            },
+        })
+    }
+
+    /// Compute the price of the lease currency at which the position gets liquidated due to its LTV
+    ///
+    /// The interest accrued by `now` is taken into account.
+    pub(crate) fn liquidation_price(&self, now: &Timestamp) -> Option<Price<Asset>> {
+        self.position.liquidation_price(&self.loan.state(now))
+    }
+
+    /// Compute the repayment that takes the position LTV down to `target_ltv` at the current market price
+    ///
+    /// The amount is rounded up so the LTV past the repayment does not exceed the target.
+    /// Zero if the position LTV is already at or below the target.
+    pub(crate) fn deleverage_amount(
+        &self,
+        target_ltv: Percent,
+        now: &Timestamp,
+    ) -> ContractResult<LpnCoin> {
+        let total_due = self.loan.state(now).total_due();
+
         self.price_of_lease_currency().map(|asset_in_lpns| {
-            self.position
-                .check_close(&due, asset_in_lpns)
-                .map(|close| CloseStatus::CloseAsked(close))
-                .unwrap_or_else(|| match self.position.debt(&due, asset_in_lpns) {
-                    Debt::No => CloseStatus::Paid,
-                    Debt::Ok { zone, steadiness } => CloseStatus::None {
-                        current_liability: zone,
-                        steadiness,
-                    },
-                    Debt::Bad(liquidation) => CloseStatus::NeedLiquidation(liquidation),
-                })
+            let target_due = target_ltv.of(price::total(self.position.amount(), asset_in_lpns));
+            total_due - total_due.min(target_due)
         })
     }
 
@@ -55,9 +87,39 @@ where
         })
     }
 
+    /// Check if the position is worth less than the minimum asset amount
+    ///
+    /// A freshly opened position may end up so due to a swap slippage.
+    pub(crate) fn undersized(&self) -> ContractResult<bool> {
+        self.price_of_lease_currency()
+            .map(|asset_in_lpns| !self.position.valid_asset(asset_in_lpns))
+    }
+
     pub(super) fn price_of_lease_currency(&self) -> ContractResult<Price<Asset>> {
         self.oracle.price_of::<Asset>().map_err(Into::into)
     }
+
+    fn close_status(&self, due: &LoanState, asset_in_lpns: Price<Asset>) -> CloseStatus<Asset> {
+        self.position
+            .check_close(due, asset_in_lpns)
+            .map(|close| CloseStatus::CloseAsked(close))
+            .unwrap_or_else(|| match self.position.debt(due, asset_in_lpns) {
+                Debt::No => CloseStatus::Paid,
+                Debt::Ok { zone, steadiness } => CloseStatus::None {
+                    current_liability: zone,
+                    steadiness,
+                },
+                Debt::Bad(liquidation) => CloseStatus::NeedLiquidation(liquidation),
+            })
+    }
+}
+
+/// The loan-to-value ratio and the liability zone of a position
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub(crate) struct Health {
+    pub ltv: Percent,
+    /// The zone the `ltv` falls in, if the position is neither paid, nor subject of a close
+    pub zone: Option<Zone>,
 }
 
 pub(crate) enum CloseStatus<Asset>
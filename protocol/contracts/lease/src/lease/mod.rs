@@ -1,5 +1,5 @@
 use currency::{Currency, CurrencyDef, MemberOf};
-use finance::duration::Duration;
+use finance::{coin::Coin, duration::Duration};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle_platform::Oracle as OracleTrait;
 use platform::batch::Batch;
@@ -16,7 +16,10 @@ use crate::{
 };
 
 pub(super) use self::{
-    close_policy::CloseStatus, dto::LeaseDTO, paid::Lease as LeasePaid, state::State,
+    close_policy::{CloseStatus, Health},
+    dto::LeaseDTO,
+    paid::Lease as LeasePaid,
+    state::State,
 };
 
 mod close;
@@ -94,6 +97,14 @@ where
         )
     }
 
+    pub(crate) fn add_collateral(&mut self, asset: Coin<Asset>) {
+        self.position.add_collateral(asset)
+    }
+
+    pub(crate) fn check_state_at(&self, at: &Timestamp) -> ContractResult<()> {
+        self.loan.check_state_at(at)
+    }
+
     pub(crate) fn state(&self, now: Timestamp, due_projection: Duration) -> State<Asset> {
         let estimate_at = now + due_projection;
         let loan = self.loan.state(&estimate_at);
@@ -164,7 +175,11 @@ pub mod tests {
     use currencies::{testing::PaymentC7, Lpn};
     use currency::{Currency, Group, MemberOf};
     use finance::{
-        coin::Coin, duration::Duration, fraction::Fraction, liability::Liability, percent::Percent,
+        coin::Coin,
+        duration::Duration,
+        fraction::Fraction,
+        liability::{Liability, Zone},
+        percent::Percent,
         price::Price,
     };
     use lpp::{
@@ -187,7 +202,7 @@ pub mod tests {
         position::{Position, Spec as PositionSpec},
     };
 
-    use super::{Lease, State};
+    use super::{Health, Lease, State};
 
     const CUSTOMER: &str = "customer";
     const LEASE_ADDR: &str = "lease_addr";
@@ -298,7 +313,7 @@ pub mod tests {
         let oracle: OracleLocalStub = Addr::unchecked(ORACLE_ADDR).into();
 
         let loan = loan.into();
-        let loan = Loan::new(loan, LEASE_START, MARGIN_INTEREST_RATE, due_period);
+        let loan = Loan::new(loan, LEASE_START, MARGIN_INTEREST_RATE, due_period, None);
         let liability = Liability::new(
             Percent::from_percent(65),
             Percent::from_percent(70),
@@ -432,6 +447,76 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn health() {
+        let lease_amount = coin(1000);
+
+        assert_eq!(
+            Health {
+                ltv: Percent::from_percent(70),
+                zone: Some(Zone::no_warnings(FIRST_LIQ_WARN)),
+            },
+            health_at_start(lease_amount, lpn_coin(700))
+        );
+        assert_eq!(
+            Health {
+                ltv: Percent::from_percent(74),
+                zone: Some(Zone::first(FIRST_LIQ_WARN, SECOND_LIQ_WARN)),
+            },
+            health_at_start(lease_amount, lpn_coin(740))
+        );
+        assert_eq!(
+            Health {
+                ltv: Percent::from_percent(85),
+                zone: None,
+            },
+            health_at_start(coin(1_000_000), lpn_coin(850_000))
+        );
+    }
+
+    #[test]
+    fn deleverage_amount() {
+        let lease = open_lease(
+            coin(1000),
+            LoanResponse {
+                principal_due: lpn_coin(700),
+                annual_interest_rate: Percent::from_permille(50),
+                interest_paid: LEASE_START,
+            },
+        );
+
+        assert_eq!(
+            lpn_coin(300),
+            lease
+                .deleverage_amount(Percent::from_percent(40), &LEASE_START)
+                .unwrap()
+        );
+        assert_eq!(
+            lpn_coin(0),
+            lease
+                .deleverage_amount(Percent::from_percent(70), &LEASE_START)
+                .unwrap()
+        );
+        assert_eq!(
+            lpn_coin(0),
+            lease
+                .deleverage_amount(Percent::from_percent(80), &LEASE_START)
+                .unwrap()
+        );
+    }
+
+    fn health_at_start(lease_amount: Coin<TestCurrency>, principal_due: Coin<TestLpn>) -> Health {
+        let lease = open_lease(
+            lease_amount,
+            LoanResponse {
+                principal_due,
+                annual_interest_rate: Percent::from_permille(50),
+                interest_paid: LEASE_START,
+            },
+        );
+        lease.health(&LEASE_START).unwrap()
+    }
+
     fn compare_now_vs_projected(lease: &TestLease, state_at: Timestamp) {
         let due_projection = Duration::from_days(12);
         let state_now = lease.state(state_at + due_projection, Duration::default());
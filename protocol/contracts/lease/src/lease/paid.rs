@@ -42,12 +42,13 @@ where
         let surplus = lease_account.balance::<Lpn, LpnCurrencies>()?;
 
         if !surplus.is_zero() {
-            lease_account.send(surplus, self.customer.clone());
+            lease_account.send(surplus, self.customer.clone())?;
         }
 
-        lease_account.send(self.position.amount(), self.customer);
-
-        Ok(lease_account.into())
+        lease_account
+            .send(self.position.amount(), self.customer)
+            .map(|()| lease_account.into())
+            .map_err(Into::into)
     }
 }
 
@@ -177,16 +178,10 @@ mod tests {
         let lease_account = BankStub::with_view(MockBankView::new(lease_amount, surplus_amount));
         let res = lease.close(lease_account).unwrap();
         assert_eq!(res, {
-            {
-                let mut sender = LazySenderStub::new(customer.clone());
-                sender.send(surplus_amount);
-                Batch::from(sender)
-            }
-            .merge({
-                let mut sender = LazySenderStub::new(customer);
-                sender.send(lease_amount);
-                sender.into()
-            })
+            let mut sender = LazySenderStub::new(customer);
+            sender.send(surplus_amount).unwrap();
+            sender.send(lease_amount).unwrap();
+            Batch::from(sender)
         });
     }
 }
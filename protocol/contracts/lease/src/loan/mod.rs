@@ -1,3 +1,5 @@
+use std::ops::Sub;
+
 use serde::{Deserialize, Serialize};
 
 use finance::{
@@ -33,9 +35,29 @@ pub(crate) struct LoanDTO {
     due_period: Duration,
     margin_interest: Percent,
     margin_paid_by: Timestamp, // only this one should vary!
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    promotion_end: Option<Timestamp>,
 }
 
 impl LoanDTO {
+    #[cfg(test)]
+    pub(crate) fn new(
+        lpp: LppRef,
+        profit: ProfitRef,
+        due_period: Duration,
+        margin_interest: Percent,
+        margin_paid_by: Timestamp,
+    ) -> Self {
+        Self {
+            lpp,
+            profit,
+            due_period,
+            margin_interest,
+            margin_paid_by,
+            promotion_end: None,
+        }
+    }
+
     pub(crate) fn annual_margin_interest(&self) -> Percent {
         self.margin_interest
     }
@@ -55,6 +77,8 @@ pub struct Loan<LppLoan> {
     due_period: Duration,
     margin_interest: Percent,
     margin_paid_by: Timestamp, // only this one should vary!
+    /// The margin interest does not accrue before this time, if any
+    promotion_end: Option<Timestamp>,
 }
 
 impl<LppLoan> Loan<LppLoan>
@@ -71,6 +95,7 @@ where
                     due_period: self.due_period,
                     margin_interest: self.margin_interest,
                     margin_paid_by: self.margin_paid_by,
+                    promotion_end: self.promotion_end,
                 },
                 lpp_batch.batch,
             )
@@ -90,17 +115,23 @@ impl<LppLoan> Loan<LppLoan>
 where
     LppLoan: LppLoanTrait<LpnCurrency, LpnCurrencies>,
 {
+    /// Create a new loan
+    ///
+    /// No margin interest accrues during the `promotion_period`, if any, since the `start`.
+    /// The loan interest is charged by the LPP and is not affected.
     pub(super) fn new(
         lpp_loan: LppLoan,
         start: Timestamp,
         annual_margin_interest: Percent,
         due_period: Duration,
+        promotion_period: Option<Duration>,
     ) -> Self {
         Self {
             lpp_loan,
             due_period,
             margin_interest: annual_margin_interest,
             margin_paid_by: start,
+            promotion_end: promotion_period.map(|period| start + period),
         }
     }
 
@@ -110,6 +141,7 @@ where
             due_period: dto.due_period,
             margin_interest: dto.margin_interest,
             margin_paid_by: dto.margin_paid_by,
+            promotion_end: dto.promotion_end,
         }
     }
 
@@ -127,7 +159,8 @@ where
     {
         self.debug_check_start_due_before(by, "before the 'repay-by' time");
 
-        let state = self.state(by);
+        self.margin_paid_by = self.margin_due_since(by);
+        let state = self.state_with(by, Sub::sub);
         let overdue_interest_payment = state.overdue.interest().min(payment);
         let overdue_margin_payment = state
             .overdue
@@ -152,7 +185,7 @@ where
         );
 
         self.repay_margin(state.principal_due, margin_paid, by);
-        profit.send(margin_paid);
+        profit.send(margin_paid)?;
         self.repay_loan(interest_paid, principal_paid, by);
 
         let receipt = RepayReceipt::new(
@@ -169,10 +202,21 @@ where
         Ok(receipt)
     }
 
+    /// Project the loan state at `now`
+    ///
+    /// The due interests are clamped at zero, since the projection is read-only and
+    /// should not fail on rounding edge cases. The repayment uses the exact amounts.
     pub(crate) fn state(&self, now: &Timestamp) -> State {
+        self.state_with(now, LpnCoin::saturating_sub)
+    }
+
+    fn state_with<DueSub>(&self, now: &Timestamp, due_sub: DueSub) -> State
+    where
+        DueSub: Fn(LpnCoin, LpnCoin) -> LpnCoin,
+    {
         self.debug_check_start_due_before(now, "in the past. Now is ");
 
-        let due_period_margin = Period::from_till(self.margin_paid_by, now);
+        let due_period_margin = Period::from_till(self.margin_due_since(now), now);
 
         let overdue = Overdue::new(
             &due_period_margin,
@@ -182,13 +226,18 @@ where
         );
 
         let principal_due = self.lpp_loan.principal_due();
-        let due_margin_interest = interest::interest(
-            self.margin_interest,
-            principal_due,
-            due_period_margin.length(),
-        ) - overdue.margin();
-        let due_interest =
-            self.lpp_loan.interest_due(&due_period_margin.till()) - overdue.interest();
+        let due_margin_interest = due_sub(
+            interest::interest(
+                self.margin_interest,
+                principal_due,
+                due_period_margin.length(),
+            ),
+            overdue.margin(),
+        );
+        let due_interest = due_sub(
+            self.lpp_loan.interest_due(&due_period_margin.till()),
+            overdue.interest(),
+        );
 
         State {
             annual_interest: self.lpp_loan.annual_interest_rate(),
@@ -211,6 +260,16 @@ where
         self.margin_paid_by += margin_paid_for;
     }
 
+    /// The start of the period the margin interest is due for
+    ///
+    /// Any time of the promotion period that has passed by `now` is considered paid.
+    fn margin_due_since(&self, now: &Timestamp) -> Timestamp {
+        self.promotion_end
+            .map_or(self.margin_paid_by, |promotion_end| {
+                self.margin_paid_by.max(promotion_end.min(*now))
+            })
+    }
+
     fn repay_loan(&mut self, interest_paid: LpnCoin, principal_paid: LpnCoin, by: &Timestamp) {
         let RepayShares {
             interest,
@@ -222,6 +281,17 @@ where
         debug_assert_eq!(excess, Coin::ZERO);
     }
 
+    pub(crate) fn check_state_at(&self, at: &Timestamp) -> ContractResult<()> {
+        if at < &self.margin_paid_by {
+            Err(ContractError::StateBeforeDuePeriod {
+                at: *at,
+                due_period_start: self.margin_paid_by,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     fn debug_check_start_due_before(&self, when: &Timestamp, when_descr: &str) {
         debug_assert!(
             &self.margin_paid_by <= when,
@@ -1073,13 +1143,25 @@ mod tests {
 
     #[cfg(test)]
     mod test_state {
-        use finance::{duration::Duration, interest, percent::Percent, period::Period};
-        use lpp::{msg::LoanResponse, stub::loan::LppLoan};
+        use finance::{
+            coin::Coin, duration::Duration, interest, percent::Percent, period::Period, zero::Zero,
+        };
+        use lpp::{
+            error::{Error as LppError, Result as LppResult},
+            loan::RepayShares,
+            msg::LoanResponse,
+            stub::{loan::LppLoan, LppBatch},
+        };
         use sdk::cosmwasm_std::Timestamp;
 
-        use crate::loan::{
-            tests::{create_loan_custom, LppLoanLocal},
-            Overdue, State,
+        use crate::{
+            error::ContractError,
+            finance::{LpnCoin, LpnCurrencies},
+            loan::{
+                tests::{create_loan_custom, Lpn, LppLoanLocal},
+                Loan, LppRef, Overdue, State,
+            },
+            position::DueTrait,
         };
 
         use super::{LEASE_START, MARGIN_INTEREST_RATE};
@@ -1172,6 +1254,231 @@ mod tests {
         fn state_two_years_plus_day() {
             test_states_paid_by(Duration::YEAR + Duration::YEAR + Duration::from_days(1))
         }
+
+        #[test]
+        fn state_at_past() {
+            let margin_paid_by = LEASE_START + Duration::from_days(30);
+            let loan = create_loan_custom(
+                MARGIN_INTEREST_RATE,
+                LoanResponse {
+                    principal_due: 10000.into(),
+                    annual_interest_rate: Percent::from_permille(145),
+                    interest_paid: LEASE_START,
+                },
+                margin_paid_by,
+                Duration::YEAR,
+            );
+
+            assert_eq!(Ok(()), loan.check_state_at(&margin_paid_by));
+            assert_eq!(
+                Ok(()),
+                loan.check_state_at(&(margin_paid_by + Duration::from_nanos(1)))
+            );
+
+            let at = margin_paid_by - Duration::from_nanos(1);
+            assert_eq!(
+                Err(ContractError::StateBeforeDuePeriod {
+                    at,
+                    due_period_start: margin_paid_by
+                }),
+                loan.check_state_at(&at)
+            );
+            assert!(loan.check_state_at(&LEASE_START).is_err());
+        }
+
+        #[test]
+        fn state_due_interest_below_overdue() {
+            let principal_due = 10000.into();
+            let due_period_len = Duration::YEAR;
+            let now = LEASE_START + due_period_len + Duration::from_days(30);
+            let overdue_till = now - due_period_len;
+
+            let lpp_loan = LppLoanShrinking {
+                loan: LppLoanLocal::new(LoanResponse {
+                    principal_due,
+                    annual_interest_rate: Percent::from_permille(145),
+                    interest_paid: LEASE_START,
+                }),
+                shrink_after: overdue_till,
+            };
+            let overdue_interest = lpp_loan.interest_due(&overdue_till);
+            assert!(!overdue_interest.is_zero());
+            assert!(lpp_loan.interest_due(&now) < overdue_interest);
+
+            let loan = Loan::new(
+                lpp_loan,
+                LEASE_START,
+                MARGIN_INTEREST_RATE,
+                due_period_len,
+                None,
+            );
+            let state = loan.state(&now);
+            assert_eq!(overdue_interest, state.overdue.interest());
+            assert_eq!(Coin::ZERO, state.due_interest);
+
+            let overdue_margin = state.overdue.margin();
+            let due_margin = state.due_margin_interest;
+            let total_interest = overdue_interest + overdue_margin + due_margin;
+            assert_eq!(principal_due + total_interest, state.total_due());
+            let overdue_collection = state.overdue_collection(Coin::ZERO);
+            assert_eq!(Duration::default(), overdue_collection.start_in());
+            assert_eq!(total_interest, overdue_collection.amount());
+        }
+
+        /// An LPP loan whose interest due drops to zero after a point in time
+        struct LppLoanShrinking {
+            loan: LppLoanLocal,
+            shrink_after: Timestamp,
+        }
+
+        impl LppLoan<Lpn, LpnCurrencies> for LppLoanShrinking {
+            fn principal_due(&self) -> LpnCoin {
+                self.loan.principal_due()
+            }
+
+            fn interest_due(&self, by: &Timestamp) -> LpnCoin {
+                if by <= &self.shrink_after {
+                    self.loan.interest_due(by)
+                } else {
+                    Coin::ZERO
+                }
+            }
+
+            fn repay(&mut self, by: &Timestamp, repayment: LpnCoin) -> RepayShares<Lpn> {
+                self.loan.repay(by, repayment)
+            }
+
+            fn annual_interest_rate(&self) -> Percent {
+                self.loan.annual_interest_rate()
+            }
+        }
+
+        impl TryFrom<LppLoanShrinking> for LppBatch<LppRef> {
+            type Error = LppError;
+            fn try_from(_: LppLoanShrinking) -> LppResult<Self> {
+                unreachable!()
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test_promotion {
+        use finance::{coin::Coin, duration::Duration, interest};
+        use lpp::msg::LoanResponse;
+        use sdk::cosmwasm_std::Timestamp;
+
+        use crate::{
+            finance::LpnCoin,
+            loan::{
+                tests::{profit_stub, LppLoanLocal},
+                Loan,
+            },
+        };
+
+        use super::{LEASE_START, LOAN_INTEREST_RATE, MARGIN_INTEREST_RATE};
+
+        const PRINCIPAL: LpnCoin = Coin::new(1_000_000);
+        const DUE_PERIOD: Duration = Duration::from_days(14);
+        const PROMOTION: Duration = Duration::from_days(30);
+
+        #[test]
+        fn no_margin_during_promotion() {
+            let promo = create_loan(LEASE_START, Some(PROMOTION));
+            let regular = create_loan(LEASE_START, None);
+
+            [Duration::default(), Duration::from_days(10), PROMOTION]
+                .into_iter()
+                .map(|since_start| LEASE_START + since_start)
+                .for_each(|now| {
+                    let promo_state = promo.state(&now);
+                    let regular_state = regular.state(&now);
+
+                    assert!(promo_state.due_margin_interest.is_zero());
+                    assert!(promo_state.overdue.margin().is_zero());
+                    assert_eq!(regular_state.principal_due, promo_state.principal_due);
+                    assert_eq!(
+                        regular_state.due_interest + regular_state.overdue.interest(),
+                        promo_state.due_interest + promo_state.overdue.interest()
+                    );
+                });
+            assert!(!regular
+                .state(&(LEASE_START + Duration::from_days(10)))
+                .due_margin_interest
+                .is_zero());
+        }
+
+        #[test]
+        fn accrual_resumes_at_promotion_end() {
+            let promotion_end = LEASE_START + PROMOTION;
+            let promo = create_loan(LEASE_START, Some(PROMOTION));
+            let paid_by_promotion_end = create_loan(promotion_end, None);
+
+            [
+                Duration::from_nanos(1),
+                Duration::from_days(1),
+                DUE_PERIOD,
+                DUE_PERIOD + Duration::from_days(1),
+                Duration::YEAR,
+            ]
+            .into_iter()
+            .map(|since_end| promotion_end + since_end)
+            .for_each(|now| assert_eq!(paid_by_promotion_end.state(&now), promo.state(&now)));
+
+            assert_eq!(
+                interest::interest(MARGIN_INTEREST_RATE, PRINCIPAL, Duration::from_days(1)),
+                promo
+                    .state(&(promotion_end + Duration::from_days(1)))
+                    .due_margin_interest
+            );
+        }
+
+        #[test]
+        fn repay_during_promotion() {
+            let mut promo = create_loan(LEASE_START, Some(PROMOTION));
+            let mut regular = create_loan(LEASE_START, None);
+
+            let payment = 100_000.into();
+            let by = LEASE_START + Duration::from_days(10);
+            let promo_receipt = promo.repay(payment, &by, &mut profit_stub()).unwrap();
+            let regular_receipt = regular.repay(payment, &by, &mut profit_stub()).unwrap();
+
+            assert!(promo_receipt.due_margin_paid().is_zero());
+            assert!(!regular_receipt.due_margin_paid().is_zero());
+            assert_eq!(
+                regular_receipt.due_interest_paid(),
+                promo_receipt.due_interest_paid()
+            );
+            assert_eq!(
+                regular_receipt.principal_paid() + regular_receipt.due_margin_paid(),
+                promo_receipt.principal_paid()
+            );
+
+            let principal_left = PRINCIPAL - promo_receipt.principal_paid();
+            assert_eq!(principal_left, promo.state(&by).principal_due);
+
+            let promotion_end = LEASE_START + PROMOTION;
+            assert!(promo.state(&promotion_end).due_margin_interest.is_zero());
+            assert_eq!(
+                interest::interest(MARGIN_INTEREST_RATE, principal_left, Duration::from_days(1)),
+                promo
+                    .state(&(promotion_end + Duration::from_days(1)))
+                    .due_margin_interest
+            );
+        }
+
+        fn create_loan(start: Timestamp, promotion_period: Option<Duration>) -> Loan<LppLoanLocal> {
+            Loan::new(
+                LppLoanLocal::new(LoanResponse {
+                    principal_due: PRINCIPAL,
+                    annual_interest_rate: LOAN_INTEREST_RATE,
+                    interest_paid: LEASE_START,
+                }),
+                start,
+                MARGIN_INTEREST_RATE,
+                DUE_PERIOD,
+                promotion_period,
+            )
+        }
     }
 
     // TODO migrate to using lpp::stub::unchecked_lpp_loan
@@ -1226,6 +1533,7 @@ mod tests {
             due_start,
             annual_margin_interest,
             due_period,
+            None,
         )
     }
 
@@ -77,7 +77,10 @@ impl Receipt {
 
 #[cfg(test)]
 mod tests {
-    use finance::{coin::Coin, zero::Zero};
+    use finance::{
+        coin::{Amount, Coin},
+        zero::Zero,
+    };
 
     use crate::loan::RepayReceipt;
 
@@ -100,6 +103,26 @@ mod tests {
         assert_eq!(principal, receipt.total());
     }
 
+    #[test]
+    fn total_at_amount_max() {
+        let principal = Coin::new(Amount::MAX);
+        let principal_paid = Coin::new(Amount::MAX - 5);
+
+        let receipt = RepayReceipt::new(
+            Coin::new(1),
+            Coin::new(1),
+            Coin::new(1),
+            Coin::new(1),
+            principal,
+            principal_paid,
+            Coin::new(1),
+        );
+
+        assert_eq!(principal_paid, receipt.principal_paid());
+        assert!(!receipt.close());
+        assert_eq!(principal, receipt.total());
+    }
+
     #[cfg(debug_assertions)]
     #[test]
     #[should_panic = "Payment exceeds principal!"]
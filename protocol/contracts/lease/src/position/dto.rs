@@ -3,6 +3,7 @@ use std::result::Result as StdResult;
 use currency::{CurrencyDef, MemberOf};
 use finance::{
     coin::{Coin, CoinDTO, WithCoin, WithCoinResult},
+    duration::Duration,
     error::Error as FinanceError,
 };
 use serde::{Deserialize, Serialize};
@@ -43,6 +44,11 @@ impl PositionDTO {
         &self.amount
     }
 
+    /// The minimum time between the starts of two consecutive liquidations
+    pub(crate) fn liquidation_cooldown(&self) -> Duration {
+        self.spec.liquidation_cooldown()
+    }
+
     pub fn with_position<V>(self, cmd: V) -> StdResult<V::Output, V::Error>
     where
         V: WithPosition,
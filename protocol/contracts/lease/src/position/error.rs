@@ -24,6 +24,9 @@ pub enum Error {
     #[error("[Position] The position past this close should worth at least {0}")]
     PositionCloseAmountTooBig(LpnCoinDTO),
 
+    #[error("[Position] The position LTV '{ltv}' past this close would not be less than the max lease liability LTV '{top_bound}'!")]
+    PositionCloseLiquidation { ltv: Percent, top_bound: Percent },
+
     #[error("[Position] Invalid close policy! The current lease LTV '{lease_ltv}' would trigger '{strategy}'!")]
     TriggerClose {
         lease_ltv: Percent,
@@ -3,7 +3,7 @@ use finance::{coin::Coin, duration::Duration};
 
 use crate::{
     api::{position::ClosePolicyChange, query::opened::ClosePolicy, LeasePaymentCurrencies},
-    finance::Price,
+    finance::{LpnCoin, Price},
 };
 
 pub use close::Strategy as CloseStrategy;
@@ -12,7 +12,7 @@ pub use error::{Error as PositionError, Result as PositionResult};
 pub use interest::{Due as DueTrait, OverdueCollection};
 pub use spec::{Spec, SpecDTO};
 pub(crate) use status::{Cause, Debt, Liquidation};
-pub(crate) use steady::Steadiness;
+pub(crate) use steady::{Steadiness, PRICE_ALARM_REPLY_ID};
 
 mod close;
 mod dto;
@@ -51,6 +51,12 @@ where
         self.amount -= asset
     }
 
+    pub fn add_collateral(&mut self, asset: Coin<Asset>) {
+        debug_assert!(!asset.is_zero(), "The collateral should be positive");
+
+        self.amount += asset
+    }
+
     /// Compute how much time is necessary for the due interest to become collectable
     ///
     /// If it is already enough to be collected then return zero.
@@ -71,6 +77,14 @@ where
         self.spec.debt(self.amount, due, asset_in_lpns)
     }
 
+    /// Determine the price of the asset at which the position gets liquidated due to its LTV
+    pub fn liquidation_price<Due>(&self, due: &Due) -> Option<Price<Asset>>
+    where
+        Due: DueTrait,
+    {
+        self.spec.liquidation_price(self.amount, due)
+    }
+
     /// Export the close policy state for querying purposes
     ///
     /// Do not use it to implent any business logic!
@@ -103,6 +117,11 @@ where
             })
     }
 
+    /// Check if the position is worth at least the minimum asset amount
+    pub fn valid_asset(&self, asset_in_lpns: Price<Asset>) -> bool {
+        self.spec.valid_asset(self.amount, asset_in_lpns)
+    }
+
     /// Check if the amount can be used for repayment.
     /// Return `error::PositionError::InsufficientTransactionAmount` when the payment amount
     /// is less than the minimum transaction amount.
@@ -135,4 +154,21 @@ where
         self.spec
             .validate_close_amount(self.amount, close_amount, asset_in_lpns)
     }
+
+    /// Check if the position past a close of `close_amount` and a `repayment` stays below
+    /// the liquidation LTV.
+    /// Return `error::PositionError::PositionCloseLiquidation` otherwise.
+    pub fn validate_close_ltv<Due>(
+        &self,
+        close_amount: Coin<Asset>,
+        repayment: LpnCoin,
+        due: &Due,
+        asset_in_lpns: Price<Asset>,
+    ) -> PositionResult<()>
+    where
+        Due: DueTrait,
+    {
+        self.spec
+            .validate_close_ltv(self.amount, close_amount, repayment, due, asset_in_lpns)
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use finance::duration::Duration;
+
 use crate::{
     api::open::PositionSpecDTO,
     position::{close::Policy as ClosePolicy, PositionError, PositionResult, Spec},
@@ -36,6 +38,10 @@ impl SpecDTO {
     fn new(r#const: PositionSpecDTO, close: ClosePolicy) -> Self {
         Self { r#const, close }
     }
+
+    pub(crate) fn liquidation_cooldown(&self) -> Duration {
+        self.r#const.liquidation_cooldown
+    }
 }
 
 impl From<PositionSpecDTO> for SpecDTO {
@@ -59,6 +65,8 @@ impl From<Spec> for SpecDTO {
                 spec.liability,
                 spec.min_asset.into(),
                 spec.min_transaction.into(),
+                spec.min_liquidation_improvement,
+                spec.liquidation_cooldown,
             ),
             spec.close,
         )
@@ -77,7 +85,14 @@ impl TryFrom<SpecDTO> for Spec {
                     .min_transaction
                     .try_into()
                     .map(|min_transaction| {
-                        Self::new(dto.r#const.liability, dto.close, min_asset, min_transaction)
+                        Self::new(
+                            dto.r#const.liability,
+                            dto.close,
+                            min_asset,
+                            min_transaction,
+                            dto.r#const.min_liquidation_improvement,
+                            dto.r#const.liquidation_cooldown,
+                        )
                     })
             })
             .map_err(Into::into)
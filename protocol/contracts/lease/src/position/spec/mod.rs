@@ -7,15 +7,16 @@ use finance::{
     fraction::Fraction,
     fractionable::Fractionable,
     liability::Liability,
-    percent::Percent,
+    percent::{Percent, Units},
     price::{self},
+    ratio::Rational,
     zero::Zero,
 };
 
 use crate::{
     api::{
-        position::ClosePolicyChange, query::opened::ClosePolicy as APIClosePolicy,
-        LeasePaymentCurrencies,
+        open::PositionSpecDTO, position::ClosePolicyChange,
+        query::opened::ClosePolicy as APIClosePolicy, LeasePaymentCurrencies,
     },
     finance::{LpnCoin, Price},
 };
@@ -38,6 +39,8 @@ pub struct Spec {
     close: ClosePolicy,
     min_asset: LpnCoin,
     min_transaction: LpnCoin,
+    min_liquidation_improvement: Percent,
+    liquidation_cooldown: Duration,
 }
 
 impl Spec {
@@ -46,6 +49,8 @@ impl Spec {
         close: ClosePolicy,
         min_asset: LpnCoin,
         min_transaction: LpnCoin,
+        min_liquidation_improvement: Percent,
+        liquidation_cooldown: Duration,
     ) -> Self {
         debug_assert!(!min_asset.is_zero(), "Min asset amount should be positive",);
         debug_assert!(
@@ -53,11 +58,15 @@ impl Spec {
             "Min transaction amount should be positive",
         );
         debug_assert!(close.liquidation_check(liability.max()).is_ok());
+        debug_assert!(min_liquidation_improvement < Percent::HUNDRED);
+        debug_assert!(liquidation_cooldown <= PositionSpecDTO::MAX_LIQUIDATION_COOLDOWN);
         Self {
             liability,
             close,
             min_asset,
             min_transaction,
+            min_liquidation_improvement,
+            liquidation_cooldown,
         }
     }
 
@@ -68,6 +77,8 @@ impl Spec {
             ClosePolicy::default(),
             min_asset,
             min_transaction,
+            Percent::ZERO,
+            Duration::default(),
         )
     }
 
@@ -108,6 +119,8 @@ impl Spec {
                     close_policy,
                     self.min_asset,
                     self.min_transaction,
+                    self.min_liquidation_improvement,
+                    self.liquidation_cooldown,
                 )
             })
     }
@@ -185,6 +198,25 @@ impl Spec {
             })
     }
 
+    /// Determine the price of the asset at which the position LTV reaches the liquidation threshold
+    ///
+    /// Return `None` if there is no such price, for example, the loan has been paid.
+    pub fn liquidation_price<Asset, Due>(
+        &self,
+        asset: Coin<Asset>,
+        due: &Due,
+    ) -> Option<Price<Asset>>
+    where
+        Asset: Currency,
+        Due: DueTrait,
+    {
+        let liquidation_asset = self.liability.max().of(asset);
+        let total_due = due.total_due();
+
+        (!liquidation_asset.is_zero() && !total_due.is_zero())
+            .then(|| price::total_of(liquidation_asset).is(total_due))
+    }
+
     /// Check if the position is subject of a full close due to trigerred close policy
     pub fn check_close<Asset, Due>(
         &self,
@@ -253,6 +285,37 @@ impl Spec {
         }
     }
 
+    /// Check if the position past a close stays below the liquidation LTV
+    ///
+    /// The `close_amount` is sold and the `repayment` goes to the loan, the rest of
+    /// the proceeds, if any, is paid out to the customer.
+    /// Return `error::PositionError::PositionCloseLiquidation` if the position
+    /// would become subject of a liquidation.
+    pub fn validate_close_ltv<Asset, Due>(
+        &self,
+        asset: Coin<Asset>,
+        close_amount: Coin<Asset>,
+        repayment: LpnCoin,
+        due: &Due,
+        asset_in_lpns: Price<Asset>,
+    ) -> PositionResult<()>
+    where
+        Asset: Currency,
+        Due: DueTrait,
+    {
+        let due_assets = Self::to_assets(due.total_due().saturating_sub(repayment), asset_in_lpns);
+        let ltv = Self::ltv(due_assets, asset.saturating_sub(close_amount));
+
+        if ltv < self.liability.max() {
+            Ok(())
+        } else {
+            Err(PositionError::PositionCloseLiquidation {
+                ltv,
+                top_bound: self.liability.max(),
+            })
+        }
+    }
+
     fn valid_transaction<TransactionC>(
         &self,
         amount: Coin<TransactionC>,
@@ -266,7 +329,7 @@ impl Spec {
         amount >= self.min_transaction
     }
 
-    fn valid_asset<TransactionC>(
+    pub(super) fn valid_asset<TransactionC>(
         &self,
         asset_amount: Coin<TransactionC>,
         transaction_currency_in_lpn: Price<TransactionC>,
@@ -291,6 +354,7 @@ impl Spec {
         let liquidation_amount = self.liability.amount_to_liquidate(asset, total_due);
         self.may_ask_liquidation(
             asset,
+            total_due,
             Cause::Liability {
                 ltv: self.liability.max(),
                 healthy_ltv: self.liability.healthy_percent(),
@@ -313,12 +377,19 @@ impl Spec {
         let collectable = self.overdue_collection(due).amount();
         debug_assert!(collectable <= due.total_due());
         let to_liquidate = Self::to_assets(collectable, asset_in_lpns);
-        self.may_ask_liquidation(asset, Cause::Overdue(), to_liquidate, asset_in_lpns)
+        self.may_ask_liquidation(
+            asset,
+            Self::to_assets(due.total_due(), asset_in_lpns),
+            Cause::Overdue(),
+            to_liquidate,
+            asset_in_lpns,
+        )
     }
 
     fn may_ask_liquidation<Asset>(
         &self,
         asset: Coin<Asset>,
+        total_due: Coin<Asset>,
         cause: Cause,
         liquidation: Coin<Asset>,
         asset_in_lpns: Price<Asset>,
@@ -332,14 +403,78 @@ impl Spec {
             Err(_) => unreachable!(), // TODO extract the two PositionError variants to a dedicated type to avoid this match arm
             Ok(()) => {
                 debug_assert!(liquidation < asset);
-                Some(Liquidation::Partial {
-                    amount: liquidation,
-                    cause,
+                Some(match cause {
+                    Cause::Liability { .. } => self.improve_liquidation(
+                        asset,
+                        total_due,
+                        cause,
+                        liquidation,
+                        asset_in_lpns,
+                    ),
+                    Cause::Overdue() => Liquidation::Partial {
+                        amount: liquidation,
+                        cause,
+                    },
                 })
             }
         }
     }
 
+    /// Enlarge a partial liquidation to decrease the LTV by at least the minimum improvement
+    ///
+    /// Escalate to a full liquidation if the position LTV is not above the minimum improvement,
+    /// the target LTV is not below 100%, or the enlarged amount would leave an asset less
+    /// than the minimum.
+    fn improve_liquidation<Asset>(
+        &self,
+        asset: Coin<Asset>,
+        total_due: Coin<Asset>,
+        cause: Cause,
+        liquidation: Coin<Asset>,
+        asset_in_lpns: Price<Asset>,
+    ) -> Liquidation<Asset>
+    where
+        Asset: Currency,
+    {
+        let ltv = Self::ltv(total_due, asset);
+        let ltv_past_liquidation =
+            Self::ltv(total_due.saturating_sub(liquidation), asset - liquidation);
+
+        if ltv_past_liquidation + self.min_liquidation_improvement <= ltv {
+            Liquidation::Partial {
+                amount: liquidation,
+                cause,
+            }
+        } else if ltv <= self.min_liquidation_improvement {
+            Liquidation::Full(cause)
+        } else {
+            // from 'due - liquidation = target% of (asset - liquidation)' follows
+            // liquidation = 100% / (100% - target%) of (due - target% of asset)
+            let target_ltv = ltv - self.min_liquidation_improvement;
+            Percent::HUNDRED
+                .checked_sub(target_ltv)
+                .ok()
+                .filter(|asset_share| !asset_share.is_zero())
+                .map(|asset_share| {
+                    let multiplier = Rational::new(Percent::HUNDRED, asset_share);
+                    Fraction::<Units>::of(
+                        &multiplier,
+                        total_due.saturating_sub(target_ltv.of(asset)),
+                    )
+                    .max(liquidation)
+                })
+                .filter(|&improved_liquidation| {
+                    self.valid_asset(asset.saturating_sub(improved_liquidation), asset_in_lpns)
+                })
+                .map_or(Liquidation::Full(cause), |improved_liquidation| {
+                    Liquidation::Partial {
+                        amount: improved_liquidation,
+                        cause,
+                    }
+                })
+        }
+    }
+
     fn no_liquidation<Asset, Due>(
         &self,
         asset: Coin<Asset>,
@@ -66,6 +66,8 @@ where
         ClosePolicy::default(),
         min_asset.into(),
         min_transaction.into(),
+        Percent::ZERO,
+        Duration::default(),
     )
 }
 
@@ -802,6 +804,75 @@ mod test_debt {
         );
     }
 
+    #[test]
+    fn liquidate_partial_min_improvement() {
+        let max_ltv = Percent::from_permille(881);
+        let spec = spec_with_healthy_improvement(
+            Percent::from_percent(85),
+            max_ltv,
+            Percent::from_percent(5),
+            100,
+            1,
+        );
+        let asset = 1000.into();
+        let cause = Cause::Liability {
+            ltv: max_ltv,
+            healthy_ltv: Percent::from_percent(85),
+        };
+
+        // 88.1% -> 85% is enlarged to 88.1% -> 83.1%
+        assert_eq!(
+            spec.debt(asset, &super::due(881, 1), super::price(1, 1)),
+            Debt::partial(295.into(), cause),
+        );
+        // 92% -> 85% is not enlarged
+        assert_eq!(
+            spec.debt(asset, &super::due(920, 1), super::price(1, 1)),
+            Debt::partial(466.into(), cause),
+        );
+    }
+
+    #[test]
+    fn liquidate_overdue_no_min_improvement() {
+        let max_ltv = Percent::from_permille(881);
+        let spec = spec_with_max_improvement(max_ltv, Percent::from_percent(5), 100, 1);
+        let asset = 1000.into();
+
+        assert_eq!(
+            spec.debt(asset, &super::due(880, 1), super::price(1, 1)),
+            Debt::partial(1.into(), Cause::Overdue()),
+        );
+        assert_eq!(
+            spec.debt(asset, &super::due(294, 294), super::price(1, 3)),
+            Debt::partial(98.into(), Cause::Overdue()),
+        );
+    }
+
+    #[test]
+    fn liquidate_full_min_improvement() {
+        let max_ltv = Percent::from_permille(881);
+        let healthy_ltv = Percent::from_percent(85);
+        let asset = 1000.into();
+        let cause = Cause::Liability {
+            ltv: max_ltv,
+            healthy_ltv,
+        };
+
+        let spec =
+            spec_with_healthy_improvement(healthy_ltv, max_ltv, Percent::from_percent(5), 800, 1);
+        assert_eq!(
+            spec.debt(asset, &super::due(881, 1), super::price(1, 1)),
+            Debt::full(cause),
+        );
+
+        let spec =
+            spec_with_healthy_improvement(healthy_ltv, max_ltv, Percent::from_percent(90), 100, 1);
+        assert_eq!(
+            spec.debt(asset, &super::due(881, 1), super::price(1, 1)),
+            Debt::full(cause),
+        );
+    }
+
     const STEP: Percent = Percent::from_permille(10);
 
     fn spec_with_first<Lpn>(warn: Percent, min_asset: Lpn, min_transaction: Lpn) -> Spec
@@ -825,8 +896,20 @@ mod test_debt {
         spec_with_max(warn + STEP, min_asset, min_transaction)
     }
 
-    // init = 1%, healthy = 1%, first = max - 3, second = max - 2, third = max - 1
     fn spec_with_max<Lpn>(max: Percent, min_asset: Lpn, min_transaction: Lpn) -> Spec
+    where
+        Lpn: Into<Coin<TestLpn>>,
+    {
+        spec_with_max_improvement(max, Percent::ZERO, min_asset, min_transaction)
+    }
+
+    // init = 1%, healthy = 1%, first = max - 3, second = max - 2, third = max - 1
+    fn spec_with_max_improvement<Lpn>(
+        max: Percent,
+        min_liquidation_improvement: Percent,
+        min_asset: Lpn,
+        min_transaction: Lpn,
+    ) -> Spec
     where
         Lpn: Into<Coin<TestLpn>>,
     {
@@ -847,11 +930,58 @@ mod test_debt {
             max,
             RECALC_IN,
         );
+        spec_with_liability(
+            liability,
+            min_liquidation_improvement,
+            min_asset,
+            min_transaction,
+        )
+    }
+
+    // init = healthy, first = max - 3, second = max - 2, third = max - 1
+    fn spec_with_healthy_improvement<Lpn>(
+        healthy: Percent,
+        max: Percent,
+        min_liquidation_improvement: Percent,
+        min_asset: Lpn,
+        min_transaction: Lpn,
+    ) -> Spec
+    where
+        Lpn: Into<Coin<TestLpn>>,
+    {
+        let liability = Liability::new(
+            healthy,
+            healthy,
+            max - STEP - STEP - STEP,
+            max - STEP - STEP,
+            max - STEP,
+            max,
+            RECALC_IN,
+        );
+        spec_with_liability(
+            liability,
+            min_liquidation_improvement,
+            min_asset,
+            min_transaction,
+        )
+    }
+
+    fn spec_with_liability<Lpn>(
+        liability: Liability,
+        min_liquidation_improvement: Percent,
+        min_asset: Lpn,
+        min_transaction: Lpn,
+    ) -> Spec
+    where
+        Lpn: Into<Coin<TestLpn>>,
+    {
         Spec::new(
             liability,
             ClosePolicy::default(),
             min_asset.into(),
             min_transaction.into(),
+            min_liquidation_improvement,
+            Duration::default(),
         )
     }
 
@@ -893,6 +1023,77 @@ mod test_debt {
     }
 }
 
+mod test_liquidation_price {
+    use finance::coin::Coin;
+
+    use crate::position::Debt;
+
+    use super::TestCurrency;
+
+    #[test]
+    fn no_due() {
+        let spec = super::spec(1, 1);
+
+        assert_eq!(
+            None,
+            spec.liquidation_price(Coin::<TestCurrency>::new(1000), &super::due(0, 0))
+        );
+    }
+
+    #[test]
+    fn no_asset() {
+        let spec = super::spec(1, 1);
+
+        assert_eq!(
+            None,
+            spec.liquidation_price(Coin::<TestCurrency>::new(0), &super::due(700, 0))
+        );
+    }
+
+    #[test]
+    fn liquidation_at_price() {
+        let spec = super::spec(1, 1);
+        let asset = 1000.into();
+        let due = super::due(700, 0);
+
+        // 700 LPNs due / 80% of 1000 Asset units
+        let liquidation_price = super::price(800, 700);
+        assert_eq!(Some(liquidation_price), spec.liquidation_price(asset, &due));
+
+        assert!(matches!(
+            spec.debt(asset, &due, liquidation_price),
+            Debt::Bad(_)
+        ));
+        assert!(matches!(
+            spec.debt(asset, &due, super::price(800, 701)),
+            Debt::Ok { .. }
+        ));
+        assert!(matches!(
+            spec.debt(asset, &due, super::price(800, 699)),
+            Debt::Bad(_)
+        ));
+    }
+
+    #[test]
+    fn liquidation_at_price_rounded() {
+        let spec = super::spec(1, 1);
+        let asset = 2857.into();
+        let due = super::due(1857, 0);
+
+        // 80% of 2857 Asset units, rounded down
+        let liquidation_price = super::price(2285, 1857);
+        assert_eq!(Some(liquidation_price), spec.liquidation_price(asset, &due));
+        assert!(matches!(
+            spec.debt(asset, &due, liquidation_price),
+            Debt::Bad(_)
+        ));
+        assert!(matches!(
+            spec.debt(asset, &due, super::price(2285, 1858)),
+            Debt::Ok { .. }
+        ));
+    }
+}
+
 mod test_validate_payment {
     use crate::position::PositionError;
 
@@ -918,6 +1119,8 @@ mod test_validate_payment {
 }
 
 mod test_validate_close {
+    use finance::percent::Percent;
+
     use crate::position::PositionError;
 
     #[test]
@@ -991,6 +1194,50 @@ mod test_validate_close {
         let result_2 = spec.validate_close_amount(asset, 89.into(), super::price(1, 4));
         assert!(result_2.is_ok());
     }
+
+    #[test]
+    fn ltv_below_max() {
+        let spec = super::spec(100, 10);
+        let asset = 1000.into();
+        let due = super::due(700, 0);
+
+        // (700 - 100) / (1000 - 100) = 66.6%
+        assert!(spec
+            .validate_close_ltv(asset, 100.into(), 100.into(), &due, super::price(1, 1))
+            .is_ok());
+        // 700 / (1000 - 100) = 77.7%
+        assert!(spec
+            .validate_close_ltv(asset, 100.into(), 0.into(), &due, super::price(1, 1))
+            .is_ok());
+        // (700 - 100) / (1000 - 200) = 75%
+        assert!(spec
+            .validate_close_ltv(asset, 200.into(), 100.into(), &due, super::price(1, 1))
+            .is_ok());
+    }
+
+    #[test]
+    fn ltv_at_or_above_max() {
+        let spec = super::spec(100, 10);
+        let asset = 1000.into();
+        let due = super::due(700, 0);
+
+        // 700 / (1000 - 200) = 87.5%
+        assert_eq!(
+            Err(PositionError::PositionCloseLiquidation {
+                ltv: Percent::from_permille(875),
+                top_bound: super::MAX_DEBT,
+            }),
+            spec.validate_close_ltv(asset, 200.into(), 0.into(), &due, super::price(1, 1))
+        );
+        // (700 - 60) / (1000 - 200) = 80%
+        assert_eq!(
+            Err(PositionError::PositionCloseLiquidation {
+                ltv: super::MAX_DEBT,
+                top_bound: super::MAX_DEBT,
+            }),
+            spec.validate_close_ltv(asset, 200.into(), 60.into(), &due, super::price(1, 1))
+        );
+    }
 }
 
 mod test_check_close {
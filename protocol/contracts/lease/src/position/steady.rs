@@ -7,7 +7,7 @@ use oracle::{
     api::alarms::Alarm,
     stub::{AsAlarms, PriceAlarms},
 };
-use platform::batch::Batch;
+use platform::batch::{Batch, ReplyId};
 use sdk::cosmwasm_std::Timestamp;
 use timealarms::stub::TimeAlarmsRef;
 
@@ -15,6 +15,9 @@ use crate::{api::LeaseAssetCurrencies, error::ContractResult, finance::OracleRef
 
 use super::Price;
 
+/// The id of the reply delivered if the oracle fails to register the price alarm
+pub(crate) const PRICE_ALARM_REPLY_ID: ReplyId = 1;
+
 /// The position would be steady, i.e. no warnings, automatic close, liquidations,
 /// if the asset price is within a range and is guaranteed for a period of time.
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -53,14 +56,17 @@ where
             .and_then(|schedule_time_alarm| {
                 let mut price_alarms = price_alarms.as_alarms::<LeaseAssetCurrencies>();
                 price_alarms
-                    .add_alarm(Alarm::<LeaseAssetCurrencies, _, _>::new(
-                        // NOTE: we miss alarms in the exact case when the price == SteadyPriceRange::above_excl
-                        // This is due to the discrepancy of the openness of liability LTV ranges and alarms.
-                        // While the former are closed at the start and open at the end, the latter are in reverse.
-                        // The best solution is to turn 'below' into 'below_or_equal' and 'above_or_equal' into 'above'.
-                        self.within.above(),
-                        self.within.may_below_or_equal(),
-                    ))
+                    .add_alarm_reply_on_error(
+                        Alarm::<LeaseAssetCurrencies, _, _>::new(
+                            // NOTE: we miss alarms in the exact case when the price == SteadyPriceRange::above_excl
+                            // This is due to the discrepancy of the openness of liability LTV ranges and alarms.
+                            // While the former are closed at the start and open at the end, the latter are in reverse.
+                            // The best solution is to turn 'below' into 'below_or_equal' and 'above_or_equal' into 'above'.
+                            self.within.above(),
+                            self.within.may_below_or_equal(),
+                        ),
+                        PRICE_ALARM_REPLY_ID,
+                    )
                     .map(|_| schedule_time_alarm.merge(price_alarms.into()))
                     .map_err(Into::into)
             })
@@ -80,7 +86,10 @@ mod tests {
     use sdk::cosmwasm_std::{self, Addr, Timestamp, WasmMsg};
     use timealarms::{msg::ExecuteMsg as TimeAlarmsCmd, stub::TimeAlarmsRef};
 
-    use crate::{api::LeaseAssetCurrencies, position::Steadiness};
+    use crate::{
+        api::LeaseAssetCurrencies,
+        position::{Steadiness, PRICE_ALARM_REPLY_ID},
+    };
 
     const TIME_ALARMS_ADDR: &str = "timealarms";
     const ORACLE_ADDR: &str = "oracle";
@@ -145,18 +154,21 @@ mod tests {
                 funds: vec![],
             });
 
-            batch.schedule_execute_no_reply(WasmMsg::Execute {
-                contract_addr: ORACLE_ADDR.into(),
-                msg: cosmwasm_std::to_json_binary(&PriceAlarmsCmd::AddPriceAlarm::<
-                    LeaseGroup,
-                    TestLpn,
-                    Lpns,
-                > {
-                    alarm: exp_alarm,
-                })
-                .unwrap(),
-                funds: vec![],
-            });
+            batch.schedule_execute_reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: ORACLE_ADDR.into(),
+                    msg: cosmwasm_std::to_json_binary(&PriceAlarmsCmd::AddPriceAlarm::<
+                        LeaseGroup,
+                        TestLpn,
+                        Lpns,
+                    > {
+                        alarm: exp_alarm,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                },
+                PRICE_ALARM_REPLY_ID,
+            );
 
             Ok(batch)
         });
@@ -1,6 +1,6 @@
 use currency::CurrencyDTO;
 use finance::percent::Percent;
-use lease::api::open::{LoanForm, NewLeaseContract, NewLeaseForm};
+use lease::api::open::{LoanForm, NewLeaseContract, NewLeaseForm, OpenOrder};
 use platform::batch::Batch;
 use platform::message::Response as MessageResponse;
 use sdk::cosmwasm_std::{Addr, Coin, Storage};
@@ -11,6 +11,13 @@ use crate::{
     ContractError,
 };
 
+/// The customer's choice of a new lease
+pub struct LeaseSpec {
+    pub currency: CurrencyDTO<LeaseCurrencies>,
+    pub max_ltd: Option<Percent>,
+    pub open_order: Option<OpenOrder>,
+}
+
 pub struct Borrow {}
 impl Borrow {
     pub fn with(
@@ -19,8 +26,7 @@ impl Borrow {
         customer: Addr,
         admin: Addr,
         finalizer: Addr,
-        currency: CurrencyDTO<LeaseCurrencies>,
-        max_ltd: Option<Percent>,
+        spec: LeaseSpec,
     ) -> Result<MessageResponse, ContractError> {
         Leases::cache_open_req(storage, &customer)
             .and_then(|()| Config::load(storage))
@@ -29,7 +35,7 @@ impl Borrow {
                 batch
                     .schedule_instantiate_wasm_reply_on_success(
                         config.lease_code,
-                        &Self::open_lease_msg(customer, config, currency, max_ltd, finalizer),
+                        &Self::open_lease_msg(customer, config, spec, finalizer),
                         Some(amount),
                         "lease".into(),
                         Some(admin), // allows lease migrations from this contract
@@ -44,25 +50,27 @@ impl Borrow {
     pub(crate) fn open_lease_msg(
         customer: Addr,
         config: Config,
-        currency: CurrencyDTO<LeaseCurrencies>,
-        max_ltd: Option<Percent>,
+        spec: LeaseSpec,
         finalizer: Addr,
     ) -> NewLeaseContract {
         NewLeaseContract {
             form: NewLeaseForm {
                 customer,
-                currency,
-                max_ltd,
+                currency: spec.currency,
+                max_ltd: spec.max_ltd,
                 position_spec: config.lease_position_spec,
                 loan: LoanForm {
                     lpp: config.lpp,
                     profit: config.profit,
                     annual_margin_interest: config.lease_interest_rate_margin,
                     due_period: config.lease_due_period,
+                    origination_fee: config.lease_origination_fee,
+                    promotion_period: config.lease_promotion_period,
                 },
                 reserve: config.reserve,
                 time_alarms: config.time_alarms,
                 market_price_oracle: config.market_price_oracle,
+                open_order: spec.open_order,
             },
             dex: config.dex,
             finalizer,
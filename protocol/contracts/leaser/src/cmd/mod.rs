@@ -1,5 +1,7 @@
-pub use borrow::Borrow;
-pub use quote::Quote;
+pub use borrow::{Borrow, LeaseSpec};
+pub use position_value::PositionValue;
+pub use quote::{Quote, QuoteParams};
 
 mod borrow;
+mod position_value;
 mod quote;
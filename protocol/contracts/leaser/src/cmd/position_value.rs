@@ -0,0 +1,86 @@
+use currency::{CurrencyDef, MemberOf};
+use finance::{
+    coin::{Coin, WithCoin, WithCoinResult},
+    liability::Liability,
+    percent::Percent,
+    price::total,
+};
+use lease::api::DownpaymentCoin;
+use oracle_platform::{Oracle as OracleTrait, WithOracle};
+
+use crate::{
+    finance::{LpnCoin, LpnCurrencies, LpnCurrency, PaymentCurrencies},
+    ContractError,
+};
+
+/// Estimate the value, in Lpn, of a position open with a downpayment
+///
+/// The value is the downpayment, converted in Lpn, plus the initial borrow
+/// amount as obtained by the `liability`.
+pub struct PositionValue {
+    downpayment: DownpaymentCoin,
+    liability: Liability,
+    max_ltd: Option<Percent>,
+}
+
+impl PositionValue {
+    pub fn new(
+        downpayment: DownpaymentCoin,
+        liability: Liability,
+        max_ltd: Option<Percent>,
+    ) -> Self {
+        Self {
+            downpayment,
+            liability,
+            max_ltd,
+        }
+    }
+}
+
+impl WithOracle<LpnCurrency, LpnCurrencies> for PositionValue {
+    type G = PaymentCurrencies;
+    type Output = LpnCoin;
+    type Error = ContractError;
+
+    fn exec<O>(self, oracle: O) -> Result<Self::Output, Self::Error>
+    where
+        O: OracleTrait<Self::G, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+    {
+        self.downpayment.with_coin(PositionValueStage2 {
+            oracle,
+            liability: self.liability,
+            max_ltd: self.max_ltd,
+        })
+    }
+}
+
+struct PositionValueStage2<Oracle> {
+    oracle: Oracle,
+    liability: Liability,
+    max_ltd: Option<Percent>,
+}
+
+impl<Oracle> WithCoin<PaymentCurrencies> for PositionValueStage2<Oracle>
+where
+    Oracle: OracleTrait<PaymentCurrencies, QuoteC = LpnCurrency, QuoteG = LpnCurrencies>,
+{
+    type Output = LpnCoin;
+    type Error = ContractError;
+
+    fn on<Dpc>(self, downpayment: Coin<Dpc>) -> WithCoinResult<PaymentCurrencies, Self>
+    where
+        Dpc: CurrencyDef,
+        Dpc::Group: MemberOf<PaymentCurrencies>,
+    {
+        self.oracle
+            .price_of::<Dpc>()
+            .map(|price| total(downpayment, price))
+            .map(|downpayment_lpn| {
+                downpayment_lpn
+                    + self
+                        .liability
+                        .init_borrow_amount(downpayment_lpn, self.max_ltd)
+            })
+            .map_err(Into::into)
+    }
+}
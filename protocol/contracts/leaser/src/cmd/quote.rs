@@ -3,6 +3,8 @@ use std::marker::PhantomData;
 use currency::{AnyVisitor, AnyVisitorResult, Currency, CurrencyDTO, CurrencyDef, MemberOf};
 use finance::{
     coin::{Coin, WithCoin, WithCoinResult},
+    fraction::Fraction,
+    interest,
     liability::Liability,
     percent::Percent,
     price::total,
@@ -17,7 +19,8 @@ use sdk::cosmwasm_std::{QuerierWrapper, StdResult};
 
 use crate::{
     finance::{LeaseCurrencies, LpnCurrencies, LpnCurrency, OracleRef, PaymentCurrencies},
-    msg::QuoteResponse,
+    msg::{QuoteParamsResponse, QuoteResponse},
+    state::config::Config,
     ContractError,
 };
 
@@ -28,6 +31,7 @@ pub struct Quote<'r> {
     oracle: OracleRef,
     liability: Liability,
     lease_interest_rate_margin: Percent,
+    origination_fee: Option<Percent>,
     max_ltd: Option<Percent>,
 }
 
@@ -37,8 +41,7 @@ impl<'r> Quote<'r> {
         downpayment: DownpaymentCoin,
         lease_asset: CurrencyDTO<LeaseCurrencies>,
         oracle: OracleRef,
-        liability: Liability,
-        lease_interest_rate_margin: Percent,
+        config: &Config,
         max_ltd: Option<Percent>,
     ) -> Self {
         Self {
@@ -46,8 +49,9 @@ impl<'r> Quote<'r> {
             lease_asset,
             downpayment,
             oracle,
-            liability,
-            lease_interest_rate_margin,
+            liability: config.lease_position_spec.liability,
+            lease_interest_rate_margin: config.lease_interest_rate_margin,
+            origination_fee: config.lease_origination_fee,
             max_ltd,
         }
     }
@@ -68,6 +72,7 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for Quote<'_> {
                 lpp_quote: LppQuote::new(lpp)?,
                 liability: self.liability,
                 lease_interest_rate_margin: self.lease_interest_rate_margin,
+                origination_fee: self.origination_fee,
                 max_ltd: self.max_ltd,
             },
             self.querier,
@@ -75,6 +80,45 @@ impl WithLppLender<LpnCurrency, LpnCurrencies> for Quote<'_> {
     }
 }
 
+pub struct QuoteParams<'c> {
+    config: &'c Config,
+}
+
+impl<'c> QuoteParams<'c> {
+    pub fn new(config: &'c Config) -> Self {
+        Self { config }
+    }
+}
+
+impl WithLppLender<LpnCurrency, LpnCurrencies> for QuoteParams<'_> {
+    type Output = QuoteParamsResponse;
+    type Error = ContractError;
+
+    fn exec<Lpp>(self, lpp: Lpp) -> Result<Self::Output, Self::Error>
+    where
+        Lpp: LppLenderTrait<LpnCurrency, LpnCurrencies>,
+    {
+        let spec = self.config.lease_position_spec;
+        let min_borrow = Coin::<LpnCurrency>::try_from(spec.min_transaction)?;
+        let lpp_borrow_rate = lpp.borrow_rate()?;
+        let lpp_utilization = lpp.utilization()?;
+        let lpp_total_due = Coin::<LpnCurrency>::try_from(lpp_utilization.total_principal_due)?
+            + Coin::<LpnCurrency>::try_from(lpp_utilization.total_interest_due)?;
+
+        LppQuote::new(lpp)?
+            .with(min_borrow)
+            .map(|lpp_annual_interest_rate| QuoteParamsResponse {
+                lease_position_spec: spec,
+                lease_interest_rate_margin: self.config.lease_interest_rate_margin,
+                lease_origination_fee: self.config.lease_origination_fee,
+                lpp_annual_interest_rate,
+                lpp_borrow_rate,
+                lpp_balance: lpp_utilization.balance,
+                lpp_total_due: lpp_total_due.into(),
+            })
+    }
+}
+
 pub struct LppQuote<Lpn, Lpp> {
     lpn: PhantomData<Lpn>,
     lpp: Lpp,
@@ -115,6 +159,7 @@ where
     lpp_quote: LppQuote<Lpn, Lpp>,
     liability: Liability,
     lease_interest_rate_margin: Percent,
+    origination_fee: Option<Percent>,
     max_ltd: Option<Percent>,
 }
 
@@ -138,6 +183,7 @@ where
             oracle,
             liability: self.liability,
             lease_interest_rate_margin: self.lease_interest_rate_margin,
+            origination_fee: self.origination_fee,
             max_ltd: self.max_ltd,
         })
     }
@@ -153,6 +199,7 @@ where
     oracle: Oracle,
     liability: Liability,
     lease_interest_rate_margin: Percent,
+    origination_fee: Option<Percent>,
     max_ltd: Option<Percent>,
 }
 
@@ -177,6 +224,7 @@ where
             oracle: self.oracle,
             liability: self.liability,
             lease_interest_rate_margin: self.lease_interest_rate_margin,
+            origination_fee: self.origination_fee,
             max_ltd: self.max_ltd,
         })
     }
@@ -193,6 +241,7 @@ where
     oracle: Oracle,
     liability: Liability,
     lease_interest_rate_margin: Percent,
+    origination_fee: Option<Percent>,
     max_ltd: Option<Percent>,
 }
 
@@ -225,15 +274,22 @@ where
 
         let asset_price = self.oracle.price_of::<Asset>()?.inv();
 
-        let total_asset = total(downpayment_lpn + borrow, asset_price);
+        let origination_fee = self
+            .origination_fee
+            .map_or_else(Coin::default, |fee| fee.of(borrow));
+
+        let total_asset = total(downpayment_lpn + borrow - origination_fee, asset_price);
 
         let annual_interest_rate = self.lpp_quote.with(borrow)?;
 
-        Ok(QuoteResponse {
-            total: total_asset.into(),
-            borrow: borrow.into(),
-            annual_interest_rate,
-            annual_interest_rate_margin: self.lease_interest_rate_margin,
-        })
+        interest::effective_apr(annual_interest_rate, self.lease_interest_rate_margin)
+            .map_err(Into::into)
+            .map(|effective_annual_interest_rate| QuoteResponse {
+                total: total_asset.into(),
+                borrow: borrow.into(),
+                annual_interest_rate,
+                annual_interest_rate_margin: self.lease_interest_rate_margin,
+                effective_annual_interest_rate,
+            })
     }
 }
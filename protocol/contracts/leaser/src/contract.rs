@@ -21,12 +21,12 @@ use versioning::{
 };
 
 use crate::{
-    cmd::Borrow,
+    cmd::{Borrow, LeaseSpec},
     error::ContractError,
     leaser::{self, Leaser},
     msg::{ExecuteMsg, InstantiateMsg, MaxLeases, MigrateMsg, QueryMsg, SudoMsg},
     result::ContractResult,
-    state::{config::Config, leases::Leases},
+    state::{config::Config, exposure::Exposure, leases::Leases},
 };
 
 const CONTRACT_STORAGE_VERSION: VersionSegment = 4;
@@ -51,6 +51,10 @@ pub fn instantiate(
     // and it is not yet instantiated
     deps.api.addr_validate(msg.protocols_registry.as_str())?;
 
+    Config::check_origination_fee(msg.lease_origination_fee)?;
+    Config::check_open_cooldown(msg.lease_open_cooldown)?;
+    Config::check_position_spec(&msg.lease_position_spec)?;
+
     ContractOwnerAccess::new(deps.storage.deref_mut()).grant_to(&info.sender)?;
 
     new_code(msg.lease_code, deps.querier)
@@ -85,28 +89,67 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::OpenLease { currency, max_ltd } => Borrow::with(
-            deps.storage,
-            info.funds,
-            info.sender,
-            env.contract.address.clone(),
-            finalizer(env),
+        ExecuteMsg::OpenLease {
             currency,
             max_ltd,
-        ),
+            open_order,
+        } => Config::load(deps.storage)
+            .and_then(|config| {
+                config
+                    .check_lease_currency(&currency)
+                    .and_then(|()| {
+                        Leases::register_open(
+                            deps.storage,
+                            &info.sender,
+                            env.block.time,
+                            config.lease_open_cooldown,
+                        )
+                    })
+                    .and_then(|()| {
+                        leaser::try_reserve_exposure(
+                            deps.storage,
+                            deps.querier,
+                            &config,
+                            currency,
+                            &info.funds,
+                            max_ltd,
+                        )
+                    })
+            })
+            .and_then(|()| {
+                Borrow::with(
+                    deps.storage,
+                    info.funds,
+                    info.sender,
+                    env.contract.address.clone(),
+                    finalizer(env),
+                    LeaseSpec {
+                        currency,
+                        max_ltd,
+                        open_order,
+                    },
+                )
+            }),
         ExecuteMsg::FinalizeLease { customer } => {
             validate_customer(customer, deps.api, deps.querier)
                 .and_then(|customer| {
                     validate_lease(info.sender, deps.as_ref()).map(|lease| (customer, lease))
                 })
                 .and_then(|(customer, lease)| {
-                    Leases::remove(deps.storage, customer, &lease).map_err(Into::into)
+                    Leases::remove_pending(deps.storage, &lease);
+                    Leases::remove(deps.storage, customer, &lease).and_then(|removed| {
+                        Exposure::release(deps.storage, &lease).map(|()| removed)
+                    })
                 })
                 .map(|removed| {
                     debug_assert!(removed);
                     MessageResponse::default()
                 })
         }
+        ExecuteMsg::LeaseOpened {} => validate_lease(info.sender, deps.as_ref()).map(|lease| {
+            Leases::remove_pending(deps.storage, &lease);
+            MessageResponse::default()
+        }),
         ExecuteMsg::MigrateLeases {
             new_code_id,
             max_leases,
@@ -151,12 +194,27 @@ pub fn sudo(deps: DepsMut<'_>, _env: Env, msg: SudoMsg) -> ContractResult<Respon
             lease_interest_rate_margin,
             lease_position_spec,
             lease_due_period,
+            lease_origination_fee,
+            lease_open_cooldown,
+            dex_timeouts,
         } => leaser::try_configure(
             deps.storage,
             lease_interest_rate_margin,
             lease_position_spec,
             lease_due_period,
+            lease_origination_fee,
+            lease_open_cooldown,
+            dex_timeouts,
         ),
+        SudoMsg::ToggleLeaseCurrency { currency, enabled } => {
+            leaser::try_toggle_lease_currency(deps.storage, currency, enabled)
+        }
+        SudoMsg::SetLeaseExposureCap { currency, cap } => {
+            leaser::try_set_lease_exposure_cap(deps.storage, currency, cap)
+        }
+        SudoMsg::SetLeasePromotionPeriod { period } => {
+            leaser::try_set_lease_promotion_period(deps.storage, period)
+        }
         SudoMsg::CloseProtocol {
             new_lease_code_id,
             migration_spec,
@@ -178,7 +236,7 @@ pub fn sudo(deps: DepsMut<'_>, _env: Env, msg: SudoMsg) -> ContractResult<Respon
 }
 
 #[entry_point]
-pub fn query(deps: Deps<'_>, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&Leaser::new(deps).config()?),
         QueryMsg::ProtocolPackageRelease {} => to_json_binary(&CURRENT_RELEASE),
@@ -187,23 +245,35 @@ pub fn query(deps: Deps<'_>, _env: Env, msg: QueryMsg) -> ContractResult<Binary>
             lease_asset,
             max_ltd,
         } => to_json_binary(&Leaser::new(deps).quote(downpayment, lease_asset, max_ltd)?),
+        QueryMsg::QuoteParams {} => to_json_binary(&Leaser::new(deps).quote_params()?),
         QueryMsg::Leases { owner } => to_json_binary(&Leaser::new(deps).customer_leases(owner)?),
+        QueryMsg::PendingLeases { start_after, limit } => {
+            to_json_binary(&Leaser::new(deps).pending_leases(env.block.time, start_after, limit)?)
+        }
+        QueryMsg::AtRisk {
+            zone,
+            start_after,
+            limit,
+        } => to_json_binary(&Leaser::new(deps).at_risk_leases(zone, start_after, limit)?),
     }
     .map_err(Into::into)
     .inspect_err(platform_error::log(deps.api))
 }
 
 #[entry_point]
-pub fn reply(deps: DepsMut<'_>, _env: Env, msg: Reply) -> ContractResult<Response> {
+pub fn reply(deps: DepsMut<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
     reply::from_instantiate_addr_only(deps.api, msg)
         .map_err(|err| ContractError::ParseError {
             err: err.to_string(),
         })
         .and_then(|lease| {
-            Leases::save(deps.storage, lease.clone()).map(|stored| {
-                debug_assert!(stored);
-                lease
-            })
+            Leases::save(deps.storage, lease.clone())
+                .and_then(|stored| {
+                    debug_assert!(stored);
+                    Exposure::save(deps.storage, &lease)
+                })
+                .and_then(|()| Leases::register_pending(deps.storage, &lease, env.block.time))
+                .map(|()| lease)
         })
         .map(|lease| Response::new().add_attribute("lease_address", lease))
         .inspect_err(platform_error::log(deps.api))
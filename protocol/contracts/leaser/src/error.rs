@@ -2,7 +2,12 @@ use std::num::TryFromIntError;
 
 use thiserror::Error;
 
-use sdk::cosmwasm_std::StdError;
+use currency::CurrencyDTO;
+use finance::{duration::Duration, percent::Percent};
+use lease::api::LpnCoinDTO;
+use sdk::cosmwasm_std::{StdError, Timestamp};
+
+use crate::finance::LeaseCurrencies;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -18,6 +23,9 @@ pub enum ContractError {
     #[error("[Leaser] {0}")]
     Lpp(#[from] lpp::error::Error),
 
+    #[error("[Leaser] {0}")]
+    UnexpectedLpn(#[from] lpp::lpn::UnexpectedLpn),
+
     #[error("[Leaser] {0}")]
     PriceOracle(#[from] oracle_platform::error::Error),
 
@@ -48,6 +56,29 @@ pub enum ContractError {
     #[error("[Leaser] The protocol is still in use. There are open leases")]
     ProtocolStillInUse(),
 
+    #[error("[Leaser] The origination fee {fee} exceeds the maximum of {max}")]
+    OriginationFeeTooHigh { fee: Percent, max: Percent },
+
+    #[error("[Leaser] The lease open cooldown {cooldown} exceeds the maximum of {max}")]
+    OpenCooldownTooLong { cooldown: Duration, max: Duration },
+
+    #[error("[Leaser] The lease promotion period {period} exceeds the maximum of {max}")]
+    PromotionPeriodTooLong { period: Duration, max: Duration },
+
+    #[error("[Leaser] A new lease may not be opened before {earliest}")]
+    OpenCooldown { earliest: Timestamp },
+
+    #[error("[Leaser] Opening leases in {currency} is disabled")]
+    LeaseCurrencyDisabled {
+        currency: CurrencyDTO<LeaseCurrencies>,
+    },
+
+    #[error("[Leaser] Opening the lease would exceed the exposure cap in {currency}. The remaining headroom is {headroom}")]
+    ExposureCapExceeded {
+        currency: CurrencyDTO<LeaseCurrencies>,
+        headroom: LpnCoinDTO,
+    },
+
     #[error("[Leaser][ProtocolsRegistry] The protocol deregistration request preparation failed! Cause: {0}")]
     ProtocolDeregistration(platform::error::Error),
 }
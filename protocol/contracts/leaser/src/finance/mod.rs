@@ -3,4 +3,6 @@ pub(crate) use currencies::{
     PaymentGroup as PaymentCurrencies,
 };
 
+pub(crate) type LpnCoin = finance::coin::Coin<LpnCurrency>;
+
 pub(crate) type OracleRef = oracle_platform::OracleRef<LpnCurrency, LpnCurrencies>;
@@ -1,27 +1,38 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, iter, num::NonZeroU16};
 
 use admin_contract::msg::{ExecuteMsg, MigrationSpec, ProtocolContracts};
 use currencies::LeaseGroup;
-use currency::CurrencyDTO;
-use finance::{duration::Duration, percent::Percent};
-use lease::api::{open::PositionSpecDTO, DownpaymentCoin, MigrateMsg};
+use currency::{never, CurrencyDTO};
+use finance::{coin::IntoDTO, duration::Duration, percent::Percent};
+use lease::api::{
+    open::{IcaTimeouts, PositionSpecDTO},
+    query::{QueryMsg as LeaseQueryMsg, StateResponse as LeaseStateResponse, SummaryResponse},
+    DownpaymentCoin, LpnCoinDTO, MigrateMsg,
+};
 use lpp::{msg::ExecuteMsg as LppExecuteMsg, stub::LppRef};
 use platform::{
+    bank,
     batch::{Batch, Emit, Emitter},
     contract::Code,
     message::Response as MessageResponse,
 };
 use reserve::api::ExecuteMsg as ReserveExecuteMsg;
-use sdk::cosmwasm_std::{Addr, Deps, Storage};
+use sdk::cosmwasm_std::{
+    Addr, Coin as CwCoin, Deps, QuerierWrapper, StdResult, Storage, Timestamp,
+};
 use versioning::ProtocolMigrationMessage;
 
 use crate::{
-    cmd::Quote,
-    finance::LpnCurrencies,
+    cmd::{PositionValue, Quote, QuoteParams},
+    finance::{LpnCoin, LpnCurrencies, PaymentCurrencies},
     migrate,
-    msg::{ConfigResponse, MaxLeases, QuoteResponse},
+    msg::{
+        AtRiskLease, AtRiskResponse, ConfigResponse, MaxLeases, PendingLease,
+        PendingLeasesResponse, QuoteParamsResponse, QuoteResponse, MAX_AT_RISK_VISITED,
+        MAX_PENDING_VISITED,
+    },
     result::ContractResult,
-    state::{config::Config, leases::Leases},
+    state::{config::Config, exposure::Exposure, leases::Leases},
 };
 use crate::{
     finance::{LpnCurrency, OracleRef},
@@ -33,6 +44,11 @@ pub struct Leaser<'a> {
     deps: Deps<'a>,
 }
 
+enum LeaseRisk {
+    AtRisk(AtRiskLease),
+    Unavailable(Addr),
+}
+
 impl<'a> Leaser<'a> {
     pub fn new(deps: Deps<'a>) -> Self {
         Self { deps }
@@ -45,6 +61,135 @@ impl<'a> Leaser<'a> {
         Leases::load_by_customer(self.deps.storage, customer)
     }
 
+    pub fn pending_leases(
+        &self,
+        now: Timestamp,
+        start_after: Option<Addr>,
+        limit: Option<NonZeroU16>,
+    ) -> ContractResult<PendingLeasesResponse> {
+        let max_visited = usize::from(limit.map_or(MAX_PENDING_VISITED, |limit| {
+            limit.get().min(MAX_PENDING_VISITED)
+        }));
+
+        let mut pending = Leases::iter_pending(self.deps.storage, start_after).peekable();
+        let mut last = None;
+        let mut leases = vec![];
+        for record in pending.by_ref().take(max_visited) {
+            let (lease, instantiated_at) = record?;
+            if !self.awaits_order(&lease) {
+                leases.push(PendingLease {
+                    lease: lease.clone(),
+                    age: Duration::between(&instantiated_at, &now),
+                });
+            }
+            last = Some(lease);
+        }
+        Ok(PendingLeasesResponse {
+            leases,
+            next: pending.peek().and(last),
+        })
+    }
+
+    pub fn at_risk_leases(
+        &self,
+        zone: u8,
+        start_after: Option<Addr>,
+        limit: Option<NonZeroU16>,
+    ) -> ContractResult<AtRiskResponse> {
+        let max_visited = usize::from(limit.map_or(MAX_AT_RISK_VISITED, |limit| {
+            limit.get().min(MAX_AT_RISK_VISITED)
+        }));
+
+        let mut customers = Leases::iter_after(self.deps.storage, start_after).peekable();
+        let mut visited = 0;
+        let (leases, unavailable, last) = iter::from_fn(|| {
+            customers
+                .next_if(|_| visited < max_visited)
+                .map(|record| record.inspect(|(_, leases)| visited += leases.len()))
+        })
+        .try_fold(
+            (vec![], vec![], None),
+            |(mut leases, mut unavailable, _), record| {
+                record.map(|(customer, customer_leases)| {
+                    // the leases are kept in a hash set so they are sorted for a deterministic response
+                    let mut customer_leases: Vec<_> = customer_leases.into_iter().collect();
+                    customer_leases.sort();
+                    customer_leases
+                        .into_iter()
+                        .filter_map(|lease| self.at_risk_lease(lease, &customer, zone))
+                        .for_each(|risk| match risk {
+                            LeaseRisk::AtRisk(lease) => leases.push(lease),
+                            LeaseRisk::Unavailable(lease) => unavailable.push(lease),
+                        });
+                    (leases, unavailable, Some(customer))
+                })
+            },
+        )?;
+        Ok(AtRiskResponse {
+            leases,
+            unavailable,
+            next: customers.peek().and(last),
+        })
+    }
+
+    fn at_risk_lease(&self, lease: Addr, customer: &Addr, zone: u8) -> Option<LeaseRisk> {
+        match self
+            .deps
+            .querier
+            .query_wasm_smart(lease.clone(), &LeaseQueryMsg::Summary {})
+        {
+            Ok(summary) => {
+                Self::at_risk_summary(summary, lease, customer, zone).map(LeaseRisk::AtRisk)
+            }
+            // the summary is supported only by the opened leases
+            Err(_) => self
+                .maybe_opened(&lease)
+                .then_some(LeaseRisk::Unavailable(lease)),
+        }
+    }
+
+    fn at_risk_summary(
+        summary: SummaryResponse,
+        lease: Addr,
+        customer: &Addr,
+        zone: u8,
+    ) -> Option<AtRiskLease> {
+        summary
+            .liability_zone
+            .filter(|lease_zone| summary.in_progress.is_none() && lease_zone.level >= zone)
+            .map(|lease_zone| AtRiskLease {
+                lease,
+                customer: customer.clone(),
+                ltv: summary.ltv,
+                zone: lease_zone.level,
+            })
+    }
+
+    /// Check whether the lease is opened, or its state is unavailable
+    fn maybe_opened(&self, lease: &Addr) -> bool {
+        self.lease_state(lease).map_or(true, |state| {
+            matches!(state, LeaseStateResponse::Opened { .. })
+        })
+    }
+
+    /// Whether the lease awaits the market price to reach the target of its open order
+    ///
+    /// A lease whose state cannot be obtained is considered not to.
+    fn awaits_order(&self, lease: &Addr) -> bool {
+        self.lease_state(lease)
+            .is_ok_and(|state| matches!(state, LeaseStateResponse::Pending { .. }))
+    }
+
+    fn lease_state(&self, lease: &Addr) -> StdResult<LeaseStateResponse> {
+        self.deps.querier.query_wasm_smart(
+            lease,
+            &LeaseQueryMsg::State {
+                due_projection: Default::default(),
+                at: None,
+            },
+        )
+    }
+
     pub fn quote(
         &self,
         downpayment: DownpaymentCoin,
@@ -52,10 +197,13 @@ impl<'a> Leaser<'a> {
         max_ltd: Option<Percent>,
     ) -> ContractResult<QuoteResponse> {
         let config = Config::load(self.deps.storage)?;
+        config.check_lease_currency(&lease_asset)?;
 
-        let lpp = LppRef::<LpnCurrency, LpnCurrencies>::try_new(config.lpp, self.deps.querier)?;
+        let lpp =
+            LppRef::<LpnCurrency, LpnCurrencies>::try_new(config.lpp.clone(), self.deps.querier)?;
 
-        let oracle = OracleRef::try_from_base(config.market_price_oracle, self.deps.querier)?;
+        let oracle =
+            OracleRef::try_from_base(config.market_price_oracle.clone(), self.deps.querier)?;
 
         lpp.execute_lender(
             Quote::new(
@@ -63,13 +211,19 @@ impl<'a> Leaser<'a> {
                 downpayment,
                 lease_asset,
                 oracle,
-                config.lease_position_spec.liability,
-                config.lease_interest_rate_margin,
+                &config,
                 max_ltd,
             ),
             self.deps.querier,
         )
     }
+
+    pub fn quote_params(&self) -> ContractResult<QuoteParamsResponse> {
+        let config = Config::load(self.deps.storage)?;
+
+        LppRef::<LpnCurrency, LpnCurrencies>::try_new(config.lpp.clone(), self.deps.querier)?
+            .execute_lender(QuoteParams::new(&config), self.deps.querier)
+    }
 }
 
 pub(super) fn try_configure(
@@ -77,16 +231,86 @@ pub(super) fn try_configure(
     lease_interest_rate_margin: Percent,
     lease_position_spec: PositionSpecDTO,
     lease_due_period: Duration,
+    lease_origination_fee: Option<Percent>,
+    lease_open_cooldown: Option<Duration>,
+    dex_timeouts: Option<IcaTimeouts>,
 ) -> ContractResult<MessageResponse> {
     Config::update(
         storage,
         lease_interest_rate_margin,
         lease_position_spec,
         lease_due_period,
+        lease_origination_fee,
+        lease_open_cooldown,
+        dex_timeouts,
     )
     .map(|()| MessageResponse::default())
 }
 
+pub(super) fn try_toggle_lease_currency(
+    storage: &mut dyn Storage,
+    currency: CurrencyDTO<LeaseGroup>,
+    enabled: bool,
+) -> ContractResult<MessageResponse> {
+    Config::update_lease_currency(storage, currency, enabled).map(|()| MessageResponse::default())
+}
+
+pub(super) fn try_set_lease_exposure_cap(
+    storage: &mut dyn Storage,
+    currency: CurrencyDTO<LeaseGroup>,
+    cap: Option<LpnCoinDTO>,
+) -> ContractResult<MessageResponse> {
+    Config::update_lease_exposure_cap(storage, currency, cap).map(|()| MessageResponse::default())
+}
+
+pub(super) fn try_set_lease_promotion_period(
+    storage: &mut dyn Storage,
+    period: Option<Duration>,
+) -> ContractResult<MessageResponse> {
+    Config::update_lease_promotion_period(storage, period).map(|()| MessageResponse::default())
+}
+
+/// Account for the position of a lease about to be open if its currency is capped
+///
+/// The position is valued by the downpayment in `funds` at the current oracle prices.
+pub(super) fn try_reserve_exposure(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper<'_>,
+    config: &Config,
+    currency: CurrencyDTO<LeaseGroup>,
+    funds: &[CwCoin],
+    max_ltd: Option<Percent>,
+) -> ContractResult<()> {
+    config
+        .lease_exposure_caps
+        .get(&currency)
+        .map_or(Ok(()), |&cap| {
+            LpnCoin::try_from(cap)
+                .map_err(ContractError::from)
+                .and_then(|cap| {
+                    bank::may_received(funds, IntoDTO::<PaymentCurrencies>::new())
+                        .map(never::safe_unwrap)
+                        .ok_or(ContractError::ZeroDownpayment {})
+                        .map(|downpayment| (cap, downpayment))
+                })
+                .and_then(|(cap, downpayment)| {
+                    OracleRef::try_from_base(config.market_price_oracle.clone(), querier)
+                        .map_err(ContractError::from)
+                        .and_then(|oracle| {
+                            oracle.execute_as_oracle(
+                                PositionValue::new(
+                                    downpayment,
+                                    config.lease_position_spec.liability,
+                                    max_ltd,
+                                ),
+                                querier,
+                            )
+                        })
+                        .and_then(|value| Exposure::reserve(storage, currency, value, cap))
+                })
+        })
+}
+
 pub(super) fn try_migrate_leases<MsgFactory>(
     storage: &mut dyn Storage,
     new_lease: Code,
@@ -200,7 +424,7 @@ mod test {
     use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent};
     use json_value::JsonValue;
     use lease::api::{
-        open::{ConnectionParams, Ics20Channel, PositionSpecDTO},
+        open::{ConnectionParams, IcaTimeouts, Ics20Channel, PositionSpecDTO},
         MigrateMsg,
     };
     use platform::{contract::Code, response};
@@ -299,15 +523,20 @@ mod test {
                 ),
                 min_asset: Coin::<Lpn>::from(120_000).into(),
                 min_transaction: Coin::<Lpn>::from(12_000).into(),
+                min_liquidation_improvement: Percent::ZERO,
+                liquidation_cooldown: Duration::default(),
             },
             lease_interest_rate_margin: Percent::from_percent(3),
             lease_due_period: Duration::from_days(14),
+            lease_origination_fee: None,
+            lease_open_cooldown: Duration::default(),
             dex: ConnectionParams {
                 connection_id: "conn-12".into(),
                 transfer_channel: Ics20Channel {
                     local_endpoint: "chan-1".into(),
                     remote_endpoint: "chan-13".into(),
                 },
+                timeouts: IcaTimeouts::default(),
             },
         }
     }
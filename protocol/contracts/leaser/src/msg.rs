@@ -1,12 +1,15 @@
+use std::num::NonZeroU16;
+
 use serde::{Deserialize, Serialize};
 
 use admin_contract::msg::{MigrationSpec, ProtocolContracts};
 use currency::CurrencyDTO;
 use finance::{duration::Duration, percent::Percent};
 use lease::api::{
-    open::{ConnectionParams, PositionSpecDTO},
+    open::{ConnectionParams, IcaTimeouts, OpenOrder, PositionSpecDTO},
     DownpaymentCoin, LeaseCoin, LpnCoinDTO,
 };
+use lpp::borrow::InterestRate;
 use sdk::{
     cosmwasm_std::{Addr, Uint64},
     schemars::{self, JsonSchema},
@@ -30,6 +33,14 @@ pub struct InstantiateMsg {
     pub lease_position_spec: PositionSpecDTO,
     pub lease_interest_rate_margin: Percent,
     pub lease_due_period: Duration,
+    /// The percent of the borrowed amount charged on opening a lease, optional
+    #[serde(default)]
+    pub lease_origination_fee: Option<Percent>,
+    /// The minimum time between two lease openings by the same customer, optional
+    ///
+    /// If not provided, a customer may open leases without any delay.
+    #[serde(default)]
+    pub lease_open_cooldown: Duration,
     pub dex: ConnectionParams,
 }
 
@@ -47,11 +58,20 @@ pub enum ExecuteMsg {
         currency: CurrencyDTO<LeaseCurrencies>,
         #[serde(default)]
         max_ltd: Option<Percent>,
+        /// Defer the lease opening until the market price reaches a target, optional
+        ///
+        /// The downpayment is held by the lease until then. Refer to [`OpenOrder`] for details.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        open_order: Option<OpenOrder>,
     },
     /// A callback from a lease that it has just entered a final state
     ///
     /// It matches the `lease::api::FinalizerExecuteMsg::FinalizeLease`.
     FinalizeLease { customer: Addr },
+    /// A callback from a lease that it has just been opened
+    ///
+    /// It matches the `lease::api::FinalizerExecuteMsg::LeaseOpened`.
+    LeaseOpened {},
     /// Start a Lease migration
     ///
     /// The consumed gas is a limitaton factor for the maximum lease instances that
@@ -95,7 +115,44 @@ pub enum SudoMsg {
         lease_interest_rate_margin: Percent,
         lease_position_spec: PositionSpecDTO,
         lease_due_period: Duration,
+        /// The new origination fee of the leases to be opened, optional
+        ///
+        /// If not provided, the current one is kept.
+        #[serde(default)]
+        lease_origination_fee: Option<Percent>,
+        /// The new minimum time between two lease openings by the same customer, optional
+        ///
+        /// If not provided, the current one is kept.
+        #[serde(default)]
+        lease_open_cooldown: Option<Duration>,
+        /// The new timeouts of the Dex transactions of the leases to be opened, optional
+        ///
+        /// If not provided, the current ones are kept.
+        #[serde(default)]
+        dex_timeouts: Option<IcaTimeouts>,
+    },
+    /// Enable or disable opening new leases in a lease currency
+    ///
+    /// The already open leases are not affected.
+    ToggleLeaseCurrency {
+        currency: CurrencyDTO<LeaseCurrencies>,
+        enabled: bool,
+    },
+    /// Set, or remove, the exposure cap of a lease currency
+    ///
+    /// The cap limits the aggregate value, in Lpn, of the positions open in the currency.
+    /// Only the leases opened while the currency is capped count against the cap.
+    /// The positions are valued at the market prices on their opening and are not
+    /// revalued afterwards.
+    SetLeaseExposureCap {
+        currency: CurrencyDTO<LeaseCurrencies>,
+        cap: Option<LpnCoinDTO>,
     },
+    /// Set, or remove, the interest-free promotion period of the leases to be opened
+    ///
+    /// No margin interest accrues on a lease during the period since its opening.
+    /// The already open leases are not affected.
+    SetLeasePromotionPeriod { period: Option<Duration> },
     CloseProtocol {
         // Since this is an external system API we should not use [Code].
         new_lease_code_id: Uint64,
@@ -133,11 +190,64 @@ pub enum QueryMsg {
         #[serde(default)]
         max_ltd: Option<Percent>,
     },
+    /// Report the parameters needed to reproduce a quote off-chain
+    ///
+    /// Returns [QuoteParamsResponse]
+    QuoteParams {},
     Leases {
         owner: Addr,
     },
+    /// List the leases that have been instantiated but have not been opened yet
+    ///
+    /// A lease stays pending until it confirms being opened, or gets finalized
+    /// beforehand. A pending lease of considerable age is likely stuck on opening.
+    /// The leases awaiting the market price to reach the target of their open order
+    /// are not listed. The age of a lease opened by an order counts since
+    /// the instantiation, not the trigger, of the order.
+    ///
+    /// The leases are visited in the order of their addresses, starting right after
+    /// `start_after`, or at the first one if not provided. The visit stops once `limit`,
+    /// or [`MAX_PENDING_VISITED`], leases have been visited. The response `next`,
+    /// if present, should be passed as `start_after` to resume the visit.
+    ///
+    /// Returns [PendingLeasesResponse]
+    PendingLeases {
+        #[serde(default)]
+        start_after: Option<Addr>,
+        #[serde(default)]
+        limit: Option<NonZeroU16>,
+    },
+    /// List the leases whose LTV is in a liability zone at or above `zone`
+    ///
+    /// The zones are ordered by their liquidation warning level, zero being the healthy one.
+    /// The leases are visited customer by customer, in the order of the customer addresses,
+    /// starting right after the customer `start_after`, or at the first one if not provided.
+    /// The visit stops once `limit`, or [`MAX_AT_RISK_VISITED`], leases have been visited,
+    /// completing the leases of the last visited customer. The response `next`, if present,
+    /// should be passed as `start_after` to resume the visit.
+    ///
+    /// Each visited lease is queried for its summary, which in turn queries the oracle
+    /// and the LPP. Therefore, the gas cost grows linearly with the number of visited leases.
+    /// The leases that are not opened, have a transaction in progress, or are subject of
+    /// a liquidation or a close, are skipped. The opened leases whose summary cannot be
+    /// obtained are reported as unavailable.
+    ///
+    /// Returns [AtRiskResponse]
+    AtRisk {
+        zone: u8,
+        #[serde(default)]
+        start_after: Option<Addr>,
+        #[serde(default)]
+        limit: Option<NonZeroU16>,
+    },
 }
 
+/// The maximum number of leases visited by a [`QueryMsg::PendingLeases`]
+pub const MAX_PENDING_VISITED: u16 = 50;
+
+/// The maximum number of leases visited by a [`QueryMsg::AtRisk`]
+pub const MAX_AT_RISK_VISITED: u16 = 50;
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -153,6 +263,76 @@ pub struct QuoteResponse {
     pub borrow: LpnCoinDTO,
     pub annual_interest_rate: Percent,
     pub annual_interest_rate_margin: Percent,
+    /// The annual interest rate the customer pays, the loan plus the margin one
+    pub effective_annual_interest_rate: Percent,
+}
+
+/// The parameters a [QuoteResponse] is calculated from
+///
+/// The borrow amount is obtained from the downpayment, converted in Lpn, by
+/// `lease_position_spec.liability`. The origination fee, if any, is a percent of
+/// the borrow amount and is deducted from the total position amount.
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct QuoteParamsResponse {
+    pub lease_position_spec: PositionSpecDTO,
+    pub lease_interest_rate_margin: Percent,
+    pub lease_origination_fee: Option<Percent>,
+    /// The LPP annual interest rate for a borrow of `lease_position_spec.min_transaction`
+    ///
+    /// This is a point-in-time snapshot. The rate depends on the LPP utilization,
+    /// so it changes over time and may be higher for bigger borrow amounts.
+    pub lpp_annual_interest_rate: Percent,
+    /// The LPP model of the annual interest rate
+    ///
+    /// The rate of a borrow is computed out of the LPP utilization past it,
+    /// i.e. of `lpp_total_due` plus the borrow, and `lpp_balance` minus the borrow.
+    pub lpp_borrow_rate: InterestRate,
+    /// The LPP balance available for borrowing
+    pub lpp_balance: LpnCoinDTO,
+    /// The total amount the LPP loans owe, principal and interest
+    pub lpp_total_due: LpnCoinDTO,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PendingLeasesResponse {
+    pub leases: Vec<PendingLease>,
+    /// The last visited lease if the visit has been truncated
+    pub next: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PendingLease {
+    pub lease: Addr,
+    /// The time elapsed since the lease has been instantiated
+    pub age: Duration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct AtRiskResponse {
+    pub leases: Vec<AtRiskLease>,
+    /// The opened leases whose summary could not be obtained
+    pub unavailable: Vec<Addr>,
+    /// The last visited customer if the visit has been truncated
+    pub next: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct AtRiskLease {
+    pub lease: Addr,
+    pub customer: Addr,
+    pub ltv: Percent,
+    /// The liquidation warning level of the zone the `ltv` falls in
+    pub zone: u8,
 }
 
 #[cfg(test)]
@@ -175,6 +355,10 @@ mod test {
             }),
             platform_tests::ser_de(&ExecuteMsg::FinalizeLease { customer }),
         );
+        assert_eq!(
+            Ok(FinalizerExecuteMsg::LeaseOpened {}),
+            platform_tests::ser_de(&ExecuteMsg::LeaseOpened {}),
+        );
     }
 
     #[test]
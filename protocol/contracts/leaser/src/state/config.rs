@@ -1,9 +1,17 @@
-use std::mem;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem,
+};
 
 use serde::{Deserialize, Serialize};
 
+use currency::CurrencyDTO;
 use finance::{duration::Duration, percent::Percent};
-use lease::api::open::{ConnectionParams, PositionSpecDTO};
+use lease::api::{
+    open::{ConnectionParams, IcaTimeouts, PositionSpecDTO},
+    LpnCoinDTO,
+};
+use lpp::lpn::LpnCoin as ConfiguredLpnCoin;
 use platform::contract::Code;
 use sdk::{
     cosmwasm_std::{Addr, Storage},
@@ -11,7 +19,12 @@ use sdk::{
     schemars::{self, JsonSchema},
 };
 
-use crate::{msg::InstantiateMsg, result::ContractResult};
+use crate::{
+    error::ContractError,
+    finance::{LeaseCurrencies, LpnCurrency},
+    msg::InstantiateMsg,
+    result::ContractResult,
+};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
@@ -26,12 +39,38 @@ pub struct Config {
     pub lease_position_spec: PositionSpecDTO,
     pub lease_interest_rate_margin: Percent,
     pub lease_due_period: Duration,
+    #[serde(default)]
+    pub lease_origination_fee: Option<Percent>,
+    #[serde(default)]
+    pub lease_open_cooldown: Duration,
+    /// How long since their opening the new leases do not accrue margin interest, optional
+    #[serde(default)]
+    pub lease_promotion_period: Option<Duration>,
     pub dex: ConnectionParams,
+    /// The lease currencies new leases may not be opened in
+    ///
+    /// All lease currencies are enabled by default.
+    #[serde(default)]
+    pub disabled_lease_currencies: BTreeSet<CurrencyDTO<LeaseCurrencies>>,
+    /// The maximum aggregate value, in Lpn, of the open positions per lease currency
+    ///
+    /// The lease currencies not listed here are not capped.
+    #[serde(default)]
+    pub lease_exposure_caps: BTreeMap<CurrencyDTO<LeaseCurrencies>, LpnCoinDTO>,
 }
 
 impl Config {
     const STORAGE: Item<Self> = Item::new("config");
 
+    /// The maximum origination fee a lease might be charged on opening
+    pub const MAX_ORIGINATION_FEE: Percent = Percent::from_permille(50);
+
+    /// The maximum time a customer might be required to wait between opening two leases
+    pub const MAX_OPEN_COOLDOWN: Duration = Duration::YEAR;
+
+    /// The maximum time a new lease might not accrue margin interest for
+    pub const MAX_PROMOTION_PERIOD: Duration = Duration::YEAR;
+
     pub fn new(lease_code: Code, msg: InstantiateMsg) -> Self {
         Self {
             lease_code,
@@ -44,7 +83,12 @@ impl Config {
             lease_position_spec: msg.lease_position_spec,
             lease_interest_rate_margin: msg.lease_interest_rate_margin,
             lease_due_period: msg.lease_due_period,
+            lease_origination_fee: msg.lease_origination_fee,
+            lease_open_cooldown: msg.lease_open_cooldown,
+            lease_promotion_period: None,
             dex: msg.dex,
+            disabled_lease_currencies: BTreeSet::default(),
+            lease_exposure_caps: BTreeMap::default(),
         }
     }
 
@@ -56,23 +100,97 @@ impl Config {
         Self::STORAGE.load(storage).map_err(Into::into)
     }
 
+    pub fn check_origination_fee(fee: Option<Percent>) -> ContractResult<()> {
+        match fee {
+            Some(fee) if fee > Self::MAX_ORIGINATION_FEE => {
+                Err(ContractError::OriginationFeeTooHigh {
+                    fee,
+                    max: Self::MAX_ORIGINATION_FEE,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_open_cooldown(cooldown: Duration) -> ContractResult<()> {
+        if cooldown > Self::MAX_OPEN_COOLDOWN {
+            Err(ContractError::OpenCooldownTooLong {
+                cooldown,
+                max: Self::MAX_OPEN_COOLDOWN,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_promotion_period(period: Duration) -> ContractResult<()> {
+        if period > Self::MAX_PROMOTION_PERIOD {
+            Err(ContractError::PromotionPeriodTooLong {
+                period,
+                max: Self::MAX_PROMOTION_PERIOD,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check the amounts of the lease position specification are in the configured Lpn
+    pub fn check_position_spec(spec: &PositionSpecDTO) -> ContractResult<()> {
+        Self::check_lpn(spec.min_asset).and_then(|()| Self::check_lpn(spec.min_transaction))
+    }
+
+    pub fn check_lpn(amount: LpnCoinDTO) -> ContractResult<()> {
+        ConfiguredLpnCoin::<LpnCurrency>::try_new(amount)
+            .map(mem::drop)
+            .map_err(Into::into)
+    }
+
+    pub fn check_lease_currency(
+        &self,
+        currency: &CurrencyDTO<LeaseCurrencies>,
+    ) -> ContractResult<()> {
+        if self.disabled_lease_currencies.contains(currency) {
+            Err(ContractError::LeaseCurrencyDisabled {
+                currency: *currency,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn update(
         storage: &mut dyn Storage,
         lease_interest_rate_margin: Percent,
         lease_position_spec: PositionSpecDTO,
         lease_due_period: Duration,
+        lease_origination_fee: Option<Percent>,
+        lease_open_cooldown: Option<Duration>,
+        dex_timeouts: Option<IcaTimeouts>,
     ) -> ContractResult<()> {
-        Self::STORAGE
-            .update(storage, |c| {
-                ContractResult::Ok(Self {
-                    lease_interest_rate_margin,
-                    lease_position_spec,
-                    lease_due_period,
-                    ..c
-                })
+        Self::check_origination_fee(lease_origination_fee)
+            .and_then(|()| lease_open_cooldown.map_or(Ok(()), Self::check_open_cooldown))
+            .and_then(|()| Self::check_position_spec(&lease_position_spec))
+            .and_then(|()| {
+                Self::STORAGE
+                    .update(storage, |mut c| {
+                        if let Some(fee) = lease_origination_fee {
+                            c.lease_origination_fee = Some(fee);
+                        }
+                        if let Some(cooldown) = lease_open_cooldown {
+                            c.lease_open_cooldown = cooldown;
+                        }
+                        if let Some(timeouts) = dex_timeouts {
+                            c.dex.timeouts = timeouts;
+                        }
+                        ContractResult::Ok(Self {
+                            lease_interest_rate_margin,
+                            lease_position_spec,
+                            lease_due_period,
+                            ..c
+                        })
+                    })
+                    .map(mem::drop)
             })
-            .map(mem::drop)
-            .map_err(Into::into)
     }
 
     pub fn update_lease_code(storage: &mut dyn Storage, new_code: Code) -> ContractResult<()> {
@@ -86,4 +204,58 @@ impl Config {
             .map(mem::drop)
             .map_err(Into::into)
     }
+
+    pub fn update_lease_currency(
+        storage: &mut dyn Storage,
+        currency: CurrencyDTO<LeaseCurrencies>,
+        enabled: bool,
+    ) -> ContractResult<()> {
+        Self::STORAGE
+            .update(storage, |mut c| -> ContractResult<Config> {
+                if enabled {
+                    c.disabled_lease_currencies.remove(&currency);
+                } else {
+                    c.disabled_lease_currencies.insert(currency);
+                }
+                Ok(c)
+            })
+            .map(mem::drop)
+    }
+
+    pub fn update_lease_promotion_period(
+        storage: &mut dyn Storage,
+        period: Option<Duration>,
+    ) -> ContractResult<()> {
+        period
+            .map_or(Ok(()), Self::check_promotion_period)
+            .and_then(|()| {
+                Self::STORAGE
+                    .update(storage, |c| -> ContractResult<Config> {
+                        Ok(Self {
+                            lease_promotion_period: period,
+                            ..c
+                        })
+                    })
+                    .map(mem::drop)
+            })
+    }
+
+    pub fn update_lease_exposure_cap(
+        storage: &mut dyn Storage,
+        currency: CurrencyDTO<LeaseCurrencies>,
+        cap: Option<LpnCoinDTO>,
+    ) -> ContractResult<()> {
+        cap.map_or(Ok(()), Self::check_lpn).and_then(|()| {
+            Self::STORAGE
+                .update(storage, |mut c| -> ContractResult<Config> {
+                    if let Some(cap) = cap {
+                        c.lease_exposure_caps.insert(currency, cap);
+                    } else {
+                        c.lease_exposure_caps.remove(&currency);
+                    }
+                    Ok(c)
+                })
+                .map(mem::drop)
+        })
+    }
 }
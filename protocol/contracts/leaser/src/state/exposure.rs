@@ -0,0 +1,222 @@
+use std::{collections::BTreeMap, mem};
+
+use currency::CurrencyDTO;
+use sdk::{
+    cosmwasm_std::{Addr, StdResult, Storage},
+    cw_storage_plus::{Item, Map},
+};
+
+use crate::{
+    error::ContractError,
+    finance::{LeaseCurrencies, LpnCoin},
+    result::ContractResult,
+};
+
+type Position = (CurrencyDTO<LeaseCurrencies>, LpnCoin);
+type Totals = BTreeMap<CurrencyDTO<LeaseCurrencies>, LpnCoin>;
+
+/// The aggregate value of the open positions per lease currency
+///
+/// A position is valued in Lpn on opening and is accounted for until its lease gets finalized.
+/// Only the leases opened while their currency has been capped are accounted for.
+///
+/// The values are never revalued, neither as the market prices move nor as the positions
+/// get repaid or partially closed. Therefore, the totals reflect the exposure at the time
+/// of opening and may deviate from the current market exposure.
+pub(crate) struct Exposure {}
+
+impl Exposure {
+    const PENDING_POSITION: Item<Position> = Item::new("pending_position");
+    const TOTALS: Item<Totals> = Item::new("exposure");
+    const LEASE_POSITIONS: Map<Addr, Position> = Map::new("lease_positions");
+
+    /// Account for the position of a lease about to be open
+    ///
+    /// Fail if the aggregate value of the positions in `currency` would exceed `cap`.
+    /// The error reports the remaining headroom.
+    pub fn reserve(
+        storage: &mut dyn Storage,
+        currency: CurrencyDTO<LeaseCurrencies>,
+        value: LpnCoin,
+        cap: LpnCoin,
+    ) -> ContractResult<()> {
+        Self::load_totals(storage)
+            .and_then(|mut totals| {
+                let total = totals.get(&currency).copied().unwrap_or_default();
+                total
+                    .checked_add(value)
+                    .filter(|new_total| new_total <= &cap)
+                    .ok_or_else(|| ContractError::ExposureCapExceeded {
+                        currency,
+                        headroom: cap.saturating_sub(total).into(),
+                    })
+                    .map(|new_total| {
+                        totals.insert(currency, new_total);
+                        totals
+                    })
+            })
+            .and_then(|totals| Self::TOTALS.save(storage, &totals).map_err(Into::into))
+            .and_then(|()| {
+                Self::PENDING_POSITION
+                    .save(storage, &(currency, value))
+                    .map_err(Into::into)
+            })
+    }
+
+    /// Assign the position reserved last, if any, to the just instantiated lease
+    pub fn save(storage: &mut dyn Storage, lease: &Addr) -> ContractResult<()> {
+        Self::PENDING_POSITION
+            .may_load(storage)
+            .and_then(|may_position| {
+                may_position.map_or(Ok(()), |position| {
+                    Self::PENDING_POSITION.remove(storage);
+                    Self::LEASE_POSITIONS.save(storage, lease.clone(), &position)
+                })
+            })
+            .map_err(Into::into)
+    }
+
+    /// Release the position of a finalized lease, if it has been accounted for
+    pub fn release(storage: &mut dyn Storage, lease: &Addr) -> ContractResult<()> {
+        Self::LEASE_POSITIONS
+            .may_load(storage, lease.clone())
+            .and_then(|may_position| {
+                may_position.map_or(Ok(()), |(currency, value)| {
+                    Self::LEASE_POSITIONS.remove(storage, lease.clone());
+                    Self::TOTALS
+                        .update(storage, |mut totals| -> StdResult<Totals> {
+                            if let Some(total) = totals.get_mut(&currency) {
+                                *total = total.saturating_sub(value);
+                            }
+                            Ok(totals)
+                        })
+                        .map(mem::drop)
+                })
+            })
+            .map_err(Into::into)
+    }
+
+    /// The aggregate value of the accounted positions in `currency`
+    #[cfg(test)]
+    pub fn total(
+        storage: &dyn Storage,
+        currency: &CurrencyDTO<LeaseCurrencies>,
+    ) -> ContractResult<LpnCoin> {
+        Self::load_totals(storage).map(|totals| totals.get(currency).copied().unwrap_or_default())
+    }
+
+    fn load_totals(storage: &dyn Storage) -> ContractResult<Totals> {
+        Self::TOTALS
+            .may_load(storage)
+            .map(Option::unwrap_or_default)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::testing::{LeaseC1, LeaseC2};
+    use currency::CurrencyDTO;
+    use sdk::cosmwasm_std::{testing::MockStorage, Addr};
+
+    use crate::{
+        finance::{LeaseCurrencies, LpnCoin},
+        state::exposure::Exposure,
+        ContractError,
+    };
+
+    const CAP: LpnCoin = LpnCoin::new(1_000);
+
+    #[test]
+    fn reserve_within_cap() {
+        let mut storage = MockStorage::default();
+        assert_eq!(
+            Ok(()),
+            Exposure::reserve(&mut storage, currency(), LpnCoin::new(400), CAP)
+        );
+        assert_eq!(
+            Ok(()),
+            Exposure::reserve(&mut storage, currency(), LpnCoin::new(600), CAP)
+        );
+        assert_eq!(Ok(CAP), Exposure::total(&storage, &currency()));
+        assert_eq!(
+            Ok(LpnCoin::default()),
+            Exposure::total(&storage, &another_currency())
+        );
+    }
+
+    #[test]
+    fn reserve_over_cap() {
+        let mut storage = MockStorage::default();
+        Exposure::reserve(&mut storage, currency(), LpnCoin::new(700), CAP).unwrap();
+
+        assert_eq!(
+            Err(ContractError::ExposureCapExceeded {
+                currency: currency(),
+                headroom: LpnCoin::new(300).into(),
+            }),
+            Exposure::reserve(&mut storage, currency(), LpnCoin::new(301), CAP)
+        );
+        assert_eq!(
+            Ok(LpnCoin::new(700)),
+            Exposure::total(&storage, &currency())
+        );
+
+        assert_eq!(
+            Ok(()),
+            Exposure::reserve(&mut storage, another_currency(), LpnCoin::new(301), CAP)
+        );
+    }
+
+    #[test]
+    fn release_on_finalize() {
+        let mut storage = MockStorage::default();
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+
+        Exposure::reserve(&mut storage, currency(), LpnCoin::new(700), CAP).unwrap();
+        Exposure::save(&mut storage, &lease1).unwrap();
+        Exposure::reserve(&mut storage, currency(), LpnCoin::new(300), CAP).unwrap();
+        Exposure::save(&mut storage, &lease2).unwrap();
+        assert!(Exposure::reserve(&mut storage, currency(), LpnCoin::new(1), CAP).is_err());
+
+        Exposure::release(&mut storage, &lease1).unwrap();
+        assert_eq!(
+            Ok(LpnCoin::new(300)),
+            Exposure::total(&storage, &currency())
+        );
+        Exposure::release(&mut storage, &lease1).unwrap();
+        assert_eq!(
+            Ok(LpnCoin::new(300)),
+            Exposure::total(&storage, &currency())
+        );
+
+        assert_eq!(
+            Ok(()),
+            Exposure::reserve(&mut storage, currency(), LpnCoin::new(700), CAP)
+        );
+    }
+
+    #[test]
+    fn release_not_accounted() {
+        let mut storage = MockStorage::default();
+        Exposure::reserve(&mut storage, currency(), LpnCoin::new(700), CAP).unwrap();
+        Exposure::save(&mut storage, &Addr::unchecked("lease1")).unwrap();
+
+        let not_capped = Addr::unchecked("lease2");
+        Exposure::save(&mut storage, &not_capped).unwrap();
+        Exposure::release(&mut storage, &not_capped).unwrap();
+        assert_eq!(
+            Ok(LpnCoin::new(700)),
+            Exposure::total(&storage, &currency())
+        );
+    }
+
+    fn currency() -> CurrencyDTO<LeaseCurrencies> {
+        currency::dto::<LeaseC1, _>()
+    }
+
+    fn another_currency() -> CurrencyDTO<LeaseCurrencies> {
+        currency::dto::<LeaseC2, _>()
+    }
+}
@@ -1,11 +1,13 @@
 use std::collections::{hash_set::IntoIter, HashSet};
 
+use finance::duration::Duration;
 use sdk::{
-    cosmwasm_std::{Addr, Order, StdResult, Storage},
+    cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp},
     cw_storage_plus::{Bound, Item, Map},
 };
 
 use crate::{
+    error::ContractError,
     migrate::{Customer, MaybeCustomer},
     result::ContractResult,
 };
@@ -15,6 +17,8 @@ pub(crate) struct Leases {}
 impl Leases {
     const PENDING_CUSTOMER: Item<Addr> = Item::new("pending_customer");
     const CUSTOMER_LEASES: Map<Addr, HashSet<Addr>> = Map::new("loans");
+    const CUSTOMER_LAST_OPEN: Map<Addr, Timestamp> = Map::new("last_open");
+    const PENDING_OPENS: Map<Addr, Timestamp> = Map::new("pending_opens");
 
     pub fn cache_open_req(storage: &mut dyn Storage, customer: &Addr) -> ContractResult<()> {
         Self::PENDING_CUSTOMER
@@ -22,6 +26,38 @@ impl Leases {
             .map_err(Into::into)
     }
 
+    /// Register a lease opening by the customer at `now`
+    ///
+    /// Fail if the previous opening by the same customer has happened less than `cooldown` ago.
+    /// Nothing is registered if there is no cooldown.
+    pub fn register_open(
+        storage: &mut dyn Storage,
+        customer: &Addr,
+        now: Timestamp,
+        cooldown: Duration,
+    ) -> ContractResult<()> {
+        if cooldown == Duration::default() {
+            Ok(())
+        } else {
+            Self::CUSTOMER_LAST_OPEN
+                .may_load(storage, customer.clone())
+                .map_err(Into::into)
+                .and_then(|may_last_open| match may_last_open {
+                    Some(last_open) if Duration::between(&last_open, &now) < cooldown => {
+                        Err(ContractError::OpenCooldown {
+                            earliest: last_open + cooldown,
+                        })
+                    }
+                    _ => Ok(()),
+                })
+                .and_then(|()| {
+                    Self::CUSTOMER_LAST_OPEN
+                        .save(storage, customer.clone(), &now)
+                        .map_err(Into::into)
+                })
+        }
+    }
+
     /// Return true if the lease has been stored or false if there has already been the same lease
     pub fn save(storage: &mut dyn Storage, lease: Addr) -> ContractResult<bool> {
         let mut stored = false;
@@ -41,6 +77,35 @@ impl Leases {
             .map_err(Into::into)
     }
 
+    /// Register a lease instantiated at `now` as pending until it confirms being opened
+    pub fn register_pending(
+        storage: &mut dyn Storage,
+        lease: &Addr,
+        now: Timestamp,
+    ) -> ContractResult<()> {
+        Self::PENDING_OPENS
+            .save(storage, lease.clone(), &now)
+            .map_err(Into::into)
+    }
+
+    /// Stop tracking the lease as pending, if it has been
+    pub fn remove_pending(storage: &mut dyn Storage, lease: &Addr) {
+        Self::PENDING_OPENS.remove(storage, lease.clone())
+    }
+
+    /// Iterate over the pending leases past `start_after`
+    ///
+    /// Each lease comes along with the time it has been instantiated at.
+    pub fn iter_pending(
+        storage: &dyn Storage,
+        start_after: Option<Addr>,
+    ) -> impl Iterator<Item = ContractResult<(Addr, Timestamp)>> + '_ {
+        let start_bound = start_after.map(Bound::<Addr>::exclusive);
+        Self::PENDING_OPENS
+            .range(storage, start_bound, None, Order::Ascending)
+            .map(|record| record.map_err(Into::into))
+    }
+
     pub fn load_by_customer(
         storage: &dyn Storage,
         customer: Addr,
@@ -66,6 +131,17 @@ impl Leases {
             .map_err(Into::into)
     }
 
+    /// Iterate over the customers, along with their leases, past `start_after`
+    pub fn iter_after(
+        storage: &dyn Storage,
+        start_after: Option<Addr>,
+    ) -> impl Iterator<Item = ContractResult<(Addr, HashSet<Addr>)>> + '_ {
+        let start_bound = start_after.map(Bound::<Addr>::exclusive);
+        Self::CUSTOMER_LEASES
+            .range(storage, start_bound, None, Order::Ascending)
+            .map(|record| record.map_err(Into::into))
+    }
+
     pub fn iter(
         storage: &dyn Storage,
         next_customer: Option<Addr>,
@@ -84,7 +160,8 @@ impl Leases {
 
 #[cfg(test)]
 mod test {
-    use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage};
+    use finance::duration::Duration;
+    use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage, Timestamp};
 
     use crate::{state::leases::Leases, ContractError};
 
@@ -133,6 +210,62 @@ mod test {
         assert!(lease_exist(&storage, &test_another_lease()));
     }
 
+    #[test]
+    fn test_register_open_no_cooldown() {
+        let mut storage = MockStorage::default();
+        let now = Timestamp::from_seconds(100);
+        assert_eq!(
+            Ok(()),
+            Leases::register_open(&mut storage, &test_customer(), now, Duration::default())
+        );
+        assert_eq!(
+            Ok(()),
+            Leases::register_open(&mut storage, &test_customer(), now, Duration::default())
+        );
+        assert_eq!(
+            Ok(None),
+            Leases::CUSTOMER_LAST_OPEN.may_load(&storage, test_customer())
+        );
+    }
+
+    #[test]
+    fn test_register_open_cooldown() {
+        let mut storage = MockStorage::default();
+        let cooldown = Duration::from_hours(1);
+        let first_open = Timestamp::from_seconds(100);
+        assert_eq!(
+            Ok(()),
+            Leases::register_open(&mut storage, &test_customer(), first_open, cooldown)
+        );
+
+        let too_early = first_open + cooldown - Duration::from_nanos(1);
+        assert_eq!(
+            Err(ContractError::OpenCooldown {
+                earliest: first_open + cooldown
+            }),
+            Leases::register_open(&mut storage, &test_customer(), too_early, cooldown)
+        );
+        assert_eq!(
+            Ok(()),
+            Leases::register_open(
+                &mut storage,
+                &Addr::unchecked("another_customer"),
+                too_early,
+                cooldown
+            )
+        );
+
+        let second_open = first_open + cooldown;
+        assert_eq!(
+            Ok(()),
+            Leases::register_open(&mut storage, &test_customer(), second_open, cooldown)
+        );
+        assert!(matches!(
+            Leases::register_open(&mut storage, &test_customer(), second_open, cooldown),
+            Err(ContractError::OpenCooldown { .. })
+        ));
+    }
+
     #[test]
     fn test_remove_not_exist() {
         let mut storage = MockStorage::default();
@@ -162,6 +295,73 @@ mod test {
         assert_lease_not_exist(&storage);
     }
 
+    #[test]
+    fn test_pending_stuck() {
+        let mut storage = MockStorage::default();
+        let instantiated_at = Timestamp::from_seconds(100);
+        Leases::register_pending(&mut storage, &test_lease(), instantiated_at).unwrap();
+        Leases::register_pending(&mut storage, &test_another_lease(), instantiated_at).unwrap();
+
+        Leases::remove_pending(&mut storage, &test_another_lease());
+        assert_eq!(vec![(test_lease(), instantiated_at)], pending(&storage));
+    }
+
+    #[test]
+    fn test_pending_opened() {
+        let mut storage = MockStorage::default();
+        assert_eq!(Vec::<(Addr, Timestamp)>::new(), pending(&storage));
+
+        Leases::register_pending(&mut storage, &test_lease(), Timestamp::from_seconds(100))
+            .unwrap();
+        Leases::remove_pending(&mut storage, &test_lease());
+        assert_eq!(Vec::<(Addr, Timestamp)>::new(), pending(&storage));
+
+        Leases::remove_pending(&mut storage, &test_lease());
+        assert_eq!(Vec::<(Addr, Timestamp)>::new(), pending(&storage));
+    }
+
+    #[test]
+    fn test_pending_after() {
+        let mut storage = MockStorage::default();
+        let instantiated_at = Timestamp::from_seconds(100);
+        Leases::register_pending(&mut storage, &test_lease(), instantiated_at).unwrap();
+        Leases::register_pending(&mut storage, &test_another_lease(), instantiated_at).unwrap();
+
+        assert_eq!(
+            vec![(test_another_lease(), instantiated_at)],
+            Leases::iter_pending(&storage, Some(test_lease()))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            Leases::iter_pending(&storage, Some(test_another_lease())).count()
+        );
+    }
+
+    #[test]
+    fn test_iter_after() {
+        let mut storage = MockStorage::default();
+        let another_customer = Addr::unchecked("customerY");
+        Leases::cache_open_req(&mut storage, &test_customer()).unwrap();
+        Leases::save(&mut storage, test_lease()).unwrap();
+        Leases::cache_open_req(&mut storage, &another_customer).unwrap();
+        Leases::save(&mut storage, test_another_lease()).unwrap();
+
+        assert_eq!(
+            vec![test_customer(), another_customer.clone()],
+            customers_after(&storage, None)
+        );
+        assert_eq!(
+            vec![another_customer.clone()],
+            customers_after(&storage, Some(test_customer()))
+        );
+        assert_eq!(
+            Vec::<Addr>::new(),
+            customers_after(&storage, Some(another_customer))
+        );
+    }
+
     fn test_customer() -> Addr {
         const CUSTOMER: &str = "customerX";
         Addr::unchecked(CUSTOMER)
@@ -187,6 +387,19 @@ mod test {
         assert!(!lease_exist(storage, &test_lease()));
     }
 
+    fn pending(storage: &dyn Storage) -> Vec<(Addr, Timestamp)> {
+        Leases::iter_pending(storage, None)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn customers_after(storage: &dyn Storage, start_after: Option<Addr>) -> Vec<Addr> {
+        Leases::iter_after(storage, start_after)
+            .map(|record| record.map(|(customer, _leases)| customer))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
     fn lease_exist(storage: &dyn Storage, lease: &Addr) -> bool {
         Leases::load_by_customer(storage, test_customer())
             .unwrap()
@@ -3,7 +3,10 @@ use cosmwasm_std::{
     OwnedDeps,
 };
 
-use currencies::{testing::LeaseC1, LeaseGroup, Lpn};
+use currencies::{
+    testing::{LeaseC1, LeaseC2},
+    LeaseGroup, Lpn,
+};
 use currency::{CurrencyDTO, CurrencyDef as _};
 use finance::{
     coin::{Amount, Coin},
@@ -12,24 +15,26 @@ use finance::{
     percent::Percent,
 };
 use lease::api::{
-    open::{ConnectionParams, Ics20Channel, PositionSpecDTO},
+    open::{ConnectionParams, IcaTimeouts, Ics20Channel, PositionSpecDTO},
     LpnCoinDTO,
 };
 use platform::contract::{Code, CodeId};
 
 use sdk::{
+    cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
-        coins, from_json, testing, to_json_binary, Addr, CosmosMsg, Deps, DepsMut, MessageInfo,
-        SubMsg, WasmMsg,
+        coins, from_json, testing, to_json_binary, Addr, CosmosMsg, Deps, DepsMut, Env,
+        MessageInfo, SubMsg, WasmMsg,
     },
     testing as sdk_testing,
 };
 
 use crate::{
-    cmd::Borrow,
+    cmd::{Borrow, LeaseSpec},
     contract::{execute, instantiate, query, sudo},
     msg::{ConfigResponse, ExecuteMsg, QueryMsg, SudoMsg},
     state::config::Config,
+    ContractError,
 };
 
 const CREATOR: &str = "creator";
@@ -72,6 +77,8 @@ fn leaser_instantiate_msg(lease_code: Code, lpp: Addr) -> crate::msg::Instantiat
         ),
         lease_interest_rate_margin: MARGIN_INTEREST_RATE,
         lease_due_period: Duration::from_days(90),
+        lease_origination_fee: None,
+        lease_open_cooldown: Duration::default(),
         dex: dex_params(),
     }
 }
@@ -111,6 +118,7 @@ fn dex_params() -> ConnectionParams {
             local_endpoint: "channel-0".into(),
             remote_endpoint: "channel-2048".into(),
         },
+        timeouts: IcaTimeouts::default(),
     }
 }
 
@@ -131,6 +139,7 @@ fn proper_initialization() {
     let config = config_response.config;
     assert_eq!(lease_code, config.lease_code);
     assert_eq!(lpp_addr, config.lpp);
+    assert!(config.disabled_lease_currencies.is_empty());
 }
 
 #[test]
@@ -152,6 +161,10 @@ fn test_update_config() {
         lpn_coin(100_000),
     );
     let expected_due_period = Duration::from_secs(100);
+    let expected_origination_fee = Some(Percent::from_permille(5));
+    let expected_open_cooldown = Duration::from_minutes(10);
+    let expected_dex_timeouts =
+        IcaTimeouts::new(Duration::from_hours(4), Duration::from_minutes(45)).unwrap();
 
     setup_test_case(deps.as_mut());
 
@@ -159,6 +172,9 @@ fn test_update_config() {
         lease_interest_rate_margin: Percent::from_percent(5),
         lease_position_spec: expected_position_spec,
         lease_due_period: expected_due_period,
+        lease_origination_fee: expected_origination_fee,
+        lease_open_cooldown: Some(expected_open_cooldown),
+        dex_timeouts: Some(expected_dex_timeouts),
     };
 
     sudo(deps.as_mut(), testing::mock_env(), msg).unwrap();
@@ -166,18 +182,309 @@ fn test_update_config() {
     let config = query_config(deps.as_ref());
     assert_eq!(expected_position_spec, config.lease_position_spec);
     assert_eq!(expected_due_period, config.lease_due_period);
+    assert_eq!(expected_origination_fee, config.lease_origination_fee);
+    assert_eq!(expected_open_cooldown, config.lease_open_cooldown);
+    assert_eq!(expected_dex_timeouts, config.dex.timeouts);
+}
+
+#[test]
+fn update_config_keeps_absent() {
+    let mut deps = deps();
+
+    let origination_fee = Some(Percent::from_permille(5));
+    let open_cooldown = Duration::from_minutes(10);
+    let msg = crate::msg::InstantiateMsg {
+        lease_origination_fee: origination_fee,
+        lease_open_cooldown: open_cooldown,
+        ..leaser_instantiate_msg(Code::unchecked(1), sdk_testing::user(LPP_ADDR))
+    };
+    instantiate(deps.as_mut(), testing::mock_env(), owner(), msg).unwrap();
+    let config = query_config(deps.as_ref());
+
+    let expected_due_period = config.lease_due_period + Duration::from_secs(100);
+    let msg = SudoMsg::Config {
+        lease_interest_rate_margin: config.lease_interest_rate_margin,
+        lease_position_spec: config.lease_position_spec,
+        lease_due_period: expected_due_period,
+        lease_origination_fee: None,
+        lease_open_cooldown: None,
+        dex_timeouts: None,
+    };
+    sudo(deps.as_mut(), testing::mock_env(), msg).unwrap();
+
+    let updated_config = query_config(deps.as_ref());
+    assert_eq!(expected_due_period, updated_config.lease_due_period);
+    assert_eq!(origination_fee, updated_config.lease_origination_fee);
+    assert_eq!(open_cooldown, updated_config.lease_open_cooldown);
+    assert_eq!(config.dex.timeouts, updated_config.dex.timeouts);
+}
+
+#[test]
+fn instantiate_too_high_origination_fee() {
+    let mut deps = deps();
+
+    let fee = Config::MAX_ORIGINATION_FEE + Percent::from_permille(1);
+    let msg = crate::msg::InstantiateMsg {
+        lease_origination_fee: Some(fee),
+        ..leaser_instantiate_msg(Code::unchecked(1), sdk_testing::user(LPP_ADDR))
+    };
+
+    assert_eq!(
+        Err(ContractError::OriginationFeeTooHigh {
+            fee,
+            max: Config::MAX_ORIGINATION_FEE
+        }),
+        instantiate(deps.as_mut(), testing::mock_env(), owner(), msg)
+    );
 }
 
-fn open_lease_with(max_ltd: Option<Percent>) {
+#[test]
+fn update_config_too_high_origination_fee() {
     let mut deps = deps();
 
     setup_test_case(deps.as_mut());
+    let config = query_config(deps.as_ref());
+
+    let fee = Config::MAX_ORIGINATION_FEE + Percent::from_permille(1);
+    let msg = SudoMsg::Config {
+        lease_interest_rate_margin: config.lease_interest_rate_margin,
+        lease_position_spec: config.lease_position_spec,
+        lease_due_period: config.lease_due_period,
+        lease_origination_fee: Some(fee),
+        lease_open_cooldown: None,
+        dex_timeouts: None,
+    };
+
+    assert_eq!(
+        Err(ContractError::OriginationFeeTooHigh {
+            fee,
+            max: Config::MAX_ORIGINATION_FEE
+        }),
+        sudo(deps.as_mut(), testing::mock_env(), msg)
+    );
+    assert_eq!(None, query_config(deps.as_ref()).lease_origination_fee);
+}
+
+#[test]
+fn instantiate_too_long_open_cooldown() {
+    let mut deps = deps();
+
+    let cooldown = Config::MAX_OPEN_COOLDOWN + Duration::from_secs(1);
+    let msg = crate::msg::InstantiateMsg {
+        lease_open_cooldown: cooldown,
+        ..leaser_instantiate_msg(Code::unchecked(1), sdk_testing::user(LPP_ADDR))
+    };
+
+    assert_eq!(
+        Err(ContractError::OpenCooldownTooLong {
+            cooldown,
+            max: Config::MAX_OPEN_COOLDOWN
+        }),
+        instantiate(deps.as_mut(), testing::mock_env(), owner(), msg)
+    );
+}
+
+#[test]
+fn open_lease_cooldown() {
+    let cooldown = Duration::from_hours(1);
+
+    let mut deps = deps();
+    let msg = crate::msg::InstantiateMsg {
+        lease_open_cooldown: cooldown,
+        ..leaser_instantiate_msg(Code::unchecked(1), sdk_testing::user(LPP_ADDR))
+    };
+    instantiate(deps.as_mut(), testing::mock_env(), owner(), msg).unwrap();
+
+    let open_lease = |deps: DepsMut<'_>, env: Env| {
+        execute(
+            deps,
+            env,
+            customer(),
+            ExecuteMsg::OpenLease {
+                currency: lease_currency(),
+                max_ltd: None,
+                open_order: None,
+            },
+        )
+    };
+
+    let mut env = testing::mock_env();
+    let first_open = env.block.time;
+    open_lease(deps.as_mut(), env.clone()).unwrap();
+
+    env.block.time = first_open + cooldown - Duration::from_secs(1);
+    assert_eq!(
+        ContractError::OpenCooldown {
+            earliest: first_open + cooldown
+        },
+        open_lease(deps.as_mut(), env.clone()).unwrap_err()
+    );
+
+    env.block.time = first_open + cooldown;
+    open_lease(deps.as_mut(), env).unwrap();
+}
+
+#[test]
+fn open_lease_no_cooldown() {
+    let mut deps = deps();
+    setup_test_case(deps.as_mut());
+
+    let msg = ExecuteMsg::OpenLease {
+        currency: lease_currency(),
+        max_ltd: None,
+        open_order: None,
+    };
+    execute(deps.as_mut(), testing::mock_env(), customer(), msg.clone()).unwrap();
+    execute(deps.as_mut(), testing::mock_env(), customer(), msg).unwrap();
+}
+
+#[test]
+fn open_lease_disabled_currency() {
+    let mut deps = deps();
+    setup_test_case(deps.as_mut());
+
+    let open_lease = |deps: DepsMut<'_>, currency| {
+        execute(
+            deps,
+            testing::mock_env(),
+            customer(),
+            ExecuteMsg::OpenLease {
+                currency,
+                max_ltd: None,
+                open_order: None,
+            },
+        )
+    };
+    let toggle_currency = |deps: DepsMut<'_>, enabled| {
+        sudo(
+            deps,
+            testing::mock_env(),
+            SudoMsg::ToggleLeaseCurrency {
+                currency: lease_currency(),
+                enabled,
+            },
+        )
+        .unwrap()
+    };
+    let other_currency = currency::dto::<LeaseC2, _>();
+
+    toggle_currency(deps.as_mut(), false);
+    assert_eq!(
+        ContractError::LeaseCurrencyDisabled {
+            currency: lease_currency()
+        },
+        open_lease(deps.as_mut(), lease_currency()).unwrap_err()
+    );
+    assert_eq!(
+        ContractError::LeaseCurrencyDisabled {
+            currency: lease_currency()
+        },
+        query(
+            deps.as_ref(),
+            testing::mock_env(),
+            QueryMsg::Quote {
+                downpayment: Coin::<TheCurrency>::from(1000).into(),
+                lease_asset: lease_currency(),
+                max_ltd: None,
+            },
+        )
+        .unwrap_err()
+    );
+    open_lease(deps.as_mut(), other_currency).unwrap();
+
+    toggle_currency(deps.as_mut(), true);
+    assert!(query_config(deps.as_ref())
+        .disabled_lease_currencies
+        .is_empty());
+    open_lease(deps.as_mut(), lease_currency()).unwrap();
+}
+
+#[test]
+fn set_lease_exposure_cap() {
+    let mut deps = deps();
+    setup_test_case(deps.as_mut());
+    assert!(query_config(deps.as_ref()).lease_exposure_caps.is_empty());
+
+    let set_cap = |deps: DepsMut<'_>, cap| {
+        sudo(
+            deps,
+            testing::mock_env(),
+            SudoMsg::SetLeaseExposureCap {
+                currency: lease_currency(),
+                cap,
+            },
+        )
+        .unwrap()
+    };
+
+    set_cap(deps.as_mut(), Some(lpn_coin(1_000_000)));
+    assert_eq!(
+        Some(&lpn_coin(1_000_000)),
+        query_config(deps.as_ref())
+            .lease_exposure_caps
+            .get(&lease_currency())
+    );
+
+    set_cap(deps.as_mut(), None);
+    assert!(query_config(deps.as_ref()).lease_exposure_caps.is_empty());
+}
+
+#[test]
+fn set_lease_promotion_period() {
+    let mut deps = deps();
+    setup_test_case(deps.as_mut());
+    assert_eq!(None, query_config(deps.as_ref()).lease_promotion_period);
+
+    let period = Config::MAX_PROMOTION_PERIOD;
+    set_promotion_period(deps.as_mut(), Some(period)).unwrap();
+    assert_eq!(
+        Some(period),
+        query_config(deps.as_ref()).lease_promotion_period
+    );
+
+    set_promotion_period(deps.as_mut(), None).unwrap();
+    assert_eq!(None, query_config(deps.as_ref()).lease_promotion_period);
+}
+
+#[test]
+fn set_too_long_lease_promotion_period() {
+    let mut deps = deps();
+    setup_test_case(deps.as_mut());
+
+    let period = Config::MAX_PROMOTION_PERIOD + Duration::from_secs(1);
+    assert_eq!(
+        Err(ContractError::PromotionPeriodTooLong {
+            period,
+            max: Config::MAX_PROMOTION_PERIOD
+        }),
+        set_promotion_period(deps.as_mut(), Some(period))
+    );
+    assert_eq!(None, query_config(deps.as_ref()).lease_promotion_period);
+}
+
+fn set_promotion_period(
+    deps: DepsMut<'_>,
+    period: Option<Duration>,
+) -> Result<CwResponse, ContractError> {
+    sudo(
+        deps,
+        testing::mock_env(),
+        SudoMsg::SetLeasePromotionPeriod { period },
+    )
+}
+
+fn open_lease_with(max_ltd: Option<Percent>, promotion_period: Option<Duration>) {
+    let mut deps = deps();
+
+    setup_test_case(deps.as_mut());
+    set_promotion_period(deps.as_mut(), promotion_period).unwrap();
 
     let config = query_config(deps.as_ref());
 
     let msg = ExecuteMsg::OpenLease {
         currency: lease_currency(),
         max_ltd,
+        open_order: None,
     };
     let info = customer();
     let env = testing::mock_env();
@@ -185,7 +492,17 @@ fn open_lease_with(max_ltd: Option<Percent>) {
     let finalizer = admin.clone();
     let res = execute(deps.as_mut(), env, info.clone(), msg).unwrap();
 
-    let msg = Borrow::open_lease_msg(info.sender, config, lease_currency(), max_ltd, finalizer);
+    let msg = Borrow::open_lease_msg(
+        info.sender,
+        config,
+        LeaseSpec {
+            currency: lease_currency(),
+            max_ltd,
+            open_order: None,
+        },
+        finalizer,
+    );
+    assert_eq!(promotion_period, msg.form.loan.promotion_period);
     assert_eq!(
         res.messages,
         vec![SubMsg::reply_on_success(
@@ -203,13 +520,18 @@ fn open_lease_with(max_ltd: Option<Percent>) {
 
 #[test]
 fn test_open_lease() {
-    open_lease_with(None);
+    open_lease_with(None, None);
 }
 
 #[test]
 fn test_open_lease_with_max_ltd() {
-    open_lease_with(None);
-    open_lease_with(Some(Percent::from_percent(5)));
+    open_lease_with(None, None);
+    open_lease_with(Some(Percent::from_percent(5)), None);
+}
+
+#[test]
+fn test_open_lease_with_promotion_period() {
+    open_lease_with(None, Some(Duration::from_days(30)));
 }
 
 fn deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
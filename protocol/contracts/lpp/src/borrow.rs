@@ -23,25 +23,26 @@ impl InterestRate {
         utilization_optimal: Percent,
         addon_optimal_interest_rate: Percent,
     ) -> Option<Self> {
-        Self::private_new(
+        Self::try_new(
             base_interest_rate,
             utilization_optimal,
             addon_optimal_interest_rate,
         )
+        .ok()
     }
 
-    fn private_new(
+    fn try_new(
         base_interest_rate: Percent,
         utilization_optimal: Percent,
         addon_optimal_interest_rate: Percent,
-    ) -> Option<Self> {
+    ) -> Result<Self, &'static str> {
         let value = Self {
             base_interest_rate,
             utilization_optimal,
             addon_optimal_interest_rate,
         };
 
-        value.validate().then_some(value)
+        value.validate().map(|()| value)
     }
 
     pub fn base_interest_rate(&self) -> Percent {
@@ -59,13 +60,15 @@ impl InterestRate {
     pub fn calculate<Lpn>(&self, total_liability: Coin<Lpn>, balance: Coin<Lpn>) -> Percent {
         let utilization_max = Percent::from_ratio(
             self.utilization_optimal.units(),
-            (Percent::HUNDRED - self.utilization_optimal).units(),
+            Percent::HUNDRED
+                .saturating_sub(self.utilization_optimal)
+                .units(),
         );
-        let utilization = if balance.is_zero() {
-            utilization_max
-        } else {
-            Percent::from_ratio(total_liability, balance).min(utilization_max)
-        };
+        // an empty pool is fully utilized
+        let utilization = Percent::checked_from_ratio(total_liability, balance)
+            .map_or(utilization_max, |utilization| {
+                utilization.min(utilization_max)
+            });
 
         let config = Rational::new(
             self.addon_optimal_interest_rate.units(),
@@ -75,11 +78,18 @@ impl InterestRate {
         self.base_interest_rate + Fraction::<Units>::of(&config, utilization)
     }
 
-    fn validate(&self) -> bool {
-        self.base_interest_rate <= Percent::HUNDRED
-            && self.utilization_optimal > Percent::ZERO
-            && self.utilization_optimal < Percent::HUNDRED
-            && self.addon_optimal_interest_rate <= Percent::HUNDRED
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.base_interest_rate > Percent::HUNDRED {
+            Err("The base interest rate should not be greater than a hundred percent!")
+        } else if self.utilization_optimal == Percent::ZERO
+            || self.utilization_optimal >= Percent::HUNDRED
+        {
+            Err("The optimal utilization should be greater than zero and less than a hundred percent!")
+        } else if self.addon_optimal_interest_rate > Percent::HUNDRED {
+            Err("The addon optimal interest rate should not be greater than a hundred percent!")
+        } else {
+            Ok(())
+        }
     }
 }
 
@@ -87,12 +97,11 @@ impl TryFrom<UncheckedInterestRate> for InterestRate {
     type Error = &'static str;
 
     fn try_from(value: UncheckedInterestRate) -> Result<Self, Self::Error> {
-        Self::private_new(
+        Self::try_new(
             value.base_interest_rate,
             value.utilization_optimal,
             value.addon_optimal_interest_rate,
         )
-        .ok_or("Rates should not be greater than a hundred percent!")
     }
 }
 
@@ -106,6 +115,7 @@ struct UncheckedInterestRate {
 #[cfg(test)]
 mod tests {
     use finance::percent::Percent;
+    use sdk::cosmwasm_std;
 
     use crate::borrow::InterestRate;
 
@@ -164,6 +174,63 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn deserialize_optimal_out_of_range() {
+        let rate = |utilization_optimal: u32| {
+            format!(
+                r#"{{"base_interest_rate":100,"utilization_optimal":{utilization_optimal},"addon_optimal_interest_rate":200}}"#
+            )
+        };
+
+        assert!(cosmwasm_std::from_json::<InterestRate>(rate(999)).is_ok());
+        assert!(cosmwasm_std::from_json::<InterestRate>(rate(1000)).is_err());
+        assert!(cosmwasm_std::from_json::<InterestRate>(rate(1001)).is_err());
+    }
+
+    #[test]
+    fn deserialize_valid() {
+        assert_eq!(
+            InterestRate::new(
+                Percent::from_permille(70),
+                Percent::from_permille(700),
+                Percent::from_permille(20),
+            ),
+            cosmwasm_std::from_json(
+                r#"{"base_interest_rate":70,"utilization_optimal":700,"addon_optimal_interest_rate":20}"#
+            )
+            .ok()
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert_invalid(
+            r#"{"base_interest_rate":1001,"utilization_optimal":700,"addon_optimal_interest_rate":20}"#,
+            "base interest rate",
+        );
+        assert_invalid(
+            r#"{"base_interest_rate":70,"utilization_optimal":0,"addon_optimal_interest_rate":20}"#,
+            "optimal utilization",
+        );
+        assert_invalid(
+            r#"{"base_interest_rate":70,"utilization_optimal":1000,"addon_optimal_interest_rate":20}"#,
+            "optimal utilization",
+        );
+        assert_invalid(
+            r#"{"base_interest_rate":70,"utilization_optimal":700,"addon_optimal_interest_rate":1001}"#,
+            "addon optimal interest rate",
+        );
+    }
+
+    #[track_caller]
+    fn assert_invalid(rate: &str, violated: &str) {
+        let err = cosmwasm_std::from_json::<InterestRate>(rate).unwrap_err();
+        assert!(
+            err.to_string().contains(violated),
+            "'{err}' does not refer to the {violated}"
+        );
+    }
+
     /// Test suit specifically for verifying correctness of [`InterestRate::calculate`](InterestRate::calculate).cargo fmt
     mod calculate {
         use crate::borrow::InterestRate;
@@ -249,6 +316,28 @@ mod tests {
             }
         }
 
+        #[test]
+        /// Verifies that an optimal utilization at or above a hundred percent, which is not
+        /// a valid configuration, yields the base interest rate rather than a panic.
+        fn test_optimal_out_of_range() {
+            for utilization_optimal in [1000, 1001, 2000] {
+                let rate = InterestRate {
+                    base_interest_rate: Percent::from_permille(100),
+                    utilization_optimal: Percent::from_permille(utilization_optimal),
+                    addon_optimal_interest_rate: Percent::from_permille(200),
+                };
+
+                do_test_calculate(
+                    rate,
+                    &[
+                        InOut((0, 10), (100, 1000)),
+                        InOut((5, 10), (100, 1000)),
+                        InOut((10, 0), (100, 1000)),
+                    ],
+                );
+            }
+        }
+
         #[test]
         fn test_corner_set() {
             let rate = rate(1000, 900, 1000);
@@ -31,7 +31,7 @@ where
     let loan = lpp.try_open_loan(&mut deps, &env, lease_addr.clone(), amount)?;
 
     let mut bank = bank::account(&env.contract.address, deps.querier);
-    bank.send(amount, lease_addr);
+    bank.send(amount, lease_addr)?;
 
     let messages: Batch = bank.into();
 
@@ -57,7 +57,7 @@ where
         Batch::default()
     } else {
         let mut bank = bank::account(&env.contract.address, deps.querier);
-        bank.send(excess_received, lease_addr);
+        bank.send(excess_received, lease_addr)?;
         bank.into()
     };
     Ok((excess_received, batch.into()))
@@ -79,6 +79,14 @@ where
     }
 }
 
+pub(super) fn query_max_borrow<Lpn>(deps: &Deps<'_>, env: &Env) -> Result<Coin<Lpn>>
+where
+    Lpn: CurrencyDef,
+{
+    LiquidityPool::<Lpn>::load(deps.storage)
+        .and_then(|lpp| lpp.query_max_borrow(&env.contract.address, deps.querier))
+}
+
 pub fn query_loan<Lpn>(storage: &dyn Storage, lease_addr: Addr) -> Result<QueryLoanResponse<Lpn>>
 where
     Lpn: 'static + Currency,
@@ -1,7 +1,11 @@
 use thiserror::Error;
 
+use finance::coin::Coin;
+use lpp_platform::NLpn;
 use sdk::cosmwasm_std::StdError;
 
+use crate::lpn::UnexpectedLpn;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("[Lpp] [Std] {0}")]
@@ -34,9 +38,15 @@ pub enum ContractError {
     #[error("[Lpp] Unknown currency, details '{0}'")]
     UnknownCurrency(currency::error::Error),
 
+    #[error("[Lpp] {0}")]
+    UnexpectedLpn(#[from] UnexpectedLpn),
+
     #[error("[Lpp] No liquidity")]
     NoLiquidity {},
 
+    #[error("[Lpp] Insufficient liquidity for the withdrawal! At most '{0}' could be withdrawn")]
+    InsufficientLiquidity(Coin<NLpn>),
+
     #[error("[Lpp] The loan does not exist")]
     NoLoan {},
 
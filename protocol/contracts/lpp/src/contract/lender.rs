@@ -1,7 +1,7 @@
 use serde::Serialize;
 
 use currency::CurrencyDef;
-use finance::{coin::Coin, zero::Zero};
+use finance::{coin::Coin, percent::Percent, zero::Zero};
 use lpp_platform::NLpn;
 use platform::{
     bank::{self, BankAccount},
@@ -14,7 +14,7 @@ use crate::{
     event,
     lpp::LiquidityPool,
     msg::{BalanceResponse, PriceResponse},
-    state::Deposit,
+    state::{Deposit, PriceSamples},
 };
 
 use super::error::{ContractError, Result};
@@ -41,11 +41,13 @@ where
     }
 
     let price = lpp.calculate_price(&deps.as_ref(), &env, pending_deposit)?;
+    PriceSamples::record(deps.storage, price.get(), env.block.time)?;
 
     let receipts = Deposit::load_or_default(deps.storage, lender_addr.clone())?.deposit(
         deps.storage,
         pending_deposit,
         price,
+        lpp.reward_tiers(),
     )?;
 
     Ok(event::emit_deposit(env, lender_addr, pending_deposit, receipts).into())
@@ -76,18 +78,21 @@ where
     let amount_nlpn: Coin<NLpn> = amount_nlpn.u128().into();
 
     let lpp = LiquidityPool::<Lpn>::load(deps.storage)?;
+    let price = lpp.calculate_price(&deps.as_ref(), &env, Coin::ZERO)?;
+    PriceSamples::record(deps.storage, price.get(), env.block.time)?;
+
     let payment_lpn = lpp.withdraw_lpn(&deps.as_ref(), &env, amount_nlpn)?;
 
     let maybe_reward = Deposit::may_load(deps.storage, lender_addr.clone())?
         .ok_or(ContractError::NoDeposit {})?
-        .withdraw(deps.storage, amount_nlpn)?;
+        .withdraw(deps.storage, amount_nlpn, lpp.reward_tiers())?;
 
     let mut bank = bank::account(&env.contract.address, deps.querier);
-    bank.send(payment_lpn, lender_addr.clone());
+    bank.send(payment_lpn, lender_addr.clone())?;
 
     if let Some(reward) = maybe_reward {
         if !reward.is_zero() {
-            bank.send(reward, lender_addr.clone());
+            bank.send(reward, lender_addr.clone())?;
         }
     }
 
@@ -114,6 +119,17 @@ where
     })
 }
 
+pub fn query_apr<Lpn>(deps: Deps<'_>, env: Env) -> Result<Option<Percent>>
+where
+    Lpn: CurrencyDef,
+{
+    LiquidityPool::<Lpn>::load(deps.storage)
+        .and_then(|lpp| lpp.calculate_price(&deps, &env, Coin::ZERO))
+        .and_then(|price| {
+            PriceSamples::apr(deps.storage, price.get(), env.block.time).map_err(Into::into)
+        })
+}
+
 pub fn query_balance(storage: &dyn Storage, addr: Addr) -> Result<BalanceResponse> {
     let balance: u128 = Deposit::query_balance_nlpn(storage, addr)?
         .unwrap_or_default()
@@ -233,7 +249,7 @@ mod test {
             use finance::coin::Amount;
             use sdk::cosmwasm_std::Uint128;
 
-            use crate::contract::{lender, test};
+            use crate::contract::{lender, test, ContractError};
 
             use super::{TheCurrency, DEPOSIT};
 
@@ -326,13 +342,15 @@ mod test {
                     )
                     .unwrap();
 
-                    lender::try_withdraw::<TheCurrency>(
-                        deps.as_mut(),
-                        env,
-                        test::lender_msg_no_funds(),
-                        (DEPOSIT << 1).into(),
-                    )
-                    .unwrap_err();
+                    assert_eq!(
+                        lender::try_withdraw::<TheCurrency>(
+                            deps.as_mut(),
+                            env,
+                            test::lender_msg_no_funds(),
+                            (DEPOSIT << 1).into(),
+                        ),
+                        Err(ContractError::InsufficientLiquidity(DEPOSIT.into()))
+                    );
                 })
             }
         }
@@ -390,6 +408,56 @@ mod test {
                 })
             }
         }
+
+        mod apr {
+            use finance::{coin::Amount, duration::Duration, percent::Percent};
+            use sdk::cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+
+            use crate::contract::{lender, test};
+
+            use super::{TheCurrency, DEPOSIT};
+
+            #[test]
+            fn test_no_sample() {
+                super::test_case(DEPOSIT, |deps, env| {
+                    assert_eq!(
+                        None,
+                        lender::query_apr::<TheCurrency>(deps.as_ref(), env).unwrap()
+                    );
+                })
+            }
+
+            #[test]
+            fn test_known_growth() {
+                const INTEREST: Amount = DEPOSIT >> 2;
+
+                super::test_case(DEPOSIT, |mut deps, mut env| {
+                    lender::try_deposit::<TheCurrency>(
+                        deps.as_mut(),
+                        env.clone(),
+                        test::lender_msg_with_funds(DEPOSIT),
+                    )
+                    .unwrap();
+
+                    assert_eq!(
+                        Some(Percent::ZERO),
+                        lender::query_apr::<TheCurrency>(deps.as_ref(), env.clone()).unwrap()
+                    );
+
+                    deps.querier
+                        .bank
+                        .update_balance(MOCK_CONTRACT_ADDR, vec![test::cwcoin(DEPOSIT + INTEREST)])
+                        .unwrap();
+                    env.block.time += Duration::from_nanos(Duration::YEAR.nanos() / 2);
+
+                    // 25% over half a year
+                    assert_eq!(
+                        Some(Percent::from_percent(50)),
+                        lender::query_apr::<TheCurrency>(deps.as_ref(), env).unwrap()
+                    );
+                })
+            }
+        }
     }
 
     mod min_utilization {
@@ -24,6 +24,7 @@ use versioning::{
 };
 
 use crate::{
+    lpn::LpnCoin,
     lpp::{LiquidityPool, LppBalances},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
     state::Config,
@@ -115,8 +116,8 @@ pub fn execute(
             rewards::try_claim_rewards(deps, env, info, other_recipient)
                 .map(response::response_only_messages)
         }
-        ExecuteMsg::OpenLoan { amount } => amount
-            .try_into()
+        ExecuteMsg::OpenLoan { amount } => LpnCoin::<LpnCurrency>::try_new(amount)
+            .map(Coin::from)
             .map_err(Into::into)
             .and_then(|amount_lpn| {
                 borrow::try_open_loan::<LpnCurrency>(deps, env, info, amount_lpn)
@@ -150,6 +151,9 @@ pub fn sudo(deps: DepsMut<'_>, _env: Env, msg: SudoMsg) -> Result<CwResponse> {
         SudoMsg::MinUtilization { min_utilization } => {
             Config::update_min_utilization(deps.storage, min_utilization)
         }
+        SudoMsg::RewardTiers { reward_tiers } => {
+            Config::update_reward_tiers(deps.storage, reward_tiers)
+        }
     }
     .map(|()| PlatformResponse::default())
     .map(response::response_only_messages)
@@ -168,11 +172,13 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg<LpnCurrencies>) -> Result<B
         QueryMsg::Rewards { address } => {
             rewards::query_rewards(deps.storage, address).and_then(|ref resp| to_json_binary(resp))
         }
-        QueryMsg::Quote { amount } => amount
-            .try_into()
+        QueryMsg::Quote { amount } => LpnCoin::<LpnCurrency>::try_new(amount)
+            .map(Coin::from)
             .map_err(Into::into)
             .and_then(|quote| borrow::query_quote::<LpnCurrency>(&deps, &env, quote))
             .and_then(|ref resp| to_json_binary(resp)),
+        QueryMsg::MaxBorrow {} => borrow::query_max_borrow::<LpnCurrency>(&deps, &env)
+            .and_then(|ref resp| to_json_binary(resp)),
         QueryMsg::Loan { lease_addr } => {
             borrow::query_loan::<LpnCurrency>(deps.storage, lease_addr)
                 .and_then(|ref resp| to_json_binary(resp))
@@ -183,6 +189,12 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg<LpnCurrencies>) -> Result<B
                     .map(|total_rewards| lpp_balances.into_response(total_rewards))
             })
             .and_then(|ref resp| to_json_binary(resp)),
+        QueryMsg::Utilization {} => rewards::query_lpp_balance::<LpnCurrency>(deps, env)
+            .map(LppBalances::into_utilization_response)
+            .and_then(|ref resp| to_json_binary(resp)),
+        QueryMsg::BorrowRate {} => {
+            Config::load(deps.storage).and_then(|config| to_json_binary(config.borrow_rate()))
+        }
         QueryMsg::StableBalance { oracle_addr } => {
             rewards::query_lpp_balance::<LpnCurrency>(deps, env)
                 .map(LppBalances::into_total)
@@ -196,6 +208,9 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg<LpnCurrencies>) -> Result<B
         }
         QueryMsg::Price() => lender::query_ntoken_price::<LpnCurrency>(deps, env)
             .and_then(|ref resp| to_json_binary(resp)),
+        QueryMsg::Apr() => {
+            lender::query_apr::<LpnCurrency>(deps, env).and_then(|ref resp| to_json_binary(resp))
+        }
         QueryMsg::DepositCapacity() => {
             to_json_binary(&lender::deposit_capacity::<LpnCurrency>(deps, env)?)
         }
@@ -221,10 +236,13 @@ fn to_stable(
 
 #[cfg(test)]
 mod test {
+    use access_control::{error::Error, SingleUserAccess};
     use currencies::Lpn;
     use finance::coin::Coin;
-    use platform::coin_legacy;
-    use sdk::cosmwasm_std::{Addr, Coin as CwCoin, MessageInfo};
+    use platform::{coin_legacy, contract::Code};
+    use sdk::cosmwasm_std::{testing, Addr, Coin as CwCoin, MessageInfo};
+
+    use crate::{access_control::LEASE_CODE_ADMIN_KEY, contract::ContractError, msg::ExecuteMsg};
 
     pub(super) type TheCurrency = Lpn;
 
@@ -257,4 +275,31 @@ mod test {
     {
         coin_legacy::to_cosmwasm::<TheCurrency>(amount.into())
     }
+
+    #[test]
+    fn new_lease_code_unauthorized() {
+        let mut deps = testing::mock_dependencies();
+        let admin = Addr::unchecked("admin");
+        SingleUserAccess::new(deps.as_mut().storage, LEASE_CODE_ADMIN_KEY)
+            .grant_to(&admin)
+            .unwrap();
+
+        let err = super::execute(
+            deps.as_mut(),
+            testing::mock_env(),
+            lender_msg_no_funds(),
+            ExecuteMsg::NewLeaseCode {
+                lease_code: Code::unchecked(12),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized(Error::UnauthorizedRole {
+                role: LEASE_CODE_ADMIN_KEY,
+                expected: admin,
+                found: lender(),
+            }),
+            err
+        );
+    }
 }
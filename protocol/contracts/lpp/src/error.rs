@@ -2,6 +2,8 @@ use thiserror::Error;
 
 use sdk::cosmwasm_std::StdError;
 
+use crate::lpn::UnexpectedLpn;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum Error {
     //yes
@@ -34,6 +36,9 @@ pub enum Error {
     #[error("[Lpp] Unknown currency, details '{0}'")]
     UnknownCurrency(currency::error::Error),
 
+    #[error("[Lpp] {0}")]
+    UnexpectedLpn(#[from] UnexpectedLpn),
+
     #[error("[Lpp] No liquidity")]
     NoLiquidity {},
 
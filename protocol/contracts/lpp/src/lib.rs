@@ -9,9 +9,11 @@ pub mod event;
 pub mod loan;
 #[cfg(feature = "contract")]
 mod loans;
+pub mod lpn;
 #[cfg(feature = "contract")]
 mod lpp;
 pub mod msg;
+pub mod reward_tiers;
 #[cfg(feature = "contract")]
 pub mod state;
 #[cfg(feature = "stub")]
@@ -0,0 +1,77 @@
+use thiserror::Error;
+
+use currency::{CurrencyDef, Group, MemberOf, SymbolStatic, Tickers};
+use finance::coin::{Coin, CoinDTO};
+
+/// A coin of the Lpn the LPP is configured with
+///
+/// Unlike a [`CoinDTO`] of the Lpn group, which may carry any of its currencies,
+/// its currency is validated on construction to be exactly the configured one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LpnCoin<Lpn>(Coin<Lpn>);
+
+#[derive(Error, Debug, PartialEq)]
+#[error("Expected the configured Lpn '{expected}', found '{found}'")]
+pub struct UnexpectedLpn {
+    expected: SymbolStatic,
+    found: SymbolStatic,
+}
+
+impl<Lpn> LpnCoin<Lpn>
+where
+    Lpn: CurrencyDef,
+{
+    pub fn try_new<G>(amount: CoinDTO<G>) -> Result<Self, UnexpectedLpn>
+    where
+        G: Group,
+        Lpn::Group: MemberOf<G>,
+    {
+        let expected = Lpn::dto();
+        amount
+            .of_currency_dto(expected)
+            .map(|()| Self(amount.as_specific(expected)))
+            .map_err(|_| UnexpectedLpn {
+                expected: expected.into_symbol::<Tickers<Lpn::Group>>(),
+                found: amount.currency().into_symbol::<Tickers<G>>(),
+            })
+    }
+}
+
+impl<Lpn> From<LpnCoin<Lpn>> for Coin<Lpn> {
+    fn from(coin: LpnCoin<Lpn>) -> Self {
+        coin.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currency::{
+        test::{SuperGroup, SuperGroupTestC1, SuperGroupTestC2},
+        CurrencyDef,
+    };
+    use finance::coin::{Coin, CoinDTO};
+
+    use super::{LpnCoin, UnexpectedLpn};
+
+    #[test]
+    fn configured_lpn() {
+        let amount = Coin::<SuperGroupTestC1>::new(100);
+        assert_eq!(
+            Ok(amount),
+            LpnCoin::<SuperGroupTestC1>::try_new(CoinDTO::<SuperGroup>::from(amount))
+                .map(Coin::from)
+        );
+    }
+
+    #[test]
+    fn another_lpn() {
+        let amount = CoinDTO::<SuperGroup>::from(Coin::<SuperGroupTestC2>::new(100));
+        assert_eq!(
+            Err(UnexpectedLpn {
+                expected: SuperGroupTestC1::ticker(),
+                found: SuperGroupTestC2::ticker(),
+            }),
+            LpnCoin::<SuperGroupTestC1>::try_new(amount)
+        );
+    }
+}
@@ -16,7 +16,8 @@ use crate::{
     contract::{ContractError, Result},
     loan::Loan,
     loans::Repo,
-    msg::{LppBalanceResponse, PriceResponse},
+    msg::{LppBalanceResponse, PriceResponse, UtilizationResponse},
+    reward_tiers::RewardTiers,
     state::{Config, Deposit, Total},
 };
 
@@ -45,6 +46,18 @@ where
             balance_nlpn: total_rewards,
         }
     }
+
+    pub(crate) fn into_utilization_response(self) -> UtilizationResponse<Lpns> {
+        UtilizationResponse {
+            utilization: utilization(
+                self.balance,
+                self.total_principal_due + self.total_interest_due,
+            ),
+            total_principal_due: self.total_principal_due.into(),
+            total_interest_due: self.total_interest_due.into(),
+            balance: self.balance.into(),
+        }
+    }
 }
 
 // TODO reverse the direction of the dependencies between LiquidityPool and Deposit,
@@ -100,6 +113,10 @@ where
 
         Ok(LiquidityPool { config, total })
     }
+
+    pub fn reward_tiers(&self) -> &RewardTiers {
+        self.config.reward_tiers()
+    }
 }
 
 impl<Lpn> LiquidityPool<Lpn>
@@ -121,7 +138,7 @@ where
 
             self.commited_balance(&env.contract.address, querier, pending_deposit)
                 .map(|balance: Coin<Lpn>| {
-                    if self.utilization(balance, total_due) > min_utilization {
+                    if utilization(balance, total_due) > min_utilization {
                         // a followup from the above true value is (total_due * 100 / min_utilization) > (balance + total_due)
                         Fraction::<Units>::of(
                             &Rational::new(Percent::HUNDRED, min_utilization),
@@ -183,6 +200,11 @@ where
             .map_err(ContractError::from)
     }
 
+    /// Return the amount of Lpn to pay for the withdrawn `amount_nlpn`
+    ///
+    /// The outstanding principal and interest are still to be collected from the leases,
+    /// so only the pool balance may cover withdrawals. A withdrawal over it is rejected
+    /// with the maximum amount of NLpn the pool could still pay for.
     pub fn withdraw_lpn(
         &self,
         deps: &Deps<'_>,
@@ -190,13 +212,14 @@ where
         amount_nlpn: Coin<NLpn>,
     ) -> Result<Coin<Lpn>> {
         let price = self.calculate_price(deps, env, Coin::ZERO)?.get();
-        let amount_lpn = price::total(amount_nlpn, price);
+        let balance = self.balance(&env.contract.address, deps.querier)?;
+        let max_nlpn = price::total(balance, price.inv());
 
-        if self.balance(&env.contract.address, deps.querier)? < amount_lpn {
-            return Err(ContractError::NoLiquidity {});
+        if amount_nlpn > max_nlpn {
+            return Err(ContractError::InsufficientLiquidity(max_nlpn));
         }
 
-        Ok(amount_lpn)
+        Ok(price::total(amount_nlpn, price))
     }
 
     pub fn query_quote(
@@ -223,6 +246,14 @@ where
         )))
     }
 
+    pub fn query_max_borrow(
+        &self,
+        account: &Addr,
+        querier: QuerierWrapper<'_>,
+    ) -> Result<Coin<Lpn>> {
+        self.balance(account, querier)
+    }
+
     pub(super) fn try_open_loan(
         &mut self,
         deps: &mut DepsMut<'_>,
@@ -320,13 +351,13 @@ where
         self.commited_balance(account, querier, pending_deposit)
             .map(|balance: Coin<Lpn>| balance + self.total_due(now))
     }
+}
 
-    fn utilization(&self, balance: Coin<Lpn>, total_due: Coin<Lpn>) -> Percent {
-        if balance.is_zero() {
-            Percent::HUNDRED
-        } else {
-            Percent::from_ratio(total_due, total_due + balance)
-        }
+fn utilization<Lpn>(balance: Coin<Lpn>, total_due: Coin<Lpn>) -> Percent {
+    if balance.is_zero() {
+        Percent::HUNDRED
+    } else {
+        Percent::from_ratio(total_due, total_due + balance)
     }
 }
 
@@ -468,6 +499,60 @@ mod test {
         assert_eq!(result, Percent::from_permille(136));
     }
 
+    #[test]
+    fn test_query_max_borrow() {
+        let balance_mock = coin_cw(10_000_000);
+        let mut deps = testing::mock_dependencies_with_balance(&[balance_mock.clone()]);
+        let env = testing::mock_env();
+
+        Config::new_unchecked(
+            Code::unchecked(123),
+            InterestRate::new(
+                BASE_INTEREST_RATE,
+                UTILIZATION_OPTIMAL,
+                ADDON_OPTIMAL_INTEREST_RATE,
+            )
+            .expect("Couldn't construct interest rate value!"),
+            // the minimum utilization does not limit the borrows
+            Percent::from_percent(50)
+                .try_into()
+                .expect("Couldn't construct minimum utilization!"),
+        )
+        .store(deps.as_mut().storage)
+        .expect("Failed to store Config!");
+        Total::<TheCurrency>::new()
+            .store(deps.as_mut().storage)
+            .expect("can't initialize Total");
+
+        let lpp = LiquidityPool::<TheCurrency>::load(deps.as_mut().storage)
+            .expect("can't load LiquidityPool");
+
+        let max_borrow = lpp
+            .query_max_borrow(&env.contract.address, deps.as_ref().querier)
+            .expect("can't query max borrow");
+        assert_eq!(max_borrow, balance_mock.amount.u128().into());
+
+        assert!(lpp
+            .query_quote(
+                max_borrow,
+                &env.contract.address,
+                deps.as_ref().querier,
+                &env.block.time,
+            )
+            .expect("can't query quote")
+            .is_some());
+        assert_eq!(
+            None,
+            lpp.query_quote(
+                max_borrow + Coin::new(1),
+                &env.contract.address,
+                deps.as_ref().querier,
+                &env.block.time,
+            )
+            .expect("can't query quote")
+        );
+    }
+
     #[test]
     fn test_open_and_repay_loan() {
         let lpp_balance: Amount = 10_000_000;
@@ -812,7 +897,12 @@ mod test {
             .bank
             .update_balance(MOCK_CONTRACT_ADDR, vec![coin_cw(10_000_000)]);
         lender
-            .deposit(deps.as_mut().storage, 10_000_000u128.into(), price)
+            .deposit(
+                deps.as_mut().storage,
+                10_000_000u128.into(),
+                price,
+                lpp.reward_tiers(),
+            )
             .expect("should deposit");
 
         let annual_interest_rate = lpp
@@ -858,6 +948,13 @@ mod test {
             lpp_balance.total_interest_due,
             Coin::<TheCurrency>::new(1_100_000)
         );
+        assert_eq!(
+            Percent::from_ratio(
+                Coin::<TheCurrency>::new(6_100_000),
+                Coin::<TheCurrency>::new(11_100_000)
+            ),
+            lpp_balance.into_utilization_response().utilization
+        );
 
         let price = lpp
             .calculate_price(&deps.as_ref(), &env, Coin::new(0))
@@ -14,7 +14,7 @@ use sdk::{
     schemars::{self, JsonSchema},
 };
 
-use crate::{borrow::InterestRate, loan::Loan};
+use crate::{borrow::InterestRate, loan::Loan, reward_tiers::RewardTiers};
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
@@ -77,6 +77,13 @@ pub enum SudoMsg {
     MinUtilization {
         min_utilization: BoundToHundredPercent,
     },
+    /// Set the multipliers boosting the reward share of the large deposits
+    ///
+    /// An empty list switches back to flat reward shares. A deposit gets its weight
+    /// re-calculated against the new tiers on its next deposit or withdrawal.
+    RewardTiers {
+        reward_tiers: RewardTiers,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
@@ -98,6 +105,11 @@ where
     Quote {
         amount: CoinDTO<Lpns>,
     },
+    /// Return the largest loan amount, [Coin<Lpn>], the pool would currently grant
+    ///
+    /// It is limited only by the available liquidity. The minimum utilization does not
+    /// apply since it restricts the deposits and a loan may only increase the utilization.
+    MaxBorrow {},
     Loan {
         lease_addr: Addr,
     },
@@ -110,12 +122,30 @@ where
     /// Return the pool's total balance in Lpn [LppBalanceResponse]
     LppBalance(),
 
+    /// Return the current pool utilization and its components, [UtilizationResponse]
+    ///
+    /// The utilization is the ratio of the total due, principal plus interest, to the sum
+    /// of the total due and the free balance, as used to compute the borrow rate.
+    Utilization {},
+
+    /// Return the borrow interest rate model, [InterestRate]
+    ///
+    /// The annual interest rate of a loan is computed by it out of the pool utilization
+    /// past the loan.
+    BorrowRate {},
+
     /// Implementation of [lpp_platform::msg::QueryMsg::StableBalance]
     StableBalance {
         oracle_addr: Addr,
     },
 
     Price(),
+    /// Return the annual percentage rate of the NLpn price, [Option<Percent>]
+    ///
+    /// It is derived from the growth of the price since the oldest retained sample. The samples
+    /// are taken on deposits and withdrawals, at least a week apart. A flat or decreasing price
+    /// yields zero. No sample retained yet yields `None`.
+    Apr(),
     DepositCapacity(),
 
     Rewards {
@@ -162,6 +192,23 @@ where
     pub balance_nlpn: Coin<NLpn>,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug, Clone, PartialEq, Eq))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    bound(serialize = "", deserialize = "")
+)]
+pub struct UtilizationResponse<Lpns>
+where
+    Lpns: Group,
+{
+    pub utilization: Percent,
+    pub total_principal_due: CoinDTO<Lpns>,
+    pub total_interest_due: CoinDTO<Lpns>,
+    pub balance: CoinDTO<Lpns>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
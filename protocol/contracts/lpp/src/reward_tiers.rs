@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use finance::{coin::Coin, fraction::Fraction, percent::Percent};
+use lpp_platform::NLpn;
+use sdk::schemars::{self, JsonSchema};
+
+/// A multiplier of the reward share of the deposits of at least `min_deposit` receipts
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct RewardTier {
+    pub min_deposit: Coin<NLpn>,
+    pub multiplier: Percent,
+}
+
+/// Tiered multipliers boosting the reward share of the large deposits
+///
+/// A deposit gets the multiplier of the tier with the greatest threshold not above its size,
+/// or a hundred percent if it is below all thresholds. No tiers means flat reward shares.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(try_from = "Vec<RewardTier>")]
+pub struct RewardTiers(Vec<RewardTier>);
+
+impl RewardTiers {
+    #[cfg(any(test, feature = "testing"))]
+    pub fn new(tiers: Vec<RewardTier>) -> Option<Self> {
+        Self::private_new(tiers)
+    }
+
+    fn private_new(tiers: Vec<RewardTier>) -> Option<Self> {
+        let value = Self(tiers);
+
+        value.validate().then_some(value)
+    }
+
+    /// The weight of a deposit in the reward distribution
+    pub fn weight(&self, deposit: Coin<NLpn>) -> Coin<NLpn> {
+        self.multiplier(deposit).of(deposit)
+    }
+
+    fn multiplier(&self, deposit: Coin<NLpn>) -> Percent {
+        self.0
+            .iter()
+            .rev()
+            .find(|tier| tier.min_deposit <= deposit)
+            .map_or(Percent::HUNDRED, |tier| tier.multiplier)
+    }
+
+    fn validate(&self) -> bool {
+        self.0
+            .iter()
+            .all(|tier| tier.multiplier >= Percent::HUNDRED)
+            && self
+                .0
+                .windows(2)
+                .all(|tiers| tiers[0].min_deposit < tiers[1].min_deposit)
+    }
+}
+
+impl TryFrom<Vec<RewardTier>> for RewardTiers {
+    type Error = &'static str;
+
+    fn try_from(tiers: Vec<RewardTier>) -> Result<Self, Self::Error> {
+        Self::private_new(tiers).ok_or(
+            "Tier thresholds should be increasing and multipliers not less than a hundred percent!",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use finance::{coin::Coin, percent::Percent};
+
+    use super::{RewardTier, RewardTiers};
+
+    #[test]
+    fn validate() {
+        assert!(RewardTiers::new(vec![]).is_some());
+        assert!(RewardTiers::new(vec![tier(100, 1000), tier(200, 1500)]).is_some());
+
+        assert!(RewardTiers::new(vec![tier(100, 999)]).is_none());
+        assert!(RewardTiers::new(vec![tier(200, 1200), tier(100, 1500)]).is_none());
+        assert!(RewardTiers::new(vec![tier(100, 1200), tier(100, 1500)]).is_none());
+    }
+
+    #[test]
+    fn flat() {
+        let tiers = RewardTiers::default();
+
+        assert_eq!(Coin::new(0), tiers.weight(Coin::new(0)));
+        assert_eq!(Coin::new(12345), tiers.weight(Coin::new(12345)));
+    }
+
+    #[test]
+    fn tiered() {
+        let tiers = RewardTiers::new(vec![tier(100, 1200), tier(1000, 2000)]).unwrap();
+
+        assert_eq!(Coin::new(99), tiers.weight(Coin::new(99)));
+        assert_eq!(Coin::new(120), tiers.weight(Coin::new(100)));
+        assert_eq!(Coin::new(1188), tiers.weight(Coin::new(990)));
+        assert_eq!(Coin::new(2000), tiers.weight(Coin::new(1000)));
+        assert_eq!(Coin::new(5000), tiers.weight(Coin::new(2500)));
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(sdk::cosmwasm_std::from_json::<RewardTiers>(
+            r#"[{"min_deposit":{"amount":"200"},"multiplier":1200},{"min_deposit":{"amount":"100"},"multiplier":1500}]"#
+        )
+        .is_err());
+    }
+
+    fn tier(min_deposit: u128, multiplier_permille: u32) -> RewardTier {
+        RewardTier {
+            min_deposit: Coin::new(min_deposit),
+            multiplier: Percent::from_permille(multiplier_permille),
+        }
+    }
+}
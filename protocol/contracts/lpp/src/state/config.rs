@@ -9,13 +9,17 @@ use lpp_platform::NLpn;
 use platform::contract::Code;
 use sdk::{cosmwasm_std::Storage, cw_storage_plus::Item};
 
-use crate::{borrow::InterestRate, contract::Result, msg::InstantiateMsg};
+use crate::{
+    borrow::InterestRate, contract::Result, msg::InstantiateMsg, reward_tiers::RewardTiers,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     lease_code: Code,
     borrow_rate: InterestRate,
     min_utilization: BoundToHundredPercent,
+    #[serde(default)]
+    reward_tiers: RewardTiers,
 }
 
 impl Config {
@@ -31,6 +35,7 @@ impl Config {
             lease_code,
             borrow_rate: msg.borrow_rate,
             min_utilization: msg.min_utilization,
+            reward_tiers: RewardTiers::default(),
         }
     }
 
@@ -44,6 +49,7 @@ impl Config {
             lease_code,
             borrow_rate,
             min_utilization,
+            reward_tiers: RewardTiers::default(),
         }
     }
 
@@ -59,6 +65,10 @@ impl Config {
         self.min_utilization
     }
 
+    pub const fn reward_tiers(&self) -> &RewardTiers {
+        &self.reward_tiers
+    }
+
     pub fn store(&self, storage: &mut dyn Storage) -> Result<()> {
         Self::STORAGE.save(storage, self).map_err(Into::into)
     }
@@ -98,6 +108,13 @@ impl Config {
         })
     }
 
+    pub fn update_reward_tiers(storage: &mut dyn Storage, reward_tiers: RewardTiers) -> Result<()> {
+        Self::update_field(storage, |config| Self {
+            reward_tiers,
+            ..config
+        })
+    }
+
     fn update_field<F>(storage: &mut dyn Storage, f: F) -> Result<()>
     where
         F: FnOnce(Config) -> Config,
@@ -15,6 +15,7 @@ use sdk::{
 use crate::{
     contract::{ContractError, Result},
     lpp::NTokenPrice,
+    reward_tiers::RewardTiers,
 };
 
 #[derive(Debug)]
@@ -30,6 +31,11 @@ struct DepositData {
     // Rewards
     reward_per_token: Option<Price<NLpn, Nls>>,
     pending_rewards_nls: Coin<Nls>,
+    /// The weight in the rewards distribution, if different from `deposited_nlpn`
+    ///
+    /// Deposits stored before the introduction of the reward tiers lack it.
+    #[serde(default)]
+    weight_nlpn: Option<Coin<NLpn>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
@@ -37,7 +43,23 @@ struct DepositsGlobals {
     balance_nlpn: Coin<NLpn>,
 
     // Rewards
+    /// The reward per unit of deposit weight
     reward_per_token: Option<Price<NLpn, Nls>>,
+    /// The total weight of the deposits, if different from `balance_nlpn`
+    #[serde(default)]
+    weight_nlpn: Option<Coin<NLpn>>,
+}
+
+impl DepositData {
+    fn weight(&self) -> Coin<NLpn> {
+        self.weight_nlpn.unwrap_or(self.deposited_nlpn)
+    }
+}
+
+impl DepositsGlobals {
+    fn weight(&self) -> Coin<NLpn> {
+        self.weight_nlpn.unwrap_or(self.balance_nlpn)
+    }
 }
 
 impl Deposit {
@@ -64,6 +86,7 @@ impl Deposit {
         storage: &mut dyn Storage,
         amount_lpn: Coin<Lpn>,
         price: NTokenPrice<Lpn>,
+        reward_tiers: &RewardTiers,
     ) -> Result<Coin<NLpn>>
     where
         Lpn: Copy,
@@ -76,7 +99,9 @@ impl Deposit {
         self.update_rewards(&globals);
 
         let deposited_nlpn = price::total(amount_lpn, price.get().inv());
+        let old_weight = self.data.weight();
         self.data.deposited_nlpn += deposited_nlpn;
+        let weight_nlpn = self.reweigh(old_weight, &globals, reward_tiers);
 
         Self::DEPOSITS.save(storage, self.addr.clone(), &self.data)?;
 
@@ -84,6 +109,7 @@ impl Deposit {
             .balance_nlpn
             .checked_add(deposited_nlpn)
             .ok_or(ContractError::OverflowError("Balance overflow"))?;
+        globals.weight_nlpn = Some(weight_nlpn);
 
         Self::GLOBALS.save(storage, &globals)?;
 
@@ -95,6 +121,7 @@ impl Deposit {
         &mut self,
         storage: &mut dyn Storage,
         amount_nlpn: Coin<NLpn>,
+        reward_tiers: &RewardTiers,
     ) -> Result<Option<Coin<Nls>>> {
         if self.data.deposited_nlpn < amount_nlpn {
             return Err(ContractError::InsufficientBalance);
@@ -103,8 +130,11 @@ impl Deposit {
         let mut globals = Self::GLOBALS.may_load(storage)?.unwrap_or_default();
         self.update_rewards(&globals);
 
+        let old_weight = self.data.weight();
         self.data.deposited_nlpn -= amount_nlpn;
+        let weight_nlpn = self.reweigh(old_weight, &globals, reward_tiers);
         globals.balance_nlpn -= amount_nlpn;
+        globals.weight_nlpn = Some(weight_nlpn);
 
         let maybe_reward = if self.data.deposited_nlpn.is_zero() {
             Self::DEPOSITS.remove(storage, self.addr.clone());
@@ -122,7 +152,7 @@ impl Deposit {
     pub fn distribute_rewards(deps: DepsMut<'_>, rewards: Coin<Nls>) -> Result<()> {
         let mut globals = Self::GLOBALS.may_load(deps.storage)?.unwrap_or_default();
 
-        if globals.balance_nlpn.is_zero() {
+        if globals.weight().is_zero() {
             return Err(ContractError::ZeroBalanceRewards {});
         }
 
@@ -130,7 +160,7 @@ impl Deposit {
             return Err(ContractError::ZeroRewardsFunds {});
         }
 
-        let partial_price = price::total_of(globals.balance_nlpn).is(rewards);
+        let partial_price = price::total_of(globals.weight()).is(rewards);
 
         if let Some(ref mut reward_per_token) = globals.reward_per_token {
             *reward_per_token += partial_price;
@@ -146,17 +176,33 @@ impl Deposit {
         self.data.reward_per_token = globals.reward_per_token;
     }
 
+    /// Re-calculate the deposit weight against `reward_tiers` and return the new total weight
+    ///
+    /// The pending rewards must have been updated beforehand. The `old_weight` is
+    /// the deposit weight before the deposited amount has been changed.
+    fn reweigh(
+        &mut self,
+        old_weight: Coin<NLpn>,
+        globals: &DepositsGlobals,
+        reward_tiers: &RewardTiers,
+    ) -> Coin<NLpn> {
+        let new_weight = reward_tiers.weight(self.data.deposited_nlpn);
+        self.data.weight_nlpn = Some(new_weight);
+
+        globals.weight() - old_weight + new_weight
+    }
+
     fn calculate_reward(&self, globals: &DepositsGlobals) -> Coin<Nls> {
         let deposit = &self.data;
 
         let global_reward = globals
             .reward_per_token
-            .map(|price| price::total(deposit.deposited_nlpn, price))
+            .map(|price| price::total(deposit.weight(), price))
             .unwrap_or_default();
 
         let deposit_reward = deposit
             .reward_per_token
-            .map(|price| price::total(deposit.deposited_nlpn, price))
+            .map(|price| price::total(deposit.weight(), price))
             .unwrap_or_default();
 
         deposit.pending_rewards_nls + global_reward - deposit_reward
@@ -201,9 +247,13 @@ impl Deposit {
 #[cfg(test)]
 mod test {
     use currencies::Lpn;
-    use sdk::cosmwasm_std::testing;
+    use finance::{coin::Amount, percent::Percent};
+    use sdk::cosmwasm_std::{
+        testing::{self, MockApi, MockQuerier, MockStorage},
+        OwnedDeps,
+    };
 
-    use crate::lpp::NTokenPrice;
+    use crate::{lpp::NTokenPrice, reward_tiers::RewardTier};
 
     use super::*;
 
@@ -219,7 +269,12 @@ mod test {
         let mut deposit1 =
             Deposit::load_or_default(deps.as_ref().storage, addr1.clone()).expect("should load");
         deposit1
-            .deposit(deps.as_mut().storage, 1000u128.into(), price)
+            .deposit(
+                deps.as_mut().storage,
+                1000u128.into(),
+                price,
+                &RewardTiers::default(),
+            )
             .expect("should deposit");
 
         Deposit::distribute_rewards(deps.as_mut(), Coin::new(1000))
@@ -229,7 +284,12 @@ mod test {
         let mut deposit2 =
             Deposit::load_or_default(deps.as_ref().storage, addr2.clone()).expect("should load");
         deposit2
-            .deposit(deps.as_mut().storage, 1000u128.into(), price)
+            .deposit(
+                deps.as_mut().storage,
+                1000u128.into(),
+                price,
+                &RewardTiers::default(),
+            )
             .expect("should deposit");
 
         let balance_nlpn =
@@ -269,7 +329,11 @@ mod test {
         assert_eq!(reward, Coin::new(500));
 
         let some_rewards = deposit1
-            .withdraw(deps.as_mut().storage, 500u128.into())
+            .withdraw(
+                deps.as_mut().storage,
+                500u128.into(),
+                &RewardTiers::default(),
+            )
             .expect("should withdraw");
         assert!(some_rewards.is_none());
 
@@ -300,7 +364,11 @@ mod test {
 
         // withdraw all, return rewards, close deposit
         let rewards = deposit1
-            .withdraw(deps.as_mut().storage, 500u128.into())
+            .withdraw(
+                deps.as_mut().storage,
+                500u128.into(),
+                &RewardTiers::default(),
+            )
             .expect("should withdraw")
             .expect("should be some rewards");
         assert_eq!(rewards, Coin::<Nls>::new(500));
@@ -326,7 +394,12 @@ mod test {
 
         // balance_nls = 0, balance_nlpn != 0
         deposit
-            .deposit(deps.as_mut().storage, Coin::<Lpn>::new(1000), price)
+            .deposit(
+                deps.as_mut().storage,
+                Coin::<Lpn>::new(1000),
+                price,
+                &RewardTiers::default(),
+            )
             .expect("should deposit");
 
         let rewards = deposit
@@ -345,7 +418,12 @@ mod test {
             Deposit::load_or_default(deps.as_ref().storage, addr).expect("should load");
 
         deposit
-            .deposit(deps.as_mut().storage, Coin::<Lpn>::new(1000), price)
+            .deposit(
+                deps.as_mut().storage,
+                Coin::<Lpn>::new(1000),
+                price,
+                &RewardTiers::default(),
+            )
             .expect("should deposit");
 
         // shouldn't change anything
@@ -359,4 +437,95 @@ mod test {
 
         Deposit::distribute_rewards(deps.as_mut(), rewards).unwrap_err();
     }
+
+    #[test]
+    fn test_flat_rewards() {
+        assert_eq!(
+            (Coin::new(1750), Coin::new(5250)),
+            small_and_large_rewards(&RewardTiers::default(), Coin::new(7000))
+        );
+    }
+
+    #[test]
+    fn test_tiered_rewards() {
+        assert_eq!(
+            (Coin::new(1000), Coin::new(6000)),
+            small_and_large_rewards(&double_from_2000(), Coin::new(7000))
+        );
+    }
+
+    #[test]
+    fn test_tiered_rewards_reweigh() {
+        let mut deps = testing::mock_dependencies();
+        let tiers = double_from_2000();
+
+        let mut small = deposit(&mut deps, "small", 1000, &tiers);
+        let mut large = deposit(&mut deps, "large", 3000, &tiers);
+
+        large
+            .withdraw(deps.as_mut().storage, 1500u128.into(), &tiers)
+            .expect("should withdraw");
+        Deposit::distribute_rewards(deps.as_mut(), Coin::new(2500))
+            .expect("should distribute rewards");
+
+        assert_eq!(
+            Coin::<Nls>::new(1000),
+            small
+                .claim_rewards(deps.as_mut().storage)
+                .expect("should claim rewards")
+        );
+        assert_eq!(
+            Coin::<Nls>::new(1500),
+            large
+                .claim_rewards(deps.as_mut().storage)
+                .expect("should claim rewards")
+        );
+    }
+
+    fn small_and_large_rewards(tiers: &RewardTiers, rewards: Coin<Nls>) -> (Coin<Nls>, Coin<Nls>) {
+        let mut deps = testing::mock_dependencies();
+
+        let small = deposit(&mut deps, "small", 1000, tiers);
+        let large = deposit(&mut deps, "large", 3000, tiers);
+
+        Deposit::distribute_rewards(deps.as_mut(), rewards).expect("should distribute rewards");
+
+        (
+            small
+                .query_rewards(deps.as_ref().storage)
+                .expect("should query rewards"),
+            large
+                .query_rewards(deps.as_ref().storage)
+                .expect("should query rewards"),
+        )
+    }
+
+    fn deposit(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        lender: &str,
+        amount: Amount,
+        tiers: &RewardTiers,
+    ) -> Deposit {
+        let price = NTokenPrice::<TheCurrency>::mock(Coin::new(1), Coin::new(1));
+
+        let mut deposit = Deposit::load_or_default(deps.as_ref().storage, Addr::unchecked(lender))
+            .expect("should load");
+        deposit
+            .deposit(
+                deps.as_mut().storage,
+                Coin::<Lpn>::new(amount),
+                price,
+                tiers,
+            )
+            .expect("should deposit");
+        deposit
+    }
+
+    fn double_from_2000() -> RewardTiers {
+        RewardTiers::new(vec![RewardTier {
+            min_deposit: Coin::new(2000),
+            multiplier: Percent::from_percent(200),
+        }])
+        .expect("valid reward tiers")
+    }
 }
@@ -1,5 +1,6 @@
-pub use self::{config::Config, deposit::Deposit, total::Total};
+pub use self::{config::Config, deposit::Deposit, price_samples::PriceSamples, total::Total};
 
 mod config;
 mod deposit;
+mod price_samples;
 mod total;
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use finance::{
+    duration::Duration,
+    percent::Percent,
+    price::{self, Price},
+};
+use lpp_platform::NLpn;
+use sdk::{
+    cosmwasm_std::{StdResult, Storage, Timestamp},
+    cw_storage_plus::Item,
+};
+
+/// The NLpn price retained at a given time
+#[derive(Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = ""))]
+struct Sample<Lpn>
+where
+    Lpn: 'static,
+{
+    price: Price<NLpn, Lpn>,
+    at: Timestamp,
+}
+
+impl<Lpn> Clone for Sample<Lpn>
+where
+    Lpn: 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Lpn> Copy for Sample<Lpn> where Lpn: 'static {}
+
+/// The last two NLpn price samples taken at least [`PriceSamples::PERIOD`] apart
+///
+/// They are the base the annual percentage rate is derived from.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = ""))]
+pub struct PriceSamples<Lpn>
+where
+    Lpn: 'static,
+{
+    previous: Option<Sample<Lpn>>,
+    last: Sample<Lpn>,
+}
+
+impl<Lpn> PriceSamples<Lpn>
+where
+    Lpn: 'static,
+{
+    const STORAGE: Item<PriceSamples<Lpn>> = Item::new("ntoken_price_samples");
+
+    pub const PERIOD: Duration = Duration::from_days(7);
+
+    /// Retain the price if there is no sample yet, or the last one is at least [`Self::PERIOD`] old
+    pub fn record(
+        storage: &mut dyn Storage,
+        price: Price<NLpn, Lpn>,
+        now: Timestamp,
+    ) -> StdResult<()> {
+        let sample = Sample { price, at: now };
+
+        match Self::STORAGE.may_load(storage)? {
+            None => Self::STORAGE.save(
+                storage,
+                &Self {
+                    previous: None,
+                    last: sample,
+                },
+            ),
+            Some(samples) if samples.last.at + Self::PERIOD <= now => Self::STORAGE.save(
+                storage,
+                &Self {
+                    previous: Some(samples.last),
+                    last: sample,
+                },
+            ),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Calculate the annual percentage rate from the oldest retained sample up to the `current` price
+    ///
+    /// Return `None` if no sample has been retained yet.
+    pub fn apr(
+        storage: &dyn Storage,
+        current: Price<NLpn, Lpn>,
+        now: Timestamp,
+    ) -> StdResult<Option<Percent>> {
+        Self::STORAGE.may_load(storage).map(|may_samples| {
+            may_samples.map(|samples| {
+                let oldest = samples.previous.unwrap_or(samples.last);
+                price::apr_from_prices(oldest.price, oldest.at, current, now)
+            })
+        })
+    }
+}
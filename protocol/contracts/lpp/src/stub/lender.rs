@@ -9,8 +9,9 @@ use platform::{
 use sdk::cosmwasm_std::{Addr, QuerierWrapper, Reply};
 
 use crate::{
+    borrow::InterestRate,
     error::Error,
-    msg::{ExecuteMsg, LoanResponse, QueryMsg, QueryQuoteResponse},
+    msg::{ExecuteMsg, LoanResponse, QueryMsg, QueryQuoteResponse, UtilizationResponse},
 };
 
 use super::{LppBatch, LppRef};
@@ -24,6 +25,10 @@ where
     fn open_loan_resp(&self, resp: Reply) -> Result<LoanResponse<Lpn>, Error>;
 
     fn quote(&self, amount: Coin<Lpn>) -> Result<QueryQuoteResponse, Error>;
+
+    fn borrow_rate(&self) -> Result<InterestRate, Error>;
+
+    fn utilization(&self) -> Result<UtilizationResponse<Lpns>, Error>;
 }
 
 pub trait WithLppLender<Lpn, Lpns>
@@ -99,6 +104,18 @@ where
             .query_wasm_smart(self.id(), &msg)
             .map_err(Error::from)
     }
+
+    fn borrow_rate(&self) -> Result<InterestRate, Error> {
+        self.querier
+            .query_wasm_smart(self.id(), &QueryMsg::<Lpns>::BorrowRate {})
+            .map_err(Error::from)
+    }
+
+    fn utilization(&self) -> Result<UtilizationResponse<Lpns>, Error> {
+        self.querier
+            .query_wasm_smart(self.id(), &QueryMsg::<Lpns>::Utilization {})
+            .map_err(Error::from)
+    }
 }
 
 impl<Lpn, Lpns> From<LppLenderStub<'_, Lpn, Lpns>> for LppBatch<LppRef<Lpn, Lpns>> {
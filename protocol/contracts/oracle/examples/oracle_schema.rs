@@ -1,3 +1,5 @@
+use std::fs;
+
 use currencies::{
     LeaseGroup as AlarmCurrencies, Lpn as BaseCurrency, Lpns as BaseCurrencies,
     PaymentGroup as PriceCurrencies,
@@ -17,4 +19,12 @@ fn main() {
     export_schema(&schema_for!(QueryMsg::<PriceCurrencies>), &out_dir);
     export_schema(&schema_for!(Config), &out_dir);
     export_schema(&schema_for!(SemVer), &out_dir);
+    let currencies = out_dir.join("currencies.json");
+    fs::write(
+        &currencies,
+        currency::schema::currencies_json::<PriceCurrencies>()
+            .expect("The price currencies should be exported"),
+    )
+    .expect("The price currencies should be written");
+    println!("Created {}", currencies.display());
 }
@@ -1,10 +1,16 @@
+use std::num::NonZeroU16;
+
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use currency::{CurrencyDTO, CurrencyDef, DefinitionRef, Group, MemberOf};
-use finance::price::{base::BasePrice, dto::PriceDTO};
-use marketprice::config::Config as PriceConfig;
+use finance::{
+    duration::Duration,
+    percent::Percent,
+    price::{base::BasePrice, dto::PriceDTO},
+};
+use marketprice::{config::Config as PriceConfig, feeders::FeederWeight, history::Sample};
 use sdk::{
-    cosmwasm_std::Addr,
+    cosmwasm_std::{Addr, Timestamp},
     schemars::{self, JsonSchema},
 };
 use tree::HumanReadableTree;
@@ -53,8 +59,16 @@ where
     },
     /// Returns [`DispatchAlarmsResponse`] as response data.
     DispatchAlarms { max_count: AlarmsCount },
+    /// Remove all price alarms of each of the subscribers
+    ///
+    /// Permitted only to the admin of the contract. The number of subscribers may not
+    /// exceed [`MAX_REMOVED_SUBSCRIBERS`] to keep the gas of the transaction bounded.
+    RemovePriceAlarms { subscribers: Vec<Addr> },
 }
 
+/// The maximum number of subscribers whose price alarms may be removed in a single transaction
+pub const MAX_REMOVED_SUBSCRIBERS: usize = 100;
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug, Clone))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -64,14 +78,55 @@ where
 {
     RegisterFeeder {
         feeder_address: String,
+        /// The weight of the feeder observations in the price calculation
+        ///
+        /// It should be greater than zero. If not provided, it is
+        /// [`marketprice::feeders::DEFAULT_WEIGHT`]. The number of feeders required
+        /// for a price is counted irrespective of their weights.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<FeederWeight>,
     },
     RemoveFeeder {
         feeder_address: String,
     },
     UpdateConfig(PriceConfig),
+    /// Set or remove the single source trusted to feed prices
+    ///
+    /// While a trusted source is set, the registered feeders are not allowed to feed prices.
+    /// If `address` is not provided, the feeding is switched back to the registered feeders.
+    TrustedSource {
+        address: Option<String>,
+    },
+    /// Pause the price feeding, for example, during an incident
+    ///
+    /// Refer to [`Config::feeds_paused`] for details.
+    PauseFeeds {},
+    /// Resume the price feeding paused by [`SudoMsg::PauseFeeds`]
+    ResumeFeeds {},
     SwapTree {
         tree: HumanReadableTree<SwapTarget<PriceCurrencies>>,
     },
+    /// Set or remove a fallback price path of a currency
+    ///
+    /// The fallback path is used to calculate the price of the currency
+    /// if its swap tree path cannot be priced, for example, due to a missing or stale feed.
+    /// It should start from the currency and terminate at the base currency.
+    /// The swap legs' prices become eligible for feeding.
+    /// If `path` is not provided, the current fallback path, if any, is removed.
+    FallbackPath {
+        currency: CurrencyDTO<PriceCurrencies>,
+        path: Option<Vec<SwapLeg<PriceCurrencies>>>,
+    },
+    /// Set or remove the number of historical price samples retained for a currency
+    ///
+    /// A sample of the currency price is recorded on each price feed transaction.
+    /// The depth may not exceed [`marketprice::history::MAX_DEPTH`] since each sample
+    /// takes storage space and adds to the gas paid by the feeders.
+    /// If `depth` is not provided, the price history of the currency is removed.
+    PriceHistoryDepth {
+        currency: CurrencyDTO<PriceCurrencies>,
+        depth: Option<u16>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
@@ -90,6 +145,11 @@ where
     // returns the contract configuration
     Config {},
 
+    /// Provides the settings that determine how the prices are aggregated and for how long they are valid
+    ///
+    /// Returns `oracle::api::PricingParamsResponse`
+    PricingParams {},
+
     // returns the supported currencies tree
     SwapTree {},
 
@@ -106,6 +166,17 @@ where
     /// Returns `oracle::api::PricesResponse`
     Prices {},
 
+    /// Provides the prices of a bounded number of supported currencies
+    ///
+    /// The swap tree is walked depth-first starting right after `start_after`, or at the root
+    /// if not provided, and stops after `max_currencies` currencies, priced or not.
+    /// The response `next`, if present, should be passed as `start_after` to resume the walk.
+    /// Returns `oracle::api::PricesPageResponse`
+    PricesPage {
+        start_after: Option<CurrencyDTO<PriceCurrencies>>,
+        max_currencies: Option<NonZeroU16>,
+    },
+
     /// Report the base currency as [SymbolOwned]
     ///
     /// Implementation of [crate::api::price::QueryMsg::BaseCurrency]
@@ -118,6 +189,39 @@ where
         currency: CurrencyDTO<PriceCurrencies>,
     },
 
+    /// Provides the price of a currency against the base currency along with the price path used
+    ///
+    /// Returns `oracle::api::BasePriceWithSourceResponse`
+    BasePriceWithSource {
+        currency: CurrencyDTO<PriceCurrencies>,
+    },
+
+    /// Provides the retained historical prices of a currency against the base currency
+    ///
+    /// Returns `oracle::api::PriceHistoryResponse`, the oldest sample first
+    PriceHistory {
+        currency: CurrencyDTO<PriceCurrencies>,
+    },
+
+    /// Provides the retained historical price of a currency as of a past time
+    ///
+    /// Returns `oracle::api::PriceAtResponse`, the latest sample taken at or before `at`.
+    /// Fails if `at` predates the retained history.
+    PriceAt {
+        currency: CurrencyDTO<PriceCurrencies>,
+        at: Timestamp,
+    },
+
+    /// Provides the price of a currency against another one serving as its quote currency
+    ///
+    /// Returns `finance::price::base::BasePrice` with `quote` as its quote currency,
+    /// composed from the prices of both currencies against the base currency.
+    /// Fails if either of them cannot be priced.
+    PriceIn {
+        currency: CurrencyDTO<PriceCurrencies>,
+        quote: CurrencyDTO<PriceCurrencies>,
+    },
+
     /// Implementation of [oracle_platform::msg::QueryMsg::StableCurrency]
     StableCurrency {},
 
@@ -136,6 +240,11 @@ where
     /// Return a `Vec<oracle::api::Currency>`
     Currencies {},
 
+    /// Provides the currencies of the protocol by their role
+    ///
+    /// Return `oracle::api::ProtocolCurrenciesResponse`
+    ProtocolCurrencies {},
+
     /// Provides a path in the swap tree between two arbitrary currencies
     ///
     /// Returns `oracle::api::swap::SwapPath`
@@ -153,6 +262,64 @@ where
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct Config {
     pub price_config: PriceConfig,
+    /// The single source trusted to feed prices, if any
+    ///
+    /// If set, only this address may feed prices and each of them is accepted
+    /// without a quorum, for example, coming from an aggregator contract.
+    /// Otherwise, the prices are fed by the registered feeders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_source: Option<Addr>,
+    /// Whether the price feeding is paused
+    ///
+    /// While paused, all price feeds are rejected. The prices are still served
+    /// as long as their observations, fed before the pause, remain valid.
+    /// The validity is not extended by the pause, hence a pause that outlasts
+    /// the price feed period leaves the currencies unpriced until the feeding
+    /// resumes. Any operation that needs a price, for example, a lease
+    /// liquidation or repayment, fails meanwhile.
+    #[serde(default)]
+    pub feeds_paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    bound(serialize = "", deserialize = "")
+)]
+pub struct PricingParamsResponse<PriceCurrencies>
+where
+    PriceCurrencies: Group,
+{
+    /// The period a fed observation is taken into account in the price calculation
+    ///
+    /// A price may be cached by clients for no longer than this period.
+    pub feed_validity: Duration,
+    /// The period of a single sample of observations
+    pub sample_period: Duration,
+    /// The number of samples the price is calculated from
+    pub samples_number: u16,
+    /// The factor each older sample is discounted by in the weighted average of the observations
+    pub discount_factor: Percent,
+    pub aggregation: PriceAggregation,
+    /// The minimum percentage of the registered feeders that should have fed a price
+    ///
+    /// It does not apply if the prices come from a trusted source.
+    pub min_feeders: Percent,
+    /// The currencies with a fallback price path configured
+    pub fallback_currencies: Vec<CurrencyDTO<PriceCurrencies>>,
+}
+
+/// The source of the observations aggregated into a price
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum PriceAggregation {
+    /// The observations of the registered feeders, subject to the `min_feeders` quorum
+    Feeders {},
+    /// The observations of a single trusted source, accepted without a quorum
+    TrustedSource { address: Addr },
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
@@ -171,6 +338,27 @@ pub type SupportedCurrencyPairsResponse<PriceCurrencies> = Vec<SwapLeg<PriceCurr
 
 pub type CurrenciesResponse = Vec<Currency>;
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    bound(serialize = "", deserialize = "")
+)]
+pub struct ProtocolCurrenciesResponse<PriceCurrencies>
+where
+    PriceCurrencies: Group,
+{
+    /// The currency the liquidity is provided in and the loans are granted in
+    pub lpn: CurrencyDTO<PriceCurrencies>,
+    /// The currency the protocol reports amounts in
+    pub stable: CurrencyDTO<PriceCurrencies>,
+    /// The currencies leases may be opened in, in the swap tree order
+    pub lease: Vec<CurrencyDTO<PriceCurrencies>>,
+    /// The currencies accepted only as payment, in the swap tree order
+    pub payment_only: Vec<CurrencyDTO<PriceCurrencies>>,
+}
+
 #[derive(Serialize)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug, PartialEq, Eq))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -224,6 +412,63 @@ where
     pub prices: Vec<BasePrice<PriceCurrencies, BaseC, BaseCurrencies>>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    bound(serialize = "", deserialize = "")
+)]
+pub struct PricesPageResponse<PriceCurrencies, BaseC, BaseCurrencies>
+where
+    PriceCurrencies: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<BaseCurrencies> + MemberOf<PriceCurrencies::TopG>,
+    BaseCurrencies: Group + MemberOf<PriceCurrencies>,
+{
+    pub prices: Vec<BasePrice<PriceCurrencies, BaseC, BaseCurrencies>>,
+    /// The last walked currency if the walk has been truncated
+    pub next: Option<CurrencyDTO<PriceCurrencies>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    bound(serialize = "", deserialize = "")
+)]
+pub struct BasePriceWithSourceResponse<PriceCurrencies, BaseC, BaseCurrencies>
+where
+    PriceCurrencies: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<BaseCurrencies> + MemberOf<PriceCurrencies::TopG>,
+    BaseCurrencies: Group + MemberOf<PriceCurrencies>,
+{
+    pub price: BasePrice<PriceCurrencies, BaseC, BaseCurrencies>,
+    pub source: PriceSource,
+    /// Whether the feeds the price is calculated from are older than half of their validity
+    #[serde(default)]
+    pub stale: bool,
+}
+
+pub type PriceHistoryResponse<PriceCurrencies, BaseC, BaseCurrencies> =
+    Vec<Sample<BasePrice<PriceCurrencies, BaseC, BaseCurrencies>>>;
+
+pub type PriceAtResponse<PriceCurrencies, BaseC, BaseCurrencies> =
+    Sample<BasePrice<PriceCurrencies, BaseC, BaseCurrencies>>;
+
+/// The price path a price is calculated through
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum PriceSource {
+    /// The path in the swap tree
+    Primary,
+    /// The fallback path configured for the currency
+    Fallback,
+}
+
 #[derive(Serialize, Deserialize, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -231,7 +476,8 @@ pub struct AlarmsStatusResponse {
     pub remaining_alarms: bool,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, JsonSchema)]
+#[schemars(with = "(CurrencyDTO<G>, SwapTarget<G>)")]
 pub struct SwapLeg<G>
 where
     G: Group,
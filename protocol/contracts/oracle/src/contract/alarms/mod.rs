@@ -134,6 +134,17 @@ where
         self.alarms.last_failed().map_err(Into::into)
     }
 
+    pub fn remove_subscribers<ErrorG>(&mut self, subscribers: &[Addr]) -> Result<(), ErrorG>
+    where
+        ErrorG: Group,
+    {
+        subscribers.iter().try_for_each(|subscriber| {
+            self.alarms
+                .remove_all(subscriber.clone())
+                .map_err(Into::into)
+        })
+    }
+
     #[cfg(test)]
     fn remove(&mut self, receiver: Addr) -> Result<(), AlarmsG> {
         self.alarms.remove_all(receiver).map_err(Into::into)
@@ -1,7 +1,11 @@
-use currency::Group;
+use currency::{CurrencyDef, Group, MemberOf};
 use sdk::cosmwasm_std::Storage;
 
-use crate::{api::Config, result::Result};
+use crate::{
+    api::{Config, PriceAggregation, PricingParamsResponse},
+    result::Result,
+    state::fallback_paths::FallbackPaths,
+};
 
 pub(super) fn query_config<PriceG>(storage: &dyn Storage) -> Result<Config, PriceG>
 where
@@ -10,6 +14,34 @@ where
     Config::load(storage)
 }
 
+pub(super) fn query_pricing_params<PriceG, BaseC>(
+    storage: &dyn Storage,
+) -> Result<PricingParamsResponse<PriceG>, PriceG>
+where
+    PriceG: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<PriceG>,
+{
+    Config::load(storage).and_then(|config| {
+        FallbackPaths::<PriceG, BaseC>::load(storage).map(|fallbacks| {
+            let price_config = config.price_config;
+            PricingParamsResponse {
+                feed_validity: price_config.feed_validity(),
+                sample_period: price_config.sample_period(),
+                samples_number: price_config.samples_number(),
+                discount_factor: price_config.discount_factor(),
+                aggregation: config
+                    .trusted_source
+                    .map_or(PriceAggregation::Feeders {}, |address| {
+                        PriceAggregation::TrustedSource { address }
+                    }),
+                min_feeders: price_config.min_feeders(),
+                fallback_currencies: fallbacks.currencies().copied().collect(),
+            }
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use currencies::{testing::PaymentC9, Lpn, PaymentGroup as PriceCurrencies};
@@ -66,7 +98,9 @@ mod tests {
                     Duration::from_secs(5),
                     7,
                     Percent::from_percent(88),
-                )
+                ),
+                trusted_source: None,
+                feeds_paused: false,
             }
         );
     }
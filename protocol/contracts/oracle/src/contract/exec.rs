@@ -1,12 +1,13 @@
 use currency::{CurrencyDef, Group, MemberOf};
+use marketprice::feeders::{FeederWeight, DEFAULT_WEIGHT};
 use platform::{contract, response};
 use sdk::{
     cosmwasm_ext::Response as CwResponse,
-    cosmwasm_std::{Addr, DepsMut, Env},
+    cosmwasm_std::{Addr, DepsMut, Env, QuerierWrapper, Storage},
 };
 
 use crate::{
-    api::{DispatchAlarmsResponse, ExecuteMsg},
+    api::{Config, DispatchAlarmsResponse, ExecuteMsg, MAX_REMOVED_SUBSCRIBERS},
     contract::alarms::MarketAlarms,
     error::Error,
     result::Result,
@@ -14,6 +15,9 @@ use crate::{
 
 use super::oracle::{feeder::Feeders, Oracle};
 
+const TRUSTED_SOURCE_ROLE: &str = "trusted_source";
+const ADMIN_ROLE: &str = "admin";
+
 pub fn do_executute<BaseCurrency, BaseCurrencies, AlarmCurrencies, PriceCurrencies>(
     deps: DepsMut<'_>,
     env: Env,
@@ -29,18 +33,13 @@ where
     PriceCurrencies: Group<TopG = PriceCurrencies>,
 {
     match msg {
-        ExecuteMsg::FeedPrices { prices } => Feeders::is_feeder(deps.storage, &sender)
-            .and_then(|found| {
-                if found {
-                    Ok(())
-                } else {
-                    Err(Error::UnknownFeeder {})
-                }
-            })
-            .and_then(|()| {
+        ExecuteMsg::FeedPrices { prices } => check_feeder(deps.storage, &sender)
+            .and_then(|sender_weight| {
                 Oracle::<_, PriceCurrencies, BaseCurrency, BaseCurrencies>::load(deps.storage)
+                    .and_then(|mut oracle| {
+                        oracle.try_feed_prices(env.block.time, sender, sender_weight, prices)
+                    })
             })
-            .and_then(|mut oracle| oracle.try_feed_prices(env.block.time, sender, prices))
             .map(|()| Default::default()),
         ExecuteMsg::DispatchAlarms { max_count } => {
             Oracle::<_, PriceCurrencies, BaseCurrency, BaseCurrencies>::load(deps.storage)?
@@ -56,5 +55,72 @@ where
                 .try_add_price_alarm(sender, alarm)
                 .map(|()| Default::default())
         }
+        ExecuteMsg::RemovePriceAlarms { subscribers } => {
+            check_admin(deps.querier, &env.contract.address, &sender)
+                .and_then(|()| {
+                    if subscribers.len() > MAX_REMOVED_SUBSCRIBERS {
+                        Err(Error::TooManySubscribers {
+                            count: subscribers.len(),
+                            max: MAX_REMOVED_SUBSCRIBERS,
+                        })
+                    } else {
+                        MarketAlarms::<_, AlarmCurrencies>::new(deps.storage)
+                            .remove_subscribers(&subscribers)
+                    }
+                })
+                .map(|()| Default::default())
+        }
     }
 }
+
+fn check_admin<PriceCurrencies>(
+    querier: QuerierWrapper<'_>,
+    oracle: &Addr,
+    sender: &Addr,
+) -> Result<(), PriceCurrencies>
+where
+    PriceCurrencies: Group,
+{
+    contract::admin(querier, oracle)
+        .map_err(Into::into)
+        .and_then(|may_admin| {
+            may_admin
+                .map_or(
+                    Err(access_control::error::Error::Unauthorized {}),
+                    |admin| access_control::check_role(ADMIN_ROLE, &admin, sender),
+                )
+                .map_err(Into::into)
+        })
+}
+
+/// Check the sender is allowed to feed prices and return its weight
+fn check_feeder<PriceCurrencies>(
+    storage: &dyn Storage,
+    sender: &Addr,
+) -> Result<FeederWeight, PriceCurrencies>
+where
+    PriceCurrencies: Group,
+{
+    Config::load(storage)
+        .and_then(|config| {
+            if config.feeds_paused {
+                Err(Error::FeedsPaused {})
+            } else {
+                Ok(config)
+            }
+        })
+        .and_then(|config| match config.trusted_source {
+            Some(trusted_source) => {
+                access_control::check_role(TRUSTED_SOURCE_ROLE, &trusted_source, sender)
+                    .map(|()| DEFAULT_WEIGHT)
+                    .map_err(Into::into)
+            }
+            None => Feeders::is_feeder(storage, sender).and_then(|found| {
+                if found {
+                    Feeders::weight(storage, sender)
+                } else {
+                    Err(Error::UnknownFeeder {})
+                }
+            }),
+        })
+}
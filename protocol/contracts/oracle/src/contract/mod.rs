@@ -9,7 +9,7 @@ use platform::{
 use sdk::{
     cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
-        entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Storage, SubMsgResult,
+        entry_point, Api, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Storage, SubMsgResult,
         Timestamp,
     },
 };
@@ -21,16 +21,19 @@ use versioning::{
 
 use crate::{
     api::{
-        Config, ExecuteMsg, InstantiateMsg, MigrateMsg, PricesResponse, QueryMsg, SudoMsg,
-        SwapTreeResponse,
+        BasePriceWithSourceResponse, Config, ExecuteMsg, InstantiateMsg, MigrateMsg,
+        PricesPageResponse, PricesResponse, QueryMsg, SudoMsg, SwapTreeResponse,
     },
     contract::{alarms::MarketAlarms, oracle::Oracle as GenericOracle},
     error::Error,
     result::Result,
-    state::supported_pairs::SupportedPairs,
+    state::{fallback_paths::FallbackPaths, supported_pairs::SupportedPairs},
 };
 
-use self::{config::query_config, oracle::feeder::Feeders};
+use self::{
+    config::{query_config, query_pricing_params},
+    oracle::feeder::Feeders,
+};
 
 mod alarms;
 mod config;
@@ -52,8 +55,8 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg<PriceCurrencies>,
 ) -> Result<CwResponse, PriceCurrencies> {
-    msg.config
-        .store(deps.storage)
+    validate_trusted_source(deps.api, &msg.config)
+        .and_then(|()| msg.config.store(deps.storage))
         .and_then(|()| {
             SupportedPairs::<PriceCurrencies, BaseCurrency>::new::<StableCurrency>(
                 msg.swap_tree.into_tree(),
@@ -91,6 +94,10 @@ pub fn query(
         QueryMsg::ContractVersion {} => to_json_binary(CURRENT_VERSION),
         QueryMsg::ProtocolPackageRelease {} => to_json_binary(&CURRENT_RELEASE),
         QueryMsg::Config {} => to_json_binary(&query_config(deps.storage)?),
+        QueryMsg::PricingParams {} => to_json_binary(&query_pricing_params::<
+            PriceCurrencies,
+            BaseCurrency,
+        >(deps.storage)?),
         QueryMsg::Feeders {} => {
             Feeders::get(deps.storage).and_then(|ref feeders| to_json_binary(feeders))
         }
@@ -113,9 +120,34 @@ pub fn query(
                 .currencies()
                 .collect::<Vec<_>>(),
         ),
+        QueryMsg::ProtocolCurrencies {} => to_json_binary(
+            &SupportedPairs::<PriceCurrencies, BaseCurrency>::load(deps.storage)?
+                .protocol_currencies::<StableCurrency>(),
+        ),
         QueryMsg::BasePrice { currency } => to_json_binary(
             &Oracle::load(deps.storage)?.try_query_base_price(env.block.time, &currency)?,
         ),
+        QueryMsg::BasePriceWithSource { currency } => {
+            let oracle = Oracle::load(deps.storage)?;
+            to_json_binary(
+                &oracle
+                    .try_query_base_price_with_source(env.block.time, &currency)
+                    .map(|(price, source)| BasePriceWithSourceResponse {
+                        price,
+                        source,
+                        stale: oracle.is_price_stale(env.block.time, &currency, source),
+                    })?,
+            )
+        }
+        QueryMsg::PriceHistory { currency } => {
+            to_json_binary(&Oracle::load(deps.storage)?.try_query_price_history(&currency)?)
+        }
+        QueryMsg::PriceAt { currency, at } => {
+            to_json_binary(&Oracle::load(deps.storage)?.try_query_price_at(&currency, at)?)
+        }
+        QueryMsg::PriceIn { currency, quote } => {
+            Oracle::load(deps.storage)?.try_query_price_in(env.block.time, &currency, &quote)
+        }
         QueryMsg::StablePrice { currency } => to_json_binary(
             &Oracle::load(deps.storage)?
                 .try_query_stable_price::<StableCurrency>(env.block.time, &currency)?,
@@ -125,6 +157,18 @@ pub fn query(
 
             to_json_binary(&PricesResponse { prices })
         }
+        QueryMsg::PricesPage {
+            start_after,
+            max_currencies,
+        } => to_json_binary(
+            &Oracle::load(deps.storage)?
+                .try_query_prices_page(
+                    env.block.time,
+                    start_after.as_ref(),
+                    max_currencies.map(Into::into),
+                )
+                .map(|(prices, next)| PricesPageResponse { prices, next })?,
+        ),
         QueryMsg::SwapPath { from, to } => to_json_binary(
             &SupportedPairs::<PriceCurrencies, BaseCurrency>::load(deps.storage)?
                 .load_swap_path(&from, &to)?,
@@ -158,14 +202,41 @@ pub fn sudo(
 ) -> Result<CwResponse, PriceCurrencies> {
     match msg {
         SudoMsg::UpdateConfig(price_config) => Config::update(deps.storage, price_config),
-        SudoMsg::RegisterFeeder { feeder_address } => Feeders::try_register(deps, feeder_address),
+        SudoMsg::RegisterFeeder {
+            feeder_address,
+            weight,
+        } => Feeders::try_register(deps, feeder_address, weight),
         SudoMsg::RemoveFeeder { feeder_address } => Feeders::try_remove(deps, feeder_address),
+        SudoMsg::TrustedSource { address } => address
+            .map(|address| {
+                deps.api
+                    .addr_validate(&address)
+                    .map_err(Error::TrustedSourceAddressValidation)
+            })
+            .transpose()
+            .and_then(|trusted_source| Config::update_trusted_source(deps.storage, trusted_source)),
+        SudoMsg::PauseFeeds {} => Config::update_feeds_paused(deps.storage, true),
+        SudoMsg::ResumeFeeds {} => Config::update_feeds_paused(deps.storage, false),
         SudoMsg::SwapTree { tree } => {
             SupportedPairs::<PriceCurrencies, BaseCurrency>::new::<StableCurrency>(tree.into_tree())
                 .and_then(|supported_pairs| supported_pairs.save(deps.storage))
                 .and_then(|()| validate_swap_tree(deps.storage, env.block.time))
             // TODO move the swap tree validation at the tree instantiation
         }
+        SudoMsg::FallbackPath { currency, path } => {
+            FallbackPaths::<PriceCurrencies, BaseCurrency>::load(deps.storage)
+                .and_then(|fallbacks| match path {
+                    Some(legs) => fallbacks.set(currency, legs),
+                    None => Ok(fallbacks.remove(&currency)),
+                })
+                .and_then(|fallbacks| fallbacks.save(deps.storage))
+                .and_then(|()| {
+                    Oracle::load(deps.storage)
+                        .and_then(|oracle| oracle.check_fallback_path(env.block.time, &currency))
+                })
+        }
+        SudoMsg::PriceHistoryDepth { currency, depth } => Oracle::load(deps.storage)
+            .and_then(|mut oracle| oracle.try_update_history_depth(currency, depth)),
     }
     .map(|()| response::empty_response())
 }
@@ -207,6 +278,14 @@ fn validate_swap_tree(store: &dyn Storage, now: Timestamp) -> Result<(), PriceCu
         .map(std::mem::drop)
 }
 
+fn validate_trusted_source(api: &dyn Api, config: &Config) -> Result<(), PriceCurrencies> {
+    config.trusted_source.as_ref().map_or(Ok(()), |source| {
+        api.addr_validate(source.as_str())
+            .map(std::mem::drop)
+            .map_err(Error::TrustedSourceAddressValidation)
+    })
+}
+
 fn to_json_binary<T>(data: &T) -> Result<Binary, PriceCurrencies>
 where
     T: Serialize + ?Sized,
@@ -253,6 +332,8 @@ mod tests {
                     1,
                     Percent::from_percent(88),
                 ),
+                trusted_source: None,
+                feeds_paused: false,
             },
             value
         );
@@ -1,22 +1,23 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, num::NonZeroUsize};
 
 use currency::{CurrencyDTO, CurrencyDef, Group, MemberOf};
 use finance::price::{base::BasePrice, dto::PriceDTO};
 use marketprice::{
-    config::Config, market_price::PriceFeeds, ObservationsReadRepo, ObservationsRepo,
+    config::Config, error::PriceFeedsError, feeders::FeederWeight, market_price::PriceFeeds,
+    ObservationsReadRepo, ObservationsRepo,
 };
 use sdk::cosmwasm_std::{Addr, Timestamp};
 
 use crate::{
-    api::{swap::SwapTarget, SwapLeg},
+    api::{swap::SwapTarget, PriceSource, SwapLeg},
     error::{self, Error},
     result::Result,
-    state::supported_pairs::SupportedPairs,
+    state::{fallback_paths::FallbackPaths, supported_pairs::SupportedPairs},
 };
 
 use self::{leg_cmd::LegCmd, price_querier::FedPrices};
 
-use super::PriceResult;
+use super::{PriceResult, PricesPage};
 
 mod leg_cmd;
 mod price_querier;
@@ -47,9 +48,13 @@ where
     BaseG: Group + MemberOf<PriceG>,
     Observations: ObservationsReadRepo<Group = PriceG>,
 {
+    /// Calculate the prices of the currencies walking the swap tree depth-first
+    ///
+    /// The currencies with no price through the tree are priced through their fallback paths, if any.
     pub fn all_prices_iter<'self_, 'iterator, I>(
         &'self_ self,
         swap_pairs_df: I,
+        fallbacks: &'iterator FallbackPaths<PriceG, BaseC>,
         at: Timestamp,
         total_feeders: usize,
     ) -> impl Iterator<Item = PriceResult<PriceG, BaseC, BaseG, PriceG>>
@@ -61,24 +66,164 @@ where
             LegCmd::new(FedPrices::new(&self.feeds, at, total_feeders));
 
         swap_pairs_df
-            .scan(cmd, |cmd, leg: SwapLeg<PriceG>| {
-                Some(currency::visit_any_on_currencies(leg.from, leg.to.target, cmd).transpose())
+            .scan(cmd, move |cmd, leg: SwapLeg<PriceG>| {
+                Some(
+                    currency::visit_any_on_currencies(leg.from, leg.to.target, cmd)
+                        .and_then(|may_price| {
+                            self.or_fallback_price(
+                                may_price,
+                                fallbacks,
+                                &leg.from,
+                                at,
+                                total_feeders,
+                            )
+                        })
+                        .transpose(),
+                )
             })
             .flatten()
     }
 
+    /// Calculate the prices of at most `max_currencies` currencies walking the swap tree depth-first
+    ///
+    /// The walk starts right after `start_after`, or at the root if not provided, and counts
+    /// each visited currency against the bound, no matter whether it has been priced.
+    /// Along with the prices, the last visited currency is returned if the walk has been truncated.
+    /// Passing it as `start_after` resumes the walk at the next currency, so each currency is priced
+    /// once per full pass irrespective of the bound, and no branch is starved by the ones preceding it.
+    /// On resume, the path from the root to `start_after` is re-priced since the prices
+    /// of the currencies that follow depend on it.
+    pub fn prices_page(
+        &self,
+        tree: &SupportedPairs<PriceG, BaseC>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
+        start_after: Option<&CurrencyDTO<PriceG>>,
+        max_currencies: Option<NonZeroUsize>,
+        at: Timestamp,
+        total_feeders: usize,
+    ) -> Result<PricesPage<PriceG, BaseC, BaseG>, PriceG> {
+        let mut cmd: LegCmd<PriceG, BaseC, BaseG, FedPrices<'_, '_, PriceG, Observations>> =
+            LegCmd::new(FedPrices::new(&self.feeds, at, total_feeders));
+
+        let mut legs = tree.swap_pairs_df().peekable();
+
+        start_after
+            .map_or(Ok(()), |last| {
+                tree.load_path(last).and_then(|last_to_base| {
+                    let root_to_last: Vec<_> = last_to_base.rev().collect();
+                    legs.by_ref().find(|leg| &leg.from == last);
+                    root_to_last.windows(2).try_for_each(|parent_child| {
+                        currency::visit_any_on_currencies(
+                            *parent_child[1],
+                            *parent_child[0],
+                            &mut cmd,
+                        )
+                        .map(std::mem::drop)
+                    })
+                })
+            })
+            .and_then(|()| {
+                let mut prices = vec![];
+                let mut last = None;
+                legs.by_ref()
+                    .take(max_currencies.map_or(usize::MAX, NonZeroUsize::get))
+                    .try_for_each(|leg| {
+                        last = Some(leg.from);
+                        currency::visit_any_on_currencies(leg.from, leg.to.target, &mut cmd)
+                            .and_then(|may_price| {
+                                self.or_fallback_price(
+                                    may_price,
+                                    fallbacks,
+                                    &leg.from,
+                                    at,
+                                    total_feeders,
+                                )
+                            })
+                            .map(|may_price| prices.extend(may_price))
+                    })?;
+                Ok((prices, legs.peek().and(last)))
+            })
+    }
+
+    /// Calculate the price of a currency through its swap tree path
+    ///
+    /// If there is no price through the tree, then the fallback path of the currency, if any, is tried.
     pub fn calc_base_price(
         &self,
         tree: &SupportedPairs<PriceG, BaseC>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
         currency: &CurrencyDTO<PriceG>,
         at: Timestamp,
         total_feeders: usize,
-    ) -> Result<BasePrice<PriceG, BaseC, BaseG>, PriceG> {
+    ) -> Result<(BasePrice<PriceG, BaseC, BaseG>, PriceSource), PriceG> {
         tree.load_path(currency)
             .and_then(|leaf_to_base_currencies| {
-                self.feeds
+                match self
+                    .feeds
                     .price::<BaseC, _, _>(at, total_feeders, leaf_to_base_currencies)
-                    .map_err(Into::<Error<PriceG>>::into)
+                {
+                    Err(PriceFeedsError::NoPrice()) => self
+                        .calc_fallback_price(fallbacks, currency, at, total_feeders)
+                        .unwrap_or(Err(PriceFeedsError::NoPrice()))
+                        .map(|price| (price, PriceSource::Fallback)),
+                    primary => primary.map(|price| (price, PriceSource::Primary)),
+                }
+                .map_err(Into::<Error<PriceG>>::into)
+            })
+    }
+
+    /// Check that the currency is in the swap tree and its fallback path, if any, is priceable
+    ///
+    /// A missing price is tolerated since the feeds of the fallback pairs may have not arrived yet.
+    pub fn check_fallback_path(
+        &self,
+        tree: &SupportedPairs<PriceG, BaseC>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
+        currency: &CurrencyDTO<PriceG>,
+        at: Timestamp,
+        total_feeders: usize,
+    ) -> Result<(), PriceG> {
+        tree.load_path(currency)
+            .and_then(|_leaf_to_base_currencies| {
+                match self.calc_fallback_price(fallbacks, currency, at, total_feeders) {
+                    None | Some(Ok(_)) | Some(Err(PriceFeedsError::NoPrice())) => Ok(()),
+                    Some(Err(error)) => Err(Error::InvalidFallbackPath {
+                        currency: *currency,
+                        cause: error.to_string(),
+                    }),
+                }
+            })
+    }
+
+    fn or_fallback_price(
+        &self,
+        may_price: Option<BasePrice<PriceG, BaseC, BaseG>>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
+        currency: &CurrencyDTO<PriceG>,
+        at: Timestamp,
+        total_feeders: usize,
+    ) -> Result<Option<BasePrice<PriceG, BaseC, BaseG>>, PriceG> {
+        may_price.map_or_else(
+            || match self.calc_fallback_price(fallbacks, currency, at, total_feeders) {
+                None | Some(Err(PriceFeedsError::NoPrice())) => Ok(None),
+                Some(fallback) => fallback.map(Some).map_err(Into::into),
+            },
+            |price| Ok(Some(price)),
+        )
+    }
+
+    fn calc_fallback_price(
+        &self,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
+        currency: &CurrencyDTO<PriceG>,
+        at: Timestamp,
+        total_feeders: usize,
+    ) -> Option<std::result::Result<BasePrice<PriceG, BaseC, BaseG>, PriceFeedsError>> {
+        fallbacks
+            .load_path(currency)
+            .map(|fallback_to_base_currencies| {
+                self.feeds
+                    .price::<BaseC, _, _>(at, total_feeders, fallback_to_base_currencies)
             })
     }
 }
@@ -91,15 +236,27 @@ where
     BaseG: Group + MemberOf<PriceG>,
     Observations: ObservationsRepo<Group = PriceG>,
 {
+    /// Feed prices of pairs either from the swap tree or from a fallback path
+    ///
+    /// Prices of the base currency, or of a currency against itself, are rejected
+    /// since they would make a loop at the root of the price paths.
     pub(crate) fn feed_prices(
         &mut self,
         tree: &SupportedPairs<PriceG, BaseC>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
         block_time: Timestamp,
         sender_raw: Addr,
+        sender_weight: FeederWeight,
         prices: &[PriceDTO<PriceG>],
     ) -> Result<(), PriceG> {
-        if let Some(unsupported) = prices.iter().find(|price| {
-            !tree.swap_pairs_df().any(
+        let supported_pairs = || tree.swap_pairs_df().chain(fallbacks.swap_pairs().cloned());
+
+        if let Some(self_loop) = prices.iter().find(|price| {
+            is_self_loop::<_, BaseC>(&price.base().currency(), &price.quote().currency())
+        }) {
+            Err(error::self_loop_price::<_, BaseC>(self_loop))
+        } else if let Some(unsupported) = prices.iter().find(|price| {
+            !supported_pairs().any(
                 |SwapLeg {
                      from,
                      to: SwapTarget { target: to, .. },
@@ -112,15 +269,36 @@ where
                 },
             )
         }) {
-            Err(error::unsupported_denom_pairs(unsupported))
+            let unsupported_base = unsupported.base().currency();
+            Err(error::unsupported_denom_pairs(
+                unsupported,
+                supported_pairs().filter_map(
+                    |SwapLeg {
+                         from,
+                         to: SwapTarget { target: to, .. },
+                     }| (from == unsupported_base).then_some(to),
+                ),
+            ))
         } else {
             self.feeds
-                .feed(block_time, sender_raw, prices)
+                .feed(block_time, sender_raw, sender_weight, prices)
                 .map_err(Into::into)
         }
     }
 }
 
+fn is_self_loop<PriceG, BaseC>(
+    price_base: &CurrencyDTO<PriceG>,
+    price_quote: &CurrencyDTO<PriceG>,
+) -> bool
+where
+    PriceG: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<PriceG>,
+{
+    price_base == BaseC::dto() || price_base == price_quote
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -184,7 +362,7 @@ mod test {
             Lpns as BaseCurrencies, PaymentGroup as PriceCurrencies,
         };
         use finance::{duration::Duration, percent::Percent, price::base::BasePrice};
-        use marketprice::{config::Config, Repo};
+        use marketprice::{config::Config, feeders::DEFAULT_WEIGHT, Repo};
         use sdk::cosmwasm_std::{
             testing::{self, MockStorage},
             Addr, Storage,
@@ -192,7 +370,9 @@ mod test {
 
         use super::BaseCurrency;
         use crate::{
-            contract::oracle::feed::Feeds, state::supported_pairs::SupportedPairs, test_tree, tests,
+            contract::oracle::feed::Feeds,
+            state::{fallback_paths::FallbackPaths, supported_pairs::SupportedPairs},
+            test_tree, tests,
         };
 
         const ROOT_NS: &str = "root";
@@ -221,8 +401,10 @@ mod test {
             oracle
                 .feed_prices(
                     &tree,
+                    &FallbackPaths::default(),
                     env.block.time,
                     Addr::unchecked("feeder"),
+                    DEFAULT_WEIGHT,
                     &[
                         tests::dto_price::<PaymentC4, _, BaseCurrency>(2, 1),
                         tests::dto_price::<PaymentC1, _, BaseCurrency>(5, 1),
@@ -235,7 +417,12 @@ mod test {
                 .unwrap();
 
             let prices: Vec<_> = oracle
-                .all_prices_iter(tree.swap_pairs_df(), env.block.time, 1)
+                .all_prices_iter(
+                    tree.swap_pairs_df(),
+                    &FallbackPaths::default(),
+                    env.block.time,
+                    1,
+                )
                 .flatten()
                 .collect();
 
@@ -275,8 +462,10 @@ mod test {
             oracle
                 .feed_prices(
                     &tree,
+                    &FallbackPaths::default(),
                     env.block.time,
                     Addr::unchecked("feeder"),
+                    DEFAULT_WEIGHT,
                     &[
                         // tests::dto_price::<PaymentC1, _, BaseCurrency, _>(5, 1), a gap for PaymentC7
                         tests::dto_price::<PaymentC4, _, BaseCurrency>(2, 1),
@@ -296,11 +485,207 @@ mod test {
             ];
 
             let prices: Vec<_> = oracle
-                .all_prices_iter(tree.swap_pairs_df(), env.block.time, 1)
+                .all_prices_iter(
+                    tree.swap_pairs_df(),
+                    &FallbackPaths::default(),
+                    env.block.time,
+                    1,
+                )
                 .collect::<Result<_, _>>()
                 .unwrap();
 
             assert_eq!(prices, expected);
         }
     }
+
+    mod prices_page {
+        use std::num::NonZeroUsize;
+
+        use currencies::{
+            testing::{PaymentC1, PaymentC3, PaymentC4, PaymentC5, PaymentC6, PaymentC7},
+            PaymentGroup as PriceCurrencies,
+        };
+        use finance::{duration::Duration, percent::Percent};
+        use marketprice::{config::Config, feeders::DEFAULT_WEIGHT, Repo};
+        use sdk::cosmwasm_std::{
+            testing::{self, MockStorage},
+            Addr, Storage,
+        };
+
+        use super::BaseCurrency;
+        use crate::{
+            contract::oracle::feed::Feeds,
+            state::{fallback_paths::FallbackPaths, supported_pairs::SupportedPairs},
+            test_tree, tests,
+        };
+
+        const ROOT_NS: &str = "root";
+
+        #[test]
+        fn truncated_and_resumed() {
+            let mut storage = MockStorage::new();
+            let env = testing::mock_env();
+            let tree = SupportedPairs::<PriceCurrencies, BaseCurrency>::new::<BaseCurrency>(
+                test_tree::dummy_swap_tree().into_tree(),
+            )
+            .unwrap();
+
+            let config = Config::new(
+                Percent::HUNDRED,
+                Duration::from_secs(5),
+                10,
+                Percent::from_percent(50),
+            );
+
+            let storage_ptr: &mut dyn Storage = &mut storage;
+            let mut oracle = Feeds::with(&config, Repo::new(ROOT_NS, storage_ptr));
+
+            oracle
+                .feed_prices(
+                    &tree,
+                    &FallbackPaths::default(),
+                    env.block.time,
+                    Addr::unchecked("feeder"),
+                    DEFAULT_WEIGHT,
+                    &[
+                        tests::dto_price::<PaymentC4, _, BaseCurrency>(2, 1),
+                        tests::dto_price::<PaymentC1, _, BaseCurrency>(5, 1),
+                        tests::dto_price::<PaymentC7, _, PaymentC1>(3, 1),
+                        tests::dto_price::<PaymentC5, _, PaymentC4>(7, 1),
+                        tests::dto_price::<PaymentC6, _, PaymentC4>(3, 1),
+                        tests::dto_price::<PaymentC3, _, PaymentC5>(11, 1),
+                    ],
+                )
+                .unwrap();
+
+            let max_currencies = NonZeroUsize::new(3);
+
+            // the swap tree consists of 7 currencies, PaymentC9 has no price
+            let (prices, next) = oracle
+                .prices_page(
+                    &tree,
+                    &FallbackPaths::default(),
+                    None,
+                    max_currencies,
+                    env.block.time,
+                    1,
+                )
+                .unwrap();
+            assert_eq!(
+                vec![
+                    tests::base_price::<PaymentC4>(2, 1),
+                    tests::base_price::<PaymentC5>(2 * 7, 1),
+                    tests::base_price::<PaymentC3>(2 * 7 * 11, 1),
+                ],
+                prices
+            );
+            assert_eq!(Some(currency::dto::<PaymentC3, _>()), next);
+
+            let (prices, next) = oracle
+                .prices_page(
+                    &tree,
+                    &FallbackPaths::default(),
+                    next.as_ref(),
+                    max_currencies,
+                    env.block.time,
+                    1,
+                )
+                .unwrap();
+            assert_eq!(
+                vec![
+                    tests::base_price::<PaymentC6>(6, 1),
+                    tests::base_price::<PaymentC1>(5, 1),
+                    tests::base_price::<PaymentC7>(3 * 5, 1),
+                ],
+                prices
+            );
+            assert_eq!(Some(currency::dto::<PaymentC7, _>()), next);
+
+            let (prices, next) = oracle
+                .prices_page(
+                    &tree,
+                    &FallbackPaths::default(),
+                    next.as_ref(),
+                    max_currencies,
+                    env.block.time,
+                    1,
+                )
+                .unwrap();
+            assert!(prices.is_empty());
+            assert_eq!(None, next);
+
+            let (prices, next) = oracle
+                .prices_page(
+                    &tree,
+                    &FallbackPaths::default(),
+                    None,
+                    None,
+                    env.block.time,
+                    1,
+                )
+                .unwrap();
+            assert_eq!(
+                oracle
+                    .all_prices_iter(
+                        tree.swap_pairs_df(),
+                        &FallbackPaths::default(),
+                        env.block.time,
+                        1,
+                    )
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap(),
+                prices
+            );
+            assert_eq!(None, next);
+        }
+    }
+
+    mod self_loop {
+        use currencies::{
+            testing::{PaymentC3, PaymentC4},
+            PaymentGroup as PriceCurrencies,
+        };
+        use currency::CurrencyDTO;
+
+        use super::BaseCurrency;
+
+        fn is_self_loop(
+            price_base: &CurrencyDTO<PriceCurrencies>,
+            price_quote: &CurrencyDTO<PriceCurrencies>,
+        ) -> bool {
+            super::super::is_self_loop::<_, BaseCurrency>(price_base, price_quote)
+        }
+
+        #[test]
+        fn base_as_amount() {
+            assert!(is_self_loop(
+                &currency::dto::<BaseCurrency, _>(),
+                &currency::dto::<PaymentC4, _>()
+            ));
+            assert!(is_self_loop(
+                &currency::dto::<BaseCurrency, _>(),
+                &currency::dto::<BaseCurrency, _>()
+            ));
+        }
+
+        #[test]
+        fn same_currencies() {
+            assert!(is_self_loop(
+                &currency::dto::<PaymentC3, _>(),
+                &currency::dto::<PaymentC3, _>()
+            ));
+        }
+
+        #[test]
+        fn pool_pair() {
+            assert!(!is_self_loop(
+                &currency::dto::<PaymentC3, _>(),
+                &currency::dto::<PaymentC4, _>()
+            ));
+            assert!(!is_self_loop(
+                &currency::dto::<PaymentC4, _>(),
+                &currency::dto::<BaseCurrency, _>()
+            ));
+        }
+    }
 }
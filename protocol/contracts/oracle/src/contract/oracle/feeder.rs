@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use currency::Group;
 use serde::{Deserialize, Serialize};
 
-use marketprice::feeders::PriceFeeders;
+use marketprice::feeders::{FeederWeight, PriceFeeders, DEFAULT_WEIGHT};
 use sdk::cosmwasm_std::{Addr, DepsMut, Storage};
 
 use crate::{api::Config, error::Error, result::Result};
@@ -14,7 +14,7 @@ pub struct Feeders {
 }
 
 impl Feeders {
-    const FEEDERS: PriceFeeders = PriceFeeders::new("feeders");
+    const FEEDERS: PriceFeeders = PriceFeeders::new("feeders", "feeder_weights");
 
     pub(crate) fn get<PriceG>(storage: &dyn Storage) -> Result<HashSet<Addr>, PriceG>
     where
@@ -34,14 +34,34 @@ impl Feeders {
             .map_err(Error::<PriceG>::LoadFeeders)
     }
 
-    pub(crate) fn try_register<PriceG>(deps: DepsMut<'_>, feeder_txt: String) -> Result<(), PriceG>
+    pub(crate) fn weight<PriceG>(
+        storage: &dyn Storage,
+        address: &Addr,
+    ) -> Result<FeederWeight, PriceG>
+    where
+        PriceG: Group,
+    {
+        Self::FEEDERS
+            .weight(storage, address)
+            .map_err(Error::<PriceG>::LoadFeeders)
+    }
+
+    pub(crate) fn try_register<PriceG>(
+        deps: DepsMut<'_>,
+        feeder_txt: String,
+        weight: Option<FeederWeight>,
+    ) -> Result<(), PriceG>
     where
         PriceG: Group,
     {
         deps.api
             .addr_validate(&feeder_txt)
             .map_err(Error::<PriceG>::RegisterFeederAddressValidation)
-            .and_then(|feeder| Self::FEEDERS.register(deps, feeder).map_err(Into::into))
+            .and_then(|feeder| {
+                Self::FEEDERS
+                    .register(deps, feeder, weight.unwrap_or(DEFAULT_WEIGHT))
+                    .map_err(Into::into)
+            })
     }
 
     pub(crate) fn try_remove<PriceG>(deps: DepsMut<'_>, address: String) -> Result<(), PriceG>
@@ -153,6 +173,7 @@ mod tests {
             mock_env(),
             SudoMsg::RegisterFeeder {
                 feeder_address: feeder.to_string(),
+                weight: None,
             },
         )
     }
@@ -1,5 +1,6 @@
 use std::{
     marker::PhantomData,
+    num::NonZeroUsize,
     ops::{Deref, DerefMut},
 };
 
@@ -12,19 +13,30 @@ use finance::price::{
     dto::PriceDTO,
     Price,
 };
-use marketprice::{config::Config as PriceConfig, Repo};
+use marketprice::{
+    config::Config as PriceConfig,
+    feeders::FeederWeight,
+    history::{History, Sample},
+    Repo,
+};
 use platform::{
     dispatcher::{AlarmsDispatcher, Id},
     message::Response as MessageResponse,
 };
-use sdk::cosmwasm_std::{Addr, Storage, Timestamp};
+use sdk::cosmwasm_std::{self, Addr, Binary, Storage, Timestamp};
 
 use crate::{
-    api::{AlarmsStatusResponse, Config, ExecuteAlarmMsg},
+    api::{
+        AlarmsStatusResponse, Config, ExecuteAlarmMsg, PriceAtResponse, PriceHistoryResponse,
+        PriceSource,
+    },
     contract::{alarms::MarketAlarms, oracle::feed::Feeds},
     error::Error,
     result::Result,
-    state::supported_pairs::SupportedPairs,
+    state::{
+        fallback_paths::FallbackPaths, history_depths::HistoryDepths,
+        supported_pairs::SupportedPairs,
+    },
 };
 
 use self::feeder::Feeders;
@@ -33,10 +45,17 @@ pub mod feed;
 pub mod feeder;
 
 const ROOT_NAMESPACE: &str = "o11s";
+const HISTORY_NAMESPACE: &str = "o11h";
 
 pub(crate) type PriceResult<PriceG, OracleBase, OracleBaseG, ErrorG> =
     Result<BasePrice<PriceG, OracleBase, OracleBaseG>, ErrorG>;
 
+/// A page of base prices and the currency to resume the walk after, if truncated
+pub(crate) type PricesPage<PriceG, OracleBase, OracleBaseG> = (
+    Vec<BasePrice<PriceG, OracleBase, OracleBaseG>>,
+    Option<CurrencyDTO<PriceG>>,
+);
+
 // TODO intro AlarmG as a sub-group of PriceG to stricter express
 // the contraint of having alarms only for currencies of AlarmsG.
 // Now alarms for non-alarm currencies are looked for in the DB!
@@ -65,8 +84,14 @@ where
     BaseG: Group + MemberOf<PriceG>,
 {
     pub fn load(storage: S) -> Result<Self, PriceG> {
-        Feeders::total_registered(storage.deref()).and_then(|feeders| {
-            Config::load(storage.deref()).map(|config| Self {
+        Config::load(storage.deref()).and_then(|config| {
+            // a trusted source is the only feeder, hence it makes up the quorum by itself
+            if config.trusted_source.is_some() {
+                Ok(1)
+            } else {
+                Feeders::total_registered(storage.deref())
+            }
+            .map(|feeders| Self {
                 storage,
                 feeders,
                 config,
@@ -82,13 +107,16 @@ where
         block_time: Timestamp,
     ) -> Result<AlarmsStatusResponse, PriceG> {
         self.tree().and_then(|tree| {
-            MarketAlarms::new(self.storage.deref())
-                .try_query_alarms::<_, BaseC, BaseG, PriceG>(self.calc_all_prices(
-                    &tree,
-                    &self.feeds_read_only(),
-                    block_time,
-                ))
-                .map(|remaining_alarms| AlarmsStatusResponse { remaining_alarms })
+            self.fallbacks().and_then(|fallbacks| {
+                MarketAlarms::new(self.storage.deref())
+                    .try_query_alarms::<_, BaseC, BaseG, PriceG>(self.calc_all_prices(
+                        &tree,
+                        &fallbacks,
+                        &self.feeds_read_only(),
+                        block_time,
+                    ))
+                    .map(|remaining_alarms| AlarmsStatusResponse { remaining_alarms })
+            })
         })
     }
 
@@ -97,8 +125,30 @@ where
         block_time: Timestamp,
     ) -> Result<Vec<BasePrice<PriceG, BaseC, BaseG>>, PriceG> {
         self.tree().and_then(|tree| {
-            self.calc_all_prices(&tree, &self.feeds_read_only(), block_time)
-                .collect()
+            self.fallbacks().and_then(|fallbacks| {
+                self.calc_all_prices(&tree, &fallbacks, &self.feeds_read_only(), block_time)
+                    .collect()
+            })
+        })
+    }
+
+    pub(super) fn try_query_prices_page(
+        &self,
+        block_time: Timestamp,
+        start_after: Option<&CurrencyDTO<PriceG>>,
+        max_currencies: Option<NonZeroUsize>,
+    ) -> Result<PricesPage<PriceG, BaseC, BaseG>, PriceG> {
+        self.tree().and_then(|tree| {
+            self.fallbacks().and_then(|fallbacks| {
+                self.feeds_read_only().prices_page(
+                    &tree,
+                    &fallbacks,
+                    start_after,
+                    max_currencies,
+                    block_time,
+                    self.feeders,
+                )
+            })
         })
     }
 
@@ -107,9 +157,114 @@ where
         at: Timestamp,
         currency: &CurrencyDTO<PriceG>,
     ) -> Result<BasePrice<PriceG, BaseC, BaseG>, PriceG> {
+        self.try_query_base_price_with_source(at, currency)
+            .map(|(price, _source)| price)
+    }
+
+    pub(super) fn try_query_base_price_with_source(
+        &self,
+        at: Timestamp,
+        currency: &CurrencyDTO<PriceG>,
+    ) -> Result<(BasePrice<PriceG, BaseC, BaseG>, PriceSource), PriceG> {
+        if currency == BaseC::dto() {
+            // the base is priced one to one against itself, no feeds are involved
+            Ok((
+                Price::<BaseC, BaseC>::identity().into(),
+                PriceSource::Primary,
+            ))
+        } else {
+            self.tree().and_then(|tree| {
+                self.fallbacks().and_then(|fallbacks| {
+                    self.feeds_read_only().calc_base_price(
+                        &tree,
+                        &fallbacks,
+                        currency,
+                        at,
+                        self.feeders,
+                    )
+                })
+            })
+        }
+    }
+
+    /// Check whether the price of a currency would not be the same without
+    /// the feeds older than half of their validity
+    ///
+    /// The price is considered stale if it cannot be calculated from the fresher
+    /// feeds only, or it is then calculated from a different source.
+    pub(super) fn is_price_stale(
+        &self,
+        at: Timestamp,
+        currency: &CurrencyDTO<PriceG>,
+        source: PriceSource,
+    ) -> bool {
+        let fresh_config = self.config.price_config.half_validity();
+        currency != BaseC::dto()
+            && self
+                .tree()
+                .and_then(|tree| {
+                    self.fallbacks().and_then(|fallbacks| {
+                        Self::feeds(&fresh_config, self.storage.deref()).calc_base_price(
+                            &tree,
+                            &fallbacks,
+                            currency,
+                            at,
+                            self.feeders,
+                        )
+                    })
+                })
+                .ok()
+                .is_none_or(|(_price, fresh_source)| fresh_source != source)
+    }
+
+    pub(super) fn try_query_price_history(
+        &self,
+        currency: &CurrencyDTO<PriceG>,
+    ) -> Result<PriceHistoryResponse<PriceG, BaseC, BaseG>, PriceG> {
+        self.tree()
+            .and_then(|tree| tree.load_path(currency).map(std::mem::drop))
+            .and_then(|()| {
+                History::new(HISTORY_NAMESPACE, currency, self.storage.deref())
+                    .samples()
+                    .map_err(Into::into)
+            })
+    }
+
+    pub(super) fn try_query_price_at(
+        &self,
+        currency: &CurrencyDTO<PriceG>,
+        at: Timestamp,
+    ) -> Result<PriceAtResponse<PriceG, BaseC, BaseG>, PriceG> {
+        self.tree()
+            .and_then(|tree| tree.load_path(currency).map(std::mem::drop))
+            .and_then(|()| {
+                History::new(HISTORY_NAMESPACE, currency, self.storage.deref())
+                    .sample_at(at)
+                    .map_err(Into::into)
+            })
+            .and_then(|may_sample| {
+                may_sample.ok_or(Error::NoPriceAt {
+                    currency: *currency,
+                    at,
+                })
+            })
+    }
+
+    pub(super) fn check_fallback_path(
+        &self,
+        at: Timestamp,
+        currency: &CurrencyDTO<PriceG>,
+    ) -> Result<(), PriceG> {
         self.tree().and_then(|tree| {
-            self.feeds_read_only()
-                .calc_base_price(&tree, currency, at, self.feeders)
+            self.fallbacks().and_then(|fallbacks| {
+                self.feeds_read_only().check_fallback_path(
+                    &tree,
+                    &fallbacks,
+                    currency,
+                    at,
+                    self.feeders,
+                )
+            })
         })
     }
 
@@ -150,7 +305,10 @@ where
                 BaseC: CurrencyDef,
                 BaseC::Group: MemberOf<Self::PriceG>,
             {
-                Ok((base_price * self.stable_to_base.inv()).into())
+                self.stable_to_base
+                    .try_inv()
+                    .map(|base_to_stable| (base_price * base_to_stable).into())
+                    .map_err(Into::into)
             }
         }
         self.try_query_base_price(at, &currency::dto::<StableCurrency, _>())
@@ -172,9 +330,112 @@ where
             })
     }
 
+    /// Compose the price of `currency` in `quote` from their prices against the base currency
+    pub(super) fn try_query_price_in(
+        &self,
+        at: Timestamp,
+        currency: &CurrencyDTO<PriceG>,
+        quote: &CurrencyDTO<PriceG>,
+    ) -> Result<Binary, PriceG> {
+        struct QuoteLegCalc<'price, G, BaseCurrency, BaseG>
+        where
+            G: Group<TopG = G>,
+            BaseCurrency: CurrencyDef,
+            BaseCurrency::Group: MemberOf<BaseG> + MemberOf<G>,
+            BaseG: Group,
+        {
+            currency_price: &'price BasePrice<G, BaseCurrency, BaseG>,
+        }
+        impl<G, BaseCurrency, BaseG> WithPrice<BaseCurrency> for QuoteLegCalc<'_, G, BaseCurrency, BaseG>
+        where
+            G: Group<TopG = G>,
+            BaseCurrency: CurrencyDef,
+            BaseCurrency::Group: MemberOf<BaseG> + MemberOf<G>,
+            BaseG: Group,
+        {
+            type PriceG = G;
+
+            type Output = Binary;
+
+            type Error = Error<Self::PriceG>;
+
+            fn exec<QuoteC>(
+                self,
+                quote_price: Price<QuoteC, BaseCurrency>,
+            ) -> std::result::Result<Self::Output, Self::Error>
+            where
+                QuoteC: CurrencyDef,
+                QuoteC::Group: MemberOf<Self::PriceG>,
+            {
+                quote_price
+                    .try_inv()
+                    .map_err(Into::into)
+                    .and_then(|base_to_quote| {
+                        with_price::execute(
+                            self.currency_price,
+                            CrossPriceCalc {
+                                _currency_group: PhantomData::<G>,
+                                base_to_quote,
+                            },
+                        )
+                    })
+            }
+        }
+
+        struct CrossPriceCalc<G, BaseCurrency, QuoteC> {
+            _currency_group: PhantomData<G>,
+            base_to_quote: Price<BaseCurrency, QuoteC>,
+        }
+        impl<G, BaseCurrency, QuoteC> WithPrice<BaseCurrency> for CrossPriceCalc<G, BaseCurrency, QuoteC>
+        where
+            G: Group<TopG = G>,
+            BaseCurrency: CurrencyDef,
+            QuoteC: CurrencyDef,
+            QuoteC::Group: MemberOf<G>,
+        {
+            type PriceG = G;
+
+            type Output = Binary;
+
+            type Error = Error<Self::PriceG>;
+
+            fn exec<C>(
+                self,
+                price: Price<C, BaseCurrency>,
+            ) -> std::result::Result<Self::Output, Self::Error>
+            where
+                C: CurrencyDef,
+                C::Group: MemberOf<Self::PriceG>,
+            {
+                // the currencies may not be in a pool with each other, hence
+                // the result does not fit in a `PriceDTO`
+                cosmwasm_std::to_json_binary(&BasePrice::<G, QuoteC, G>::from_price(
+                    &(price * self.base_to_quote),
+                    currency::dto::<C, G>(),
+                ))
+                .map_err(Error::ConvertToBinary)
+            }
+        }
+
+        let leg_price = |leg: &CurrencyDTO<PriceG>| {
+            self.try_query_base_price(at, leg)
+                .map_err(|cause| Error::NoCrossPriceLeg {
+                    currency: *leg,
+                    cause: cause.to_string(),
+                })
+        };
+
+        leg_price(currency).and_then(|ref currency_price| {
+            leg_price(quote).and_then(|ref quote_price| {
+                with_price::execute(quote_price, QuoteLegCalc { currency_price })
+            })
+        })
+    }
+
     fn calc_all_prices<'self_, 'tree, 'feeds, 'st>(
         &'self_ self,
         tree: &'tree SupportedPairs<PriceG, BaseC>,
+        fallbacks: &'tree FallbackPaths<PriceG, BaseC>,
         feeds: &'feeds Feeds<'_, PriceG, BaseC, BaseG, Repo<'st, &(dyn Storage + 'st), PriceG>>,
         at: Timestamp,
     ) -> impl Iterator<Item = PriceResult<PriceG, BaseC, BaseG, PriceG>> + 'feeds
@@ -184,13 +445,21 @@ where
         'tree: 'feeds,
         'storage: 'feeds,
     {
-        feeds.all_prices_iter(tree.swap_pairs_df(), at, self.feeders)
+        feeds.all_prices_iter(tree.swap_pairs_df(), fallbacks, at, self.feeders)
     }
 
     fn tree(&self) -> Result<SupportedPairs<PriceG, BaseC>, PriceG> {
         SupportedPairs::load(self.storage.deref())
     }
 
+    fn fallbacks(&self) -> Result<FallbackPaths<PriceG, BaseC>, PriceG> {
+        FallbackPaths::load(self.storage.deref())
+    }
+
+    fn history_depths(&self) -> Result<HistoryDepths<PriceG>, PriceG> {
+        HistoryDepths::load(self.storage.deref())
+    }
+
     fn feeds_read_only(
         &self,
     ) -> Feeds<'_, PriceG, BaseC, BaseG, Repo<'storage, &(dyn Storage + 'storage), PriceG>> {
@@ -223,29 +492,106 @@ where
         &mut self,
         block_time: Timestamp,
         sender: Addr,
+        sender_weight: FeederWeight,
         prices: Vec<PriceDTO<PriceG>>,
     ) -> Result<(), PriceG> {
         self.tree().and_then(|tree| {
-            self.feeds_read_write()
-                .feed_prices(&tree, block_time, sender, &prices)
+            self.fallbacks().and_then(|fallbacks| {
+                self.feeds_read_write()
+                    .feed_prices(
+                        &tree,
+                        &fallbacks,
+                        block_time,
+                        sender,
+                        sender_weight,
+                        &prices,
+                    )
+                    .and_then(|()| self.record_history(&tree, &fallbacks, block_time))
+            })
         })
     }
 
+    /// Set or remove the number of historical price samples retained for a currency
+    ///
+    /// The samples beyond the new depth, or all of them on removal, are evicted immediately.
+    pub(super) fn try_update_history_depth(
+        &mut self,
+        currency: CurrencyDTO<PriceG>,
+        depth: Option<u16>,
+    ) -> Result<(), PriceG> {
+        self.tree()
+            .and_then(|tree| tree.load_path(&currency).map(std::mem::drop))
+            .and_then(|()| self.history_depths())
+            .and_then(|depths| match depth {
+                Some(depth) => depths.set(currency, depth),
+                None => Ok(depths.remove(&currency)),
+            })
+            .and_then(|depths| depths.save(self.storage.deref_mut()))
+            .and_then(|()| {
+                self.history_mut(&currency)
+                    .truncate(depth.unwrap_or_default())
+                    .map_err(Into::into)
+            })
+    }
+
+    /// Record the current prices of the currencies configured to keep a price history
+    ///
+    /// Currencies that cannot be priced yet, for example, due to an insufficient number of feeds,
+    /// are skipped.
+    fn record_history(
+        &mut self,
+        tree: &SupportedPairs<PriceG, BaseC>,
+        fallbacks: &FallbackPaths<PriceG, BaseC>,
+        block_time: Timestamp,
+    ) -> Result<(), PriceG> {
+        self.history_depths().and_then(|depths| {
+            let samples: Vec<_> = {
+                let feeds = self.feeds_read_only();
+                depths
+                    .iter()
+                    .filter_map(|(currency, depth)| {
+                        feeds
+                            .calc_base_price(tree, fallbacks, currency, block_time, self.feeders)
+                            .ok()
+                            .map(|(price, _source)| (currency, depth, price))
+                    })
+                    .collect()
+            };
+            samples
+                .into_iter()
+                .try_for_each(|(currency, depth, price)| {
+                    self.history_mut(currency)
+                        .record(Sample::new(block_time, price), depth)
+                })
+                .map_err(Into::into)
+        })
+    }
+
+    fn history_mut(
+        &mut self,
+        currency: &CurrencyDTO<PriceG>,
+    ) -> History<'storage, &mut (dyn Storage + 'storage), BasePrice<PriceG, BaseC, BaseG>> {
+        History::new(HISTORY_NAMESPACE, currency, self.storage.deref_mut())
+    }
+
     pub(super) fn try_notify_alarms(
         &mut self,
         block_time: Timestamp,
         max_count: u32,
     ) -> Result<(u32, MessageResponse), PriceG> {
         let subscribers: Vec<Addr> = self.tree().and_then(|tree| {
-            MarketAlarms::new(self.storage.deref())
-                .ensure_no_in_delivery()?
-                .notify_alarms_iter::<_, BaseC, BaseG, PriceG>(self.calc_all_prices(
-                    &tree,
-                    &self.feeds_read_only(),
-                    block_time,
-                ))?
-                .take(max_count.try_into()?)
-                .collect::<Result<Vec<Addr>, PriceG>>()
+            self.fallbacks().and_then(|fallbacks| {
+                MarketAlarms::new(self.storage.deref())
+                    .ensure_no_in_delivery()?
+                    .notify_alarms_iter::<_, BaseC, BaseG, PriceG>(self.calc_all_prices(
+                        &tree,
+                        &fallbacks,
+                        &self.feeds_read_only(),
+                        block_time,
+                    ))?
+                    .take(max_count.try_into()?)
+                    .collect::<Result<Vec<Addr>, PriceG>>()
+            })
         })?;
 
         #[cfg(debug_assertions)]
@@ -294,7 +640,7 @@ mod test_normalized_price_not_found {
         PaymentGroup as AlarmCurrencies, Stable as StableCurrency,
     };
     use finance::{coin::Coin, duration::Duration, percent::Percent, price};
-    use marketprice::{config::Config as PriceConfig, Repo};
+    use marketprice::{config::Config as PriceConfig, feeders::DEFAULT_WEIGHT, Repo};
     use sdk::{
         cosmwasm_std::{
             testing::{MockApi, MockQuerier, MockStorage},
@@ -306,7 +652,7 @@ mod test_normalized_price_not_found {
     use crate::{
         api::{Alarm, Config},
         contract::alarms::MarketAlarms,
-        state::supported_pairs::SupportedPairs,
+        state::{fallback_paths::FallbackPaths, supported_pairs::SupportedPairs},
         test_tree,
     };
 
@@ -357,6 +703,7 @@ mod test_normalized_price_not_found {
                 querier: QuerierWrapper::new(&MockQuerier::<Empty>::new(&[])),
             },
             testing::user("feeder").to_string(),
+            None,
         )
         .unwrap();
 
@@ -394,8 +741,10 @@ mod test_normalized_price_not_found {
         Feeds::<_, _, BaseCurrencies, _>::with(price_config, Repo::new(ROOT_NAMESPACE, storage))
             .feed_prices(
                 tree,
+                &FallbackPaths::default(),
                 NOW,
                 Addr::unchecked("feeder"),
+                DEFAULT_WEIGHT,
                 &[price::total_of(PRICE_BASE).is(PRICE_QUOTE).into()],
             )
             .unwrap();
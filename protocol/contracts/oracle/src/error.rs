@@ -8,7 +8,7 @@ use currency::{CurrencyDTO, CurrencyDef, Group, MemberOf};
 #[cfg(feature = "contract")]
 use finance::price::dto::PriceDTO;
 use marketprice::{alarms::errors::AlarmError, error::PriceFeedsError, feeders::PriceFeedersError};
-use sdk::cosmwasm_std::{Addr, StdError};
+use sdk::cosmwasm_std::{Addr, StdError, Timestamp};
 use versioning::Error as VersioningError;
 
 #[derive(Error, Debug, PartialEq)]
@@ -46,6 +46,43 @@ where
     #[error("[Oracle] Failed to store supported pairs! Cause: {0}")]
     StoreSupportedPairs(StdError),
 
+    #[error("[Oracle] Failed to load fallback price paths! Cause: {0}")]
+    LoadFallbackPaths(StdError),
+
+    #[error("[Oracle] Failed to store fallback price paths! Cause: {0}")]
+    StoreFallbackPaths(StdError),
+
+    #[error("[Oracle] Invalid fallback price path of '{currency}'! Cause: {cause}")]
+    InvalidFallbackPath {
+        currency: CurrencyDTO<PriceG>,
+        cause: String,
+    },
+
+    #[error("[Oracle] Failed to load price history depths! Cause: {0}")]
+    LoadHistoryDepths(StdError),
+
+    #[error("[Oracle] Failed to store price history depths! Cause: {0}")]
+    StoreHistoryDepths(StdError),
+
+    #[error("[Oracle] Invalid price history depth {depth} of '{currency}'! It should be between 1 and {max}")]
+    InvalidHistoryDepth {
+        currency: CurrencyDTO<PriceG>,
+        depth: u16,
+        max: u16,
+    },
+
+    #[error("[Oracle] No retained price of '{currency}' as of {at}")]
+    NoPriceAt {
+        currency: CurrencyDTO<PriceG>,
+        at: Timestamp,
+    },
+
+    #[error("[Oracle] No cross price through '{currency}'! Cause: {cause}")]
+    NoCrossPriceLeg {
+        currency: CurrencyDTO<PriceG>,
+        cause: String,
+    },
+
     #[error("[Oracle] Failed to convert query response to binary! Cause: {0}")]
     ConvertToBinary(StdError),
 
@@ -64,8 +101,19 @@ where
     #[error("[Oracle] {0}")]
     Finance(#[from] finance::error::Error),
 
-    #[error("[Oracle] Unsupported price {0}")]
-    UnsupportedDenomPairs(String),
+    #[error("[Oracle] Unsupported price {price} of the pair '{base}'/'{quote}'! Supported quote currencies of '{base}': [{supported_quotes}]")]
+    UnsupportedDenomPairs {
+        base: CurrencyDTO<PriceG>,
+        quote: CurrencyDTO<PriceG>,
+        price: String,
+        supported_quotes: String,
+    },
+
+    #[error("[Oracle][Base='{base}'] Price {price} makes a self-loop through the base currency")]
+    SelfLoopPrice {
+        base: CurrencyDTO<PriceG>,
+        price: String,
+    },
 
     #[error("[Oracle] Invalid feeder address")]
     InvalidAddress {},
@@ -85,9 +133,23 @@ where
     #[error("[Oracle] No feeder data for the specified address")]
     UnknownFeeder {},
 
+    #[error("[Oracle] The price feeding is paused")]
+    FeedsPaused {},
+
+    #[error("[Oracle] Failed to validate the trusted source address! Cause: {0}")]
+    TrustedSourceAddressValidation(StdError),
+
+    #[error("[Oracle] {0}")]
+    Unauthorized(#[from] access_control::error::Error),
+
     #[error("[Oracle] Invalid alarm notification address: {0:?}")]
     InvalidAlarmAddress(Addr),
 
+    #[error(
+        "[Oracle] Too many subscribers to remove the alarms of, {count}, the maximum is {max}"
+    )]
+    TooManySubscribers { count: usize, max: usize },
+
     #[error("[Oracle] {0}")]
     Platform(#[from] platform::error::Error),
 
@@ -128,9 +190,35 @@ where
 }
 
 #[cfg(feature = "contract")]
-pub(crate) fn unsupported_denom_pairs<G>(price: &PriceDTO<G>) -> Error<G>
+pub(crate) fn unsupported_denom_pairs<G, SupportedQuotes>(
+    price: &PriceDTO<G>,
+    supported_quotes: SupportedQuotes,
+) -> Error<G>
+where
+    G: Group<TopG = G>,
+    SupportedQuotes: IntoIterator<Item = CurrencyDTO<G>>,
+{
+    Error::UnsupportedDenomPairs {
+        base: price.base().currency(),
+        quote: price.quote().currency(),
+        price: price.to_string(),
+        supported_quotes: supported_quotes
+            .into_iter()
+            .map(|quote| quote.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+#[cfg(feature = "contract")]
+pub(crate) fn self_loop_price<G, BaseC>(price: &PriceDTO<G>) -> Error<G>
 where
     G: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<G>,
 {
-    Error::UnsupportedDenomPairs(price.to_string())
+    Error::SelfLoopPrice {
+        base: BaseC::dto().into_super_group(),
+        price: price.to_string(),
+    }
 }
@@ -3,7 +3,7 @@ use std::mem;
 use currency::Group;
 use marketprice::config::Config as PriceConfig;
 use sdk::{
-    cosmwasm_std::{StdResult, Storage},
+    cosmwasm_std::{Addr, StdResult, Storage},
     cw_storage_plus::Item,
 };
 
@@ -13,7 +13,11 @@ impl Config {
     const STORAGE: Item<Self> = Item::new("config");
 
     pub fn new(price_config: PriceConfig) -> Self {
-        Self { price_config }
+        Self {
+            price_config,
+            trusted_source: None,
+            feeds_paused: false,
+        }
     }
 
     pub fn store<PriceG>(self, storage: &mut dyn Storage) -> Result<(), PriceG>
@@ -49,4 +53,36 @@ impl Config {
             .map(mem::drop)
             .map_err(Error::<PriceG>::UpdateConfig)
     }
+
+    pub fn update_trusted_source<PriceG>(
+        storage: &mut dyn Storage,
+        trusted_source: Option<Addr>,
+    ) -> Result<(), PriceG>
+    where
+        PriceG: Group,
+    {
+        Self::STORAGE
+            .update(storage, |mut c| -> StdResult<_> {
+                c.trusted_source = trusted_source;
+                Ok(c)
+            })
+            .map(mem::drop)
+            .map_err(Error::<PriceG>::UpdateConfig)
+    }
+
+    pub fn update_feeds_paused<PriceG>(
+        storage: &mut dyn Storage,
+        paused: bool,
+    ) -> Result<(), PriceG>
+    where
+        PriceG: Group,
+    {
+        Self::STORAGE
+            .update(storage, |mut c| -> StdResult<_> {
+                c.feeds_paused = paused;
+                Ok(c)
+            })
+            .map(mem::drop)
+            .map_err(Error::<PriceG>::UpdateConfig)
+    }
 }
@@ -0,0 +1,272 @@
+use std::{iter, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+
+use currency::{CurrencyDTO, CurrencyDef, Group, MemberOf};
+use sdk::{cosmwasm_std::Storage, cw_storage_plus::Item};
+
+use crate::{api::SwapLeg, error::Error, result::Result};
+
+/// Alternative price paths of currencies
+///
+/// A fallback path is tried only if the price of a currency cannot be
+/// calculated through its path in the swap tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", bound(serialize = "", deserialize = ""))]
+pub(crate) struct FallbackPaths<PriceG, BaseC>
+where
+    PriceG: Group,
+{
+    paths: Vec<FallbackPath<PriceG>>,
+    #[serde(skip)]
+    _type: PhantomData<BaseC>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", bound(serialize = "", deserialize = ""))]
+struct FallbackPath<G>
+where
+    G: Group,
+{
+    currency: CurrencyDTO<G>,
+    legs: Vec<SwapLeg<G>>,
+}
+
+impl<PriceG, BaseC> FallbackPaths<PriceG, BaseC>
+where
+    PriceG: Group,
+    BaseC: CurrencyDef,
+    BaseC::Group: MemberOf<PriceG>,
+{
+    const DB_ITEM: Item<FallbackPaths<PriceG, BaseC>> = Item::new("fallback_paths");
+
+    pub fn load(storage: &dyn Storage) -> Result<Self, PriceG> {
+        Self::DB_ITEM
+            .may_load(storage)
+            .map(Option::unwrap_or_default)
+            .map_err(Error::LoadFallbackPaths)
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage) -> Result<(), PriceG> {
+        Self::DB_ITEM
+            .save(storage, self)
+            .map_err(Error::StoreFallbackPaths)
+    }
+
+    /// Set the fallback path of `currency` replacing the previous one, if any
+    ///
+    /// The legs should form a chain starting from `currency` and terminating at the base currency.
+    pub fn set(
+        mut self,
+        currency: CurrencyDTO<PriceG>,
+        legs: Vec<SwapLeg<PriceG>>,
+    ) -> Result<Self, PriceG> {
+        Self::check_path(&currency, &legs).map(|()| {
+            self.remove_path(&currency);
+            self.paths.push(FallbackPath { currency, legs });
+            self
+        })
+    }
+
+    pub fn remove(mut self, currency: &CurrencyDTO<PriceG>) -> Self {
+        self.remove_path(currency);
+        self
+    }
+
+    /// Provide the currencies on the fallback path of `currency`, starting with it and
+    /// terminating at the base currency
+    pub fn load_path<'r>(
+        &'r self,
+        currency: &CurrencyDTO<PriceG>,
+    ) -> Option<
+        impl DoubleEndedIterator<Item = &'r CurrencyDTO<PriceG>> + 'r + use<'r, PriceG, BaseC>,
+    > {
+        self.paths
+            .iter()
+            .find(|path| &path.currency == currency)
+            .map(|path| {
+                iter::once(&path.currency).chain(path.legs.iter().map(|leg| &leg.to.target))
+            })
+    }
+
+    /// Provide the currencies having a fallback path
+    pub fn currencies(&self) -> impl Iterator<Item = &CurrencyDTO<PriceG>> + '_ {
+        self.paths.iter().map(|path| &path.currency)
+    }
+
+    /// Provide the swap legs of all fallback paths
+    pub fn swap_pairs(&self) -> impl Iterator<Item = &SwapLeg<PriceG>> + '_ {
+        self.paths.iter().flat_map(|path| path.legs.iter())
+    }
+
+    fn remove_path(&mut self, currency: &CurrencyDTO<PriceG>) {
+        self.paths.retain(|path| &path.currency != currency)
+    }
+
+    fn check_path(currency: &CurrencyDTO<PriceG>, legs: &[SwapLeg<PriceG>]) -> Result<(), PriceG> {
+        let invalid_path = |cause: &str| Error::InvalidFallbackPath {
+            currency: *currency,
+            cause: cause.into(),
+        };
+
+        if currency == &currency::dto::<BaseC, _>() {
+            Err(invalid_path("the base currency is always priced"))
+        } else if legs.first().is_none_or(|leg| &leg.from != currency) {
+            Err(invalid_path("the path should start from the currency"))
+        } else if legs
+            .windows(2)
+            .any(|window| window[0].to.target != window[1].from)
+        {
+            Err(invalid_path("the legs should form a chain"))
+        } else if legs
+            .last()
+            .is_some_and(|leg| leg.to.target != currency::dto::<BaseC, _>())
+        {
+            Err(invalid_path(
+                "the path should terminate at the base currency",
+            ))
+        } else {
+            let mut currencies: Vec<&CurrencyDTO<PriceG>> = iter::once(currency)
+                .chain(legs.iter().map(|leg| &leg.to.target))
+                .collect();
+            currencies.sort_unstable();
+            if currencies.windows(2).any(|window| window[0] == window[1]) {
+                Err(invalid_path(
+                    "the path should not pass through a currency twice",
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<PriceG, BaseC> Default for FallbackPaths<PriceG, BaseC>
+where
+    PriceG: Group,
+{
+    fn default() -> Self {
+        Self {
+            paths: vec![],
+            _type: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::{
+        testing::{PaymentC3, PaymentC4, PaymentC5},
+        Lpn, PaymentGroup as PriceCurrencies,
+    };
+    use currency::{CurrencyDTO, CurrencyDef, MemberOf};
+    use sdk::cosmwasm_std::testing::MockStorage;
+
+    use crate::{
+        api::{swap::SwapTarget, SwapLeg},
+        error::Error,
+    };
+
+    use super::FallbackPaths;
+
+    type TestFallbackPaths = FallbackPaths<PriceCurrencies, Lpn>;
+
+    #[test]
+    fn set_load_remove() {
+        let mut storage = MockStorage::new();
+        assert_eq!(
+            Ok(TestFallbackPaths::default()),
+            TestFallbackPaths::load(&storage)
+        );
+
+        TestFallbackPaths::default()
+            .set(dto::<PaymentC3>(), c3_c4_lpn())
+            .unwrap()
+            .save(&mut storage)
+            .unwrap();
+
+        let paths = TestFallbackPaths::load(&storage).unwrap();
+        assert_eq!(
+            vec![&dto::<PaymentC3>(), &dto::<PaymentC4>(), &dto::<Lpn>()],
+            paths
+                .load_path(&dto::<PaymentC3>())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert!(paths.load_path(&dto::<PaymentC4>()).is_none());
+        assert_eq!(
+            c3_c4_lpn().iter().collect::<Vec<_>>(),
+            paths.swap_pairs().collect::<Vec<_>>()
+        );
+
+        let paths = paths
+            .set(dto::<PaymentC3>(), vec![leg::<PaymentC3, Lpn>(3)])
+            .unwrap();
+        assert_eq!(
+            vec![&dto::<PaymentC3>(), &dto::<Lpn>()],
+            paths
+                .load_path(&dto::<PaymentC3>())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        let paths = paths.remove(&dto::<PaymentC3>());
+        assert_eq!(TestFallbackPaths::default(), paths);
+    }
+
+    #[test]
+    fn invalid_paths() {
+        assert_invalid(dto::<PaymentC3>(), vec![]);
+        assert_invalid(dto::<Lpn>(), vec![leg::<Lpn, Lpn>(1)]);
+        assert_invalid(dto::<PaymentC3>(), vec![leg::<PaymentC5, Lpn>(1)]);
+        assert_invalid(
+            dto::<PaymentC3>(),
+            vec![leg::<PaymentC3, PaymentC4>(1), leg::<PaymentC5, Lpn>(2)],
+        );
+        assert_invalid(dto::<PaymentC3>(), vec![leg::<PaymentC3, PaymentC4>(1)]);
+        assert_invalid(
+            dto::<PaymentC3>(),
+            vec![
+                leg::<PaymentC3, PaymentC4>(1),
+                leg::<PaymentC4, PaymentC3>(2),
+                leg::<PaymentC3, Lpn>(3),
+            ],
+        );
+    }
+
+    #[track_caller]
+    fn assert_invalid(currency: CurrencyDTO<PriceCurrencies>, legs: Vec<SwapLeg<PriceCurrencies>>) {
+        assert!(matches!(
+            TestFallbackPaths::default().set(currency, legs),
+            Err(Error::InvalidFallbackPath { .. })
+        ));
+    }
+
+    fn c3_c4_lpn() -> Vec<SwapLeg<PriceCurrencies>> {
+        vec![leg::<PaymentC3, PaymentC4>(1), leg::<PaymentC4, Lpn>(2)]
+    }
+
+    fn leg<From, To>(pool_id: u64) -> SwapLeg<PriceCurrencies>
+    where
+        From: CurrencyDef,
+        From::Group: MemberOf<PriceCurrencies>,
+        To: CurrencyDef,
+        To::Group: MemberOf<PriceCurrencies>,
+    {
+        SwapLeg {
+            from: dto::<From>(),
+            to: SwapTarget {
+                pool_id,
+                target: dto::<To>(),
+            },
+        }
+    }
+
+    fn dto<C>() -> CurrencyDTO<PriceCurrencies>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<PriceCurrencies>,
+    {
+        currency::dto::<C, _>()
+    }
+}
@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+use currency::{CurrencyDTO, Group};
+use marketprice::history;
+use sdk::{cosmwasm_std::Storage, cw_storage_plus::Item};
+
+use crate::{error::Error, result::Result};
+
+/// The number of historical price samples retained per currency
+///
+/// Currencies without a configured depth do not keep a price history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", bound(serialize = "", deserialize = ""))]
+pub(crate) struct HistoryDepths<PriceG>
+where
+    PriceG: Group,
+{
+    depths: Vec<HistoryDepth<PriceG>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", bound(serialize = "", deserialize = ""))]
+struct HistoryDepth<G>
+where
+    G: Group,
+{
+    currency: CurrencyDTO<G>,
+    depth: u16,
+}
+
+impl<PriceG> HistoryDepths<PriceG>
+where
+    PriceG: Group,
+{
+    const DB_ITEM: Item<HistoryDepths<PriceG>> = Item::new("history_depths");
+
+    pub fn load(storage: &dyn Storage) -> Result<Self, PriceG> {
+        Self::DB_ITEM
+            .may_load(storage)
+            .map(Option::unwrap_or_default)
+            .map_err(Error::LoadHistoryDepths)
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage) -> Result<(), PriceG> {
+        Self::DB_ITEM
+            .save(storage, self)
+            .map_err(Error::StoreHistoryDepths)
+    }
+
+    /// Set the history depth of `currency` replacing the previous one, if any
+    ///
+    /// The depth should be positive and not exceed [`history::MAX_DEPTH`].
+    pub fn set(mut self, currency: CurrencyDTO<PriceG>, depth: u16) -> Result<Self, PriceG> {
+        if depth == 0 || depth > history::MAX_DEPTH {
+            Err(Error::InvalidHistoryDepth {
+                currency,
+                depth,
+                max: history::MAX_DEPTH,
+            })
+        } else {
+            self.remove_depth(&currency);
+            self.depths.push(HistoryDepth { currency, depth });
+            Ok(self)
+        }
+    }
+
+    pub fn remove(mut self, currency: &CurrencyDTO<PriceG>) -> Self {
+        self.remove_depth(currency);
+        self
+    }
+
+    /// Provide the currencies keeping a price history along with their depths
+    pub fn iter(&self) -> impl Iterator<Item = (&CurrencyDTO<PriceG>, u16)> + '_ {
+        self.depths
+            .iter()
+            .map(|depth| (&depth.currency, depth.depth))
+    }
+
+    fn remove_depth(&mut self, currency: &CurrencyDTO<PriceG>) {
+        self.depths.retain(|depth| &depth.currency != currency)
+    }
+}
+
+impl<PriceG> Default for HistoryDepths<PriceG>
+where
+    PriceG: Group,
+{
+    fn default() -> Self {
+        Self { depths: vec![] }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::{
+        testing::{PaymentC3, PaymentC4},
+        PaymentGroup as PriceCurrencies,
+    };
+    use currency::{CurrencyDTO, CurrencyDef, MemberOf};
+    use marketprice::history;
+    use sdk::cosmwasm_std::testing::MockStorage;
+
+    use crate::error::Error;
+
+    use super::HistoryDepths;
+
+    type TestHistoryDepths = HistoryDepths<PriceCurrencies>;
+
+    #[test]
+    fn set_load_remove() {
+        let mut storage = MockStorage::new();
+        assert_eq!(
+            Ok(TestHistoryDepths::default()),
+            TestHistoryDepths::load(&storage)
+        );
+
+        TestHistoryDepths::default()
+            .set(dto::<PaymentC3>(), 10)
+            .and_then(|depths| depths.set(dto::<PaymentC4>(), 20))
+            .unwrap()
+            .save(&mut storage)
+            .unwrap();
+
+        let depths = TestHistoryDepths::load(&storage).unwrap();
+        assert_eq!(
+            vec![(&dto::<PaymentC3>(), 10), (&dto::<PaymentC4>(), 20)],
+            depths.iter().collect::<Vec<_>>()
+        );
+
+        let depths = depths.set(dto::<PaymentC3>(), 5).unwrap();
+        assert_eq!(
+            vec![(&dto::<PaymentC4>(), 20), (&dto::<PaymentC3>(), 5)],
+            depths.iter().collect::<Vec<_>>()
+        );
+
+        let depths = depths
+            .remove(&dto::<PaymentC3>())
+            .remove(&dto::<PaymentC4>());
+        assert_eq!(TestHistoryDepths::default(), depths);
+    }
+
+    #[test]
+    fn invalid_depths() {
+        assert_invalid(0);
+        assert_invalid(history::MAX_DEPTH + 1);
+        assert!(TestHistoryDepths::default()
+            .set(dto::<PaymentC3>(), history::MAX_DEPTH)
+            .is_ok());
+    }
+
+    #[track_caller]
+    fn assert_invalid(depth: u16) {
+        assert!(matches!(
+            TestHistoryDepths::default().set(dto::<PaymentC3>(), depth),
+            Err(Error::InvalidHistoryDepth { .. })
+        ));
+    }
+
+    fn dto<C>() -> CurrencyDTO<PriceCurrencies>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<PriceCurrencies>,
+    {
+        currency::dto::<C, _>()
+    }
+}
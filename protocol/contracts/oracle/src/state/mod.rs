@@ -1,2 +1,4 @@
 pub mod config;
+pub mod fallback_paths;
+pub mod history_depths;
 pub mod supported_pairs;
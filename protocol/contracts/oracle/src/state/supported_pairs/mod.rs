@@ -194,6 +194,36 @@ where
     pub fn currencies(&self) -> impl Iterator<Item = api::Currency> + '_ {
         currencies::currencies(self.tree.iter())
     }
+
+    /// Classify the supported currencies by their role in the protocol
+    ///
+    /// Each currency is resolved against the currency groups the same way
+    /// as the listed [`Self::currencies`] are.
+    pub fn protocol_currencies<StableC>(&self) -> api::ProtocolCurrenciesResponse<PriceG>
+    where
+        BaseC: CurrencyDef,
+        BaseC::Group: MemberOf<PriceG>,
+        StableC: CurrencyDef,
+        StableC::Group: MemberOf<PriceG>,
+    {
+        self.tree.iter().zip(self.currencies()).fold(
+            api::ProtocolCurrenciesResponse {
+                lpn: currency::dto::<BaseC, _>(),
+                stable: currency::dto::<StableC, _>(),
+                lease: vec![],
+                payment_only: vec![],
+            },
+            |mut response, (node, currency)| {
+                let target = node.value().target;
+                match currency.group {
+                    api::CurrencyGroup::Lease => response.lease.push(target),
+                    api::CurrencyGroup::PaymentOnly => response.payment_only.push(target),
+                    api::CurrencyGroup::Lpn | api::CurrencyGroup::Native => {}
+                }
+                response
+            },
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 use oracle_platform::OracleRef;
 
 use currency::{Currency, CurrencyDef, Group, MemberOf};
-use platform::batch::Batch;
+use platform::batch::{Batch, ReplyId};
 use sdk::cosmwasm_std::{wasm_execute, Addr};
 
 use crate::api::alarms::{Alarm, Error, ExecuteMsg, Result};
@@ -19,6 +19,18 @@ where
     where
         <Self::BaseC as CurrencyDef>::Group:
             MemberOf<Self::BaseG> + MemberOf<AlarmCurrencies::TopG>;
+
+    /// Add an alarm whose registration failure does not abort the transaction
+    ///
+    /// Instead, the subscriber gets a reply with `reply_id` and the error.
+    fn add_alarm_reply_on_error(
+        &mut self,
+        alarm: Alarm<AlarmCurrencies, Self::BaseC, Self::BaseG>,
+        reply_id: ReplyId,
+    ) -> Result<()>
+    where
+        <Self::BaseC as CurrencyDef>::Group:
+            MemberOf<Self::BaseG> + MemberOf<AlarmCurrencies::TopG>;
 }
 
 pub trait AsAlarms<OracleBase, OracleBaseG>
@@ -97,6 +109,24 @@ where
 
         Ok(())
     }
+
+    fn add_alarm_reply_on_error(
+        &mut self,
+        alarm: Alarm<AlarmCurrencies, Self::BaseC, Self::BaseG>,
+        reply_id: ReplyId,
+    ) -> Result<()> {
+        self.batch.schedule_execute_reply_on_error(
+            wasm_execute(
+                self.addr().clone(),
+                &ExecuteMsg::AddPriceAlarm { alarm },
+                vec![],
+            )
+            .map_err(Error::StubAddAlarm)?,
+            reply_id,
+        );
+
+        Ok(())
+    }
 }
 
 impl<OracleBase, OracleBaseG> From<AlarmsStub<'_, OracleBase, OracleBaseG>> for Batch
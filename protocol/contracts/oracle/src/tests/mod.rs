@@ -72,6 +72,8 @@ pub(crate) fn dummy_instantiate_msg(
                 1,
                 Percent::from_percent(88),
             ),
+            trusted_source: None,
+            feeds_paused: false,
         },
         swap_tree,
     }
@@ -127,6 +129,7 @@ pub(crate) fn setup_test(
         testing::mock_env(),
         SudoMsg::RegisterFeeder {
             feeder_address: sdk_testing::user(CREATOR).to_string(),
+            weight: None,
         },
     )
     .unwrap();
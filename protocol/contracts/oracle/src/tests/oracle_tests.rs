@@ -1,28 +1,46 @@
 use currencies::{
-    testing::{PaymentC1, PaymentC3, PaymentC4, PaymentC5, PaymentC8},
-    LeaseGroup, Lpn, Lpns, PaymentGroup as PriceCurrencies,
+    testing::{
+        PaymentC1, PaymentC3, PaymentC4, PaymentC5, PaymentC6, PaymentC7, PaymentC8, PaymentC9,
+    },
+    LeaseGroup, Lpn, Lpns, PaymentGroup as PriceCurrencies, Stable,
 };
+use currency::{CurrencyDTO, CurrencyDef, MemberOf};
 use finance::{
     coin::Coin,
+    duration::Duration,
+    percent::Percent,
     price::{self, base::BasePrice, dto::PriceDTO},
 };
+use marketprice::{
+    error::PriceFeedsError,
+    feeders::{FeederWeight, PriceFeedersError, DEFAULT_WEIGHT},
+    history::{self, Sample},
+};
 use platform::{contract::testing, tests};
 use sdk::{
     cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
         testing::{self as cw_testing, MockQuerier},
-        Addr, Event, MessageInfo,
+        Addr, ContractInfoResponse, ContractResult, Deps, DepsMut, Env, Event, MessageInfo,
+        SystemResult, Timestamp,
     },
+    testing as sdk_testing,
 };
 
 use crate::{
-    api::{Alarm, AlarmsCount, DispatchAlarmsResponse, ExecuteMsg, QueryMsg},
+    api::{
+        swap::SwapTarget, Alarm, AlarmsCount, BasePriceWithSourceResponse, DispatchAlarmsResponse,
+        ExecuteMsg, PriceAggregation, PriceAtResponse, PriceHistoryResponse, PriceSource,
+        PricingParamsResponse, ProtocolCurrenciesResponse, QueryMsg, SudoMsg, SwapLeg,
+        MAX_REMOVED_SUBSCRIBERS,
+    },
     contract, error,
     error::Error,
+    test_tree,
     tests::{dummy_default_instantiate_msg, setup_test},
 };
 
-use super::dummy_feed_prices_msg;
+use super::{base_price, dummy_feed_prices_msg};
 
 #[test]
 fn feed_prices_unknown_feeder() {
@@ -130,7 +148,838 @@ fn feed_prices_unsupported_pairs() {
 
     let msg = ExecuteMsg::FeedPrices { prices };
     let err = contract::execute(deps.as_mut(), cw_testing::mock_env(), info, msg).unwrap_err();
-    assert_eq!(error::unsupported_denom_pairs(&unsupported), err);
+    assert_eq!(
+        error::unsupported_denom_pairs(&unsupported, [dto::<PaymentC5>()]),
+        err
+    );
+}
+
+#[test]
+fn feed_prices_unsupported_pair_in_batch() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+
+    let prices = vec![
+        PriceDTO::from(price::total_of(Coin::<PaymentC5>::new(10)).is(Coin::<PaymentC4>::new(22))),
+        PriceDTO::from(price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC5>::new(12))),
+        PriceDTO::from(price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC4>::new(12))),
+        PriceDTO::from(price::total_of(Coin::<PaymentC6>::new(10)).is(Coin::<PaymentC4>::new(12))),
+    ];
+
+    let msg = ExecuteMsg::FeedPrices { prices };
+    let err = contract::execute(deps.as_mut(), cw_testing::mock_env(), info, msg).unwrap_err();
+    let Error::UnsupportedDenomPairs {
+        base,
+        quote,
+        supported_quotes,
+        ..
+    } = &err
+    else {
+        panic!("unexpected error {err:?}");
+    };
+    assert_eq!(&dto::<PaymentC3>(), base);
+    assert_eq!(&dto::<PaymentC4>(), quote);
+    assert_eq!(&dto::<PaymentC5>().to_string(), supported_quotes);
+}
+
+#[test]
+fn feed_prices_replayed_in_block() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let env = cw_testing::mock_env();
+
+    contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+    let price = query_base_price_with_source::<PaymentC3>(deps.as_ref(), env.clone());
+
+    // the replay is accepted and ignored
+    contract::execute(deps.as_mut(), env.clone(), info, dummy_feed_prices_msg()).unwrap();
+    assert_eq!(
+        price,
+        query_base_price_with_source::<PaymentC3>(deps.as_ref(), env)
+    );
+}
+
+#[test]
+fn query_base_price_of_base() {
+    let (deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    assert_eq!(
+        BasePrice::<PriceCurrencies, Lpn, Lpns>::from(
+            price::total_of(Coin::<Lpn>::new(1)).is(Coin::<Lpn>::new(1))
+        ),
+        cosmwasm_std::from_json(
+            contract::query(
+                deps.as_ref(),
+                cw_testing::mock_env(),
+                QueryMsg::BasePrice {
+                    currency: dto::<Lpn>(),
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+    );
+
+    let with_source = query_base_price_with_source::<Lpn>(deps.as_ref(), cw_testing::mock_env());
+    assert_eq!(PriceSource::Primary, with_source.source);
+}
+
+#[test]
+fn query_protocol_currencies() {
+    let (deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    assert_eq!(
+        ProtocolCurrenciesResponse {
+            lpn: currency::dto::<Lpn, PriceCurrencies>(),
+            stable: currency::dto::<Stable, PriceCurrencies>(),
+            lease: vec![
+                currency::dto::<PaymentC4, PriceCurrencies>(),
+                currency::dto::<PaymentC5, PriceCurrencies>(),
+                currency::dto::<PaymentC3, PriceCurrencies>(),
+                currency::dto::<PaymentC6, PriceCurrencies>(),
+                currency::dto::<PaymentC7, PriceCurrencies>(),
+                currency::dto::<PaymentC9, PriceCurrencies>(),
+            ],
+            payment_only: vec![],
+        },
+        cosmwasm_std::from_json::<ProtocolCurrenciesResponse<PriceCurrencies>>(
+            contract::query(
+                deps.as_ref(),
+                cw_testing::mock_env(),
+                QueryMsg::ProtocolCurrencies {}
+            )
+            .unwrap()
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn feed_prices_trusted_source() {
+    // a quorum of all feeders is required in the multi-feeder mode
+    let (mut deps, feeder) = setup_test(super::dummy_instantiate_msg(
+        60,
+        Percent::HUNDRED,
+        test_tree::dummy_swap_tree(),
+    ));
+    () = register_feeder(deps.as_mut(), sdk_testing::user("another_feeder"));
+    let source = MessageInfo {
+        sender: sdk_testing::user("aggregator"),
+        funds: vec![],
+    };
+    () = set_trusted_source(deps.as_mut(), Some(&source.sender));
+    let trusted_source = source.sender.clone();
+    let not_trusted = feeder.sender.clone();
+
+    let _res = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        source,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+    assert_eq!(
+        PriceDTO::from(price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(120))),
+        query_base_price::<PaymentC1>(deps.as_ref())
+    );
+
+    let err = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        Error::Unauthorized(access_control::error::Error::UnauthorizedRole {
+            role: "trusted_source",
+            expected: trusted_source,
+            found: not_trusted,
+        }),
+        err
+    );
+}
+
+#[test]
+fn instantiate_invalid_trusted_source() {
+    let mut msg = dummy_default_instantiate_msg();
+    msg.config.trusted_source = Some(Addr::unchecked("invalid address"));
+
+    let err = contract::instantiate(
+        cw_testing::mock_dependencies().as_mut(),
+        cw_testing::mock_env(),
+        MessageInfo {
+            sender: sdk_testing::user("creator"),
+            funds: vec![],
+        },
+        msg,
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::TrustedSourceAddressValidation(_)));
+}
+
+#[test]
+fn feed_prices_trusted_source_removed() {
+    let (mut deps, feeder) = setup_test(dummy_default_instantiate_msg());
+    let source = MessageInfo {
+        sender: sdk_testing::user("aggregator"),
+        funds: vec![],
+    };
+    () = set_trusted_source(deps.as_mut(), Some(&source.sender));
+    () = set_trusted_source(deps.as_mut(), None);
+
+    let err = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        source,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(Error::UnknownFeeder {}, err);
+
+    let _res = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn feed_prices_paused() {
+    let (mut deps, feeder) = setup_test(dummy_default_instantiate_msg());
+    let fed_price =
+        PriceDTO::from(price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(120)));
+
+    let _res = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder.clone(),
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+
+    () = set_feeds_paused(deps.as_mut(), true);
+    let err = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder.clone(),
+        dummy_feed_prices_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(Error::FeedsPaused {}, err);
+    assert_eq!(fed_price, query_base_price::<PaymentC1>(deps.as_ref()));
+
+    () = set_feeds_paused(deps.as_mut(), false);
+    let _res = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+    assert_eq!(fed_price, query_base_price::<PaymentC1>(deps.as_ref()));
+}
+
+#[test]
+fn feed_prices_paused_outlasts_validity() {
+    let (mut deps, feeder) = setup_test(dummy_default_instantiate_msg());
+
+    let _res = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        feeder,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap();
+    () = set_feeds_paused(deps.as_mut(), true);
+
+    let mut env = cw_testing::mock_env();
+    env.block.time = env.block.time.plus_seconds(61);
+    let err = contract::query(
+        deps.as_ref(),
+        env,
+        QueryMsg::BasePrice {
+            currency: dto::<PaymentC1>(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(Error::PriceFeedsError(PriceFeedsError::NoPrice()), err);
+}
+
+#[test]
+fn feed_prices_paused_trusted_source() {
+    let (mut deps, _feeder) = setup_test(dummy_default_instantiate_msg());
+    let source = MessageInfo {
+        sender: sdk_testing::user("aggregator"),
+        funds: vec![],
+    };
+    () = set_trusted_source(deps.as_mut(), Some(&source.sender));
+    () = set_feeds_paused(deps.as_mut(), true);
+
+    let err = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        source,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(Error::FeedsPaused {}, err);
+}
+
+#[test]
+fn feed_prices_weighted() {
+    fn weighted_price(weight: Option<FeederWeight>) -> PriceDTO<PriceCurrencies> {
+        let (mut deps, feeder) = setup_test(dummy_default_instantiate_msg());
+        let weighted = MessageInfo {
+            sender: sdk_testing::user("weighted_feeder"),
+            funds: vec![],
+        };
+        let _res =
+            register_weighted_feeder(deps.as_mut(), weighted.sender.clone(), weight).unwrap();
+
+        [(feeder, 120), (weighted, 160)]
+            .into_iter()
+            .for_each(|(info, quote)| {
+                let _res = contract::execute(
+                    deps.as_mut(),
+                    cw_testing::mock_env(),
+                    info,
+                    ExecuteMsg::FeedPrices {
+                        prices: vec![PriceDTO::from(
+                            price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(quote)),
+                        )],
+                    },
+                )
+                .unwrap();
+            });
+
+        query_base_price::<PaymentC1>(deps.as_ref())
+    }
+
+    let equal_weights_price =
+        PriceDTO::from(price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(140)));
+    assert_eq!(equal_weights_price, weighted_price(None));
+    assert_eq!(equal_weights_price, weighted_price(Some(DEFAULT_WEIGHT)));
+    // (120 + 3 * 160) / 4
+    assert_eq!(
+        PriceDTO::from(price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(150))),
+        weighted_price(Some(3))
+    );
+}
+
+#[test]
+fn register_feeder_zero_weight() {
+    let (mut deps, _) = setup_test(dummy_default_instantiate_msg());
+    let feeder = sdk_testing::user("weighted_feeder");
+
+    assert_eq!(
+        Err(Error::PriceFeedersError(PriceFeedersError::ZeroWeight {})),
+        register_weighted_feeder(deps.as_mut(), feeder.clone(), Some(0))
+    );
+    let res = contract::query(
+        deps.as_ref(),
+        cw_testing::mock_env(),
+        QueryMsg::IsFeeder { address: feeder },
+    )
+    .unwrap();
+    let is_feeder: bool = cosmwasm_std::from_json(res).unwrap();
+    assert!(!is_feeder);
+}
+
+#[test]
+fn feeder_removed_keeps_quorum() {
+    // a quorum of all feeders is required
+    let (mut deps, feeder) = setup_test(super::dummy_instantiate_msg(
+        60,
+        Percent::HUNDRED,
+        test_tree::dummy_swap_tree(),
+    ));
+    let removed = MessageInfo {
+        sender: sdk_testing::user("removed_feeder"),
+        funds: vec![],
+    };
+    () = register_feeder(deps.as_mut(), removed.sender.clone());
+
+    [feeder, removed.clone()].into_iter().for_each(|info| {
+        let _res = contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            info,
+            dummy_feed_prices_msg(),
+        )
+        .unwrap();
+    });
+
+    let _res = contract::sudo(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        SudoMsg::RemoveFeeder {
+            feeder_address: removed.sender.to_string(),
+        },
+    )
+    .unwrap();
+
+    // the quorum is a share of the registered feeders so it shrinks along with them,
+    // and the observations already made stay until they get out of the price feed period
+    assert_eq!(
+        PriceDTO::from(price::total_of(Coin::<PaymentC1>::new(10)).is(Coin::<Lpn>::new(120))),
+        query_base_price::<PaymentC1>(deps.as_ref())
+    );
+
+    let err = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        removed,
+        dummy_feed_prices_msg(),
+    )
+    .unwrap_err();
+    assert_eq!(Error::UnknownFeeder {}, err);
+}
+
+#[test]
+fn fallback_price_on_stale_primary() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+
+    let mut env = cw_testing::mock_env();
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::FallbackPath {
+            currency: dto::<PaymentC3>(),
+            path: Some(vec![
+                leg::<PaymentC3, PaymentC4>(11),
+                leg::<PaymentC4, Lpn>(12),
+            ]),
+        },
+    )
+    .unwrap();
+
+    let c4_to_lpn =
+        PriceDTO::from(price::total_of(Coin::<PaymentC4>::new(10)).is(Coin::<Lpn>::new(5)));
+    let msg = ExecuteMsg::FeedPrices {
+        prices: vec![
+            PriceDTO::from(
+                price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC5>::new(120)),
+            ),
+            PriceDTO::from(
+                price::total_of(Coin::<PaymentC5>::new(10)).is(Coin::<PaymentC4>::new(5)),
+            ),
+            c4_to_lpn,
+        ],
+    };
+    contract::execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(
+        BasePriceWithSourceResponse {
+            price: base_price::<PaymentC3>(1, 3),
+            source: PriceSource::Primary,
+            stale: false,
+        },
+        query_base_price_with_source::<PaymentC3>(deps.as_ref(), env.clone())
+    );
+
+    // the PaymentC5 to PaymentC4 price goes stale
+    env.block.time = env.block.time.plus_seconds(61);
+    let msg = ExecuteMsg::FeedPrices {
+        prices: vec![
+            PriceDTO::from(
+                price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC4>::new(20)),
+            ),
+            c4_to_lpn,
+        ],
+    };
+    contract::execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(
+        BasePriceWithSourceResponse {
+            price: base_price::<PaymentC3>(1, 1),
+            source: PriceSource::Fallback,
+            stale: false,
+        },
+        query_base_price_with_source::<PaymentC3>(deps.as_ref(), env.clone())
+    );
+    assert_eq!(
+        base_price::<PaymentC3>(1, 1),
+        cosmwasm_std::from_json::<BasePrice<PriceCurrencies, Lpn, Lpns>>(
+            contract::query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::BasePrice {
+                    currency: dto::<PaymentC3>()
+                },
+            )
+            .unwrap()
+        )
+        .unwrap()
+    );
+
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::FallbackPath {
+            currency: dto::<PaymentC3>(),
+            path: None,
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        contract::query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::BasePriceWithSource {
+                currency: dto::<PaymentC3>()
+            },
+        ),
+        Err(Error::PriceFeedsError(_))
+    ));
+    let unsupported =
+        PriceDTO::from(price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC4>::new(20)));
+    let msg = ExecuteMsg::FeedPrices {
+        prices: vec![unsupported],
+    };
+    assert_eq!(
+        Err(error::unsupported_denom_pairs(
+            &unsupported,
+            [dto::<PaymentC5>()]
+        )),
+        contract::execute(deps.as_mut(), env, info, msg)
+    );
+}
+
+#[test]
+fn stale_price_flag() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+
+    let mut env = cw_testing::mock_env();
+    contract::execute(deps.as_mut(), env.clone(), info, dummy_feed_prices_msg()).unwrap();
+    let price = base_price::<PaymentC1>(10, 120);
+
+    let fresh = query_base_price_with_source::<PaymentC1>(deps.as_ref(), env.clone());
+    assert_eq!(price, fresh.price);
+    assert_eq!(PriceSource::Primary, fresh.source);
+    assert!(!fresh.stale);
+
+    // past the half of the 60s validity
+    env.block.time = env.block.time.plus_seconds(31);
+    let half_stale = query_base_price_with_source::<PaymentC1>(deps.as_ref(), env.clone());
+    assert_eq!(price, half_stale.price);
+    assert_eq!(PriceSource::Primary, half_stale.source);
+    assert!(half_stale.stale);
+
+    env.block.time = env.block.time.plus_seconds(30);
+    assert!(matches!(
+        contract::query(
+            deps.as_ref(),
+            env,
+            QueryMsg::BasePriceWithSource {
+                currency: dto::<PaymentC1>()
+            },
+        ),
+        Err(Error::PriceFeedsError(_))
+    ));
+}
+
+#[test]
+fn fallback_path_not_to_base() {
+    let (mut deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    assert!(matches!(
+        contract::sudo(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            SudoMsg::FallbackPath {
+                currency: dto::<PaymentC3>(),
+                path: Some(vec![leg::<PaymentC3, PaymentC4>(11)]),
+            },
+        ),
+        Err(Error::InvalidFallbackPath { .. })
+    ));
+}
+
+#[test]
+fn fallback_path_unsupported_currency() {
+    let (mut deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    let detached = dto::<PaymentC8>();
+    assert_eq!(
+        Err(error::unsupported_currency::<_, Lpn>(detached)),
+        contract::sudo(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            SudoMsg::FallbackPath {
+                currency: detached,
+                path: Some(vec![leg::<PaymentC8, Lpn>(11)]),
+            },
+        )
+    );
+}
+
+#[test]
+fn pricing_params() {
+    let (mut deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    let expected = PricingParamsResponse {
+        feed_validity: Duration::from_secs(60),
+        sample_period: Duration::from_secs(60),
+        samples_number: 1,
+        discount_factor: Percent::from_percent(88),
+        aggregation: PriceAggregation::Feeders {},
+        min_feeders: Percent::from_percent(50),
+        fallback_currencies: vec![],
+    };
+    assert_eq!(expected, query_pricing_params(deps.as_ref()));
+
+    contract::sudo(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        SudoMsg::FallbackPath {
+            currency: dto::<PaymentC3>(),
+            path: Some(vec![
+                leg::<PaymentC3, PaymentC4>(11),
+                leg::<PaymentC4, Lpn>(12),
+            ]),
+        },
+    )
+    .unwrap();
+    let source = sdk_testing::user("aggregator");
+    () = set_trusted_source(deps.as_mut(), Some(&source));
+    assert_eq!(
+        PricingParamsResponse {
+            aggregation: PriceAggregation::TrustedSource { address: source },
+            fallback_currencies: vec![dto::<PaymentC3>()],
+            ..expected
+        },
+        query_pricing_params(deps.as_ref())
+    );
+}
+
+#[test]
+fn price_history() {
+    const DEPTH: u16 = 2;
+
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let mut env = cw_testing::mock_env();
+
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::PriceHistoryDepth {
+            currency: dto::<PaymentC1>(),
+            depth: Some(DEPTH),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        Vec::<Sample<BasePrice<PriceCurrencies, Lpn, Lpns>>>::new(),
+        query_price_history::<PaymentC1>(deps.as_ref(), env.clone())
+    );
+
+    let mut expected = vec![];
+    for quote in [120, 130, 140] {
+        env.block.time = env.block.time.plus_seconds(100);
+        contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::FeedPrices {
+                prices: vec![price::total_of(Coin::<PaymentC1>::new(10))
+                    .is(Coin::<Lpn>::new(quote))
+                    .into()],
+            },
+        )
+        .unwrap();
+        expected.push(Sample::new(
+            env.block.time,
+            base_price::<PaymentC1>(10, quote),
+        ));
+    }
+    assert_eq!(
+        expected[1..],
+        query_price_history::<PaymentC1>(deps.as_ref(), env.clone())
+    );
+
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::PriceHistoryDepth {
+            currency: dto::<PaymentC1>(),
+            depth: None,
+        },
+    )
+    .unwrap();
+    assert!(query_price_history::<PaymentC1>(deps.as_ref(), env).is_empty());
+}
+
+#[test]
+fn price_at() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let mut env = cw_testing::mock_env();
+    let start = env.block.time;
+
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::PriceHistoryDepth {
+            currency: dto::<PaymentC1>(),
+            depth: Some(3),
+        },
+    )
+    .unwrap();
+
+    let mut samples = vec![];
+    for quote in [120, 130, 140] {
+        env.block.time = env.block.time.plus_seconds(100);
+        contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::FeedPrices {
+                prices: vec![price::total_of(Coin::<PaymentC1>::new(10))
+                    .is(Coin::<Lpn>::new(quote))
+                    .into()],
+            },
+        )
+        .unwrap();
+        samples.push(Sample::new(
+            env.block.time,
+            base_price::<PaymentC1>(10, quote),
+        ));
+    }
+
+    assert_eq!(
+        Ok(samples[1].clone()),
+        query_price_at::<PaymentC1>(deps.as_ref(), env.clone(), start.plus_seconds(200))
+    );
+    assert_eq!(
+        Ok(samples[1].clone()),
+        query_price_at::<PaymentC1>(deps.as_ref(), env.clone(), start.plus_seconds(250))
+    );
+    assert_eq!(
+        Ok(samples[2].clone()),
+        query_price_at::<PaymentC1>(deps.as_ref(), env.clone(), env.block.time)
+    );
+
+    let before_history = start.plus_seconds(50);
+    assert_eq!(
+        Err(Error::NoPriceAt {
+            currency: dto::<PaymentC1>(),
+            at: before_history,
+        }),
+        query_price_at::<PaymentC1>(deps.as_ref(), env, before_history)
+    );
+}
+
+#[test]
+fn price_in() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let env = cw_testing::mock_env();
+
+    contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::FeedPrices {
+            prices: vec![
+                price::total_of(Coin::<PaymentC1>::new(10))
+                    .is(Coin::<Lpn>::new(120))
+                    .into(),
+                price::total_of(Coin::<PaymentC4>::new(10))
+                    .is(Coin::<Lpn>::new(30))
+                    .into(),
+            ],
+        },
+    )
+    .unwrap();
+
+    let c1_in_c4 = price::total_of(Coin::<PaymentC1>::new(1)).is(Coin::<PaymentC4>::new(4));
+    assert_eq!(
+        Ok(c1_in_c4),
+        query_price_in::<PaymentC1, PaymentC4>(deps.as_ref(), env.clone())
+            .map(|price| price.try_into().unwrap())
+    );
+    assert_eq!(
+        Ok(c1_in_c4.inv()),
+        query_price_in::<PaymentC4, PaymentC1>(deps.as_ref(), env.clone())
+            .map(|price| price.try_into().unwrap())
+    );
+    assert_eq!(
+        Ok(price::total_of(Coin::<PaymentC1>::new(1)).is(Coin::<Lpn>::new(12))),
+        query_price_in::<PaymentC1, Lpn>(deps.as_ref(), env).map(|price| price.try_into().unwrap())
+    );
+}
+
+#[test]
+fn price_in_unpriced_leg() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let env = cw_testing::mock_env();
+
+    contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::FeedPrices {
+            prices: vec![price::total_of(Coin::<PaymentC1>::new(10))
+                .is(Coin::<Lpn>::new(120))
+                .into()],
+        },
+    )
+    .unwrap();
+
+    let cause = contract::query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::BasePrice {
+            currency: dto::<PaymentC4>(),
+        },
+    )
+    .unwrap_err()
+    .to_string();
+    let unpriced = || Error::NoCrossPriceLeg {
+        currency: dto::<PaymentC4>(),
+        cause: cause.clone(),
+    };
+    assert_eq!(
+        Err(unpriced()),
+        query_price_in::<PaymentC1, PaymentC4>(deps.as_ref(), env.clone())
+    );
+    assert_eq!(
+        Err(unpriced()),
+        query_price_in::<PaymentC4, PaymentC1>(deps.as_ref(), env)
+    );
+}
+
+#[test]
+fn price_history_depth_invalid() {
+    let (mut deps, _) = setup_test(dummy_default_instantiate_msg());
+
+    assert!(matches!(
+        contract::sudo(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            SudoMsg::PriceHistoryDepth {
+                currency: dto::<PaymentC1>(),
+                depth: Some(history::MAX_DEPTH + 1),
+            },
+        ),
+        Err(Error::InvalidHistoryDepth { .. })
+    ));
+
+    let detached = dto::<PaymentC8>();
+    assert_eq!(
+        Err(error::unsupported_currency::<_, Lpn>(detached)),
+        contract::sudo(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            SudoMsg::PriceHistoryDepth {
+                currency: detached,
+                depth: Some(1),
+            },
+        )
+    );
 }
 
 #[test]
@@ -209,10 +1058,250 @@ fn deliver_alarm() {
     }
 }
 
+#[test]
+fn deliver_alarm_on_fallback_price() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    setup_receiver(&mut deps.querier);
+
+    let env = cw_testing::mock_env();
+    contract::sudo(
+        deps.as_mut(),
+        env.clone(),
+        SudoMsg::FallbackPath {
+            currency: dto::<PaymentC3>(),
+            path: Some(vec![
+                leg::<PaymentC3, PaymentC4>(11),
+                leg::<PaymentC4, Lpn>(12),
+            ]),
+        },
+    )
+    .unwrap();
+
+    // no PaymentC3 price through the swap tree, one to one through the fallback path
+    let feed_price_msg = ExecuteMsg::FeedPrices {
+        prices: vec![
+            PriceDTO::from(
+                price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<PaymentC4>::new(20)),
+            ),
+            PriceDTO::from(price::total_of(Coin::<PaymentC4>::new(10)).is(Coin::<Lpn>::new(5))),
+        ],
+    };
+    contract::execute(deps.as_mut(), env.clone(), info.clone(), feed_price_msg).unwrap();
+
+    let alarm_below_price = price::total_of(Coin::<PaymentC3>::new(10)).is(Coin::<Lpn>::new(11));
+    let add_alarm_msg = ExecuteMsg::AddPriceAlarm {
+        alarm: Alarm::new(alarm_below_price, None),
+    };
+    assert_eq!(
+        Ok(CwResponse::default()),
+        contract::execute(deps.as_mut(), env.clone(), info.clone(), add_alarm_msg)
+    );
+
+    let dispatch_alarms_msg = ExecuteMsg::DispatchAlarms { max_count: 10 };
+    let dispatch_alarms_resp =
+        contract::execute(deps.as_mut(), env, info, dispatch_alarms_msg).unwrap();
+    assert!(!any_error(&dispatch_alarms_resp));
+    assert_eq!(sent_alarms(&dispatch_alarms_resp), Some(1));
+    assert_eq!(1, dispatch_alarms_resp.messages.len());
+}
+
+#[test]
+fn dispatch_alarms_event_lists_notified() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    setup_receiver(&mut deps.querier);
+
+    let price = |lpn: u128| price::total_of(Coin::<PaymentC4>::new(10)).is(Coin::<Lpn>::new(lpn));
+
+    assert_eq!(
+        Ok(CwResponse::default()),
+        contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            info.clone(),
+            ExecuteMsg::FeedPrices {
+                prices: vec![price(23451).into()],
+            },
+        )
+    );
+
+    let below_triggered = sdk_testing::user("below_triggered");
+    let not_triggered = sdk_testing::user("not_triggered");
+    let above_triggered = sdk_testing::user("above_triggered");
+
+    [
+        (&below_triggered, Alarm::new(price(23452), None)),
+        (&not_triggered, Alarm::new(price(23450), Some(price(23460)))),
+        (
+            &above_triggered,
+            Alarm::new(price(23000), Some(price(23400))),
+        ),
+    ]
+    .into_iter()
+    .for_each(|(subscriber, alarm)| {
+        assert_eq!(
+            Ok(CwResponse::default()),
+            contract::execute(
+                deps.as_mut(),
+                cw_testing::mock_env(),
+                MessageInfo {
+                    sender: subscriber.clone(),
+                    funds: vec![],
+                },
+                ExecuteMsg::AddPriceAlarm { alarm },
+            )
+        );
+    });
+
+    let dispatch_alarms_resp = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        info,
+        ExecuteMsg::DispatchAlarms { max_count: 10 },
+    )
+    .unwrap();
+    assert!(!any_error(&dispatch_alarms_resp));
+    assert_eq!(sent_alarms(&dispatch_alarms_resp), Some(2));
+
+    let [event] = dispatch_alarms_resp.events.as_slice() else {
+        panic!(
+            "a single event expected, got {:?}",
+            dispatch_alarms_resp.events
+        );
+    };
+    assert_eq!("pricealarm", event.ty);
+
+    let mut receivers: Vec<&str> = event
+        .attributes
+        .iter()
+        .map(|attribute| {
+            assert_eq!("receiver", attribute.key);
+            attribute.value.as_str()
+        })
+        .collect();
+    receivers.sort_unstable();
+    let mut expected = vec![above_triggered.as_str(), below_triggered.as_str()];
+    expected.sort_unstable();
+    assert_eq!(expected, receivers);
+}
+
+#[test]
+fn remove_price_alarms() {
+    let (mut deps, info) = setup_test(dummy_default_instantiate_msg());
+    let admin = sdk_testing::user("admin");
+    setup_admin(&mut deps.querier, admin.clone());
+
+    let price = |lpn: u128| price::total_of(Coin::<PaymentC4>::new(10)).is(Coin::<Lpn>::new(lpn));
+
+    assert_eq!(
+        Ok(CwResponse::default()),
+        contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            info.clone(),
+            ExecuteMsg::FeedPrices {
+                prices: vec![price(23451).into()],
+            },
+        )
+    );
+
+    let subscribers: Vec<Addr> = ["subscriber1", "subscriber2", "subscriber3"]
+        .into_iter()
+        .map(sdk_testing::user)
+        .collect();
+    subscribers.iter().for_each(|subscriber| {
+        assert_eq!(
+            Ok(CwResponse::default()),
+            contract::execute(
+                deps.as_mut(),
+                cw_testing::mock_env(),
+                MessageInfo {
+                    sender: subscriber.clone(),
+                    funds: vec![],
+                },
+                ExecuteMsg::AddPriceAlarm {
+                    alarm: Alarm::new(price(23452), None),
+                },
+            )
+        );
+    });
+
+    assert_eq!(
+        Err(Error::Unauthorized(
+            access_control::error::Error::UnauthorizedRole {
+                role: "admin",
+                expected: admin.clone(),
+                found: info.sender.clone(),
+            }
+        )),
+        contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            info.clone(),
+            ExecuteMsg::RemovePriceAlarms {
+                subscribers: subscribers.clone(),
+            },
+        )
+    );
+
+    let admin_info = MessageInfo {
+        sender: admin,
+        funds: vec![],
+    };
+    assert_eq!(
+        Err(Error::TooManySubscribers {
+            count: MAX_REMOVED_SUBSCRIBERS + 1,
+            max: MAX_REMOVED_SUBSCRIBERS,
+        }),
+        contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            admin_info.clone(),
+            ExecuteMsg::RemovePriceAlarms {
+                subscribers: vec![subscribers[0].clone(); MAX_REMOVED_SUBSCRIBERS + 1],
+            },
+        )
+    );
+
+    assert_eq!(
+        Ok(CwResponse::default()),
+        contract::execute(
+            deps.as_mut(),
+            cw_testing::mock_env(),
+            admin_info,
+            ExecuteMsg::RemovePriceAlarms { subscribers },
+        )
+    );
+
+    let dispatch_alarms_resp = contract::execute(
+        deps.as_mut(),
+        cw_testing::mock_env(),
+        info,
+        ExecuteMsg::DispatchAlarms { max_count: 10 },
+    )
+    .unwrap();
+    assert!(!any_error(&dispatch_alarms_resp));
+    assert_eq!(sent_alarms(&dispatch_alarms_resp), Some(0));
+}
+
 fn setup_receiver(querier: &mut MockQuerier) {
     querier.update_wasm(testing::valid_contract_handler);
 }
 
+fn setup_admin(querier: &mut MockQuerier, admin: Addr) {
+    querier.update_wasm(move |_| {
+        SystemResult::Ok(ContractResult::Ok(
+            cosmwasm_std::to_json_binary(&ContractInfoResponse::new(
+                testing::CODE.into(),
+                sdk_testing::user("creator"),
+                Some(admin.clone()),
+                false,
+                None,
+            ))
+            .unwrap(),
+        ))
+    });
+}
+
 fn sent_alarms(resp: &CwResponse) -> Option<AlarmsCount> {
     tests::parse_resp::<DispatchAlarmsResponse>(&resp.data).map(|resp| resp.0)
 }
@@ -220,3 +1309,178 @@ fn sent_alarms(resp: &CwResponse) -> Option<AlarmsCount> {
 fn any_error(resp: &CwResponse) -> bool {
     tests::any_error(&resp.events)
 }
+
+fn register_feeder(deps: DepsMut<'_>, feeder: Addr) {
+    let _res = register_weighted_feeder(deps, feeder, None).unwrap();
+}
+
+fn register_weighted_feeder(
+    deps: DepsMut<'_>,
+    feeder: Addr,
+    weight: Option<FeederWeight>,
+) -> crate::result::Result<CwResponse, PriceCurrencies> {
+    contract::sudo(
+        deps,
+        cw_testing::mock_env(),
+        SudoMsg::RegisterFeeder {
+            feeder_address: feeder.into_string(),
+            weight,
+        },
+    )
+}
+
+fn set_trusted_source(deps: DepsMut<'_>, source: Option<&Addr>) {
+    let _res = contract::sudo(
+        deps,
+        cw_testing::mock_env(),
+        SudoMsg::TrustedSource {
+            address: source.map(ToString::to_string),
+        },
+    )
+    .unwrap();
+}
+
+fn set_feeds_paused(deps: DepsMut<'_>, paused: bool) {
+    let _res = contract::sudo(
+        deps,
+        cw_testing::mock_env(),
+        if paused {
+            SudoMsg::PauseFeeds {}
+        } else {
+            SudoMsg::ResumeFeeds {}
+        },
+    )
+    .unwrap();
+}
+
+fn query_pricing_params(deps: Deps<'_>) -> PricingParamsResponse<PriceCurrencies> {
+    cosmwasm_std::from_json(
+        contract::query(deps, cw_testing::mock_env(), QueryMsg::PricingParams {}).unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_base_price<C>(deps: Deps<'_>) -> PriceDTO<PriceCurrencies>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+{
+    cosmwasm_std::from_json(
+        contract::query(
+            deps,
+            cw_testing::mock_env(),
+            QueryMsg::BasePrice {
+                currency: dto::<C>(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_base_price_with_source<C>(
+    deps: Deps<'_>,
+    env: Env,
+) -> BasePriceWithSourceResponse<PriceCurrencies, Lpn, Lpns>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+{
+    cosmwasm_std::from_json(
+        contract::query(
+            deps,
+            env,
+            QueryMsg::BasePriceWithSource {
+                currency: dto::<C>(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_price_history<C>(
+    deps: Deps<'_>,
+    env: Env,
+) -> PriceHistoryResponse<PriceCurrencies, Lpn, Lpns>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+{
+    cosmwasm_std::from_json(
+        contract::query(
+            deps,
+            env,
+            QueryMsg::PriceHistory {
+                currency: dto::<C>(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_price_at<C>(
+    deps: Deps<'_>,
+    env: Env,
+    at: Timestamp,
+) -> Result<PriceAtResponse<PriceCurrencies, Lpn, Lpns>, Error<PriceCurrencies>>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+{
+    contract::query(
+        deps,
+        env,
+        QueryMsg::PriceAt {
+            currency: dto::<C>(),
+            at,
+        },
+    )
+    .map(|resp| cosmwasm_std::from_json(resp).unwrap())
+}
+
+fn query_price_in<C, QuoteC>(
+    deps: Deps<'_>,
+    env: Env,
+) -> Result<BasePrice<PriceCurrencies, QuoteC, PriceCurrencies>, Error<PriceCurrencies>>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+    QuoteC: CurrencyDef,
+    QuoteC::Group: MemberOf<PriceCurrencies>,
+{
+    contract::query(
+        deps,
+        env,
+        QueryMsg::PriceIn {
+            currency: dto::<C>(),
+            quote: dto::<QuoteC>(),
+        },
+    )
+    .map(|resp| cosmwasm_std::from_json(resp).unwrap())
+}
+
+fn leg<From, To>(pool_id: u64) -> SwapLeg<PriceCurrencies>
+where
+    From: CurrencyDef,
+    From::Group: MemberOf<PriceCurrencies>,
+    To: CurrencyDef,
+    To::Group: MemberOf<PriceCurrencies>,
+{
+    SwapLeg {
+        from: dto::<From>(),
+        to: SwapTarget {
+            pool_id,
+            target: dto::<To>(),
+        },
+    }
+}
+
+fn dto<C>() -> CurrencyDTO<PriceCurrencies>
+where
+    C: CurrencyDef,
+    C::Group: MemberOf<PriceCurrencies>,
+{
+    currency::dto::<C, _>()
+}
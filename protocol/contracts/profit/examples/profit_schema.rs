@@ -1,4 +1,4 @@
-use profit::msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use profit::msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, PendingResponse, QueryMsg};
 use sdk::cosmwasm_schema::{export_schema, schema_for};
 
 fn main() {
@@ -8,4 +8,5 @@ fn main() {
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(PendingResponse), &out_dir);
 }
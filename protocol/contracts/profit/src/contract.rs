@@ -2,6 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use access_control::{ContractOwnerAccess, SingleUserAccess};
 use dex::{ContinueResult as DexResult, Handler as _, Response as DexResponse};
+use finance::percent::Percent;
 use oracle_platform::OracleRef;
 use platform::{
     error as platform_error, message::Response as MessageResponse, response,
@@ -10,7 +11,8 @@ use platform::{
 use sdk::{
     cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
-        entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply,
+        entry_point, to_json_binary, Api, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
+        Reply,
     },
     neutron_sdk::sudo::msg::SudoMsg as NeutronSudoMsg,
 };
@@ -22,7 +24,7 @@ use versioning::{
 
 use crate::{
     error::ContractError,
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReserveFunding},
     profit::Profit,
     result::ContractResult,
     state::{Config, ConfigManagement as _, State},
@@ -45,6 +47,9 @@ pub fn instantiate(
     platform::contract::validate_addr(deps.querier, &msg.treasury)?;
     platform::contract::validate_addr(deps.querier, &msg.oracle)?;
     platform::contract::validate_addr(deps.querier, &msg.timealarms)?;
+    if let Some(reserve_funding) = &msg.reserve_funding {
+        validate_reserve_funding(deps.api, reserve_funding)?;
+    }
 
     ContractOwnerAccess::new(deps.storage.deref_mut()).grant_to(&info.sender)?;
 
@@ -60,6 +65,7 @@ pub fn instantiate(
             msg.treasury,
             OracleRef::try_from_base(msg.oracle, deps.querier)?,
             TimeAlarmsRef::new(msg.timealarms, deps.querier)?,
+            msg.reserve_funding,
         ),
         msg.dex,
     );
@@ -69,6 +75,18 @@ pub fn instantiate(
         .map(|()| response::response_only_messages(response))
 }
 
+fn validate_reserve_funding(api: &dyn Api, reserve_funding: &ReserveFunding) -> ContractResult<()> {
+    api.addr_validate(reserve_funding.reserve.as_str())?;
+
+    if reserve_funding.share.is_zero() || reserve_funding.share > Percent::HUNDRED {
+        Err(ContractError::InvalidReserveFunding(
+            "The share should be in the range (0%, 100%]!",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 #[entry_point]
 pub fn migrate(
     deps: DepsMut<'_>,
@@ -115,6 +133,18 @@ pub fn execute(
 
             Ok(response::response_only_messages(response))
         }
+        ExecuteMsg::ReserveFunding { reserve_funding } => {
+            ContractOwnerAccess::new(deps.storage.deref()).check(&info.sender)?;
+
+            if let Some(reserve_funding) = &reserve_funding {
+                validate_reserve_funding(deps.api, reserve_funding)?;
+            }
+
+            State::load(deps.storage)?
+                .try_update_reserve_funding(reserve_funding)
+                .and_then(|next_state| next_state.store(deps.storage))
+                .map(|()| response::empty_response())
+        }
         ExecuteMsg::DexCallback() => {
             access_control::check(&env.contract.address, &info.sender)?;
 
@@ -226,6 +256,9 @@ pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary>
             env.block.time,
             deps.querier,
         )?),
+        QueryMsg::Pending {} => {
+            to_json_binary(&Profit::query_pending(&env.contract.address, deps.querier)?)
+        }
         QueryMsg::ProtocolPackageRelease {} => to_json_binary(&CURRENT_RELEASE),
     }
     .map_err(Into::into)
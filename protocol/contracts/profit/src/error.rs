@@ -37,6 +37,9 @@ pub enum ContractError {
     #[error("[Profit] Alarm comming from unknown address: {0:?}")]
     UnrecognisedAlarm(Addr),
 
+    #[error("[Profit] Invalid reserve funding. Cause: {0}")]
+    InvalidReserveFunding(&'static str),
+
     #[error("[Profit] Operation is not supported at this time. Cause: {0}")]
     UnsupportedOperation(String),
 
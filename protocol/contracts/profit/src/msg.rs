@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use currencies::PaymentGroup;
 use dex::ConnectionParams;
+use finance::{coin::CoinDTO, percent::Percent};
 use sdk::{
-    cosmwasm_std::Addr,
+    cosmwasm_std::{Addr, Uint128},
     schemars::{self, JsonSchema},
 };
 
@@ -17,6 +19,22 @@ pub struct InstantiateMsg {
     pub oracle: Addr,
     pub timealarms: Addr,
     pub dex: ConnectionParams,
+    #[serde(default)]
+    pub reserve_funding: Option<ReserveFunding>,
+}
+
+/// Funding of the reserve out of the profit
+///
+/// On each cadence, before the profit is bought back, `share` of the LPN profit
+/// is transferred to the `reserve` as long as its LPN balance is below `target`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct ReserveFunding {
+    pub reserve: Addr,
+    /// The part of the LPN profit routed to the reserve, in the range (0%, 100%]
+    pub share: Percent,
+    /// The reserve LPN balance the funding is capped at
+    pub target: Uint128,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -31,6 +49,10 @@ pub enum ExecuteMsg {
     Config {
         cadence_hours: CadenceHours,
     },
+    /// Set, or clear if `None`, the funding of the reserve out of the profit
+    ReserveFunding {
+        reserve_funding: Option<ReserveFunding>,
+    },
 
     /// An entry point for safe delivery of a Dex response
     ///
@@ -48,6 +70,11 @@ pub enum ExecuteMsg {
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
+    /// Return the profit accumulated since the last distribution
+    ///
+    /// The result is the contract's current balance of each currency
+    /// of the payment group.
+    Pending {},
     /// Implementation of [versioning::query::ProtocolPackage::Release]
     ProtocolPackageRelease {},
 }
@@ -58,6 +85,14 @@ pub enum QueryMsg {
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct ConfigResponse {
     pub cadence_hours: CadenceHours,
+    pub reserve_funding: Option<ReserveFunding>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Debug))]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PendingResponse {
+    pub balances: Vec<CoinDTO<PaymentGroup>>,
 }
 
 #[cfg(test)]
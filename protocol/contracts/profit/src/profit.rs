@@ -1,14 +1,23 @@
-use currencies::Nls;
+use currencies::{Lpn, Nls, PaymentGroup};
+use currency::{CurrencyDef, Group, MemberOf};
 use dex::Contract;
-use finance::{coin::Coin, duration::Duration};
+use finance::{
+    coin::{Coin, CoinDTO, WithCoin, WithCoinResult},
+    duration::Duration,
+};
 use platform::{
-    bank::BankAccount,
+    bank::{self, BankAccount, BankAccountView as _},
     batch::{Emit as _, Emitter},
     message::Response as PlatformResponse,
 };
 use sdk::cosmwasm_std::{Addr, Env, QuerierWrapper, Storage, Timestamp};
 
-use crate::{msg::ConfigResponse, result::ContractResult, state::State};
+use crate::{
+    error::ContractError,
+    msg::{ConfigResponse, PendingResponse},
+    result::ContractResult,
+    state::State,
+};
 
 pub struct Profit;
 
@@ -20,26 +29,53 @@ impl Profit {
         to_treasury: Addr,
         mut amount: Coin<Nls>,
         env: &Env,
-    ) -> PlatformResponse
+    ) -> ContractResult<PlatformResponse>
     where
         B: BankAccount,
     {
         amount = amount.saturating_sub(Self::IBC_FEE_RESERVE);
 
         if amount.is_zero() {
-            PlatformResponse::messages_only(from_my_account.into())
+            Ok(PlatformResponse::messages_only(from_my_account.into()))
         } else {
-            from_my_account.send(amount, to_treasury);
-
-            PlatformResponse::messages_with_events(
-                from_my_account.into(),
-                Emitter::of_type("tr-profit")
-                    .emit_tx_info(env)
-                    .emit_coin("profit-amount", amount),
-            )
+            from_my_account
+                .send(amount, to_treasury)
+                .map(|()| {
+                    PlatformResponse::messages_with_events(
+                        from_my_account.into(),
+                        Emitter::of_type("tr-profit")
+                            .emit_tx_info(env)
+                            .emit_coin("profit-amount", amount),
+                    )
+                })
+                .map_err(Into::into)
         }
     }
 
+    pub(crate) fn fund_reserve<B>(
+        mut from_my_account: B,
+        to_reserve: Addr,
+        amount: Coin<Lpn>,
+        env: &Env,
+    ) -> ContractResult<PlatformResponse>
+    where
+        B: BankAccount,
+    {
+        debug_assert!(!amount.is_zero());
+
+        from_my_account
+            .send(amount, to_reserve)
+            .map(|()| {
+                PlatformResponse::messages_with_events(
+                    from_my_account.into(),
+                    Emitter::of_type("tr-profit-reserve")
+                        .emit_tx_info(env)
+                        .emit_coin("reserve-amount", amount),
+                )
+            })
+            .map_err(Into::into)
+    }
+
     pub fn query_config(
         storage: &dyn Storage,
         now: Timestamp,
@@ -47,4 +83,35 @@ impl Profit {
     ) -> ContractResult<ConfigResponse> {
         State::load(storage).map(|state: State| state.state(now, Duration::default(), querier))
     }
+
+    pub fn query_pending(
+        profit: &Addr,
+        querier: QuerierWrapper<'_>,
+    ) -> ContractResult<PendingResponse> {
+        bank::account(profit, querier)
+            .balances::<PaymentGroup, _>(CoinsToDTO)
+            .map_err(ContractError::from)
+            .and_then(|balances| balances.transpose())
+            .map(Option::unwrap_or_default)
+            .map(|balances| PendingResponse { balances })
+    }
+}
+
+#[derive(Clone)]
+struct CoinsToDTO;
+
+impl<G> WithCoin<G> for CoinsToDTO
+where
+    G: Group,
+{
+    type Output = Vec<CoinDTO<G>>;
+    type Error = ContractError;
+
+    fn on<C>(self, coin: Coin<C>) -> WithCoinResult<G, Self>
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<G>,
+    {
+        Ok(vec![coin.into()])
+    }
 }
@@ -121,7 +121,7 @@ impl SwapTask for BuyBack {
         let balance_nls: Coin<Nls> = account.balance::<_, Native>()?;
 
         let bank_response: PlatformResponse =
-            Profit::transfer_nls(account, self.config.treasury().clone(), balance_nls, env);
+            Profit::transfer_nls(account, self.config.treasury().clone(), balance_nls, env)?;
 
         let next_state: Idle = Idle::new(self.config, self.account);
 
@@ -146,6 +146,7 @@ impl<DexState> ContractInSwap<DexState> for BuyBack {
     ) -> <Self as SwapTask>::StateResponse {
         ConfigResponse {
             cadence_hours: self.config.cadence_hours(),
+            reserve_funding: self.config.reserve_funding().cloned(),
         }
     }
 }
@@ -184,7 +185,7 @@ mod tests {
     use super::BuyBack;
 
     fn buy_back_instance(coins: Vec<CoinDTO<PaymentGroup>>) -> BuyBack {
-        use dex::{Account, ConnectionParams, Ics20Channel};
+        use dex::{Account, ConnectionParams, IcaTimeouts, Ics20Channel};
         use oracle_platform::OracleRef;
         use platform::ica::HostAccount;
         use sdk::cosmwasm_std::Addr;
@@ -199,6 +200,7 @@ mod tests {
                 Addr::unchecked("DEADCODE"),
                 OracleRef::<Lpn, Lpns>::unchecked(Addr::unchecked("DEADCODE")),
                 TimeAlarmsRef::unchecked("DEADCODE"),
+                None,
             ),
             Account::unchecked(
                 Addr::unchecked("DEADCODE"),
@@ -209,6 +211,7 @@ mod tests {
                         local_endpoint: String::from("DEADCODE"),
                         remote_endpoint: String::from("DEADCODE"),
                     },
+                    timeouts: IcaTimeouts::default(),
                 },
             ),
             coins,
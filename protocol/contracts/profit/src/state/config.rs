@@ -6,7 +6,7 @@ use oracle::stub::SwapPath;
 use sdk::cosmwasm_std::Addr;
 use timealarms::stub::TimeAlarmsRef;
 
-use crate::typedefs::CadenceHours;
+use crate::{msg::ReserveFunding, typedefs::CadenceHours};
 
 type OracleRef = oracle_platform::OracleRef<QuoteC, QuoteG>;
 
@@ -16,6 +16,8 @@ pub(crate) struct Config {
     treasury: Addr,
     oracle: OracleRef,
     time_alarms: TimeAlarmsRef,
+    #[serde(default)]
+    reserve_funding: Option<ReserveFunding>,
 }
 
 impl Config {
@@ -24,12 +26,14 @@ impl Config {
         treasury: Addr,
         oracle: OracleRef,
         time_alarms: TimeAlarmsRef,
+        reserve_funding: Option<ReserveFunding>,
     ) -> Self {
         Self {
             cadence_hours,
             treasury,
             oracle,
             time_alarms,
+            reserve_funding,
         }
     }
 
@@ -40,6 +44,13 @@ impl Config {
         }
     }
 
+    pub fn update_reserve_funding(self, reserve_funding: Option<ReserveFunding>) -> Self {
+        Self {
+            reserve_funding,
+            ..self
+        }
+    }
+
     pub fn cadence_hours(&self) -> CadenceHours {
         self.cadence_hours
     }
@@ -58,4 +69,8 @@ impl Config {
     pub fn time_alarms(&self) -> &TimeAlarmsRef {
         &self.time_alarms
     }
+
+    pub fn reserve_funding(&self) -> Option<&ReserveFunding> {
+        self.reserve_funding.as_ref()
+    }
 }
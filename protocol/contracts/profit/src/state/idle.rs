@@ -5,7 +5,7 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
-use currencies::{Nls, PaymentGroup};
+use currencies::{Lpn, Lpns, Nls, PaymentGroup};
 use currency::{Currency, CurrencyDef, Group, MemberOf};
 use dex::{
     Account, Contract, Enterable, Error as DexError, Handler, Response as DexResponse,
@@ -14,6 +14,7 @@ use dex::{
 use finance::{
     coin::{Coin, CoinDTO, WithCoin, WithCoinResult},
     duration::Duration,
+    fraction::Fraction,
 };
 use platform::{
     bank::{self, Aggregate, BankAccount, BankAccountView, BankStub, BankView},
@@ -25,7 +26,10 @@ use sdk::cosmwasm_std::{Addr, Env, QuerierWrapper, Timestamp};
 use timealarms::stub::Result as TimeAlarmsResult;
 
 use crate::{
-    error::ContractError, msg::ConfigResponse, profit::Profit, result::ContractResult,
+    error::ContractError,
+    msg::{ConfigResponse, ReserveFunding},
+    profit::Profit,
+    result::ContractResult,
     typedefs::CadenceHours,
 };
 
@@ -57,11 +61,11 @@ impl Idle {
     {
         self.enter(env.block.time, querier)
             .map(PlatformResponse::messages_only)
-            .map(|state_response: PlatformResponse| {
+            .map_err(Into::into)
+            .and_then(|state_response: PlatformResponse| {
                 Profit::transfer_nls(account, self.config.treasury().clone(), nls, env)
-                    .merge_with(state_response)
+                    .map(|response| response.merge_with(state_response))
             })
-            .map_err(Into::into)
     }
 
     fn on_time_alarm(
@@ -71,19 +75,71 @@ impl Idle {
     ) -> ContractResult<DexResponse<Self>> {
         let account: BankStub<BankView<'_>> = bank::account(&env.contract.address, querier);
 
-        let balances: SplitCoins<Nls, PaymentGroup> = account
+        let mut balances: SplitCoins<Nls, PaymentGroup> = account
             .balances::<PaymentGroup, _>(CoinToDTO(PhantomData, PhantomData))?
             .transpose()?
             .unwrap_or_default();
 
+        let reserve_funding: PlatformResponse =
+            self.try_fund_reserve(&env, querier, &mut balances.rest)?;
+
         if balances.rest.is_empty() {
             self.send_nls(&env, querier, account, balances.filtered)
                 .map(|response: PlatformResponse| DexResponse::<Self> {
-                    response,
+                    response: reserve_funding.merge_with(response),
                     next_state: State(StateEnum::Idle(self)),
                 })
         } else {
             self.try_enter_buy_back(querier, env.contract.address, env.block.time, balances.rest)
+                .map(|buy_back: DexResponse<Self>| DexResponse::<Self> {
+                    response: reserve_funding.merge_with(buy_back.response),
+                    next_state: buy_back.next_state,
+                })
+        }
+    }
+
+    /// Transfer the configured share of the LPN profit to the reserve
+    ///
+    /// The amount is capped so the reserve balance does not exceed the target.
+    /// It is deducted from `profit`, leaving only the rest to be bought back.
+    fn try_fund_reserve(
+        &self,
+        env: &Env,
+        querier: QuerierWrapper<'_>,
+        profit: &mut Vec<CoinDTO<PaymentGroup>>,
+    ) -> ContractResult<PlatformResponse> {
+        let Some(funding) = self.config.reserve_funding() else {
+            return Ok(PlatformResponse::default());
+        };
+
+        let lpn_profit: Coin<Lpn> = bank::balance::<_, Lpns>(&env.contract.address, querier)?;
+
+        let reserve_balance: Coin<Lpn> = bank::balance::<_, Lpns>(&funding.reserve, querier)?;
+
+        let amount: Coin<Lpn> = funding
+            .share
+            .of(lpn_profit)
+            .min(Coin::<Lpn>::new(funding.target.u128()).saturating_sub(reserve_balance));
+
+        if amount.is_zero() {
+            Ok(PlatformResponse::default())
+        } else {
+            let lpn = currency::dto::<Lpn, PaymentGroup>();
+
+            profit.retain(|coin: &CoinDTO<PaymentGroup>| coin.currency() != lpn);
+
+            let lpn_rest: Coin<Lpn> = lpn_profit - amount;
+
+            if !lpn_rest.is_zero() {
+                profit.push(lpn_rest.into());
+            }
+
+            Profit::fund_reserve(
+                bank::account(&env.contract.address, querier),
+                funding.reserve.clone(),
+                amount,
+                env,
+            )
         }
     }
 
@@ -135,6 +191,7 @@ impl Contract for Idle {
     ) -> Self::StateResponse {
         ConfigResponse {
             cadence_hours: self.config.cadence_hours(),
+            reserve_funding: self.config.reserve_funding().cloned(),
         }
     }
 }
@@ -155,6 +212,16 @@ impl ConfigManagement for Idle {
             })
             .map_err(Into::into)
     }
+
+    fn try_update_reserve_funding(
+        self,
+        reserve_funding: Option<ReserveFunding>,
+    ) -> ContractResult<Self> {
+        Ok(Self {
+            config: self.config.update_reserve_funding(reserve_funding),
+            ..self
+        })
+    }
 }
 
 impl Handler for Idle {
@@ -19,7 +19,10 @@ use sdk::{
 use swap::Impl;
 
 use crate::{
-    error::ContractError, msg::ConfigResponse, result::ContractResult, typedefs::CadenceHours,
+    error::ContractError,
+    msg::{ConfigResponse, ReserveFunding},
+    result::ContractResult,
+    typedefs::CadenceHours,
 };
 
 pub(crate) use self::config::Config;
@@ -50,6 +53,12 @@ where
             "Configuration changes are not allowed in this state!",
         ))
     }
+
+    fn try_update_reserve_funding(self, _: Option<ReserveFunding>) -> ContractResult<Self> {
+        Err(ContractError::unsupported_operation(
+            "Configuration changes are not allowed in this state!",
+        ))
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -81,6 +90,23 @@ impl ConfigManagement for State {
                 .map(state_machine::from),
         }
     }
+
+    fn try_update_reserve_funding(
+        self,
+        reserve_funding: Option<ReserveFunding>,
+    ) -> ContractResult<Self> {
+        match self.0 {
+            StateEnum::OpenIca(ica) => ica
+                .try_update_reserve_funding(reserve_funding)
+                .map(Into::into),
+            StateEnum::Idle(idle) => idle
+                .try_update_reserve_funding(reserve_funding)
+                .map(Into::into),
+            StateEnum::BuyBack(buy_back) => buy_back
+                .try_update_reserve_funding(reserve_funding)
+                .map(Into::into),
+        }
+    }
 }
 
 impl State {
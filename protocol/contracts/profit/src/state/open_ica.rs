@@ -51,6 +51,7 @@ impl Contract for OpenIca {
     ) -> Self::StateResponse {
         ConfigResponse {
             cadence_hours: self.config.cadence_hours(),
+            reserve_funding: self.config.reserve_funding().cloned(),
         }
     }
 }
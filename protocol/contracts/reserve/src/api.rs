@@ -5,7 +5,7 @@ use currency::CurrencyDTO;
 use finance::coin::CoinDTO;
 use platform::contract::{Code, CodeId};
 use sdk::{
-    cosmwasm_std::Uint64,
+    cosmwasm_std::{Addr, Timestamp, Uint64},
     schemars::{self, JsonSchema},
 };
 
@@ -19,6 +19,20 @@ pub struct InstantiateMsg {
     pub lease_code_admin: String,
     // Since this is an external system API we should not use [Code].
     pub lease_code: Uint64,
+    #[serde(default)]
+    pub surplus_return: Option<SurplusReturn>,
+}
+
+/// Return of the reserve surplus to the treasury
+///
+/// The part of the reserve LPN balance above `target` is returnable to the `treasury`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct SurplusReturn {
+    /// Unchecked address of the treasury
+    pub treasury: String,
+    /// The reserve LPN balance above which the surplus is returned
+    pub target: LpnCoin,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -36,7 +50,20 @@ pub enum ExecuteMsg {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
-pub enum SudoMsg {}
+pub enum SudoMsg {
+    /// Send the reserve LPN balance above the configured target to the treasury
+    ///
+    /// No-op if the balance is at or below the target.
+    ReturnSurplus {},
+
+    /// Replace the configuration of the surplus return
+    ///
+    /// Passing no configuration disables the surplus return.
+    UpdateSurplusReturn {
+        #[serde(default)]
+        surplus_return: Option<SurplusReturn>,
+    },
+}
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Debug))]
@@ -48,6 +75,28 @@ pub enum QueryMsg {
     Config(),
     /// Implementation of [versioning::query::ProtocolPackage::Release]
     ProtocolPackageRelease {},
+    /// Return a [CoverHistoryResponse] of up to `limit` latest covers, the newest first
+    ///
+    /// Only the latest [MAX_COVER_HISTORY] covers are retained. If `limit` is not provided,
+    /// all of them are returned.
+    CoverHistory {
+        #[serde(default)]
+        limit: Option<u16>,
+    },
+}
+
+/// The maximum number of covers of liquidation losses retained in the history
+pub const MAX_COVER_HISTORY: u16 = 100;
+
+pub type CoverHistoryResponse = Vec<Cover>;
+
+/// A cover of liquidation losses
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct Cover {
+    pub at: Timestamp,
+    pub lease: Addr,
+    pub amount: LpnCoin,
 }
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
@@ -15,7 +15,7 @@ use platform::{
 use sdk::{
     cosmwasm_ext::Response as CwResponse,
     cosmwasm_std::{
-        self, entry_point, Addr, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
+        self, entry_point, Addr, Api, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
     },
 };
 use versioning::{
@@ -24,9 +24,12 @@ use versioning::{
 };
 
 use crate::{
-    api::{ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    api::{
+        ConfigResponse, Cover, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg,
+        SurplusReturn as SurplusReturnDTO, MAX_COVER_HISTORY,
+    },
     error::{Error, Result},
-    state::Config,
+    state::{Config, CoverHistory, SurplusReturn},
 };
 
 const CONTRACT_STORAGE_VERSION: VersionSegment = 0;
@@ -54,10 +57,12 @@ pub fn instantiate(
             .grant_to(&lease_code_admin)
             .map_err(Into::into)
         })
-        .and_then(|()| {
-            Code::try_new(new_reserve.lease_code.into(), &deps.querier).map_err(Into::into)
+        .and_then(|()| validate_surplus_return(new_reserve.surplus_return, deps.api))
+        .and_then(|surplus_return| {
+            Code::try_new(new_reserve.lease_code.into(), &deps.querier)
+                .map_err(Into::into)
+                .and_then(|lease_code| Config::new(lease_code, surplus_return).store(deps.storage))
         })
-        .and_then(|lease_code| Config::new(lease_code).store(deps.storage))
         .map(|()| response::empty_response())
         .inspect_err(platform_error::log(deps.api))
 }
@@ -103,8 +108,44 @@ pub fn execute(
                 })
                 .and_then(|()| amount.try_into().map_err(Into::into))
                 .and_then(|losses| {
-                    do_cover_losses(lease, losses, &env.contract.address, deps.querier)
+                    do_cover_losses(lease.clone(), losses, &env.contract.address, deps.querier)
+                        .and_then(|response| {
+                            CoverHistory::record(
+                                deps.storage,
+                                &Cover {
+                                    at: env.block.time,
+                                    lease,
+                                    amount: losses.into(),
+                                },
+                            )
+                            .map(|()| response)
+                        })
+                })
+        }
+    }
+    .map(response::response_only_messages)
+    .inspect_err(platform_error::log(deps.api))
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<CwResponse> {
+    match msg {
+        SudoMsg::ReturnSurplus {} => Config::load(deps.storage)
+            .and_then(|config| {
+                config
+                    .surplus_return()
+                    .cloned()
+                    .ok_or(Error::NoSurplusReturn)
+            })
+            .and_then(|surplus_return| {
+                do_return_surplus(surplus_return, &env.contract.address, deps.querier)
+            }),
+        SudoMsg::UpdateSurplusReturn { surplus_return } => {
+            validate_surplus_return(surplus_return, deps.api)
+                .and_then(|surplus_return| {
+                    Config::update_surplus_return(deps.storage, surplus_return)
                 })
+                .map(|()| PlatformResponse::default())
         }
     }
     .map(response::response_only_messages)
@@ -124,6 +165,10 @@ pub fn query(deps: Deps<'_>, _env: Env, msg: QueryMsg) -> Result<Binary> {
         QueryMsg::ProtocolPackageRelease {} => {
             cosmwasm_std::to_json_binary(&CURRENT_RELEASE).map_err(Into::into)
         }
+        QueryMsg::CoverHistory { limit } => {
+            CoverHistory::latest(deps.storage, limit.unwrap_or(MAX_COVER_HISTORY))
+                .and_then(|covers| cosmwasm_std::to_json_binary(&covers).map_err(Into::into))
+        }
     }
     .inspect_err(platform_error::log(deps.api))
 }
@@ -141,7 +186,7 @@ fn do_cover_losses(
             if balance < amount {
                 Err(Error::InsufficientBalance)
             } else {
-                bank.send(amount, lease.clone());
+                bank.send(amount, lease.clone())?;
                 let emitter = Emitter::of_type("reserve-cover-loss")
                     .emit("to", lease)
                     .emit_coin("payment", amount);
@@ -150,3 +195,35 @@ fn do_cover_losses(
             }
         })
 }
+
+fn validate_surplus_return(
+    surplus_return: Option<SurplusReturnDTO>,
+    api: &dyn Api,
+) -> Result<Option<SurplusReturn>> {
+    surplus_return
+        .map(|surplus_return| SurplusReturn::try_new(surplus_return, api))
+        .transpose()
+}
+
+fn do_return_surplus(
+    surplus_return: SurplusReturn,
+    this_contract: &Addr,
+    querier: QuerierWrapper<'_>,
+) -> Result<PlatformResponse> {
+    let mut bank = bank::account(this_contract, querier);
+    bank.balance::<LpnCurrency, Lpns>()
+        .map_err(Into::into)
+        .and_then(|balance| {
+            let surplus = balance.saturating_sub(surplus_return.target());
+            if surplus.is_zero() {
+                Ok(PlatformResponse::default())
+            } else {
+                bank.send(surplus, surplus_return.treasury().clone())?;
+                let emitter = Emitter::of_type("reserve-return-surplus")
+                    .emit("to", surplus_return.treasury())
+                    .emit_coin("payment", surplus);
+
+                Ok(PlatformResponse::messages_with_events(bank.into(), emitter))
+            }
+        })
+}
@@ -21,6 +21,9 @@ pub enum Error {
 
     #[error("[Reserve] Insufficient balance")]
     InsufficientBalance,
+
+    #[error("[Reserve] No surplus return is configured")]
+    NoSurplusReturn,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
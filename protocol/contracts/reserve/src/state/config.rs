@@ -11,22 +11,33 @@ use sdk::{
 
 use crate::error::Result;
 
+use super::SurplusReturn;
+
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
 pub struct Config {
     lease_code: Code,
+    #[serde(default)]
+    surplus_return: Option<SurplusReturn>,
 }
 
 impl Config {
     const STORAGE: Item<Self> = Item::new("config");
 
-    pub const fn new(lease_code: Code) -> Self {
-        Self { lease_code }
+    pub const fn new(lease_code: Code, surplus_return: Option<SurplusReturn>) -> Self {
+        Self {
+            lease_code,
+            surplus_return,
+        }
     }
 
     pub const fn lease_code(&self) -> Code {
         self.lease_code
     }
 
+    pub const fn surplus_return(&self) -> Option<&SurplusReturn> {
+        self.surplus_return.as_ref()
+    }
+
     pub fn store(&self, storage: &mut dyn Storage) -> Result<()> {
         Self::STORAGE.save(storage, self).map_err(Into::into)
     }
@@ -37,23 +48,45 @@ impl Config {
 
     pub fn update_lease_code(storage: &mut dyn Storage, lease_code: Code) -> Result<()> {
         Self::STORAGE
-            .update(storage, |_config: Self| Ok(Self::new(lease_code)))
+            .update(storage, |config: Self| {
+                Ok(Self {
+                    lease_code,
+                    ..config
+                })
+            })
+            .map(mem::drop)
+    }
+
+    pub fn update_surplus_return(
+        storage: &mut dyn Storage,
+        surplus_return: Option<SurplusReturn>,
+    ) -> Result<()> {
+        Self::STORAGE
+            .update(storage, |config: Self| {
+                Ok(Self {
+                    surplus_return,
+                    ..config
+                })
+            })
             .map(mem::drop)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use cosmwasm_std::{testing::MockStorage, Storage};
+    use cosmwasm_std::{testing::MockStorage, Addr, Storage};
+    use finance::coin::Coin;
     use platform::contract::{Code, CodeId};
 
+    use crate::state::SurplusReturn;
+
     use super::Config;
 
     #[test]
     fn store_load() {
         let lease_code = Code::unchecked(12);
         let mut store = MockStorage::new();
-        assert_eq!(Ok(()), Config::new(lease_code).store(&mut store));
+        assert_eq!(Ok(()), Config::new(lease_code, None).store(&mut store));
         assert_lease_code_id(lease_code, &store);
     }
 
@@ -62,12 +95,41 @@ mod test {
         let lease_code_id = Code::unchecked(28);
         let new_lease_code_id = Code::unchecked(CodeId::from(lease_code_id) + 10);
         let mut store = MockStorage::new();
-        assert_eq!(Ok(()), Config::new(lease_code_id).store(&mut store));
+        let surplus_return = SurplusReturn::new(Addr::unchecked("treasury"), Coin::new(1_000));
+        assert_eq!(
+            Ok(()),
+            Config::new(lease_code_id, Some(surplus_return.clone())).store(&mut store)
+        );
         assert_eq!(
             Ok(()),
             Config::update_lease_code(&mut store, new_lease_code_id)
         );
         assert_lease_code_id(new_lease_code_id, &store);
+        assert_eq!(
+            Some(&surplus_return),
+            Config::load(&store).unwrap().surplus_return()
+        );
+    }
+
+    #[test]
+    fn update_surplus_return() {
+        let lease_code = Code::unchecked(28);
+        let mut store = MockStorage::new();
+        assert_eq!(Ok(()), Config::new(lease_code, None).store(&mut store));
+
+        let surplus_return = SurplusReturn::new(Addr::unchecked("treasury"), Coin::new(1_000));
+        assert_eq!(
+            Ok(()),
+            Config::update_surplus_return(&mut store, Some(surplus_return.clone()))
+        );
+        assert_lease_code_id(lease_code, &store);
+        assert_eq!(
+            Some(&surplus_return),
+            Config::load(&store).unwrap().surplus_return()
+        );
+
+        assert_eq!(Ok(()), Config::update_surplus_return(&mut store, None));
+        assert_eq!(None, Config::load(&store).unwrap().surplus_return());
     }
 
     fn assert_lease_code_id(lease_code: Code, store: &dyn Storage) {
@@ -0,0 +1,94 @@
+use sdk::{cosmwasm_std::Storage, cw_storage_plus::Deque};
+
+use crate::{
+    api::{Cover, MAX_COVER_HISTORY},
+    error::Result,
+};
+
+/// A ring buffer of the latest covers of liquidation losses
+///
+/// The covers are ordered by time, the oldest first.
+pub struct CoverHistory {}
+
+impl CoverHistory {
+    const STORAGE: Deque<Cover> = Deque::new("cover_history");
+
+    /// Record a newer cover evicting the oldest ones beyond [`MAX_COVER_HISTORY`]
+    pub fn record(storage: &mut dyn Storage, cover: &Cover) -> Result<()> {
+        Self::STORAGE
+            .push_back(storage, cover)
+            .and_then(|()| Self::STORAGE.len(storage))
+            .and_then(|len| {
+                (u32::from(MAX_COVER_HISTORY)..len)
+                    .try_for_each(|_| Self::STORAGE.pop_front(storage).map(|_| ()))
+            })
+            .map_err(Into::into)
+    }
+
+    /// Load up to `limit` latest covers, the newest first
+    pub fn latest(storage: &dyn Storage, limit: u16) -> Result<Vec<Cover>> {
+        Self::STORAGE
+            .iter(storage)
+            .and_then(|covers| covers.rev().take(limit.into()).collect())
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currencies::Lpn;
+    use finance::coin::Coin;
+    use sdk::cosmwasm_std::{testing::MockStorage, Addr, Timestamp};
+
+    use crate::api::{Cover, MAX_COVER_HISTORY};
+
+    use super::CoverHistory;
+
+    #[test]
+    fn empty() {
+        let storage = MockStorage::new();
+        assert_eq!(
+            Ok(vec![]),
+            CoverHistory::latest(&storage, MAX_COVER_HISTORY)
+        );
+    }
+
+    #[test]
+    fn newest_first() {
+        let mut storage = MockStorage::new();
+        let covers: Vec<Cover> = (1..=3).map(cover).collect();
+        covers
+            .iter()
+            .for_each(|cover| CoverHistory::record(&mut storage, cover).unwrap());
+
+        assert_eq!(
+            Ok(covers.iter().rev().cloned().collect()),
+            CoverHistory::latest(&storage, MAX_COVER_HISTORY)
+        );
+        assert_eq!(
+            Ok(vec![cover(3), cover(2)]),
+            CoverHistory::latest(&storage, 2)
+        );
+        assert_eq!(Ok(vec![]), CoverHistory::latest(&storage, 0));
+    }
+
+    #[test]
+    fn retention() {
+        let mut storage = MockStorage::new();
+        let total = u64::from(MAX_COVER_HISTORY) + 2;
+        (1..=total).for_each(|seq| CoverHistory::record(&mut storage, &cover(seq)).unwrap());
+
+        let latest = CoverHistory::latest(&storage, MAX_COVER_HISTORY).unwrap();
+        assert_eq!(usize::from(MAX_COVER_HISTORY), latest.len());
+        assert_eq!(Some(&cover(total)), latest.first());
+        assert_eq!(Some(&cover(3)), latest.last());
+    }
+
+    fn cover(seq: u64) -> Cover {
+        Cover {
+            at: Timestamp::from_seconds(seq),
+            lease: Addr::unchecked(format!("lease{seq}")),
+            amount: Coin::<Lpn>::new(seq.into()).into(),
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use crate::api::ConfigResponse;
 
-pub use self::config::Config;
+pub use self::{config::Config, cover_history::CoverHistory, surplus_return::SurplusReturn};
 
 mod config;
+mod cover_history;
+mod surplus_return;
 
 impl From<Config> for ConfigResponse {
     fn from(cfg: Config) -> Self {
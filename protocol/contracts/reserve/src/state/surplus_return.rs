@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use currencies::Lpn as LpnCurrency;
+use finance::coin::Coin;
+use sdk::{
+    cosmwasm_std::{Addr, Api},
+    schemars::{self, JsonSchema},
+};
+
+use crate::{api::SurplusReturn as SurplusReturnDTO, error::Result};
+
+/// A validated configuration of the reserve surplus return
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, JsonSchema)]
+#[cfg_attr(test, derive(Debug))]
+pub struct SurplusReturn {
+    treasury: Addr,
+    target: Coin<LpnCurrency>,
+}
+
+impl SurplusReturn {
+    pub const fn new(treasury: Addr, target: Coin<LpnCurrency>) -> Self {
+        Self { treasury, target }
+    }
+
+    pub fn try_new(dto: SurplusReturnDTO, api: &dyn Api) -> Result<Self> {
+        api.addr_validate(dto.treasury.as_str())
+            .map_err(Into::into)
+            .and_then(|treasury| {
+                dto.target
+                    .try_into()
+                    .map_err(Into::into)
+                    .map(|target| Self::new(treasury, target))
+            })
+    }
+
+    pub const fn treasury(&self) -> &Addr {
+        &self.treasury
+    }
+
+    pub const fn target(&self) -> Coin<LpnCurrency> {
+        self.target
+    }
+}
@@ -7,10 +7,16 @@ use crate::currencies_tree::{self, CurrenciesTree};
 
 use super::{super::super::generator, GeneratedSourceEntry};
 
-pub(super) type GenerateEntryResult<'dex_currency, MaybeVisit, CurrencyDefinition> = Result<
-    GeneratedSourceEntry<Either<MaybeVisit, iter::Empty<&'dex_currency str>>, CurrencyDefinition>,
+pub(super) type GenerateEntryResult<'dex_currency, 'r, MaybeVisit, CurrencyDefinition> = Result<
+    GeneratedSourceEntry<
+        Either<MaybeVisit, iter::Empty<&'dex_currency str>>,
+        DexSymbol<'r>,
+        CurrencyDefinition,
+    >,
 >;
 
+pub(super) type DexSymbol<'r> = [&'r str; 5];
+
 pub(super) struct CurrencyDefinition<
     'currencies_tree,
     'parents_of,
@@ -107,6 +113,7 @@ where
         ticker: &'r str,
     ) -> GenerateEntryResult<
         'dex_currencies,
+        'r,
         impl IntoIterator<Item = &'dex_currencies str> + use<'dex_currencies, Generator>,
         impl Iterator<Item = Cow<'r, str>>
             + use<
@@ -143,6 +150,7 @@ where
         parents: &'parents currencies_tree::Parents<'parent>,
     ) -> GenerateEntryResult<
         'dex_currencies,
+        'r,
         impl IntoIterator<Item = &'dex_currencies str> + use<'dex_currencies, Generator>,
         impl Iterator<Item = Cow<'r, str>>
             + use<
@@ -186,6 +194,7 @@ where
                                 } else {
                                     Either::Right(iter::empty())
                                 },
+                                dex_symbol: dex_symbol(ticker, resolved.definition()),
                                 currency_definition: currency_definition(
                                     resolved.name(),
                                     ticker,
@@ -198,6 +207,18 @@ where
     }
 }
 
+#[inline]
+fn dex_symbol<'r>(ticker: &'r str, currency: &'r topology::CurrencyDefinition) -> DexSymbol<'r> {
+    [
+        r#"
+    (""#,
+        currency.dex().symbol(),
+        r#"", ""#,
+        ticker,
+        r#""),"#,
+    ]
+}
+
 fn currency_definition<'r>(
     name: &'r str,
     ticker: &'r str,
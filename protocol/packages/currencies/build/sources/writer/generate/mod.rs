@@ -7,7 +7,7 @@ use crate::subtype_lifetime::SubtypeLifetime;
 
 use super::{super::generator, FinalizedSources, Writer};
 
-use self::currency_definition::CurrencyDefinition;
+use self::currency_definition::{CurrencyDefinition, DexSymbol};
 
 mod currency_definition;
 
@@ -63,11 +63,13 @@ where
             .map(|non_finalized_sources| {
                 non_finalized_sources
                     .map_maybe_visit(Either::Left)
+                    .map_dex_symbols(Either::Left)
                     .map_currency_definitions(Either::Left)
             })
         } else {
             Ok(generate_blank_sources()
                 .map_maybe_visit(Either::Right)
+                .map_dex_symbols(Either::Right)
                 .map_currency_definitions(Either::Right))
         }
         .map(NonFinalizedSources::finalize)
@@ -94,6 +96,7 @@ fn with_currencies_sources<
     NonFinalizedSources<
         impl Iterator<Item = &'dex_currencies str>
             + use<'dex_currencies, 'generator, Generator, Tickers>,
+        impl Iterator<Item = &'r str> + use<'r, Generator, Tickers>,
         impl Iterator<Item = Cow<'r, str>>
             + use<
                 'r,
@@ -141,20 +144,23 @@ where
                     )
                 })
             })
+            .map_dex_symbols(|dex_symbols| dex_symbols.into_iter().flatten())
             .map_currency_definitions(|currency_definitions| {
                 currency_definitions.into_iter().flatten()
             })
     })
 }
 
-struct GeneratedSourceEntry<MaybeVisit, CurrencyDefinition> {
+struct GeneratedSourceEntry<MaybeVisit, DexSymbol, CurrencyDefinition> {
     maybe_visit: MaybeVisit,
+    dex_symbol: DexSymbol,
     currency_definition: CurrencyDefinition,
 }
 
-type PerCurrencySourcesResult<'dex_currencies, MaybeVisit, CurrencyDefinition> = Result<
+type PerCurrencySourcesResult<'dex_currencies, 'r, MaybeVisit, CurrencyDefinition> = Result<
     NonFinalizedSources<
         Either<Vec<MaybeVisit>, iter::Empty<&'dex_currencies str>>,
+        Vec<DexSymbol<'r>>,
         Vec<CurrencyDefinition>,
     >,
 >;
@@ -177,6 +183,7 @@ fn per_currency_sources<
     tail_tickers: Tickers,
 ) -> PerCurrencySourcesResult<
     'dex_currencies,
+    'r,
     impl Iterator<Item = &'dex_currencies str> + use<'dex_currencies, 'generator, Generator, Tickers>,
     impl Iterator<Item = Cow<'r, str>>
         + use<
@@ -208,6 +215,7 @@ where
             entry
                 .maybe_visit
                 .map_left(|maybe_visit| Either::Left(maybe_visit.into_iter())),
+            entry.dex_symbol,
             entry.currency_definition,
         )
     }))
@@ -227,6 +235,7 @@ where
                         .chain(iter::once(")"))
                     })
                 }),
+                entry.dex_symbol,
                 entry.currency_definition,
             )
         })
@@ -240,6 +249,7 @@ where
                 Either::Right(iter::empty())
             },
             vec![],
+            vec![],
         ),
         NonFinalizedSources::try_fold,
     )
@@ -320,8 +330,10 @@ where
 }
 
 #[inline]
-fn generate_blank_sources<'maybe_visit, 'currency_definitions>() -> NonFinalizedSources<
+fn generate_blank_sources<'maybe_visit, 'dex_symbols, 'currency_definitions>(
+) -> NonFinalizedSources<
     impl Iterator<Item = &'maybe_visit str> + use<'maybe_visit>,
+    impl Iterator<Item = &'dex_symbols str> + use<'dex_symbols>,
     impl Iterator<Item = Cow<'currency_definitions, str>> + use<'currency_definitions>,
 > {
     const VISITOR_PARAMETER: &str = "visitor";
@@ -335,69 +347,106 @@ fn generate_blank_sources<'maybe_visit, 'currency_definitions>() -> NonFinalized
             ["currency::visit_noone(", VISITOR_PARAMETER, ")"],
         ),
         const { iter::empty() },
+        const { iter::empty() },
     )
 }
 
-struct NonFinalizedSources<MaybeVisit, CurrencyDefinitions> {
+struct NonFinalizedSources<MaybeVisit, DexSymbols, CurrencyDefinitions> {
     currencies_count: usize,
     maybe_visit: MaybeVisit,
+    dex_symbols: DexSymbols,
     currency_definitions: CurrencyDefinitions,
 }
 
-impl<MaybeVisit, CurrencyDefinitions> NonFinalizedSources<MaybeVisit, CurrencyDefinitions> {
+impl<MaybeVisit, DexSymbols, CurrencyDefinitions>
+    NonFinalizedSources<MaybeVisit, DexSymbols, CurrencyDefinitions>
+{
     #[inline]
     const fn new(
         currencies_count: usize,
         maybe_visit: MaybeVisit,
+        dex_symbols: DexSymbols,
         currency_definitions: CurrencyDefinitions,
     ) -> Self {
         Self {
             currencies_count,
             maybe_visit,
+            dex_symbols,
             currency_definitions,
         }
     }
 
     #[inline]
-    fn map_maybe_visit<F, R>(self, f: F) -> NonFinalizedSources<R, CurrencyDefinitions>
+    fn map_maybe_visit<F, R>(self, f: F) -> NonFinalizedSources<R, DexSymbols, CurrencyDefinitions>
     where
         F: FnOnce(MaybeVisit) -> R,
     {
         let Self {
             currencies_count,
             maybe_visit,
+            dex_symbols,
             currency_definitions,
         } = self;
 
         NonFinalizedSources {
             currencies_count,
             maybe_visit: f(maybe_visit),
+            dex_symbols,
             currency_definitions,
         }
     }
 
     #[inline]
-    fn map_currency_definitions<F, R>(self, f: F) -> NonFinalizedSources<MaybeVisit, R>
+    fn map_dex_symbols<F, R>(self, f: F) -> NonFinalizedSources<MaybeVisit, R, CurrencyDefinitions>
+    where
+        F: FnOnce(DexSymbols) -> R,
+    {
+        let Self {
+            currencies_count,
+            maybe_visit,
+            dex_symbols,
+            currency_definitions,
+        } = self;
+
+        NonFinalizedSources {
+            currencies_count,
+            maybe_visit,
+            dex_symbols: f(dex_symbols),
+            currency_definitions,
+        }
+    }
+
+    #[inline]
+    fn map_currency_definitions<F, R>(self, f: F) -> NonFinalizedSources<MaybeVisit, DexSymbols, R>
     where
         F: FnOnce(CurrencyDefinitions) -> R,
     {
         let Self {
             currencies_count,
             maybe_visit,
+            dex_symbols,
             currency_definitions,
         } = self;
 
         NonFinalizedSources {
             currencies_count,
             maybe_visit,
+            dex_symbols,
             currency_definitions: f(currency_definitions),
         }
     }
 }
 
-impl<'maybe_visit, MaybeVisit, CurrencyDefinition>
+type SourceEntryResult<'maybe_visit, MaybeVisit, DexSymbol, CurrencyDefinition> = Result<(
+    Either<MaybeVisit, iter::Empty<&'maybe_visit str>>,
+    DexSymbol,
+    CurrencyDefinition,
+)>;
+
+impl<'maybe_visit, MaybeVisit, DexSymbol, CurrencyDefinition>
     NonFinalizedSources<
         Either<Vec<MaybeVisit>, iter::Empty<&'maybe_visit str>>,
+        Vec<DexSymbol>,
         Vec<CurrencyDefinition>,
     >
 {
@@ -405,15 +454,13 @@ impl<'maybe_visit, MaybeVisit, CurrencyDefinition>
         Self {
             currencies_count,
             maybe_visit,
+            mut dex_symbols,
             mut currency_definitions,
         }: Self,
-        element: Result<(
-            Either<MaybeVisit, iter::Empty<&'maybe_visit str>>,
-            CurrencyDefinition,
-        )>,
+        element: SourceEntryResult<'maybe_visit, MaybeVisit, DexSymbol, CurrencyDefinition>,
     ) -> Result<Self> {
         element.map(
-            |(maybe_visit_entry, currency_definition)| NonFinalizedSources {
+            |(maybe_visit_entry, dex_symbol, currency_definition)| NonFinalizedSources {
                 currencies_count: currencies_count + 1,
                 maybe_visit: match (maybe_visit, maybe_visit_entry) {
                     (Either::Left(mut maybe_visit), Either::Left(entry)) => {
@@ -428,6 +475,11 @@ impl<'maybe_visit, MaybeVisit, CurrencyDefinition>
                         Either::Right(iter::Empty { .. }),
                     ) => iter,
                 },
+                dex_symbols: {
+                    dex_symbols.push(dex_symbol);
+
+                    dex_symbols
+                },
                 currency_definitions: {
                     currency_definitions.push(currency_definition);
 
@@ -438,12 +490,21 @@ impl<'maybe_visit, MaybeVisit, CurrencyDefinition>
     }
 }
 
-impl<'r, 'maybe_visit, 'currency_definition, MaybeVisit, CurrencyDefinitions>
-    NonFinalizedSources<MaybeVisit, CurrencyDefinitions>
+impl<
+        'r,
+        'maybe_visit,
+        'dex_symbols,
+        'currency_definition,
+        MaybeVisit,
+        DexSymbols,
+        CurrencyDefinitions,
+    > NonFinalizedSources<MaybeVisit, DexSymbols, CurrencyDefinitions>
 where
     'maybe_visit: 'r,
+    'dex_symbols: 'r,
     'currency_definition: 'r,
     MaybeVisit: Iterator<Item = &'maybe_visit str>,
+    DexSymbols: Iterator<Item = &'dex_symbols str>,
     CurrencyDefinitions: Iterator<Item = Cow<'currency_definition, str>>,
 {
     #[inline]
@@ -451,7 +512,15 @@ where
         self,
     ) -> FinalizedSources<
         impl Iterator<Item = Cow<'r, str>>
-            + use<'r, 'maybe_visit, 'currency_definition, MaybeVisit, CurrencyDefinitions>,
+            + use<
+                'r,
+                'maybe_visit,
+                'dex_symbols,
+                'currency_definition,
+                MaybeVisit,
+                DexSymbols,
+                CurrencyDefinitions,
+            >,
     > {
         FinalizedSources {
             currencies_count: self.currencies_count,
@@ -459,6 +528,16 @@ where
                 .chain(self.maybe_visit.map(SubtypeLifetime::subtype))
                 .chain(iter::once(
                     r#"
+pub(super) const DEX_SYMBOLS: &[(currency::SymbolStatic, currency::SymbolStatic)] = &["#,
+                ))
+                .chain(self.dex_symbols.map(SubtypeLifetime::subtype))
+                .chain(iter::once(
+                    r#"
+];
+"#,
+                ))
+                .chain(iter::once(
+                    r#"
 pub(super) mod definitions {"#,
                 ))
                 .map(Cow::Borrowed)
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf};
+use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf, SymbolStatic};
 use sdk::schemars::{self, JsonSchema};
 
 use crate::payment::Group as PaymentGroup;
@@ -31,6 +31,7 @@ pub enum Group {}
 
 impl currency::Group for Group {
     const DESCR: &'static str = "lease";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = impl_mod::DEX_SYMBOLS;
 
     type TopG = PaymentGroup;
 
@@ -57,7 +58,7 @@ impl MemberOf<PaymentGroup> for Group {}
 
 #[cfg(all(feature = "testing", test))]
 mod test {
-    use currency::CurrencyDef as _;
+    use currency::{test, CurrencyDef as _};
 
     use crate::{
         lpn::Lpn,
@@ -68,7 +69,10 @@ mod test {
         },
     };
 
-    use super::{impl_mod::definitions::LeaseC1, Group};
+    use super::{
+        impl_mod::definitions::{LeaseC1, LeaseC2, LeaseC3, LeaseC4, LeaseC5, LeaseC6, LeaseC7},
+        Group,
+    };
 
     #[test]
     fn maybe_visit_on_ticker() {
@@ -83,4 +87,17 @@ mod test {
         maybe_visit_on_bank_symbol_err::<LeaseC1, Group>(Lpn::bank());
         maybe_visit_on_bank_symbol_err::<LeaseC1, Group>(Nls::bank());
     }
+
+    #[test]
+    fn dex_symbols() {
+        test::assert_dex_symbols::<Group>(&[
+            LeaseC1::dto().definition(),
+            LeaseC2::dto().definition(),
+            LeaseC3::dto().definition(),
+            LeaseC4::dto().definition(),
+            LeaseC5::dto().definition(),
+            LeaseC6::dto().definition(),
+            LeaseC7::dto().definition(),
+        ]);
+    }
 }
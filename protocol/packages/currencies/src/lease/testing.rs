@@ -1,4 +1,4 @@
-use currency::{AnyVisitor, Group, Matcher, MaybeAnyVisitResult, MemberOf};
+use currency::{AnyVisitor, Group, Matcher, MaybeAnyVisitResult, MemberOf, SymbolStatic};
 
 use crate::payment::Group as PaymentGroup;
 
@@ -27,6 +27,16 @@ where
         .or_else(|visitor| visit::<_, LeaseC7, VisitedG, _>(matcher, visitor))
 }
 
+pub(super) const DEX_SYMBOLS: &[(SymbolStatic, SymbolStatic)] = &[
+    ("ibc/dex_LC1", "LC1"),
+    ("ibc/dex_LC2", "LC2"),
+    ("ibc/dex_LC3", "LC3"),
+    ("ibc/dex_LC4", "LC4"),
+    ("ibc/dex_LC5", "LC5"),
+    ("ibc/dex_LC6", "LC6"),
+    ("ibc/dex_LC7", "LC7"),
+];
+
 pub(super) mod definitions {
     use serde::{Deserialize, Serialize};
 
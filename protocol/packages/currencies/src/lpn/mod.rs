@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use currency::{
     AnyVisitor, Matcher, MaybeAnyVisitResult, MaybePairsVisitorResult, MemberOf, PairsGroup,
-    PairsVisitor,
+    PairsVisitor, SymbolStatic,
 };
 use sdk::schemars::{self, JsonSchema};
 
@@ -27,6 +27,7 @@ pub enum Group {}
 
 impl currency::Group for Group {
     const DESCR: &'static str = "lpns";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = impl_mod::DEX_SYMBOLS;
 
     type TopG = PaymentGroup;
 
@@ -70,10 +71,11 @@ impl PairsGroup for Lpn {
 
 #[cfg(test)]
 mod test {
-    use currency::CurrencyDef as _;
+    use currency::{test, CurrencyDef as _};
 
     use crate::{
         native::Nls,
+        payment::Group as PaymentGroup,
         test_impl::{
             maybe_visit_on_bank_symbol_err, maybe_visit_on_bank_symbol_impl,
             maybe_visit_on_ticker_err, maybe_visit_on_ticker_impl,
@@ -95,4 +97,22 @@ mod test {
         maybe_visit_on_bank_symbol_err::<Lpn, Lpns>(Lpn::ticker());
         maybe_visit_on_bank_symbol_err::<Lpn, Lpns>(Nls::bank());
     }
+
+    #[test]
+    fn dex_symbols() {
+        test::assert_dex_symbols::<Lpns>(&[Lpn::dto().definition()]);
+    }
+
+    #[test]
+    fn same_as_in_payment_group() {
+        let lpn = currency::dto::<Lpn, PaymentGroup>();
+
+        assert!(lpn.same_as(Lpn::dto()));
+        assert!(Lpn::dto().same_as(&lpn));
+
+        let nls = currency::dto::<Nls, PaymentGroup>();
+
+        assert!(!nls.same_as(Lpn::dto()));
+        assert!(!Lpn::dto().same_as(&nls));
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf};
+use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf, SymbolStatic};
 use sdk::schemars::{self, JsonSchema};
 
 use crate::payment::Group as PaymentGroup;
@@ -24,6 +24,7 @@ pub enum Group {}
 
 impl currency::Group for Group {
     const DESCR: &'static str = "native";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = impl_mod::DEX_SYMBOLS;
 
     type TopG = PaymentGroup;
 
@@ -50,7 +51,7 @@ impl MemberOf<PaymentGroup> for Group {}
 
 #[cfg(test)]
 mod test {
-    use currency::CurrencyDef as _;
+    use currency::{test, CurrencyDef as _};
 
     use crate::{
         lpn::Lpn,
@@ -75,4 +76,9 @@ mod test {
         maybe_visit_on_bank_symbol_err::<Nls, NativeGroup>(Nls::ticker());
         maybe_visit_on_bank_symbol_err::<Nls, NativeGroup>(Lpn::bank());
     }
+
+    #[test]
+    fn dex_symbols() {
+        test::assert_dex_symbols::<NativeGroup>(&[Nls::dto().definition()]);
+    }
 }
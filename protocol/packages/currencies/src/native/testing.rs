@@ -1,3 +1,7 @@
+use currency::SymbolStatic;
+
+pub(super) const DEX_SYMBOLS: &[(SymbolStatic, SymbolStatic)] = &[("ibc/dex_NLS", "NLS")];
+
 pub(super) mod definitions {
     use serde::{Deserialize, Serialize};
 
@@ -27,16 +27,7 @@ impl currency::Group for Group {
 
     type TopG = Self;
 
-    fn maybe_visit<M, V>(matcher: &M, visitor: V) -> MaybeAnyVisitResult<Self, V>
-    where
-        M: Matcher,
-        V: AnyVisitor<Self>,
-    {
-        LeaseGroup::maybe_visit_member(matcher, visitor)
-            .or_else(|visitor| LpnGroup::maybe_visit_member(matcher, visitor))
-            .or_else(|visitor| NativeGroup::maybe_visit_member(matcher, visitor))
-            .or_else(|visitor| OnlyGroup::maybe_visit_member(matcher, visitor))
-    }
+    currency::group_union!(LeaseGroup, LpnGroup, NativeGroup, OnlyGroup);
 
     fn maybe_visit_member<M, V>(_: &M, _: V) -> MaybeAnyVisitResult<Self::TopG, V>
     where
@@ -48,3 +39,62 @@ impl currency::Group for Group {
 }
 
 impl MemberOf<Self> for Group {}
+
+#[cfg(all(feature = "testing", test))]
+mod test {
+    use currency::{
+        test::{self, Expect},
+        CurrencyDef, GroupVisit, MemberOf, Tickers,
+    };
+
+    use crate::{
+        lease::{LeaseC1, LeaseC2, LeaseC3, LeaseC4, LeaseC5, LeaseC6, LeaseC7},
+        lpn::Lpn,
+        native::Nls,
+    };
+
+    use super::Group as PaymentGroup;
+
+    #[test]
+    fn union_members() {
+        visit_through_union::<LeaseC1>();
+        visit_through_union::<LeaseC2>();
+        visit_through_union::<LeaseC3>();
+        visit_through_union::<LeaseC4>();
+        visit_through_union::<LeaseC5>();
+        visit_through_union::<LeaseC6>();
+        visit_through_union::<LeaseC7>();
+        visit_through_union::<Lpn>();
+        visit_through_union::<Nls>();
+    }
+
+    #[test]
+    fn dex_symbols() {
+        test::assert_dex_symbols::<PaymentGroup>(&[
+            LeaseC1::dto().definition(),
+            LeaseC2::dto().definition(),
+            LeaseC3::dto().definition(),
+            LeaseC4::dto().definition(),
+            LeaseC5::dto().definition(),
+            LeaseC6::dto().definition(),
+            LeaseC7::dto().definition(),
+            Lpn::dto().definition(),
+            Nls::dto().definition(),
+        ]);
+    }
+
+    #[track_caller]
+    fn visit_through_union<C>()
+    where
+        C: CurrencyDef,
+        C::Group: MemberOf<PaymentGroup>,
+    {
+        assert_eq!(
+            Tickers::<PaymentGroup>::visit_any(
+                C::ticker(),
+                Expect::<C, PaymentGroup, PaymentGroup>::default()
+            ),
+            Ok(true)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf};
+use currency::{AnyVisitor, Matcher, MaybeAnyVisitResult, MemberOf, SymbolStatic};
 use sdk::schemars::{self, JsonSchema};
 
 use super::Group as PaymentGroup;
@@ -26,6 +26,7 @@ pub enum Group {}
 
 impl currency::Group for Group {
     const DESCR: &'static str = "payment only";
+    const DEX_SYMBOLS: &'static [(SymbolStatic, SymbolStatic)] = impl_mod::DEX_SYMBOLS;
 
     type TopG = PaymentGroup;
 
@@ -1,4 +1,4 @@
-use currency::{AnyVisitor, Group, Matcher, MaybeAnyVisitResult};
+use currency::{AnyVisitor, Group, Matcher, MaybeAnyVisitResult, SymbolStatic};
 
 use super::super::Group as PaymentGroup;
 
@@ -11,3 +11,5 @@ where
 {
     currency::visit_noone(visitor)
 }
+
+pub(super) const DEX_SYMBOLS: &[(SymbolStatic, SymbolStatic)] = &[];
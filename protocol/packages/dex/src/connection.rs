@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use finance::duration::Duration;
 use sdk::schemars::{self, JsonSchema};
 
 /// Parameters needed to operate with the Dex network
@@ -11,6 +12,9 @@ pub struct ConnectionParams {
     /// The IBC ICS-20 channel used to transfer assets in/out.
     /// It must be established over the same connection.
     pub transfer_channel: Ics20Channel,
+    /// The timeouts of the transactions sent over the connection
+    #[serde(default)]
+    pub timeouts: IcaTimeouts,
 }
 
 /// IBC ICS-20 channel parameters
@@ -22,3 +26,148 @@ pub struct Ics20Channel {
     /// The id of the remote endpoint
     pub remote_endpoint: String,
 }
+
+/// Timeouts of the IBC transfers and ICA transactions
+///
+/// A transaction that is not acknowledged within its timeout is reported as timed out.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "snake_case",
+    try_from = "unchecked::IcaTimeouts"
+)]
+pub struct IcaTimeouts {
+    /// How long an ICS-20 transfer, in or out, may remain not acknowledged
+    transfer: Duration,
+    /// How long an ICA swap transaction may remain not acknowledged
+    swap: Duration,
+}
+
+impl IcaTimeouts {
+    //enough for the IBC channels migration to complete
+    const DEFAULT: Duration = Duration::from_days(100);
+
+    pub const MIN: Duration = Duration::from_minutes(1);
+    pub const MAX: Duration = Duration::from_days(365);
+
+    pub fn new(transfer: Duration, swap: Duration) -> Result<Self, String> {
+        let res = Self { transfer, swap };
+        res.invariant_held().map(|()| res)
+    }
+
+    pub const fn transfer(&self) -> Duration {
+        self.transfer
+    }
+
+    pub const fn swap(&self) -> Duration {
+        self.swap
+    }
+
+    fn invariant_held(&self) -> Result<(), String> {
+        Self::check(self.transfer, "transfer").and_then(|()| Self::check(self.swap, "swap"))
+    }
+
+    fn check(timeout: Duration, kind: &str) -> Result<(), String> {
+        if (Self::MIN..=Self::MAX).contains(&timeout) {
+            Ok(())
+        } else {
+            Err(format!(
+                "The {kind} timeout should be within [{min}, {max}]",
+                min = Self::MIN,
+                max = Self::MAX
+            ))
+        }
+    }
+}
+
+impl Default for IcaTimeouts {
+    fn default() -> Self {
+        Self {
+            transfer: Self::DEFAULT,
+            swap: Self::DEFAULT,
+        }
+    }
+}
+
+mod unchecked {
+    use serde::Deserialize;
+
+    use finance::duration::Duration;
+
+    /// Bring invariant checking as a step in deserializing an IcaTimeouts
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields, rename_all = "snake_case")]
+    pub(super) struct IcaTimeouts {
+        transfer: Duration,
+        swap: Duration,
+    }
+
+    impl TryFrom<IcaTimeouts> for super::IcaTimeouts {
+        type Error = String;
+
+        fn try_from(value: IcaTimeouts) -> Result<Self, Self::Error> {
+            Self::new(value.transfer, value.swap)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use finance::duration::Duration;
+    use sdk::cosmwasm_std::{from_json, StdError};
+
+    use super::{ConnectionParams, IcaTimeouts, Ics20Channel};
+
+    #[test]
+    fn read_no_timeouts() {
+        assert_eq!(
+            Ok(connection(IcaTimeouts::default())),
+            from_json(
+                r#"{"connection_id":"connection-0","transfer_channel":{"local_endpoint":"channel-0","remote_endpoint":"channel-2048"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn read_timeouts() {
+        assert_eq!(
+            Ok(connection(
+                IcaTimeouts::new(Duration::from_hours(2), Duration::from_minutes(30)).unwrap()
+            )),
+            from_json(
+                r#"{"connection_id":"connection-0","transfer_channel":{"local_endpoint":"channel-0","remote_endpoint":"channel-2048"},"timeouts":{"transfer":7200000000000,"swap":1800000000000}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn timeouts_out_of_bounds() {
+        assert!(IcaTimeouts::new(IcaTimeouts::MIN, IcaTimeouts::MAX).is_ok());
+        assert!(IcaTimeouts::new(
+            IcaTimeouts::MIN - Duration::from_nanos(1),
+            Duration::from_hours(1)
+        )
+        .is_err());
+        assert!(IcaTimeouts::new(
+            Duration::from_hours(1),
+            IcaTimeouts::MAX + Duration::from_nanos(1)
+        )
+        .is_err());
+
+        assert!(matches!(
+            from_json::<IcaTimeouts>(r#"{"transfer":7200000000000,"swap":0}"#),
+            Err(StdError::ParseErr { msg, .. }) if msg.contains("swap timeout")
+        ));
+    }
+
+    fn connection(timeouts: IcaTimeouts) -> ConnectionParams {
+        ConnectionParams {
+            connection_id: "connection-0".into(),
+            transfer_channel: Ics20Channel {
+                local_endpoint: "channel-0".into(),
+                remote_endpoint: "channel-2048".into(),
+            },
+            timeouts,
+        }
+    }
+}
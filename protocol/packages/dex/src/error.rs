@@ -19,6 +19,9 @@ pub enum Error {
     #[error("[Dex] {0}")]
     OracleSwapError(#[from] oracle::api::swap::Error),
 
+    #[error("[Dex] {0}")]
+    Oracle(#[from] oracle_platform::error::Error),
+
     #[error("[Dex] {0}")]
     TimeAlarmError(#[from] timealarms::stub::Error),
 }
@@ -52,6 +52,7 @@ impl Account {
             &self.owner,
             &self.host,
             now,
+            self.dex.timeouts.transfer(),
             format!(
                 "Transfer out: {sender} -> {receiver}",
                 sender = self.owner,
@@ -69,7 +70,13 @@ impl Account {
         SwapGroup: Group,
         SwapPathImpl: SwapPath<SwapGroup>,
     {
-        SwapTrx::new(&self.dex.connection_id, &self.host, swap_path, querier)
+        SwapTrx::new(
+            &self.dex.connection_id,
+            &self.host,
+            self.dex.timeouts.swap(),
+            swap_path,
+            querier,
+        )
     }
 
     pub(super) fn transfer_from(&self, now: Timestamp) -> TransferInTrx<'_> {
@@ -79,6 +86,7 @@ impl Account {
             &self.host,
             &self.owner,
             now,
+            self.dex.timeouts.transfer(),
         )
     }
 
@@ -99,3 +107,95 @@ impl DexConnectable for Account {
         &self.dex
     }
 }
+
+#[cfg(test)]
+mod test {
+    use currency::test::{SuperGroup, SuperGroupTestC1};
+    use finance::{coin::Coin, duration::Duration};
+    use platform::{
+        bank_ibc::{local::Sender as LocalSender, remote::Sender as RemoteSender},
+        batch::Batch,
+        ica::{self, HostAccount},
+    };
+    use sdk::cosmwasm_std::{Addr, Timestamp};
+
+    use crate::{ConnectionParams, IcaTimeouts, Ics20Channel};
+
+    use super::{
+        super::trx::{
+            ICA_SWAP_ACK_TIP, ICA_SWAP_TIMEOUT_TIP, ICA_TRANSFER_ACK_TIP, ICA_TRANSFER_TIMEOUT_TIP,
+        },
+        Account,
+    };
+
+    const TRANSFER_TIMEOUT: Duration = Duration::from_hours(3);
+    const SWAP_TIMEOUT: Duration = Duration::from_minutes(20);
+
+    #[test]
+    fn transfer_to_timeout() {
+        let account = account();
+        let now = Timestamp::from_seconds(1000);
+        let amount = Coin::<SuperGroupTestC1>::new(2134).into();
+
+        let mut trx = account.transfer_to(now);
+        trx.send::<SuperGroup>(&amount).unwrap();
+
+        let mut exp = LocalSender::new(
+            &account.dex.transfer_channel.local_endpoint,
+            account.owner.clone(),
+            account.host.clone(),
+            now + TRANSFER_TIMEOUT,
+            ICA_TRANSFER_ACK_TIP,
+            ICA_TRANSFER_TIMEOUT_TIP,
+            format!(
+                "Transfer out: {sender} -> {receiver}",
+                sender = account.owner,
+                receiver = account.host
+            ),
+        );
+        exp.send::<SuperGroup>(&amount).unwrap();
+        assert_eq!(Batch::from(exp), Batch::from(trx));
+    }
+
+    #[test]
+    fn transfer_from_timeout() {
+        let account = account();
+        let now = Timestamp::from_seconds(1000);
+        let amount = Coin::<SuperGroupTestC1>::new(2134).into();
+
+        let mut trx = account.transfer_from(now);
+        trx.send::<SuperGroup>(&amount).unwrap();
+
+        let mut exp_sender = RemoteSender::new(
+            &account.dex.transfer_channel.remote_endpoint,
+            account.host.clone(),
+            account.owner.clone(),
+            now + TRANSFER_TIMEOUT,
+        );
+        exp_sender.send::<SuperGroup>(&amount).unwrap();
+        let exp = ica::submit_transaction(
+            &account.dex.connection_id,
+            exp_sender.into(),
+            "memo",
+            TRANSFER_TIMEOUT,
+            ICA_SWAP_ACK_TIP,
+            ICA_SWAP_TIMEOUT_TIP,
+        );
+        assert_eq!(exp, Batch::from(trx));
+    }
+
+    fn account() -> Account {
+        Account {
+            owner: Addr::unchecked("owner"),
+            host: HostAccount::try_from(String::from("host")).unwrap(),
+            dex: ConnectionParams {
+                connection_id: "connection-0".into(),
+                transfer_channel: Ics20Channel {
+                    local_endpoint: "channel-0".into(),
+                    remote_endpoint: "channel-2048".into(),
+                },
+                timeouts: IcaTimeouts::new(TRANSFER_TIMEOUT, SWAP_TIMEOUT).unwrap(),
+            },
+        }
+    }
+}
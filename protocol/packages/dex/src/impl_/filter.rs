@@ -40,7 +40,6 @@ where
         self.filtered
     }
 
-    #[cfg(debug_assertions)]
     pub fn passed_any(&self) -> PassedThrough {
         self.pass_any
     }
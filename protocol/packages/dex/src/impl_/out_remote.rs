@@ -531,8 +531,9 @@ mod impl_migration {
     use super::{OpenIcaRespDelivery, State};
     use crate::{
         impl_::{
-            migration::MigrateSpec, swap_task::SwapTask as SwapTaskT, DexConnectable,
-            ForwardToInner, IcaConnectee, IcaConnector,
+            migration::{InspectSpec, MigrateSpec},
+            swap_task::SwapTask as SwapTaskT,
+            DexConnectable, ForwardToInner, IcaConnectee, IcaConnector,
         },
         swap::ExactAmountIn,
     };
@@ -620,4 +621,40 @@ mod impl_migration {
             }
         }
     }
+
+    impl<
+            SwapTask,
+            R,
+            OpenIca,
+            SwapGroup,
+            SwapClient,
+            ForwardToInnerMsg,
+            ForwardToInnerContinueMsg,
+        > InspectSpec<SwapTask, R>
+        for State<
+            OpenIca,
+            SwapTask,
+            SwapGroup,
+            SwapClient,
+            ForwardToInnerMsg,
+            ForwardToInnerContinueMsg,
+        >
+    where
+        SwapTask: SwapTaskT,
+        OpenIca: InspectSpec<SwapTask, R>,
+    {
+        fn inspect_spec<InspectFn>(&self, inspect_fn: InspectFn) -> R
+        where
+            InspectFn: FnOnce(&SwapTask) -> R,
+        {
+            match self {
+                State::OpenIca(inner) => inner.inspect_spec(inspect_fn),
+                State::OpenIcaRespDelivery(inner) => inner.inspect_spec(inspect_fn),
+                State::TransferOut(inner) => inner.inspect_spec(inspect_fn),
+                State::TransferOutRespDelivery(inner) => inner.inspect_spec(inspect_fn),
+                State::SwapExactIn(inner) => inner.inspect_spec(inspect_fn),
+                State::SwapExactInRespDelivery(inner) => inner.inspect_spec(inspect_fn),
+            }
+        }
+    }
 }
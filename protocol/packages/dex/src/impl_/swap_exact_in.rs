@@ -12,7 +12,11 @@ use finance::{
     duration::Duration,
     zero::Zero,
 };
-use platform::{batch::Batch, trx};
+use platform::{
+    batch::{Batch, Emit, Emitter},
+    message::Response as MessageResponse,
+    trx,
+};
 use sdk::{
     cosmos_sdk_proto::Any,
     cosmwasm_std::{Binary, Env, QuerierWrapper, Timestamp},
@@ -49,6 +53,9 @@ use super::{Contract, SwapState};
 ))]
 pub struct SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient> {
     spec: SwapTask,
+    /// Whether the swap has already completed with a zero output
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    zero_output: bool,
     #[serde(skip)]
     _state_enum: PhantomData<SEnum>,
     #[serde(skip)]
@@ -64,6 +71,7 @@ where
     pub(super) fn new(spec: SwapTask) -> Self {
         Self {
             spec,
+            zero_output: false,
             _state_enum: PhantomData,
             _swap_group: PhantomData,
             _swap_client: PhantomData,
@@ -84,25 +92,27 @@ where
     ) -> Result<Batch> {
         let swap_trx = self.spec.dex_account().swap(self.spec.oracle(), querier);
         // TODO apply nls_swap_fee on the downpayment only!
-        struct SwapWorker<'a, SwapPathImpl, SwapIn, SwapOut, SwapInOut, SwapClient>(
+        struct SwapWorker<'a, Spec, SwapPathImpl, SwapOut, SwapInOut, SwapClient>(
             SwapTrx<'a, SwapInOut, SwapPathImpl>,
-            PhantomData<SwapIn>,
+            &'a Spec,
+            QuerierWrapper<'a>,
             CurrencyDTO<SwapOut>,
             PhantomData<SwapClient>,
         )
         where
             SwapOut: Group;
 
-        impl<SwapPathImpl, SwapIn, SwapOut, SwapInOut, SwapClient> CoinVisitor
-            for SwapWorker<'_, SwapPathImpl, SwapIn, SwapOut, SwapInOut, SwapClient>
+        impl<Spec, SwapPathImpl, SwapOut, SwapInOut, SwapClient> CoinVisitor
+            for SwapWorker<'_, Spec, SwapPathImpl, SwapOut, SwapInOut, SwapClient>
         where
+            Spec: SwapTaskT,
             SwapPathImpl: SwapPath<SwapInOut>,
-            SwapIn: Group + MemberOf<SwapInOut>,
+            Spec::InG: MemberOf<SwapInOut>,
             SwapOut: Group + MemberOf<SwapInOut>,
             SwapInOut: Group,
             SwapClient: ExactAmountIn,
         {
-            type GIn = SwapIn;
+            type GIn = Spec::InG;
 
             type Result = IterNext;
 
@@ -112,15 +122,21 @@ where
             where
                 G: Group + MemberOf<Self::GIn>,
             {
-                self.0
-                    .swap_exact_in::<_, SwapIn, SwapOut, SwapClient>(*coin, self.2)?;
-                Ok(IterNext::Continue)
+                self.1
+                    .min_output(coin, self.2)
+                    .and_then(|min_output| {
+                        self.0.swap_exact_in::<_, Spec::InG, SwapOut, SwapClient>(
+                            *coin, min_output, self.3,
+                        )
+                    })
+                    .map(|()| IterNext::Continue)
             }
         }
 
         let mut swapper = SwapWorker(
             swap_trx,
-            PhantomData::<SwapTask::InG>,
+            &self.spec,
+            querier,
             self.spec.out_currency(),
             PhantomData::<SwapClient>,
         );
@@ -132,11 +148,46 @@ where
         let res = self.spec.on_coins(&mut filtered_swapper)?;
 
         #[cfg(debug_assertions)]
-        self.debug_check(&filtered_swapper, res);
+        Self::debug_check(res);
+        debug_assert!(
+            filtered_swapper.passed_any(),
+            "No coins with currency != {}",
+            self.spec.out_currency()
+        );
 
         Ok(swapper.0.into())
     }
 
+    /// Determine whether all coins are already in the output currency
+    pub(super) fn nothing_to_swap(&self) -> bool {
+        struct NoopVisitor<G>(PhantomData<G>);
+
+        impl<GIn> CoinVisitor for NoopVisitor<GIn>
+        where
+            GIn: Group,
+        {
+            type GIn = GIn;
+
+            type Result = IterNext;
+
+            type Error = Error;
+
+            fn visit<G>(&mut self, _coin: &CoinDTO<G>) -> Result<Self::Result>
+            where
+                G: Group + MemberOf<Self::GIn>,
+            {
+                Ok(IterNext::Continue)
+            }
+        }
+
+        let mut noop = NoopVisitor(PhantomData::<SwapTask::InG>);
+        let mut filter = CurrencyFilter::<_, _, _>::new(&mut noop, self.spec.out_currency());
+        self.spec
+            .on_coins(&mut filter)
+            .expect("the no-op visitor should not fail");
+        !filter.passed_any()
+    }
+
     fn decode_response(&self, resp: &[u8], spec: &SwapTask) -> Result<CoinDTO<SwapTask::OutG>> {
         struct ExactInResponse<I, SwapIn, SwapClient>(
             I,
@@ -181,7 +232,7 @@ where
         let res = self.spec.on_coins(&mut filtered_resp)?;
 
         #[cfg(debug_assertions)]
-        self.debug_check(&filtered_resp, res);
+        Self::debug_check(res);
 
         Ok(coin::from_amount_ticker(
             filtered_resp.filtered() + resp.1,
@@ -190,18 +241,7 @@ where
     }
 
     #[cfg(debug_assertions)]
-    fn debug_check<V>(
-        &self,
-        filter: &CurrencyFilter<'_, V, SwapTask::InG, SwapTask::OutG>,
-        res: IterState,
-    ) where
-        V: CoinVisitor,
-    {
-        debug_assert!(
-            filter.passed_any(),
-            "No coins with currency != {}",
-            self.spec.out_currency()
-        );
+    fn debug_check(res: IterState) {
         debug_assert_eq!(res, IterState::Complete);
     }
 }
@@ -211,12 +251,42 @@ where
     SwapTask: SwapTaskT,
     SwapGroup: Group,
     SwapClient: ExactAmountIn,
-    Self: Handler<Response = SEnum> + Into<SEnum>,
+    Self: Handler<Response = SEnum, SwapResult = SwapTask::Result> + Into<SEnum>,
 {
     fn retry(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
         let state_label = self.spec.label();
         timeout::on_timeout_retry(self, state_label, querier, env).into()
     }
+
+    fn heal_or_retry(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
+        if self.zero_output {
+            self.finish_zero_output(querier, env)
+        } else {
+            self.retry(querier, env)
+        }
+    }
+
+    /// Stay in this state instead of proceeding with a zero swap output
+    ///
+    /// A zero output usually means an empty pool or an extreme slippage, so it would
+    /// only mis-account the swapped coins. The swap input has already been consumed
+    /// though, so a `heal` does not replay it but finishes with the zero output.
+    fn on_zero_output(mut self, env: Env) -> HandlerResult<Self> {
+        self.zero_output = true;
+        let emitter = Emitter::of_type(self.spec.label())
+            .emit("id", env.contract.address)
+            .emit("swap", "zero-output");
+        response::res_continue::<_, _, Self>(
+            MessageResponse::messages_with_events(Batch::default(), emitter),
+            self,
+        )
+        .into()
+    }
+
+    fn finish_zero_output(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
+        let amount_out = coin::from_amount_ticker(Amount::ZERO, self.spec.out_currency());
+        response::res_finished(self.spec.finish(amount_out, &env, querier))
+    }
 }
 
 impl<SwapTask, SEnum, SwapGroup, SwapClient> Enterable
@@ -264,14 +334,17 @@ where
         env: Env,
     ) -> HandlerResult<Self> {
         // TODO transfer (downpayment - transferred_and_swapped), i.e. the nls_swap_fee to the profit
-        self.decode_response(resp.as_slice(), &self.spec)
-            .map(|amount_out| TransferInInit::new(self.spec, amount_out))
-            .and_then(|next_state| {
+        match self.decode_response(resp.as_slice(), &self.spec) {
+            Ok(amount_out) if amount_out.is_zero() => self.on_zero_output(env),
+            Ok(amount_out) => {
+                let next_state = TransferInInit::new(self.spec, amount_out);
                 next_state
                     .enter(env.block.time, querier)
                     .and_then(|resp| response::res_continue::<_, _, Self>(resp, next_state))
-            })
-            .into()
+                    .into()
+            }
+            Err(err) => err.into(),
+        }
     }
 
     fn on_timeout(self, querier: QuerierWrapper<'_>, env: Env) -> ContinueResult<Self> {
@@ -280,7 +353,7 @@ where
     }
 
     fn heal(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
-        self.retry(querier, env)
+        self.heal_or_retry(querier, env)
     }
 }
 
@@ -320,11 +393,11 @@ where
         env: Env,
     ) -> HandlerResult<Self> {
         // TODO transfer (downpayment - transferred_and_swapped), i.e. the nls_swap_fee to the profit
-        self.decode_response(resp.as_slice(), &self.spec)
-            .map_or_else(
-                |err| HandlerResult::Continue(Err(err)),
-                |amount_out| response::res_finished(self.spec.finish(amount_out, &env, querier)),
-            )
+        match self.decode_response(resp.as_slice(), &self.spec) {
+            Ok(amount_out) if amount_out.is_zero() => self.on_zero_output(env),
+            Ok(amount_out) => response::res_finished(self.spec.finish(amount_out, &env, querier)),
+            Err(err) => err.into(),
+        }
     }
 
     fn on_timeout(self, querier: QuerierWrapper<'_>, env: Env) -> ContinueResult<Self> {
@@ -333,7 +406,7 @@ where
     }
 
     fn heal(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
-        self.retry(querier, env)
+        self.heal_or_retry(querier, env)
     }
 }
 
@@ -389,7 +462,10 @@ where
     where
         MigrateFn: FnOnce(SwapTask) -> SwapTaskNew,
     {
-        Self::Out::new(migrate_fn(self.spec))
+        Self::Out {
+            zero_output: self.zero_output,
+            ..Self::Out::new(migrate_fn(self.spec))
+        }
     }
 }
 
@@ -1,10 +1,13 @@
 use currency::{CurrencyDTO, Group, MemberOf};
-use finance::coin::CoinDTO;
+use finance::{
+    coin::{Amount, CoinDTO},
+    zero::Zero,
+};
 use oracle::stub::SwapPath;
 use sdk::cosmwasm_std::{Env, QuerierWrapper};
 use timealarms::stub::TimeAlarmsRef;
 
-use super::Account;
+use super::{Account, DexResult};
 
 pub type CoinsNb = u8;
 
@@ -34,6 +37,22 @@ pub trait SwapTask {
     where
         Visitor: CoinVisitor<GIn = Self::InG, Result = IterNext>;
 
+    /// The least amount of the output currency acceptable for swapping `coin_in`
+    ///
+    /// Guards against an excessive slippage. It is obtained on each swap attempt,
+    /// including retries, so a task may derive it from the current market price.
+    /// By default, any amount is accepted.
+    fn min_output<G>(
+        &self,
+        _coin_in: &CoinDTO<G>,
+        _querier: QuerierWrapper<'_>,
+    ) -> DexResult<Amount>
+    where
+        G: Group + MemberOf<Self::InG>,
+    {
+        Ok(Amount::ZERO)
+    }
+
     fn finish(
         self,
         amount_out: CoinDTO<Self::OutG>,
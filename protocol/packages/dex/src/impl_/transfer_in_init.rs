@@ -17,7 +17,6 @@ use super::{
     swap_task::SwapTask as SwapTaskT,
     timeout,
     transfer_in_finish::TransferInFinish,
-    trx::IBC_TIMEOUT,
     Contract, ContractInSwap, DexConnectable, Enterable, TimeAlarm, TransferInInitState,
 };
 
@@ -96,8 +95,9 @@ where
     TransferInFinish<SwapTask, SEnum>: Into<SEnum>,
 {
     fn on_response(self, querier: QuerierWrapper<'_>, env: Env) -> HandlerResult<Self> {
+        let timeout = self.dex().timeouts.transfer();
         let finish: TransferInFinish<SwapTask, SEnum> =
-            TransferInFinish::new(self.spec, self.amount_in, env.block.time + IBC_TIMEOUT);
+            TransferInFinish::new(self.spec, self.amount_in, env.block.time + timeout);
         finish.try_complete(querier, env).map_into()
     }
 }
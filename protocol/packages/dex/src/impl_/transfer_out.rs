@@ -58,7 +58,8 @@ where
     SwapGroup: Group,
     SwapClient: ExactAmountIn,
     Self: Into<SEnum>,
-    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>: Into<SEnum>,
+    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>:
+        Into<SEnum> + Handler<Response = SEnum, SwapResult = SwapTask::Result>,
 {
     fn next(self) -> Self {
         debug_assert!(!self.last_coin());
@@ -179,7 +180,8 @@ where
     SwapGroup: Group,
     SwapClient: ExactAmountIn,
     Self: Into<SEnum>,
-    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>: Into<SEnum>,
+    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>:
+        Into<SEnum> + Handler<Response = SEnum, SwapResult = SwapTask::Result>,
 {
     fn enter(&self, now: Timestamp, querier: QuerierWrapper<'_>) -> Result<Batch> {
         self.enter_state(now, querier)
@@ -193,7 +195,8 @@ where
     SwapGroup: Group,
     SwapClient: ExactAmountIn,
     Self: Into<SEnum>,
-    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>: Into<SEnum>,
+    SwapExactIn<SwapTask, SEnum, SwapGroup, SwapClient>:
+        Into<SEnum> + Handler<Response = SEnum, SwapResult = SwapTask::Result>,
 {
     type Response = SEnum;
     type SwapResult = SwapTask::Result;
@@ -207,11 +210,17 @@ where
         let label = self.spec.label();
         let now = env.block.time;
         if self.last_coin() {
-            Self::on_response(SwapExactIn::new(self.spec), label, now, querier)
+            let swap = SwapExactIn::new(self.spec);
+            if swap.nothing_to_swap() {
+                // there is no swap transaction to send, and an empty response
+                // accounts the transferred coins as the swap output
+                Handler::on_response(swap, Binary::default(), querier, env).map_into()
+            } else {
+                Self::on_response(swap, label, now, querier).into()
+            }
         } else {
-            Self::on_response(self.next(), label, now, querier)
+            Self::on_response(self.next(), label, now, querier).into()
         }
-        .into()
     }
 
     fn on_timeout(self, querier: QuerierWrapper<'_>, env: Env) -> ContinueResult<Self> {
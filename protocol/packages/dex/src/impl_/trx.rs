@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use currency::{platform::Nls, CurrencyDTO, Group, MemberOf};
 use finance::{
-    coin::{Coin, CoinDTO},
+    coin::{Amount, Coin, CoinDTO},
     duration::Duration,
 };
 use oracle::stub::SwapPath;
@@ -16,15 +16,13 @@ use sdk::cosmwasm_std::{Addr, QuerierWrapper, Timestamp};
 
 use crate::{error::Result, swap::ExactAmountIn};
 
-pub(super) const IBC_TIMEOUT: Duration = Duration::from_days(100); //enough for the IBC channels migration to complete
-
 //TODO take them as input from the client
-const ICA_TRANSFER_ACK_TIP: Coin<Nls> = Coin::new(1);
-const ICA_TRANSFER_TIMEOUT_TIP: Coin<Nls> = ICA_TRANSFER_ACK_TIP;
+pub(super) const ICA_TRANSFER_ACK_TIP: Coin<Nls> = Coin::new(1);
+pub(super) const ICA_TRANSFER_TIMEOUT_TIP: Coin<Nls> = ICA_TRANSFER_ACK_TIP;
 
 //TODO take them as input from the client
-const ICA_SWAP_ACK_TIP: Coin<Nls> = Coin::new(1);
-const ICA_SWAP_TIMEOUT_TIP: Coin<Nls> = ICA_SWAP_ACK_TIP;
+pub(super) const ICA_SWAP_ACK_TIP: Coin<Nls> = Coin::new(1);
+pub(super) const ICA_SWAP_TIMEOUT_TIP: Coin<Nls> = ICA_SWAP_ACK_TIP;
 
 pub(super) struct TransferOutTrx<'a> {
     sender: LocalSender<'a>,
@@ -36,13 +34,14 @@ impl<'a> TransferOutTrx<'a> {
         sender: &Addr,
         receiver: &HostAccount,
         now: Timestamp,
+        timeout: Duration,
         memo: String,
     ) -> Self {
         let sender = LocalSender::new(
             channel,
             sender.clone(),
             receiver.clone(),
-            now + IBC_TIMEOUT,
+            now + timeout,
             ICA_TRANSFER_ACK_TIP,
             ICA_TRANSFER_TIMEOUT_TIP,
             memo,
@@ -68,6 +67,7 @@ impl<'r> From<TransferOutTrx<'r>> for LocalBatch {
 pub(super) struct SwapTrx<'a, SwapGroup, SwapPathImpl> {
     conn: &'a str,
     ica_account: &'a HostAccount,
+    timeout: Duration,
     trx: Transaction,
     swap_path: &'a SwapPathImpl,
     querier: QuerierWrapper<'a>,
@@ -82,6 +82,7 @@ where
     pub(super) fn new(
         conn: &'a str,
         ica_account: &'a HostAccount,
+        timeout: Duration,
         swap_path: &'a SwapPathImpl,
         querier: QuerierWrapper<'a>,
     ) -> Self {
@@ -89,6 +90,7 @@ where
         Self {
             conn,
             ica_account,
+            timeout,
             trx,
             swap_path,
             querier,
@@ -99,6 +101,7 @@ where
     pub fn swap_exact_in<GIn, SwapGIn, SwapGOut, SwapClient>(
         &mut self,
         amount: CoinDTO<GIn>,
+        min_amount_out: Amount,
         currency_out: CurrencyDTO<SwapGOut>,
     ) -> Result<()>
     where
@@ -119,6 +122,7 @@ where
                     &mut self.trx,
                     self.ica_account.clone(),
                     &amount,
+                    min_amount_out,
                     &swap_path,
                 )
                 .map_err(Into::into)
@@ -132,7 +136,7 @@ impl<SwapGroup, SwapPathImpl> From<SwapTrx<'_, SwapGroup, SwapPathImpl>> for Loc
             value.conn,
             value.trx,
             "memo",
-            IBC_TIMEOUT,
+            value.timeout,
             ICA_SWAP_ACK_TIP,
             ICA_SWAP_TIMEOUT_TIP,
         )
@@ -141,6 +145,7 @@ impl<SwapGroup, SwapPathImpl> From<SwapTrx<'_, SwapGroup, SwapPathImpl>> for Loc
 
 pub(super) struct TransferInTrx<'a> {
     conn: &'a str,
+    timeout: Duration,
     sender: RemoteSender<'a>,
 }
 
@@ -151,10 +156,14 @@ impl<'a> TransferInTrx<'a> {
         sender: &HostAccount,
         receiver: &Addr,
         now: Timestamp,
+        timeout: Duration,
     ) -> Self {
-        let sender =
-            RemoteSender::new(channel, sender.clone(), receiver.clone(), now + IBC_TIMEOUT);
-        TransferInTrx { conn, sender }
+        let sender = RemoteSender::new(channel, sender.clone(), receiver.clone(), now + timeout);
+        TransferInTrx {
+            conn,
+            timeout,
+            sender,
+        }
     }
 
     pub fn send<G>(&mut self, amount: &CoinDTO<G>) -> Result<()>
@@ -171,7 +180,7 @@ impl<'r> From<TransferInTrx<'r>> for LocalBatch {
             value.conn,
             value.sender.into(),
             "memo",
-            IBC_TIMEOUT,
+            value.timeout,
             ICA_SWAP_ACK_TIP,
             ICA_SWAP_TIMEOUT_TIP,
         )
@@ -58,8 +58,18 @@ impl Config {
         }
     }
 
-    pub fn min_feeders(&self, total: usize) -> usize {
-        self.min_feeders.of(total)
+    /// Calculate the number of feeders required to provide a price
+    ///
+    /// It is the configured percentage of `total_registered` feeders rounded up,
+    /// for example, 50% of 3 feeders requires 2 of them. Since the percentage is
+    /// never zero, at least one feeder is required if any is registered.
+    pub fn required_feeders(&self, total_registered: usize) -> usize {
+        // ceil(p * n) == n - floor((100% - p) * n), and p <= 100% by the invariant
+        total_registered - (Percent::HUNDRED - self.min_feeders).of(total_registered)
+    }
+
+    pub fn min_feeders(&self) -> Percent {
+        self.min_feeders
     }
 
     pub fn sample_period(&self) -> Duration {
@@ -70,6 +80,11 @@ impl Config {
         self.samples_number
     }
 
+    /// The period a fed observation is taken into account in the price calculation
+    pub fn feed_validity(&self) -> Duration {
+        self.feed_validity
+    }
+
     pub fn feed_valid_since(&self, now: Timestamp) -> Timestamp {
         debug_assert!(now > Timestamp::default());
 
@@ -86,6 +101,19 @@ impl Config {
         self.discount_factor
     }
 
+    /// Derive a configuration that considers only the feeds received within
+    /// the more recent half of the validity window of this one
+    ///
+    /// The invariant guarantees that the halved validity is longer than zero.
+    pub fn half_validity(&self) -> Self {
+        let half = Self {
+            feed_validity: Duration::from_nanos(self.feed_validity.nanos() / 2),
+            ..self.clone()
+        };
+        debug_assert!(half.feed_validity > Duration::default());
+        half
+    }
+
     fn check_invariant(self) -> Result<Self, PriceFeedsError> {
         error::config_error_if(
             self.min_feeders == Percent::ZERO || self.min_feeders > Percent::HUNDRED,
@@ -105,6 +133,11 @@ impl Config {
         debug_assert!(self.feed_validity > Duration::default());
         debug_assert!(self.sample_period <= self.feed_validity);
 
+        error::config_error_if(
+            self.feed_validity.nanos() < 2,
+            "The half of the price feeds validity should be longer than zero",
+        )?;
+
         error::config_error_if(
             self.discount_factor == Percent::ZERO || self.discount_factor > Percent::HUNDRED,
             "The discounting factor should be greater than 0 and less or equal to 100%",
@@ -168,7 +201,7 @@ mod test {
     use finance::{duration::Duration, percent::Percent};
     use sdk::cosmwasm_std::{from_json, to_json_vec, StdError, Timestamp};
 
-    use crate::config::Config;
+    use crate::{config::Config, error::PriceFeedsError};
 
     #[test]
     fn feed_valid_since() {
@@ -200,31 +233,96 @@ mod test {
         );
     }
 
-    fn min_feders_impl(min_feeders: u16, total: usize, exp: usize) {
+    #[test]
+    fn half_validity_empty() {
+        assert_eq!(
+            Err(PriceFeedsError::Configuration(
+                "The half of the price feeds validity should be longer than zero".into()
+            )),
+            Config::new_private(
+                Percent::from_permille(1),
+                Duration::from_nanos(1),
+                1,
+                Percent::from_permille(1000),
+            )
+        );
+        assert_eq!(
+            Duration::from_nanos(1),
+            Config::new(
+                Percent::from_permille(1),
+                Duration::from_nanos(1),
+                2,
+                Percent::from_permille(1000),
+            )
+            .half_validity()
+            .feed_validity()
+        );
+    }
+
+    #[test]
+    fn half_validity() {
+        let c = Config::new(
+            Percent::from_permille(1),
+            Duration::from_secs(5),
+            12,
+            Percent::from_permille(1000),
+        )
+        .half_validity();
+        assert_eq!(Duration::from_secs(5), c.sample_period());
+        assert_eq!(12, c.samples_number());
+        assert_eq!(
+            Timestamp::from_seconds(0),
+            c.feed_valid_since(Timestamp::from_seconds(30))
+        );
+        assert_eq!(
+            Timestamp::from_seconds(1),
+            c.feed_valid_since(Timestamp::from_seconds(31))
+        );
+        assert_eq!(
+            Timestamp::from_seconds(70),
+            c.feed_valid_since(Timestamp::from_seconds(100))
+        );
+    }
+
+    fn required_feeders_impl(min_feeders: u16, total: usize, exp: usize) {
         let c = Config::new(
             Percent::from_percent(min_feeders),
             Duration::HOUR,
             1,
             Percent::from_percent(75),
         );
-        assert_eq!(exp, c.min_feeders(total));
+        assert_eq!(exp, c.required_feeders(total));
     }
     #[test]
     fn feeders_needed_rounds_properly() {
-        min_feders_impl(100, 3, 3);
-        min_feders_impl(100, 30, 30);
-        min_feders_impl(50, 34, 17);
-        min_feders_impl(50, 33, 16);
-
-        min_feders_impl(25, 48, 12);
-        min_feders_impl(25, 49, 12);
-        min_feders_impl(25, 50, 12);
-        min_feders_impl(25, 51, 12);
-        min_feders_impl(25, 52, 13);
-
-        min_feders_impl(1, 132, 1);
-        min_feders_impl(1, 199, 1);
-        min_feders_impl(1, 200, 2);
+        required_feeders_impl(100, 3, 3);
+        required_feeders_impl(100, 30, 30);
+        required_feeders_impl(50, 34, 17);
+        required_feeders_impl(50, 33, 17);
+
+        required_feeders_impl(25, 48, 12);
+        required_feeders_impl(25, 49, 13);
+        required_feeders_impl(25, 50, 13);
+        required_feeders_impl(25, 51, 13);
+        required_feeders_impl(25, 52, 13);
+        required_feeders_impl(25, 53, 14);
+
+        required_feeders_impl(1, 132, 2);
+        required_feeders_impl(1, 199, 2);
+        required_feeders_impl(1, 200, 2);
+        required_feeders_impl(1, 201, 3);
+    }
+
+    #[test]
+    fn feeders_needed_fractional() {
+        required_feeders_impl(50, 3, 2);
+        required_feeders_impl(50, 1, 1);
+        required_feeders_impl(34, 3, 2);
+        required_feeders_impl(33, 3, 1);
+        required_feeders_impl(67, 3, 3);
+        required_feeders_impl(66, 3, 2);
+        required_feeders_impl(1, 1, 1);
+        required_feeders_impl(1, 0, 0);
     }
 
     #[test]
@@ -118,7 +118,10 @@ mod test {
     };
     use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage, Timestamp};
 
-    use crate::feed::observations::{Observations, ObservationsRead};
+    use crate::{
+        feed::observations::{Observations, ObservationsRead},
+        feeders::DEFAULT_WEIGHT,
+    };
 
     use super::{Deque, Observation};
 
@@ -136,7 +139,12 @@ mod test {
 
         let feed1_time = BLOCK_TIME - VALIDITY_PERIOD;
         let feed1_price = price(19, 5100);
-        let observation1 = Observation::new(Addr::unchecked(FEEDER), feed1_time, feed1_price);
+        let observation1 = Observation::new(
+            Addr::unchecked(FEEDER),
+            DEFAULT_WEIGHT,
+            feed1_time,
+            feed1_price,
+        );
         deque.register(observation1.clone()).unwrap();
         assert_eq!(1, deque.len());
         assert_eq!(Some(Ok(observation1)), deque.as_iter().unwrap().next());
@@ -157,12 +165,22 @@ mod test {
 
         let feed1_time = BLOCK_TIME - VALIDITY_PERIOD;
         let feed1_price = price(19, 5100);
-        let observation1 = Observation::new(Addr::unchecked(FEEDER), feed1_time, feed1_price);
+        let observation1 = Observation::new(
+            Addr::unchecked(FEEDER),
+            DEFAULT_WEIGHT,
+            feed1_time,
+            feed1_price,
+        );
         deque.register(observation1.clone()).unwrap();
 
         let feed2_time = feed1_time + VALIDITY_PERIOD;
         let feed2_price = price(20, 5100);
-        let observation2 = Observation::new(Addr::unchecked(FEEDER), feed2_time, feed2_price);
+        let observation2 = Observation::new(
+            Addr::unchecked(FEEDER),
+            DEFAULT_WEIGHT,
+            feed2_time,
+            feed2_price,
+        );
         deque.register(observation2.clone()).unwrap();
 
         assert_eq!(2, deque.len());
@@ -8,6 +8,7 @@ use crate::{
     config::Config,
     error::{PriceFeedsError, Result},
     feed::sample::Sample,
+    feeders::FeederWeight,
 };
 
 pub(crate) use self::observation::Observation;
@@ -55,7 +56,7 @@ where
 {
     /// Calculate the price of this feed
     ///
-    /// Provide no price if there are no observations from at least configurable percentage * <number_of_whitelisted_feeders>.
+    /// Provide no price if there are no observations from at least configurable percentage * <number_of_whitelisted_feeders>, rounded up.
     /// Observations older than a configurable period are not taken into consideration.
     /// Calculate the price at a sample period as per the formula:
     /// discounting_factor * avg_price_during_the_period + (1 - discounting_factor) * price_at_the_previos_period
@@ -117,7 +118,7 @@ where
     where
         Observations: for<'item> Iterator<Item = &'items Observation<C, QuoteC>>,
     {
-        self.count_unique_feeders(items) >= config.min_feeders(total_feeders)
+        self.count_unique_feeders(items) >= config.required_feeders(total_feeders)
     }
 
     fn count_unique_feeders<'items, Observations>(&self, items: Observations) -> usize
@@ -134,22 +135,50 @@ where
     QuoteC: 'static,
     ObservationsImpl: Observations<C = C, QuoteC = QuoteC>,
 {
+    /// Register an observation of a feeder
+    ///
+    /// The observation is weighted in the price calculation by the `weight` of the feeder.
+    /// A replay of the last observation of the feeder, for example, due to a duplicated
+    /// transaction within the same block, is ignored so it would not count twice.
     pub fn add_observation(
         mut self,
         from: Addr,
+        weight: FeederWeight,
         at: Timestamp,
         price: Price<C, QuoteC>,
         valid_since: &Timestamp,
     ) -> Result<Self> {
         debug_assert!(valid_since < &at, "{valid_since} >= {at}");
+        let observation = Observation::new(from, weight, at, price);
         self.observations
             .retain(valid_since)
-            .and_then(|()| {
-                self.observations
-                    .register(Observation::new(from, at, price))
+            .and_then(|()| self.is_replay(&observation))
+            .and_then(|replay| {
+                if replay {
+                    Ok(())
+                } else {
+                    self.observations.register(observation)
+                }
             })
             .map(|()| self)
     }
+
+    fn is_replay(&self, observation: &Observation<C, QuoteC>) -> Result<bool> {
+        self.observations
+            .as_iter()
+            .and_then(|mut items| {
+                items.try_fold(None, |last_of_feeder, may_item| {
+                    may_item.map(|item| {
+                        if item.feeder() == observation.feeder() {
+                            Some(item)
+                        } else {
+                            last_of_feeder
+                        }
+                    })
+                })
+            })
+            .map(|last_of_feeder| last_of_feeder.is_some_and(|last| observation.replays(&last)))
+    }
 }
 
 #[cfg(test)]
@@ -163,9 +192,13 @@ mod test {
     };
     use sdk::cosmwasm_std::{Addr, Timestamp};
 
-    use crate::{config::Config, error::PriceFeedsError};
+    use crate::{config::Config, error::PriceFeedsError, feeders::DEFAULT_WEIGHT};
 
-    use super::{memory::InMemoryObservations, observations::Observations, PriceFeed};
+    use super::{
+        memory::InMemoryObservations,
+        observations::{Observations, ObservationsRead},
+        PriceFeed,
+    };
 
     const ONE_FEEDER: usize = 1;
     const SAMPLE_PERIOD: Duration = Duration::from_secs(5);
@@ -198,6 +231,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1.clone(),
+                DEFAULT_WEIGHT,
                 feed1_time,
                 feed1_price,
                 &config.feed_valid_since(feed1_time),
@@ -212,7 +246,13 @@ mod test {
         let feed2_time = feed1_time + Duration::from_nanos(1);
         let feed2_price = price(19, 5000);
         feed = feed
-            .add_observation(feeder1, feed2_time, feed2_price, &feed1_time)
+            .add_observation(
+                feeder1,
+                DEFAULT_WEIGHT,
+                feed2_time,
+                feed2_price,
+                &feed1_time,
+            )
             .unwrap();
         assert_eq!(
             Ok(feed2_price),
@@ -220,6 +260,90 @@ mod test {
         );
     }
 
+    #[test]
+    fn replayed_observation() {
+        let block_time = Timestamp::from_seconds(100);
+        let valid_since = block_time - VALIDITY;
+        let feeder1 = Addr::unchecked("feeder1");
+        let feed_price = price(20, 5000);
+
+        let feed = feed()
+            .add_observation(
+                feeder1.clone(),
+                DEFAULT_WEIGHT,
+                block_time,
+                feed_price,
+                &valid_since,
+            )
+            .unwrap()
+            .add_observation(
+                feeder1,
+                DEFAULT_WEIGHT,
+                block_time,
+                feed_price,
+                &valid_since,
+            )
+            .unwrap();
+        assert_eq!(1, feed.observations.len());
+    }
+
+    #[test]
+    fn not_replayed_observations() {
+        let block_time = Timestamp::from_seconds(100);
+        let valid_since = block_time - VALIDITY;
+        let feeder1 = Addr::unchecked("feeder1");
+        let feeder2 = Addr::unchecked("feeder2");
+        let feed_price = price(20, 5000);
+
+        let mut feed = feed();
+        feed = feed
+            .add_observation(
+                feeder1.clone(),
+                DEFAULT_WEIGHT,
+                block_time,
+                feed_price,
+                &valid_since,
+            )
+            .unwrap();
+        feed = feed
+            .add_observation(
+                feeder2,
+                DEFAULT_WEIGHT,
+                block_time,
+                feed_price,
+                &valid_since,
+            )
+            .unwrap();
+        feed = feed
+            .add_observation(
+                feeder1.clone(),
+                DEFAULT_WEIGHT,
+                block_time,
+                price(20, 5001),
+                &valid_since,
+            )
+            .unwrap();
+        feed = feed
+            .add_observation(
+                feeder1.clone(),
+                DEFAULT_WEIGHT,
+                block_time,
+                feed_price,
+                &valid_since,
+            )
+            .unwrap();
+        feed = feed
+            .add_observation(
+                feeder1,
+                DEFAULT_WEIGHT,
+                block_time + Duration::from_nanos(1),
+                feed_price,
+                &valid_since,
+            )
+            .unwrap();
+        assert_eq!(5, feed.observations.len());
+    }
+
     #[test]
     fn less_feeders() {
         let validity_period = Duration::from_secs(60);
@@ -233,6 +357,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1,
+                DEFAULT_WEIGHT,
                 feed1_time,
                 feed1_price,
                 &(block_time - validity_period),
@@ -269,6 +394,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1,
+                DEFAULT_WEIGHT,
                 feed1_time,
                 feed1_price,
                 &(feed1_time - validity_period),
@@ -281,6 +407,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder2,
+                DEFAULT_WEIGHT,
                 feed2_time,
                 feed2_price,
                 &(feed2_time - validity_period),
@@ -342,6 +469,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1.clone(),
+                DEFAULT_WEIGHT,
                 s1,
                 price(19, 5160),
                 &config.feed_valid_since(s1),
@@ -350,6 +478,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1.clone(),
+                DEFAULT_WEIGHT,
                 s21,
                 price(19, 5500),
                 &config.feed_valid_since(s21),
@@ -358,6 +487,7 @@ mod test {
         feed = feed
             .add_observation(
                 feeder1.clone(),
+                DEFAULT_WEIGHT,
                 s22,
                 price(19, 5000 + 10),
                 &config.feed_valid_since(s22),
@@ -366,13 +496,20 @@ mod test {
         feed = feed
             .add_observation(
                 feeder2,
+                DEFAULT_WEIGHT,
                 s22,
                 price(19, 5000 - 10),
                 &config.feed_valid_since(s22),
             )
             .unwrap();
         feed = feed
-            .add_observation(feeder1, s3, price(19, 5000), &config.feed_valid_since(s3))
+            .add_observation(
+                feeder1,
+                DEFAULT_WEIGHT,
+                s3,
+                price(19, 5000),
+                &config.feed_valid_since(s3),
+            )
             .unwrap();
 
         assert_eq!(
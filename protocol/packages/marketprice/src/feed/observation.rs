@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use finance::price::Price;
 use sdk::cosmwasm_std::{Addr, Timestamp};
 
+use crate::feeders::{FeederWeight, DEFAULT_WEIGHT};
+
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(test, derive(Debug, Eq, PartialEq))]
 #[serde(bound(serialize = "", deserialize = ""))]
@@ -12,6 +14,9 @@ where
     QuoteC: 'static,
 {
     feeder_addr: Addr,
+    /// The weight of the feeder at the time of the observation
+    #[serde(default = "default_weight")]
+    feeder_weight: FeederWeight,
     time: Timestamp,
     price: Price<C, QuoteC>,
 }
@@ -19,11 +24,13 @@ where
 impl<C, QuoteC> Observation<C, QuoteC> {
     pub fn new(
         feeder_addr: Addr,
+        feeder_weight: FeederWeight,
         time: Timestamp,
         price: Price<C, QuoteC>,
     ) -> Observation<C, QuoteC> {
         Observation {
             feeder_addr,
+            feeder_weight,
             time,
             price,
         }
@@ -33,6 +40,10 @@ impl<C, QuoteC> Observation<C, QuoteC> {
         &self.feeder_addr
     }
 
+    pub fn feeder_weight(&self) -> FeederWeight {
+        self.feeder_weight
+    }
+
     pub fn price(&self) -> Price<C, QuoteC> {
         self.price
     }
@@ -40,6 +51,19 @@ impl<C, QuoteC> Observation<C, QuoteC> {
     pub fn valid_since(&self, since: &Timestamp) -> bool {
         since < &self.time
     }
+
+    /// Check if this is a replay of another observation
+    ///
+    /// A replay comes from the same feeder, at the same time, with the same price.
+    pub fn replays(&self, other: &Self) -> bool {
+        self.feeder_addr == other.feeder_addr
+            && self.time == other.time
+            && self.price == other.price
+    }
+}
+
+const fn default_weight() -> FeederWeight {
+    DEFAULT_WEIGHT
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -51,8 +75,51 @@ where
     fn clone(&self) -> Self {
         Self {
             feeder_addr: self.feeder_addr.clone(),
+            feeder_weight: self.feeder_weight,
             time: self.time,
             price: self.price,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use currency::test::{SuperGroupTestC4, SuperGroupTestC5};
+    use finance::{
+        coin::Coin,
+        price::{self, Price},
+    };
+    use sdk::cosmwasm_std::{self, Addr, Timestamp};
+
+    use crate::feeders::DEFAULT_WEIGHT;
+
+    use super::Observation;
+
+    #[test]
+    fn read_no_weight() {
+        let price: Price<SuperGroupTestC4, SuperGroupTestC5> =
+            price::total_of(Coin::new(2)).is(Coin::new(3));
+        let observation = Observation::new(
+            Addr::unchecked("feeder1"),
+            7,
+            Timestamp::from_seconds(100),
+            price,
+        );
+        let stored = String::from_utf8(
+            cosmwasm_std::to_json_vec(&observation).expect("serialization passed"),
+        )
+        .expect("valid UTF-8");
+        let stored_no_weight = stored.replace(r#","feeder_weight":7"#, "");
+        assert_ne!(stored, stored_no_weight);
+
+        assert_eq!(
+            Observation::new(
+                Addr::unchecked("feeder1"),
+                DEFAULT_WEIGHT,
+                Timestamp::from_seconds(100),
+                price,
+            ),
+            cosmwasm_std::from_json(stored_no_weight).expect("deserialization passed")
+        );
+    }
+}
@@ -1,14 +1,17 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Add};
 
 use finance::{duration::Duration, fraction::Fraction, price::Price, ratio::Rational};
 use sdk::cosmwasm_std::{Addr, Timestamp};
 
+use crate::feeders::FeederWeight;
+
 use super::observation::Observation;
 
 /// Builds an infinite iterator of samples
 ///
 /// It loops over #Observation instances, groups them by time in periods,
-/// takes the last by feeder, and computes an average for each period.
+/// takes the last by feeder, and computes an average for each period
+/// weighted by the feeder weights.
 /// If there are no observations for a period, the sample from the last
 /// period is yielded again.
 pub fn from_observations<'a, Observations, C, QuoteC>(
@@ -78,7 +81,7 @@ where
     sample_start: Timestamp,
     sample_span: Duration,
     consumed: Option<IterO::Item>,
-    sample_prices: HashMap<&'a Addr, Price<C, QuoteC>>,
+    sample_prices: HashMap<&'a Addr, (Price<C, QuoteC>, FeederWeight)>,
     last_sample: <Self as Iterator>::Item,
 }
 
@@ -102,15 +105,22 @@ where
     }
 
     fn end_of_period(&mut self) {
-        let prices_number = self.sample_prices.len();
-        if prices_number > 0 {
-            let mut values = self.sample_prices.values();
-            let first = values
-                .next()
+        let total_weight: usize = self
+            .sample_prices
+            .values()
+            .map(|&(_price, weight)| usize::from(weight))
+            .sum();
+        if total_weight > 0 {
+            let sum = self
+                .sample_prices
+                .values()
+                .map(|&(price, weight)| {
+                    Fraction::<usize>::of(&Rational::new(usize::from(weight), 1), price)
+                })
+                .reduce(Add::add)
                 .expect("should have been checked that there is at least one member");
 
-            let sum = values.fold(*first, |acc, current| acc + *current);
-            let part = Rational::new(1, prices_number);
+            let part = Rational::new(1, total_weight);
             let avg = Fraction::<usize>::of(&part, sum);
             self.last_sample = Sample { price: Some(avg) }
         }
@@ -139,7 +149,8 @@ where
                 self.consumed = Some(o);
                 break;
             } else {
-                self.sample_prices.insert(o.feeder(), o.price());
+                self.sample_prices
+                    .insert(o.feeder(), (o.price(), o.feeder_weight()));
             }
         }
         self.end_of_period();
@@ -153,9 +164,12 @@ mod test {
     use finance::{coin::Amount, duration::Duration, price};
     use sdk::cosmwasm_std::{Addr, Timestamp};
 
-    use crate::feed::{
-        observation::Observation,
-        sample::{self, Sample},
+    use crate::{
+        feed::{
+            observation::Observation,
+            sample::{self, Sample},
+        },
+        feeders::{FeederWeight, DEFAULT_WEIGHT},
     };
 
     type TheCurrency = SuperGroupTestC4;
@@ -166,7 +180,7 @@ mod test {
         let start_from = Timestamp::from_seconds(150);
         let t1 = Timestamp::from_seconds(200);
         let p1 = price(1, 12000);
-        let obs = [Observation::new(feeder1(), t1, p1)];
+        let obs = [Observation::new(feeder1(), DEFAULT_WEIGHT, t1, p1)];
 
         let mut samples =
             sample::from_observations(obs.iter(), start_from, Duration::from_secs(25));
@@ -186,10 +200,10 @@ mod test {
         let p2 = price(1, 13000);
         let p3 = price(1, 14000);
         let obs = vec![
-            Observation::new(feeder1(), t11, p1), // first period
-            Observation::new(feeder1(), t21, p2), // second period
-            Observation::new(feeder2(), t21, p3),
-            Observation::new(feeder1(), t22, p3),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t11, p1), // first period
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t21, p2), // second period
+            Observation::new(feeder2(), DEFAULT_WEIGHT, t21, p3),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t22, p3),
         ];
 
         let mut samples =
@@ -215,15 +229,15 @@ mod test {
         let p13 = p2;
         let p23 = price(1, 13500);
         let obs = vec![
-            Observation::new(feeder1(), t11, p1), // first period
-            Observation::new(feeder1(), t11, p3),
-            Observation::new(feeder2(), t11, p1),
-            Observation::new(feeder1(), t21, p2), // second period
-            Observation::new(feeder2(), t21, p3),
-            Observation::new(feeder1(), t22, p2),
-            Observation::new(feeder2(), t31, p2), // third period
-            Observation::new(feeder2(), t32, p1),
-            Observation::new(feeder1(), t32, p1),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t11, p1), // first period
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t11, p3),
+            Observation::new(feeder2(), DEFAULT_WEIGHT, t11, p1),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t21, p2), // second period
+            Observation::new(feeder2(), DEFAULT_WEIGHT, t21, p3),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t22, p2),
+            Observation::new(feeder2(), DEFAULT_WEIGHT, t31, p2), // third period
+            Observation::new(feeder2(), DEFAULT_WEIGHT, t32, p1),
+            Observation::new(feeder1(), DEFAULT_WEIGHT, t32, p1),
         ];
 
         let mut samples =
@@ -234,6 +248,43 @@ mod test {
         assert_eq!(Some(Sample { price: Some(p1) }), samples.next());
     }
 
+    #[test]
+    fn weighted_observations() {
+        let start_from = Timestamp::from_seconds(150);
+        let t1 = Timestamp::from_seconds(160);
+        let p1 = price(1, 12000);
+        let p2 = price(1, 15000);
+
+        let weighted_sample = |weight2: FeederWeight| {
+            let obs = [
+                Observation::new(feeder1(), DEFAULT_WEIGHT, t1, p1),
+                Observation::new(feeder2(), weight2, t1, p2),
+            ];
+            let sample =
+                sample::from_observations(obs.iter(), start_from, Duration::from_secs(25)).next();
+            sample
+        };
+
+        assert_eq!(
+            Some(Sample {
+                price: Some(price(1, 13500))
+            }),
+            weighted_sample(DEFAULT_WEIGHT)
+        );
+        assert_eq!(
+            Some(Sample {
+                price: Some(price(1, 14000))
+            }),
+            weighted_sample(2)
+        );
+        assert_eq!(
+            Some(Sample {
+                price: Some(price(1, 14500))
+            }),
+            weighted_sample(5)
+        );
+    }
+
     fn price(of: Amount, is: Amount) -> price::Price<TheCurrency, TheQuote> {
         price::total_of(of.into()).is(is.into())
     }
@@ -4,9 +4,15 @@ use thiserror::Error;
 
 use sdk::{
     cosmwasm_std::{Addr, DepsMut, StdError, StdResult, Storage},
-    cw_storage_plus::Item,
+    cw_storage_plus::{Item, Map},
 };
 
+/// The weight of the observations of a feeder in the price aggregation
+pub type FeederWeight = u16;
+
+/// The weight of a feeder registered with no explicit one
+pub const DEFAULT_WEIGHT: FeederWeight = 1;
+
 /// Errors returned from Feeders
 #[derive(Error, Debug, PartialEq)]
 pub enum PriceFeedersError {
@@ -19,53 +25,87 @@ pub enum PriceFeedersError {
     #[error("Given address not registered as a price feeder")]
     FeederNotRegistered {},
 
+    #[error("The weight of a price feeder should be greater than zero")]
+    ZeroWeight {},
+
     #[error("Unauthorized")]
     Unauthorized {},
 }
 
 // state/logic
-pub struct PriceFeeders(Item<HashSet<Addr>>);
+pub struct PriceFeeders {
+    feeders: Item<HashSet<Addr>>,
+    /// The feeders with no weight stored are of [`DEFAULT_WEIGHT`]
+    weights: Map<Addr, FeederWeight>,
+}
 
 // this is the core business logic we expose
 impl PriceFeeders {
-    pub const fn new(namespace: &'static str) -> Self {
-        Self(Item::new(namespace))
+    pub const fn new(namespace: &'static str, weights_namespace: &'static str) -> Self {
+        Self {
+            feeders: Item::new(namespace),
+            weights: Map::new(weights_namespace),
+        }
     }
 
     pub fn get(&self, storage: &dyn Storage) -> StdResult<HashSet<Addr>> {
-        self.0.may_load(storage).map(Option::unwrap_or_default)
+        self.feeders
+            .may_load(storage)
+            .map(Option::unwrap_or_default)
     }
 
     pub fn is_registered(&self, storage: &dyn Storage, address: &Addr) -> StdResult<bool> {
-        self.0
+        self.feeders
             .may_load(storage)
             .map(|maybe_addrs: Option<HashSet<Addr>>| {
                 maybe_addrs.is_some_and(|addrs: HashSet<Addr>| addrs.contains(address))
             })
     }
 
-    pub fn register(&self, deps: DepsMut<'_>, feeder: Addr) -> Result<(), PriceFeedersError> {
-        let mut db = self.0.may_load(deps.storage)?.unwrap_or_default();
+    /// The weight of a feeder
+    ///
+    /// It is not checked whether the feeder is registered.
+    pub fn weight(&self, storage: &dyn Storage, feeder: &Addr) -> StdResult<FeederWeight> {
+        self.weights
+            .may_load(storage, feeder.clone())
+            .map(|weight| weight.unwrap_or(DEFAULT_WEIGHT))
+    }
+
+    pub fn register(
+        &self,
+        deps: DepsMut<'_>,
+        feeder: Addr,
+        weight: FeederWeight,
+    ) -> Result<(), PriceFeedersError> {
+        if weight == 0 {
+            return Err(PriceFeedersError::ZeroWeight {});
+        }
+
+        let mut db = self.feeders.may_load(deps.storage)?.unwrap_or_default();
 
         if db.contains(&feeder) {
             return Err(PriceFeedersError::FeederAlreadyRegistered {});
         }
 
+        self.weights.save(deps.storage, feeder.clone(), &weight)?;
+
         db.insert(feeder);
 
-        self.0.save(deps.storage, &db)?;
+        self.feeders.save(deps.storage, &db)?;
 
         Ok(())
     }
 
     pub fn remove(&self, deps: DepsMut<'_>, feeder: &Addr) -> Result<(), PriceFeedersError> {
-        self.0
+        self.weights.remove(deps.storage, feeder.clone());
+
+        self.feeders
             .may_load(deps.storage)
             .and_then(|feeders| {
                 feeders.map_or(const { Ok(()) }, |mut feeders| {
                     feeders.remove(feeder);
 
-                    self.0.save(deps.storage, &feeders)
+                    self.feeders.save(deps.storage, &feeders)
                 })
             })
             .map_err(Into::into)
@@ -76,12 +116,14 @@ impl PriceFeeders {
 mod tests {
     use sdk::cosmwasm_std::{testing, Addr};
 
-    use crate::feeders::PriceFeeders;
+    use crate::feeders::{PriceFeeders, PriceFeedersError, DEFAULT_WEIGHT};
+
+    const FEEDERS: PriceFeeders = PriceFeeders::new("storage_namespace", "weights_namespace");
 
     #[test]
     fn remove_empty() {
         let mut deps = testing::mock_dependencies();
-        let feeders = PriceFeeders::new("storage_namespace");
+        let feeders = FEEDERS;
         feeders
             .remove(deps.as_mut(), &Addr::unchecked("test_feeder"))
             .unwrap();
@@ -90,13 +132,49 @@ mod tests {
     #[test]
     fn remove_existing() {
         let mut deps = testing::mock_dependencies();
-        let feeders = PriceFeeders::new("storage_namespace");
+        let feeders = FEEDERS;
         let new_feeder = Addr::unchecked("feeder34");
-        feeders.register(deps.as_mut(), new_feeder.clone()).unwrap();
+        feeders
+            .register(deps.as_mut(), new_feeder.clone(), 3)
+            .unwrap();
         assert_eq!(Ok(true), feeders.is_registered(&deps.storage, &new_feeder));
 
         feeders.remove(deps.as_mut(), &new_feeder).unwrap();
 
         assert_eq!(Ok(false), feeders.is_registered(&deps.storage, &new_feeder));
+        assert_eq!(
+            Ok(DEFAULT_WEIGHT),
+            feeders.weight(&deps.storage, &new_feeder)
+        );
+    }
+
+    #[test]
+    fn register_weight() {
+        let mut deps = testing::mock_dependencies();
+        let feeders = FEEDERS;
+        let feeder1 = Addr::unchecked("feeder1");
+        let feeder2 = Addr::unchecked("feeder2");
+
+        assert_eq!(Ok(DEFAULT_WEIGHT), feeders.weight(&deps.storage, &feeder1));
+
+        feeders
+            .register(deps.as_mut(), feeder1.clone(), DEFAULT_WEIGHT)
+            .unwrap();
+        feeders.register(deps.as_mut(), feeder2.clone(), 5).unwrap();
+        assert_eq!(Ok(DEFAULT_WEIGHT), feeders.weight(&deps.storage, &feeder1));
+        assert_eq!(Ok(5), feeders.weight(&deps.storage, &feeder2));
+    }
+
+    #[test]
+    fn register_zero_weight() {
+        let mut deps = testing::mock_dependencies();
+        let feeders = FEEDERS;
+        let feeder = Addr::unchecked("feeder1");
+
+        assert_eq!(
+            Err(PriceFeedersError::ZeroWeight {}),
+            feeders.register(deps.as_mut(), feeder.clone(), 0)
+        );
+        assert_eq!(Ok(false), feeders.is_registered(&deps.storage, &feeder));
     }
 }
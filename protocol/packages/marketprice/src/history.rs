@@ -0,0 +1,266 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use currency::{CurrencyDTO, Group};
+use sdk::{
+    cosmwasm_std::{Storage, Timestamp},
+    cw_storage_plus::Deque,
+    schemars::{self, JsonSchema},
+};
+
+use crate::{
+    alarms::prefix::Prefix,
+    error::{PriceFeedsError, Result},
+};
+
+/// The maximum number of samples retained per currency
+///
+/// Each sample takes around 200 bytes of storage, so the history of a currency
+/// may occupy up to 20KB. Since a sample is recorded on each feed transaction
+/// the depth is also proportional to the extra gas spent by the feeders.
+pub const MAX_DEPTH: u16 = 100;
+
+/// An aggregated price as calculated at a given time
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct Sample<Price> {
+    pub at: Timestamp,
+    pub price: Price,
+}
+
+impl<Price> Sample<Price> {
+    pub const fn new(at: Timestamp, price: Price) -> Self {
+        Self { at, price }
+    }
+}
+
+/// A ring buffer of the latest price samples of a currency
+///
+/// The samples are ordered by time, the oldest first.
+pub struct History<'storage, S, Price>
+where
+    S: Deref<Target = dyn Storage + 'storage>,
+{
+    samples: Deque<Sample<Price>>,
+    store: S,
+}
+
+impl<'storage, S, Price> History<'storage, S, Price>
+where
+    S: Deref<Target = dyn Storage + 'storage>,
+    Price: Serialize + DeserializeOwned,
+{
+    pub fn new<G>(root_ns: &str, currency: &CurrencyDTO<G>, store: S) -> Self
+    where
+        G: Group,
+    {
+        Self {
+            samples: Deque::new_dyn(format!("{}_{}", root_ns, currency.first_key())),
+            store,
+        }
+    }
+
+    pub fn len(&self) -> Result<usize> {
+        self.samples
+            .len(self.store.deref())
+            .map(|len| len.try_into().expect("u32 to fit in usize"))
+            .map_err(PriceFeedsError::FeedRead)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    pub fn samples(&self) -> Result<Vec<Sample<Price>>> {
+        self.samples
+            .iter(self.store.deref())
+            .and_then(Iterator::collect)
+            .map_err(PriceFeedsError::FeedRead)
+    }
+
+    /// Find the latest sample taken at or before `at`
+    ///
+    /// Return `None` if `at` predates the retained history.
+    pub fn sample_at(&self, at: Timestamp) -> Result<Option<Sample<Price>>> {
+        self.samples
+            .iter(self.store.deref())
+            .and_then(|samples| {
+                samples
+                    .rev()
+                    .find(|may_sample| may_sample.as_ref().map_or(true, |sample| sample.at <= at))
+                    .transpose()
+            })
+            .map_err(PriceFeedsError::FeedRead)
+    }
+}
+
+impl<'storage, S, Price> History<'storage, S, Price>
+where
+    S: Deref<Target = dyn Storage + 'storage> + DerefMut,
+    Price: Serialize + DeserializeOwned,
+{
+    /// Record a newer sample evicting the oldest ones beyond `depth`
+    ///
+    /// A sample taken at the same time as the latest one replaces it.
+    /// The sample time must always flow monotonically forward!
+    pub fn record(&mut self, sample: Sample<Price>, depth: u16) -> Result<()> {
+        debug_assert!(depth <= MAX_DEPTH);
+
+        self.samples
+            .back(self.store.deref())
+            .map_err(PriceFeedsError::FeedRead)
+            .and_then(|may_last| match may_last {
+                Some(last) if last.at == sample.at => self
+                    .samples
+                    .pop_back(self.store.deref_mut())
+                    .map(|_| ())
+                    .map_err(PriceFeedsError::FeedRemove),
+                Some(last) => {
+                    debug_assert!(last.at < sample.at);
+                    Ok(())
+                }
+                None => Ok(()),
+            })
+            .and_then(|()| {
+                self.samples
+                    .push_back(self.store.deref_mut(), &sample)
+                    .map_err(PriceFeedsError::FeedPush)
+            })
+            .and_then(|()| self.truncate(depth))
+    }
+
+    /// Evict the oldest samples until no more than `depth` remain
+    pub fn truncate(&mut self, depth: u16) -> Result<()> {
+        self.len().and_then(|len| {
+            (usize::from(depth)..len).try_for_each(|_| {
+                self.samples
+                    .pop_front(self.store.deref_mut())
+                    .map(|_| ())
+                    .map_err(PriceFeedsError::FeedRemove)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currency::test::{SuperGroup, SuperGroupTestC1, SuperGroupTestC2};
+    use sdk::cosmwasm_std::{testing::MockStorage, Storage, Timestamp};
+
+    use super::{History, Sample};
+
+    const ROOT_NS: &str = "history";
+    const DEPTH: u16 = 3;
+
+    type TestHistory<'storage> = History<'storage, &'storage mut dyn Storage, u32>;
+
+    #[test]
+    fn retains_depth() {
+        let mut store = MockStorage::new();
+        let mut history = history(&mut store);
+        assert!(history.is_empty().unwrap());
+
+        (1..=u32::from(DEPTH))
+            .try_for_each(|i| history.record(sample(i), DEPTH))
+            .unwrap();
+        assert_eq!(usize::from(DEPTH), history.len().unwrap());
+        assert_eq!(
+            vec![sample(1), sample(2), sample(3)],
+            history.samples().unwrap()
+        );
+    }
+
+    #[test]
+    fn evicts_oldest() {
+        let mut store = MockStorage::new();
+        let mut history = history(&mut store);
+
+        (1..=7)
+            .try_for_each(|i| history.record(sample(i), DEPTH))
+            .unwrap();
+        assert_eq!(usize::from(DEPTH), history.len().unwrap());
+        assert_eq!(
+            vec![sample(5), sample(6), sample(7)],
+            history.samples().unwrap()
+        );
+
+        history.truncate(1).unwrap();
+        assert_eq!(vec![sample(7)], history.samples().unwrap());
+
+        history.truncate(0).unwrap();
+        assert!(history.is_empty().unwrap());
+    }
+
+    #[test]
+    fn same_time_replaces() {
+        let mut store = MockStorage::new();
+        let mut history = history(&mut store);
+
+        history.record(sample(1), DEPTH).unwrap();
+        history.record(sample(2), DEPTH).unwrap();
+        history
+            .record(Sample::new(Timestamp::from_seconds(2), 20), DEPTH)
+            .unwrap();
+        assert_eq!(
+            vec![sample(1), Sample::new(Timestamp::from_seconds(2), 20)],
+            history.samples().unwrap()
+        );
+    }
+
+    #[test]
+    fn sample_at() {
+        let mut store = MockStorage::new();
+        let mut history = history(&mut store);
+        assert_eq!(None, history.sample_at(Timestamp::from_seconds(2)).unwrap());
+
+        [2, 4, 6]
+            .into_iter()
+            .try_for_each(|i| history.record(sample(i), DEPTH))
+            .unwrap();
+        assert_eq!(None, history.sample_at(Timestamp::from_seconds(1)).unwrap());
+        assert_eq!(
+            Some(sample(2)),
+            history.sample_at(Timestamp::from_seconds(2)).unwrap()
+        );
+        assert_eq!(
+            Some(sample(4)),
+            history.sample_at(Timestamp::from_seconds(5)).unwrap()
+        );
+        assert_eq!(
+            Some(sample(6)),
+            history.sample_at(Timestamp::from_seconds(60)).unwrap()
+        );
+    }
+
+    #[test]
+    fn separate_currencies() {
+        let mut store = MockStorage::new();
+        History::<'_, &mut dyn Storage, u32>::new(
+            ROOT_NS,
+            &currency::dto::<SuperGroupTestC1, SuperGroup>(),
+            &mut store as &mut dyn Storage,
+        )
+        .record(sample(1), DEPTH)
+        .unwrap();
+
+        let other = History::<'_, &dyn Storage, u32>::new(
+            ROOT_NS,
+            &currency::dto::<SuperGroupTestC2, SuperGroup>(),
+            &store as &dyn Storage,
+        );
+        assert!(other.is_empty().unwrap());
+    }
+
+    fn history(store: &mut MockStorage) -> TestHistory<'_> {
+        History::new(
+            ROOT_NS,
+            &currency::dto::<SuperGroupTestC1, SuperGroup>(),
+            store as &mut dyn Storage,
+        )
+    }
+
+    fn sample(at_secs: u32) -> Sample<u32> {
+        Sample::new(Timestamp::from_seconds(at_secs.into()), at_secs)
+    }
+}
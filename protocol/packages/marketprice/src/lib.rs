@@ -5,6 +5,7 @@ pub mod config;
 pub mod error;
 mod feed;
 pub mod feeders;
+pub mod history;
 pub mod market_price;
 
 #[cfg(test)]
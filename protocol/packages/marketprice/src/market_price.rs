@@ -15,6 +15,7 @@ use crate::{
     config::Config,
     error::PriceFeedsError,
     feed::{ObservationsReadRepo, ObservationsRepo, PriceFeed},
+    feeders::FeederWeight,
 };
 
 pub struct PriceFeeds<'config, PriceG, ObservationsRepoImpl> {
@@ -170,16 +171,19 @@ where
 {
     /// Feed new price observations
     ///
+    /// The observations are weighted by the `sender_weight` in the price calculation.
     /// The time `at` must always flow monotonically forward!
     pub fn feed(
         &mut self,
         at: Timestamp,
         sender_raw: Addr,
+        sender_weight: FeederWeight,
         prices: &[PriceDTO<PriceG>],
     ) -> Result<(), PriceFeedsError> {
         prices.iter().try_for_each(|price| {
             self.add_observation(
                 sender_raw.clone(),
+                sender_weight,
                 at,
                 price,
                 &self.config.feed_valid_since(at),
@@ -190,6 +194,7 @@ where
     fn add_observation(
         &mut self,
         from: Addr,
+        weight: FeederWeight,
         at: Timestamp,
         price: &PriceDTO<PriceG>,
         valid_since: &Timestamp,
@@ -203,6 +208,7 @@ where
             amount_c: CurrencyDTO<G>,
             quote_c: CurrencyDTO<G>,
             from: Addr,
+            weight: FeederWeight,
             at: Timestamp,
             valid_since: &'since Timestamp,
             group: PhantomData<G>,
@@ -226,7 +232,7 @@ where
                     self.observations
                         .observations::<C, QuoteC>(&self.amount_c, &self.quote_c),
                 )
-                .add_observation(self.from, self.at, price, self.valid_since)
+                .add_observation(self.from, self.weight, self.at, price, self.valid_since)
                 .map(mem::drop)
             }
         }
@@ -237,6 +243,7 @@ where
                 amount_c: price.base().currency(),
                 quote_c: price.quote().currency(),
                 from,
+                weight,
                 at,
                 valid_since,
                 group: PhantomData,
@@ -413,7 +420,7 @@ mod test {
     };
     use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage, Timestamp};
 
-    use crate::{error::PriceFeedsError, market_price::Config, Repo};
+    use crate::{error::PriceFeedsError, feeders::DEFAULT_WEIGHT, market_price::Config, Repo};
 
     use super::PriceFeeds;
 
@@ -469,7 +476,12 @@ mod test {
         let storage_dyn_ref: &mut dyn Storage = &mut storage;
         let mut feeds = PriceFeeds::new(Repo::new(ROOT_NS, storage_dyn_ref), &config);
         feeds
-            .feed(NOW, Addr::unchecked(FEEDER), &[build_price().into()])
+            .feed(
+                NOW,
+                Addr::unchecked(FEEDER),
+                DEFAULT_WEIGHT,
+                &[build_price().into()],
+            )
             .unwrap();
 
         assert_eq!(
@@ -515,6 +527,7 @@ mod test {
             .feed(
                 NOW,
                 Addr::unchecked(FEEDER),
+                DEFAULT_WEIGHT,
                 &[new_price110.into(), new_price21.into(), new_price14.into()],
             )
             .unwrap();
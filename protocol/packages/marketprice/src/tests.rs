@@ -22,7 +22,10 @@ use sdk::{
 use crate::feed::ObservationsRepo;
 use crate::Repo;
 use crate::{
-    config::Config, error::PriceFeedsError, feeders::PriceFeeders, market_price::PriceFeeds,
+    config::Config,
+    error::PriceFeedsError,
+    feeders::{PriceFeeders, DEFAULT_WEIGHT},
+    market_price::PriceFeeds,
 };
 
 const ROOT_NS: &str = "root_ns";
@@ -35,12 +38,14 @@ const DISCOUNTING_FACTOR: Percent = Percent::from_permille(750);
 fn register_feeder() {
     let mut deps = mock_dependencies();
 
-    let control = PriceFeeders::new("foo");
+    let control = PriceFeeders::new("foo", "foo_weights");
     let f_address = testing::user("address1");
     let resp = control.is_registered(&deps.storage, &f_address).unwrap();
     assert!(!resp);
 
-    control.register(deps.as_mut(), f_address.clone()).unwrap();
+    control
+        .register(deps.as_mut(), f_address.clone(), DEFAULT_WEIGHT)
+        .unwrap();
 
     let resp = control.is_registered(&deps.storage, &f_address).unwrap();
     assert!(resp);
@@ -49,14 +54,18 @@ fn register_feeder() {
     assert_eq!(1, feeders.len());
 
     // should return error that address is already added
-    let res = control.register(deps.as_mut(), f_address);
+    let res = control.register(deps.as_mut(), f_address, DEFAULT_WEIGHT);
     assert!(res.is_err());
 
     let f_address = testing::user("address2");
-    control.register(deps.as_mut(), f_address).unwrap();
+    control
+        .register(deps.as_mut(), f_address, DEFAULT_WEIGHT)
+        .unwrap();
 
     let f_address = testing::user("address3");
-    control.register(deps.as_mut(), f_address).unwrap();
+    control
+        .register(deps.as_mut(), f_address, DEFAULT_WEIGHT)
+        .unwrap();
 
     let feeders = control.get(&deps.storage).unwrap();
     assert_eq!(3, feeders.len());
@@ -101,7 +110,7 @@ fn marketprice_add_feed_empty_vec() {
     let ts = Timestamp::from_seconds(now.as_secs());
 
     let prices: Vec<PriceDTO<SuperGroup>> = Vec::new();
-    market.feed(ts, f_address, &prices).unwrap();
+    market.feed(ts, f_address, DEFAULT_WEIGHT, &prices).unwrap();
 }
 
 #[test]
@@ -123,7 +132,7 @@ fn marketprice_add_feed() {
         .unwrap();
     let ts = Timestamp::from_seconds(now.as_secs());
 
-    market.feed(ts, f_address, &prices).unwrap();
+    market.feed(ts, f_address, DEFAULT_WEIGHT, &prices).unwrap();
     let err = market
         .price::<SuperGroupTestC3, SuperGroup, _>(
             ts,
@@ -322,7 +331,9 @@ where
 
     let price = PriceDTO::<G>::from(price);
 
-    market.feed(ts, f_address, &[price]).map(|()| ts)
+    market
+        .feed(ts, f_address, DEFAULT_WEIGHT, &[price])
+        .map(|()| ts)
 }
 
 fn config() -> Config {
@@ -2,7 +2,10 @@ use std::marker::PhantomData;
 
 use currency::{self, DexSymbols, Group};
 use dex::swap::{Error, ExactAmountIn, Result};
-use finance::coin::{Amount, CoinDTO};
+use finance::{
+    coin::{Amount, CoinDTO},
+    zero::Zero,
+};
 use oracle::api::swap::{SwapPath, SwapTarget};
 use platform::{
     coin_legacy,
@@ -50,6 +53,7 @@ where
         trx: &mut Transaction,
         sender: HostAccount,
         token_in: &CoinDTO<GIn>,
+        min_token_out: Amount,
         swap_path: &SwapPath<GSwap>,
     ) -> Result<()>
     where
@@ -58,11 +62,13 @@ where
     {
         debug_assert!(!swap_path.is_empty());
         let token_in = to_dex_proto_coin(token_in)?;
+        // None disables checks on the received amount
+        let minimum_receive = (min_token_out != Amount::ZERO).then(|| min_token_out.into());
 
         cosmwasm_std::to_json_vec(&ExecuteMsg::ExecuteSwapOperations {
             operations: to_operations::<GSwap>(&token_in.denom, swap_path),
-            minimum_receive: None, // disable checks on the received amount
-            to: None,              // means the sender
+            minimum_receive,
+            to: None,                     // means the sender
             max_spread: Some(MAX_IMPACT), // if None that would be equivalent to `astroport::pair::DEFAULT_SLIPPAGE`, i.e. 0.5%
         })
         .map_err(Into::into)
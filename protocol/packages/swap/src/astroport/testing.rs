@@ -47,7 +47,7 @@ where
 
         let ExecuteMsg::ExecuteSwapOperations {
             operations,
-            minimum_receive: None {},
+            minimum_receive,
             to: None {},
             max_spread: Some(super::MAX_IMPACT),
         } = cosmwasm_std::from_json(msg).unwrap_or_else(|_| {
@@ -65,6 +65,7 @@ where
 
         SwapRequest {
             token_in,
+            min_token_out: minimum_receive.map_or(Amount::ZERO, Into::into),
             swap_path,
         }
     }
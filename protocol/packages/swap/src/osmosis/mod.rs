@@ -21,6 +21,9 @@ mod testing;
 type RequestMsg = MsgSwapExactAmountIn;
 type ResponseMsg = MsgSwapExactAmountInResponse;
 
+/// The least minimum output the Dex accepts in a swap request
+const MIN_OUT_AMOUNT: Amount = 1;
+
 pub enum Impl
 where
     Self: ExactAmountIn, {}
@@ -30,6 +33,7 @@ impl ExactAmountIn for Impl {
         trx: &mut Transaction,
         sender: HostAccount,
         token_in: &CoinDTO<GIn>,
+        min_token_out: Amount,
         swap_path: &SwapPath<GSwap>,
     ) -> Result<()>
     where
@@ -40,11 +44,10 @@ impl ExactAmountIn for Impl {
         // into the oracle in order to calculate the tokenOut as per the formula at
         // https://docs.osmosis.zone/osmosis-core/modules/gamm/#swap.
         // Then apply the parameterized maximum slippage to get the minimum amount.
-        // For the first version, we accept whatever price impact and slippage.
-        const MIN_OUT_AMOUNT: &str = "1";
+        // Unless the caller provides a minimum, we accept whatever price impact and slippage.
         let routes = to_route::<GSwap>(swap_path);
         let token_in = Some(to_dex_cwcoin(token_in)?);
-        let token_out_min_amount = MIN_OUT_AMOUNT.into();
+        let token_out_min_amount = min_token_out.max(MIN_OUT_AMOUNT).to_string();
         let msg = RequestMsg {
             sender: sender.into(),
             routes,
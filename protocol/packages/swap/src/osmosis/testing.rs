@@ -27,12 +27,16 @@ impl ExactAmountInSkel for Impl {
             testing::pattern_match_else(type_name::<RequestMsg>())
         };
 
-        assert_eq!({ token_out_min_amount }, "1");
+        let min_token_out: Amount = token_out_min_amount
+            .parse()
+            .expect("Expected the minimum swap-out amount to be an unsigned integer!");
+        assert!(min_token_out >= super::MIN_OUT_AMOUNT);
 
         let token_in = testing::parse_dex_token(&token_in.amount, &token_in.denom);
 
         SwapRequest {
             token_in,
+            min_token_out,
             swap_path: routes
                 .into_iter()
                 .map(
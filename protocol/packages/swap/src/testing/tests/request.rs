@@ -1,6 +1,6 @@
 use currency::test::{SubGroup, SubGroupTestC10, SuperGroup, SuperGroupTestC2, SuperGroupTestC3};
 use dex::swap::ExactAmountIn;
-use finance::coin::{Coin, CoinDTO};
+use finance::coin::{Amount, Coin, CoinDTO};
 use oracle::api::swap::SwapTarget;
 use platform::trx::Transaction;
 use sdk::{
@@ -27,19 +27,28 @@ fn build_and_parse() {
         },
     ];
 
-    let request: CosmosAny = build_request(expected_token_in, expected_swap_path.clone());
+    let expected_min_token_out: Amount = 15;
+
+    let request: CosmosAny = build_request(
+        expected_token_in,
+        expected_min_token_out,
+        expected_swap_path.clone(),
+    );
 
     let SwapRequest {
         token_in,
+        min_token_out,
         swap_path,
     } = <Impl as ExactAmountInSkel>::parse_request::<SubGroup, SuperGroup>(request);
 
     assert_eq!(token_in, expected_token_in);
+    assert_eq!(min_token_out, expected_min_token_out);
     assert_eq!(swap_path, expected_swap_path);
 }
 
 fn build_request(
     expected_token_in: CoinDTO<SubGroup>,
+    expected_min_token_out: Amount,
     expected_swap_path: Vec<SwapTarget<SuperGroup>>,
 ) -> CosmosAny {
     let mut tx = Transaction::default();
@@ -48,6 +57,7 @@ fn build_request(
         &mut tx,
         String::from("host_account").try_into().unwrap(),
         &expected_token_in,
+        expected_min_token_out,
         &expected_swap_path,
     )
     .unwrap();
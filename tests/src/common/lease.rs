@@ -1,38 +1,26 @@
-use currencies::{LeaseGroup, PaymentGroup};
-use currency::{Currency, CurrencyDTO, CurrencyDef};
+use currencies::PaymentGroup;
+use currency::CurrencyDef;
 use finance::{
-    coin::Coin,
-    duration::{Duration, Seconds},
-    liability::Liability,
-    percent::Percent,
+    coin::{Amount, Coin},
+    duration::Seconds,
 };
 use lease::{
-    api::{
-        open::{
-            ConnectionParams, Ics20Channel, LoanForm, NewLeaseContract, NewLeaseForm,
-            PositionSpecDTO,
-        },
-        query::{QueryMsg, StateResponse},
-    },
+    api::query::{QueryMsg, StateResponse},
     contract::{execute, instantiate, query, reply, sudo},
 };
 use platform::{coin_legacy::to_cosmwasm, contract::Code};
 use sdk::{
-    cosmwasm_std::{Addr, Coin as CwCoin},
+    cosmwasm_std::{Addr, Coin as CwCoin, StdResult, Timestamp},
     cw_multi_test::AppResponse,
     neutron_sdk::sudo::msg::SudoMsg,
-    testing,
 };
 use swap::testing::SwapRequest;
 
 use super::{
     ibc,
-    test_case::{
-        app::App,
-        response::{RemoteChain as _, ResponseWithInterChainMsgs},
-        TestCase,
-    },
-    CwContractWrapper, ADMIN, USER,
+    swap::DexDenom,
+    test_case::{app::App, response::ResponseWithInterChainMsgs, TestCase},
+    CwContractWrapper,
 };
 
 pub(crate) struct Instantiator;
@@ -45,172 +33,47 @@ impl Instantiator {
 
         app.store_code(Box::new(endpoints))
     }
-
-    #[track_caller]
-    pub fn instantiate<D>(
-        app: &mut App,
-        code: Code,
-        addresses: InstantiatorAddresses,
-        lease_config: InitConfig<D>,
-        config: InstantiatorConfig,
-        dex_connection_id: &str,
-        lease_ica_id: &str,
-    ) -> Addr
-    where
-        D: CurrencyDef,
-    {
-        let msg = Self::lease_instantiate_msg(
-            lease_config.lease_currency,
-            addresses,
-            config,
-            lease_config.max_ltd,
-        );
-
-        let mut response: ResponseWithInterChainMsgs<'_, Addr> = app
-            .instantiate(
-                code,
-                testing::user(ADMIN),
-                &msg,
-                &[to_cosmwasm(lease_config.downpayment)],
-                "lease",
-                None,
-            )
-            .unwrap();
-
-        response.expect_register_ica(dex_connection_id, lease_ica_id);
-
-        response.unwrap_response()
-    }
-
-    fn lease_instantiate_msg(
-        lease_currency: CurrencyDTO<LeaseGroup>,
-        addresses: InstantiatorAddresses,
-        config: InstantiatorConfig,
-        max_ltd: Option<Percent>,
-    ) -> NewLeaseContract {
-        NewLeaseContract {
-            form: NewLeaseForm {
-                customer: config.customer,
-                currency: lease_currency,
-                max_ltd,
-                position_spec: PositionSpecDTO::new(
-                    Liability::new(
-                        config.liability_init_percent,
-                        config.liability_healthy_percent,
-                        config.liability_first_liq_warn,
-                        config.liability_second_liq_warn,
-                        config.liability_third_liq_warn,
-                        config.liability_max_percent,
-                        config.liability_recalc_time,
-                    ),
-                    super::lpn_coin(1478),
-                    super::lpn_coin(345),
-                ),
-                loan: LoanForm {
-                    lpp: addresses.lpp,
-                    profit: addresses.profit,
-                    annual_margin_interest: config.annual_margin_interest,
-                    due_period: config.lease_due_period,
-                },
-                reserve: addresses.reserve,
-                time_alarms: addresses.time_alarms,
-                market_price_oracle: addresses.oracle,
-            },
-            dex: config.dex,
-            finalizer: addresses.finalizer,
-        }
-    }
 }
 
-pub(crate) struct InitConfig<D>
-where
-    D: Currency,
-{
-    lease_currency: CurrencyDTO<LeaseGroup>,
-    downpayment: Coin<D>,
-    max_ltd: Option<Percent>,
-}
-
-impl<D> InitConfig<D>
-where
-    D: Currency,
+pub(crate) fn complete_initialization<DownpaymentC, Lpn>(
+    app: &mut App,
+    connection_id: &str,
+    lease_addr: Addr,
+    downpayment: Coin<DownpaymentC>,
+    exp_borrow: Coin<Lpn>,
+) where
+    DownpaymentC: CurrencyDef,
+    Lpn: CurrencyDef,
 {
-    pub fn new(
-        lease_currency: CurrencyDTO<LeaseGroup>,
-        downpayment: Coin<D>,
-        max_ltd: Option<Percent>,
-    ) -> Self {
-        Self {
-            lease_currency,
-            downpayment,
-            max_ltd,
-        }
-    }
-}
-
-pub(crate) struct InstantiatorConfig {
-    //NewLeaseForm
-    pub customer: Addr,
-    // Liability
-    pub liability_init_percent: Percent,
-    pub liability_healthy_percent: Percent,
-    pub liability_first_liq_warn: Percent,
-    pub liability_second_liq_warn: Percent,
-    pub liability_third_liq_warn: Percent,
-    pub liability_max_percent: Percent,
-    pub liability_recalc_time: Duration,
-    // LoanForm
-    pub annual_margin_interest: Percent,
-    pub lease_due_period: Duration,
-    // Dex
-    pub dex: ConnectionParams,
-}
-
-impl Default for InstantiatorConfig {
-    fn default() -> Self {
-        Self {
-            customer: testing::user(USER),
-            liability_init_percent: Percent::from_percent(65),
-            liability_healthy_percent: Percent::from_percent(70),
-            liability_first_liq_warn: Percent::from_percent(73),
-            liability_second_liq_warn: Percent::from_percent(75),
-            liability_third_liq_warn: Percent::from_percent(78),
-            liability_max_percent: Percent::from_percent(80),
-            liability_recalc_time: Duration::from_days(20),
-
-            annual_margin_interest: Percent::from_permille(31),
-            lease_due_period: Duration::from_secs(100),
-
-            dex: ConnectionParams {
-                connection_id: "connection-0".into(),
-                transfer_channel: Ics20Channel {
-                    local_endpoint: "channel-0".into(),
-                    remote_endpoint: "channel-2048".into(),
-                },
-            },
-        }
-    }
-}
+    () = complete_initialization_with_swap(
+        app,
+        connection_id,
+        lease_addr.clone(),
+        downpayment,
+        exp_borrow,
+        |price, _, _| price,
+    )
+    .ignore_response()
+    .unwrap_response();
 
-#[derive(Debug, Clone)]
-pub struct InstantiatorAddresses {
-    pub lpp: Addr,
-    pub time_alarms: Addr,
-    pub oracle: Addr,
-    pub profit: Addr,
-    pub reserve: Addr,
-    pub finalizer: Addr,
+    check_state_opened(app, lease_addr);
 }
 
-pub(crate) fn complete_initialization<DownpaymentC, Lpn>(
-    app: &mut App,
+/// Drive a lease opening up to and including the delivery of the swap response
+///
+/// The swap output amounts are computed by `swap_f`, for example, to simulate a slippage.
+pub(crate) fn complete_initialization_with_swap<'app, DownpaymentC, Lpn, F>(
+    app: &'app mut App,
     connection_id: &str,
     lease_addr: Addr,
     downpayment: Coin<DownpaymentC>,
     exp_borrow: Coin<Lpn>,
-) where
+    swap_f: F,
+) -> ResponseWithInterChainMsgs<'app, AppResponse>
+where
     DownpaymentC: CurrencyDef,
     Lpn: CurrencyDef,
+    F: for<'r, 't> FnMut(Amount, DexDenom<'r>, DexDenom<'t>) -> Amount,
 {
     check_state_opening(app, lease_addr.clone());
 
@@ -244,17 +107,7 @@ pub(crate) fn complete_initialization<DownpaymentC, Lpn>(
         [],
     );
 
-    () = super::swap::do_swap(
-        app,
-        lease_addr.clone(),
-        ica_addr,
-        requests.into_iter(),
-        |price, _, _| price,
-    )
-    .ignore_response()
-    .unwrap_response();
-
-    check_state_opened(app, lease_addr);
+    super::swap::do_swap(app, lease_addr, ica_addr, requests.into_iter(), swap_f)
 }
 
 fn confirm_ica_and_transfer_funds<'r, DownpaymentC, Lpn>(
@@ -290,6 +143,7 @@ where
     assert_eq!(downpayment, to_cosmwasm(exp_downpayment));
 
     check_state_opening(app, lease_addr.clone());
+    check_dex_account_open(app, lease_addr.clone(), &ica_addr);
 
     let mut response: ResponseWithInterChainMsgs<'_, ()> = ibc::do_transfer(
         app,
@@ -348,14 +202,17 @@ fn send_open_ica_response<'r>(
 
 #[track_caller]
 pub(crate) fn fetch_state(app: &App, lease: Addr) -> StateResponse {
+    query_state(app, lease, Seconds::default(), None).unwrap()
+}
+
+pub(crate) fn query_state(
+    app: &App,
+    lease: Addr,
+    due_projection: Seconds,
+    at: Option<Timestamp>,
+) -> StdResult<StateResponse> {
     app.query()
-        .query_wasm_smart(
-            lease,
-            &QueryMsg::State {
-                due_projection: Seconds::default(),
-            },
-        )
-        .unwrap()
+        .query_wasm_smart(lease, &QueryMsg::State { due_projection, at })
 }
 
 #[track_caller]
@@ -365,6 +222,18 @@ fn check_state_opening(app: &mut App, lease: Addr) {
     }
 }
 
+#[track_caller]
+fn check_dex_account_open(app: &mut App, lease: Addr, exp_ica_addr: &Addr) {
+    let StateResponse::Opening {
+        dex_account: Some(dex_account),
+        ..
+    } = fetch_state(app, lease)
+    else {
+        panic!("The Dex account is expected to be present once the ICA is open!");
+    };
+    assert_eq!(exp_ica_addr.as_str(), dex_account.ica_account);
+}
+
 #[track_caller]
 fn check_state_opened(app: &mut App, lease: Addr) {
     if !matches!(fetch_state(app, lease), StateResponse::Opened { .. }) {
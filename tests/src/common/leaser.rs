@@ -1,23 +1,29 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, num::NonZeroU16};
 
 use currencies::{LeaseGroup, PaymentGroup};
-use currency::{CurrencyDef, MemberOf};
+use currency::{CurrencyDTO, CurrencyDef, MemberOf};
 use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent, test};
 use lease::api::{
-    open::{ConnectionParams, Ics20Channel, PositionSpecDTO},
+    open::{ConnectionParams, IcaTimeouts, Ics20Channel, PositionSpecDTO},
     LpnCoinDTO,
 };
 use leaser::{
     execute, instantiate,
-    msg::{InstantiateMsg, QueryMsg, QuoteResponse},
+    msg::{
+        AtRiskResponse, ExecuteMsg, InstantiateMsg, PendingLeasesResponse, QueryMsg,
+        QuoteParamsResponse, QuoteResponse,
+    },
     query, reply, sudo,
 };
 use platform::contract::{Code, CodeId};
-use sdk::{cosmwasm_std::Addr, testing};
+use sdk::{
+    cosmwasm_std::{Addr, StdResult},
+    testing,
+};
 
 use super::{
-    test_case::{app::App, TestCase},
-    CwContractWrapper, ADMIN,
+    test_case::{app::App, response::RemoteChain, TestCase},
+    CwCoin, CwContractWrapper, ADMIN,
 };
 
 pub(crate) struct Instantiator;
@@ -62,6 +68,17 @@ impl Instantiator {
         )
     }
 
+    pub fn dex() -> ConnectionParams {
+        ConnectionParams {
+            connection_id: TestCase::DEX_CONNECTION_ID.into(),
+            transfer_channel: Ics20Channel {
+                local_endpoint: TestCase::LEASER_IBC_CHANNEL.into(),
+                remote_endpoint: "channel-422".into(),
+            },
+            timeouts: IcaTimeouts::default(),
+        }
+    }
+
     pub fn expected_addr() -> Addr {
         testing::user("contract5")
     }
@@ -92,15 +109,11 @@ impl Instantiator {
             lease_interest_rate_margin: Self::INTEREST_RATE_MARGIN,
             lease_position_spec: Self::position_spec(),
             lease_due_period: Self::REPAYMENT_PERIOD,
+            lease_origination_fee: None,
+            lease_open_cooldown: Duration::default(),
             time_alarms: alarms.time_alarm,
             market_price_oracle: alarms.market_price_oracle,
-            dex: ConnectionParams {
-                connection_id: TestCase::DEX_CONNECTION_ID.into(),
-                transfer_channel: Ics20Channel {
-                    local_endpoint: TestCase::LEASER_IBC_CHANNEL.into(),
-                    remote_endpoint: "channel-422".into(),
-                },
-            },
+            dex: Self::dex(),
         };
 
         app.instantiate(code_id, testing::user(ADMIN), &msg, &[], "leaser", None)
@@ -126,18 +139,120 @@ where
     LeaseC: CurrencyDef,
     LeaseC::Group: MemberOf<LeaseGroup>,
 {
+    try_query_quote::<DownpaymentC, LeaseC>(app, leaser, downpayment, max_ltd).unwrap()
+}
+
+pub(crate) fn try_query_quote<DownpaymentC, LeaseC>(
+    app: &App,
+    leaser: Addr,
+    downpayment: Coin<DownpaymentC>,
+    max_ltd: Option<Percent>,
+) -> StdResult<QuoteResponse>
+where
+    DownpaymentC: CurrencyDef,
+    DownpaymentC::Group: MemberOf<PaymentGroup>,
+    LeaseC: CurrencyDef,
+    LeaseC::Group: MemberOf<LeaseGroup>,
+{
+    app.query().query_wasm_smart(
+        leaser,
+        &QueryMsg::Quote {
+            downpayment: test::funds::<_, DownpaymentC>(downpayment.into()),
+            lease_asset: currency::dto::<LeaseC, _>(),
+            max_ltd,
+        },
+    )
+}
+
+pub(crate) fn query_quote_params(app: &App, leaser: Addr) -> QuoteParamsResponse {
+    app.query()
+        .query_wasm_smart(leaser, &QueryMsg::QuoteParams {})
+        .unwrap()
+}
+
+pub(crate) fn query_pending_leases(
+    app: &App,
+    leaser: Addr,
+    start_after: Option<Addr>,
+    limit: Option<NonZeroU16>,
+) -> PendingLeasesResponse {
+    app.query()
+        .query_wasm_smart(leaser, &QueryMsg::PendingLeases { start_after, limit })
+        .unwrap()
+}
+
+pub(crate) fn query_at_risk(
+    app: &App,
+    leaser: Addr,
+    zone: u8,
+    start_after: Option<Addr>,
+    limit: Option<NonZeroU16>,
+) -> AtRiskResponse {
     app.query()
         .query_wasm_smart(
             leaser,
-            &QueryMsg::Quote {
-                downpayment: test::funds::<_, DownpaymentC>(downpayment.into()),
-                lease_asset: currency::dto::<LeaseC, _>(),
-                max_ltd,
+            &QueryMsg::AtRisk {
+                zone,
+                start_after,
+                limit,
             },
         )
         .unwrap()
 }
 
+/// Open a batch of leases of `customer`, one per lease currency and downpayment
+///
+/// The leases are open through the leaser, as in production, with the same
+/// `max_ltd`, and are left awaiting their Dex accounts. Return their addresses
+/// in the same order.
+pub(crate) fn open_leases<ProtocolsRegistry, Treasury, Profit, Reserve, Lpp, Oracle, TimeAlarms>(
+    test_case: &mut TestCase<
+        ProtocolsRegistry,
+        Treasury,
+        Profit,
+        Reserve,
+        Addr,
+        Lpp,
+        Oracle,
+        TimeAlarms,
+    >,
+    customer: Addr,
+    specs: &[(CurrencyDTO<LeaseGroup>, CwCoin)],
+    max_ltd: Option<Percent>,
+) -> Vec<Addr> {
+    let leaser = test_case.address_book.leaser().clone();
+
+    specs
+        .iter()
+        .map(|(currency, downpayment)| {
+            let opened_before = leases(&test_case.app, leaser.clone(), customer.clone());
+
+            let mut response = test_case
+                .app
+                .execute(
+                    customer.clone(),
+                    leaser.clone(),
+                    &ExecuteMsg::OpenLease {
+                        currency: *currency,
+                        max_ltd,
+                        open_order: None,
+                    },
+                    std::slice::from_ref(downpayment),
+                )
+                .unwrap();
+            response.expect_register_ica(TestCase::DEX_CONNECTION_ID, TestCase::LEASE_ICA_ID);
+            () = response.ignore_response().unwrap_response();
+
+            let mut opened = leases(&test_case.app, leaser.clone(), customer.clone())
+                .into_iter()
+                .filter(|lease| !opened_before.contains(lease));
+            let lease = opened.next().expect("a new lease");
+            assert_eq!(None, opened.next());
+            lease
+        })
+        .collect()
+}
+
 pub(crate) fn expect_a_lease(app: &App, leaser: Addr, customer: Addr) -> Addr {
     let leases = leases(app, leaser, customer);
     assert_eq!(1, leases.len());
@@ -1,6 +1,6 @@
 use currencies::Lpns;
 use currency::{CurrencyDef, MemberOf};
-use finance::percent::{bound::BoundToHundredPercent, Percent};
+use finance::percent::{bound::BoundToHundredPercent, Percent, Units as PercentUnits};
 use lpp::{
     borrow::InterestRate,
     contract::{sudo, ContractError},
@@ -92,11 +92,28 @@ pub(crate) fn mock_quote_query(
     deps: Deps<'_>,
     env: Env,
     msg: QueryMsg<Lpns>,
+) -> Result<Binary, ContractError> {
+    quote_query(deps, env, msg, Percent::HUNDRED)
+}
+
+pub(crate) fn mock_max_quote_query(
+    deps: Deps<'_>,
+    env: Env,
+    msg: QueryMsg<Lpns>,
+) -> Result<Binary, ContractError> {
+    quote_query(deps, env, msg, Percent::from_permille(PercentUnits::MAX))
+}
+
+fn quote_query(
+    deps: Deps<'_>,
+    env: Env,
+    msg: QueryMsg<Lpns>,
+    rate: Percent,
 ) -> Result<Binary, ContractError> {
     let res = match msg {
-        QueryMsg::Quote { amount: _amount } => to_json_binary(
-            &lpp::msg::QueryQuoteResponse::QuoteInterestRate(Percent::HUNDRED),
-        ),
+        QueryMsg::Quote { amount: _amount } => {
+            to_json_binary(&lpp::msg::QueryQuoteResponse::QuoteInterestRate(rate))
+        }
         _ => Ok(lpp::contract::query(deps, env, msg)?),
     }?;
 
@@ -52,6 +52,8 @@ impl Instantiator {
                     12,
                     Percent::from_percent(75),
                 ),
+                trusted_source: None,
+                feeds_paused: false,
             },
 
             swap_tree: test_tree::dummy_swap_tree(),
@@ -112,6 +114,7 @@ pub(crate) fn add_feeder<ProtocolsRegistry, Treasury, Profit, Reserve, Leaser, L
             oracle.clone(),
             &SudoMsg::<PriceCurrencies>::RegisterFeeder {
                 feeder_address: feeder.into(),
+                weight: None,
             },
         )
         .unwrap()
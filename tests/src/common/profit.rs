@@ -1,7 +1,7 @@
-use dex::{ConnectionParams, Ics20Channel};
+use dex::{ConnectionParams, IcaTimeouts, Ics20Channel};
 use profit::{
     contract::{execute, instantiate, query, reply, sudo},
-    msg::InstantiateMsg,
+    msg::{InstantiateMsg, ReserveFunding},
     typedefs::CadenceHours,
 };
 use sdk::{cosmwasm_std::Addr, testing};
@@ -23,6 +23,7 @@ impl Instantiator {
         treasury: Addr,
         oracle: Addr,
         timealarms: Addr,
+        reserve_funding: Option<ReserveFunding>,
     ) -> Addr {
         // TODO [Rust 1.70] Convert to static item with OnceCell
         let endpoints = CwContractWrapper::new(execute, instantiate, query)
@@ -42,7 +43,9 @@ impl Instantiator {
                     local_endpoint: TestCase::PROFIT_IBC_CHANNEL.into(),
                     remote_endpoint: "channel-262".into(),
                 },
+                timeouts: IcaTimeouts::default(),
             },
+            reserve_funding,
         };
 
         app.instantiate(code_id, testing::user(ADMIN), &msg, &[], "profit", None)
@@ -1,7 +1,7 @@
 use platform::contract::{Code, CodeId};
 use reserve::{
-    api::InstantiateMsg,
-    contract::{execute, instantiate, query},
+    api::{InstantiateMsg, SurplusReturn},
+    contract::{execute, instantiate, query, sudo},
 };
 use sdk::{cosmwasm_std::Addr, testing};
 
@@ -13,8 +13,12 @@ pub(crate) struct Instantiator;
 
 impl Instantiator {
     #[track_caller]
-    pub fn instantiate(app: &mut App, lease_code: Code) -> Addr {
-        let endpoints = CwContractWrapper::new(execute, instantiate, query);
+    pub fn instantiate(
+        app: &mut App,
+        lease_code: Code,
+        surplus_return: Option<SurplusReturn>,
+    ) -> Addr {
+        let endpoints = CwContractWrapper::new(execute, instantiate, query).with_sudo(sudo);
 
         let code_id = app.store_code(Box::new(endpoints));
         let lease_code_admin = LeaserInstantiator::expected_addr().into(); //the Leaser address
@@ -22,6 +26,7 @@ impl Instantiator {
         let msg = InstantiateMsg {
             lease_code_admin,
             lease_code: CodeId::from(lease_code).into(),
+            surplus_return,
         };
 
         app.instantiate(code_id, testing::user(ADMIN), &msg, &[], "reserve", None)
@@ -161,12 +161,14 @@ where
         },
     );
 
-    app.send_tokens(
-        testing::user(ADMIN),
-        ica_addr,
-        &[CwCoin::new(amount_out, dex_denom_out)],
-    )
-    .unwrap();
+    if amount_out != 0 {
+        app.send_tokens(
+            testing::user(ADMIN),
+            ica_addr,
+            &[CwCoin::new(amount_out, dex_denom_out)],
+        )
+        .unwrap();
+    }
 
     amount_out
 }
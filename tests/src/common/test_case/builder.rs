@@ -6,9 +6,10 @@ use finance::percent::{bound::BoundToHundredPercent, Percent};
 use lpp::borrow::InterestRate;
 use platform::ica::OpenAckVersion;
 use profit::{
-    msg::{ConfigResponse as ProfitConfigResponse, QueryMsg as ProfitQueryMsg},
+    msg::{ConfigResponse as ProfitConfigResponse, QueryMsg as ProfitQueryMsg, ReserveFunding},
     typedefs::CadenceHours,
 };
+use reserve::api::SurplusReturn;
 use sdk::{
     cosmwasm_std::{self, Addr, Coin as CwCoin},
     cw_multi_test::next_block,
@@ -146,6 +147,14 @@ where
     pub fn init_profit(
         self,
         cadence_hours: CadenceHours,
+    ) -> Builder<Lpn, ProtocolsRegistry, Addr, Addr, Reserve, Leaser, Lpp, Addr, Addr> {
+        self.init_profit_with_reserve_funding(cadence_hours, None)
+    }
+
+    pub fn init_profit_with_reserve_funding(
+        self,
+        cadence_hours: CadenceHours,
+        reserve_funding: Option<ReserveFunding>,
     ) -> Builder<Lpn, ProtocolsRegistry, Addr, Addr, Reserve, Leaser, Lpp, Addr, Addr> {
         let Self {
             mut test_case,
@@ -158,6 +167,7 @@ where
             test_case.address_book.treasury().clone(),
             test_case.address_book.oracle().clone(),
             test_case.address_book.time_alarms().clone(),
+            reserve_funding,
         );
 
         Self::send_open_ica_response(&mut test_case, profit_addr.clone());
@@ -185,6 +195,7 @@ where
     ) {
         let ProfitConfigResponse {
             cadence_hours: reported_cadence_hours,
+            ..
         } = test_case
             .app
             .query()
@@ -233,6 +244,14 @@ where
     pub fn init_reserve(
         self,
     ) -> Builder<Lpn, ProtocolsRegistry, Treasury, Profit, Addr, Leaser, Lpp, Oracle, TimeAlarms>
+    {
+        self.init_reserve_with_surplus_return(None)
+    }
+
+    pub fn init_reserve_with_surplus_return(
+        self,
+        surplus_return: Option<SurplusReturn>,
+    ) -> Builder<Lpn, ProtocolsRegistry, Treasury, Profit, Addr, Leaser, Lpp, Oracle, TimeAlarms>
     {
         let Self {
             mut test_case,
@@ -242,6 +261,7 @@ where
         let reserve_addr = ReserveInstantiator::instantiate(
             &mut test_case.app,
             test_case.address_book.lease_code(),
+            surplus_return,
         );
 
         test_case.app.update_block(next_block);
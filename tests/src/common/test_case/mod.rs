@@ -12,13 +12,7 @@ use sdk::{
 };
 use versioning::ProtocolMigrationMessage;
 
-use super::{
-    lease::{
-        InitConfig, Instantiator as LeaseInstantiator, InstantiatorAddresses,
-        InstantiatorConfig as LeaseInstantiatorConfig,
-    },
-    mock_app, CwContractWrapper, ADMIN,
-};
+use super::{lease::Instantiator as LeaseInstantiator, mock_app, CwContractWrapper, ADMIN};
 
 use self::{address_book::AddressBook, app::App};
 
@@ -41,7 +35,7 @@ type OptionalLppEndpoints = Option<
 >;
 
 type OracleError = oracle::error::Error<PriceCurrencies>;
-type OptionalOracleWrapper = Option<
+pub(crate) type OptionalOracleWrapper = Option<
     CwContractWrapper<
         oracle::api::ExecuteMsg<BaseCurrency, BaseCurrencies, AlarmCurrencies, PriceCurrencies>,
         OracleError,
@@ -130,21 +124,14 @@ impl<ProtocolsRegistry, Treasury>
     where
         D: CurrencyDef,
     {
-        LeaseInstantiator::instantiate::<D>(
-            &mut self.app,
-            self.address_book.lease_code(),
-            InstantiatorAddresses {
-                lpp: self.address_book.lpp().clone(),
-                time_alarms: self.address_book.time_alarms().clone(),
-                oracle: self.address_book.oracle().clone(),
-                profit: self.address_book.profit().clone(),
-                reserve: self.address_book.reserve().clone(),
-                finalizer: self.address_book.leaser().clone(),
-            },
-            InitConfig::new(lease_currency, 1000.into(), None),
-            LeaseInstantiatorConfig::default(),
-            TestCase::DEX_CONNECTION_ID,
-            TestCase::LEASE_ICA_ID,
+        let [lease]: [Addr; 1] = super::leaser::open_leases(
+            self,
+            testing::user(ADMIN),
+            &[(lease_currency, super::cwcoin::<D, _>(1000))],
+            None,
         )
+        .try_into()
+        .unwrap();
+        lease
     }
 }
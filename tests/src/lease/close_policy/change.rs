@@ -185,6 +185,134 @@ fn tp_set() {
     assert_trigger_tp_error(err, tp)
 }
 
+#[test]
+fn sl_only_update() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let lease = lease::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let tp = Percent::from_percent(28);
+    let sl = LeaserInstantiator::INITIAL_LTV + Percent::from_permille(1);
+    set_both(&mut test_case, lease.clone(), tp, sl);
+
+    let sl_new = sl + Percent::from_permille(10);
+    super::change_ok(
+        &mut test_case,
+        lease.clone(),
+        None,
+        Some(ChangeCmd::Set(sl_new)),
+    );
+    assert_eq!(
+        ClosePolicy::new(Some(tp), Some(sl_new)),
+        query_policy(&test_case, lease.clone())
+    );
+
+    super::change_ok(&mut test_case, lease.clone(), None, Some(ChangeCmd::Reset));
+    assert_eq!(
+        ClosePolicy::new(Some(tp), None),
+        query_policy(&test_case, lease)
+    );
+}
+
+#[test]
+fn tp_only_update() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let lease = lease::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let tp = Percent::from_percent(28);
+    let sl = LeaserInstantiator::INITIAL_LTV + Percent::from_permille(1);
+    set_both(&mut test_case, lease.clone(), tp, sl);
+
+    let tp_new = tp - Percent::from_percent(3);
+    super::change_ok(
+        &mut test_case,
+        lease.clone(),
+        Some(ChangeCmd::Set(tp_new)),
+        None,
+    );
+    assert_eq!(
+        ClosePolicy::new(Some(tp_new), Some(sl)),
+        query_policy(&test_case, lease.clone())
+    );
+
+    super::change_ok(&mut test_case, lease.clone(), Some(ChangeCmd::Reset), None);
+    assert_eq!(
+        ClosePolicy::new(None, Some(sl)),
+        query_policy(&test_case, lease)
+    );
+}
+
+#[test]
+fn both_update() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let lease = lease::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let tp = Percent::from_percent(28);
+    let sl = LeaserInstantiator::INITIAL_LTV + Percent::from_permille(1);
+    set_both(&mut test_case, lease.clone(), tp, sl);
+
+    let tp_new = tp - Percent::from_percent(3);
+    let sl_new = sl + Percent::from_permille(10);
+    set_both(&mut test_case, lease.clone(), tp_new, sl_new);
+
+    super::change_ok(
+        &mut test_case,
+        lease.clone(),
+        Some(ChangeCmd::Reset),
+        Some(ChangeCmd::Reset),
+    );
+    assert_eq!(ClosePolicy::default(), query_policy(&test_case, lease));
+}
+
+#[test]
+fn remove_not_set() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let lease = lease::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    super::remove_ok(&mut test_case, lease.clone());
+    assert_eq!(ClosePolicy::default(), query_policy(&test_case, lease));
+}
+
+#[test]
+fn remove_set() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let lease = lease::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let tp = Percent::from_percent(28);
+    let sl = LeaserInstantiator::INITIAL_LTV + Percent::from_permille(1);
+    super::change_ok(
+        &mut test_case,
+        lease.clone(),
+        Some(ChangeCmd::Set(tp)),
+        Some(ChangeCmd::Set(sl)),
+    );
+    assert_eq!(
+        ClosePolicy::new(Some(tp), Some(sl)),
+        query_policy(&test_case, lease.clone())
+    );
+
+    super::remove_ok(&mut test_case, lease.clone());
+    assert_eq!(
+        ClosePolicy::default(),
+        query_policy(&test_case, lease.clone())
+    );
+
+    super::remove_ok(&mut test_case, lease.clone());
+    assert_eq!(ClosePolicy::default(), query_policy(&test_case, lease));
+}
+
+fn set_both(test_case: &mut LeaseTestCase, lease: Addr, tp: Percent, sl: Percent) {
+    super::change_ok(
+        test_case,
+        lease.clone(),
+        Some(ChangeCmd::Set(tp)),
+        Some(ChangeCmd::Set(sl)),
+    );
+    assert_eq!(
+        ClosePolicy::new(Some(tp), Some(sl)),
+        query_policy(test_case, lease)
+    );
+}
+
 fn query_policy(test_case: &LeaseTestCase, lease: Addr) -> ClosePolicy {
     let StateResponse::Opened { close_policy, .. } = lease::state_query(test_case, lease) else {
         unreachable!()
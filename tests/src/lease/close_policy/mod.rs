@@ -36,6 +36,20 @@ fn change_ok(
     .unwrap_response()
 }
 
+fn remove_ok(test_case: &mut LeaseTestCase, lease: Addr) {
+    test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::RemoveClosePolicy(),
+            &[],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response()
+}
+
 fn change_err(
     test_case: &mut LeaseTestCase,
     lease: Addr,
@@ -44,6 +44,27 @@ fn trigger_sl() {
     assert_events(&resp, &lease, "stop-loss-ltv", sl);
 }
 
+#[test]
+fn removed_sl_not_triggered() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+
+    let sl = LeaserInstantiator::INITIAL_LTV + Percent::from_permille(1);
+    let lease = open_lease(&mut test_case, None, Some(sl));
+    super::remove_ok(&mut test_case, lease);
+
+    // LeaseC/LpnC = 1.01, the removed stop loss would have fired
+    let resp = lease::deliver_new_price(
+        &mut test_case,
+        Coin::<LeaseCurrency>::from(101),
+        Coin::<LpnCurrency>::from(100),
+    )
+    .unwrap_response();
+    assert!(!resp
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-auto-close-position"));
+}
+
 fn open_lease(test_case: &mut LeaseTestCase, tp: Option<Percent>, sl: Option<Percent>) -> Addr {
     // LeaseC/LpnC = 1
     let lease = lease::open_lease(test_case, DOWNPAYMENT, None);
@@ -2,20 +2,22 @@ use currencies::{testing::PaymentC5, LeaseGroup, PaymentGroup};
 use currency::CurrencyDef;
 use finance::{
     coin::{Amount, Coin},
+    fraction::Fraction,
+    percent::Percent,
     price,
     zero::Zero,
 };
 use lease::{
     api::{
-        position::{FullClose, PartialClose, PositionClose},
-        query::StateResponse,
+        position::{FullClose, PartialClose, Payout, PositionClose},
+        query::{ClosePreviewResponse, MessagePreview, QueryMsg, StateResponse},
         ExecuteMsg,
     },
     error::{ContractError, PositionError},
 };
-use platform::coin_legacy::to_cosmwasm_on_dex;
+use platform::{batch::MsgKind, coin_legacy::to_cosmwasm_on_dex};
 use sdk::{
-    cosmwasm_std::{Addr, Event},
+    cosmwasm_std::{Addr, Event, StdResult},
     cw_multi_test::AppResponse,
     testing,
 };
@@ -40,13 +42,14 @@ fn close_by_another_user() {
     assert_unauthorized(
         &mut test_case,
         lease.clone(),
-        ExecuteMsg::ClosePosition(PositionClose::FullClose(FullClose {})),
+        ExecuteMsg::ClosePosition(PositionClose::FullClose(FullClose::default())),
     );
     assert_unauthorized(
         &mut test_case,
         lease,
         ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
             amount: LeaseCoin::from(1234414).into(),
+            repay_share: None,
         })),
     );
 }
@@ -63,7 +66,7 @@ fn full_close() {
         &mut test_case,
         &customer,
         lease_amount,
-        PositionClose::FullClose(FullClose {}),
+        PositionClose::FullClose(FullClose::default()),
         exp_loan_close,
         exp_change,
         LeaseCoin::ZERO,
@@ -88,6 +91,158 @@ fn full_close() {
     );
 }
 
+#[test]
+fn full_close_payout() {
+    let lease_amount: LeaseCoin = lease_amount();
+    let customer = testing::user(USER);
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let user_balance_before: PaymentCoin = user_balance(&customer, &test_case);
+
+    let exp_loan_close = true;
+    let exp_change = price::total(DOWNPAYMENT, super::price_lpn_of());
+    let lease = do_close(
+        &mut test_case,
+        &customer,
+        lease_amount,
+        PositionClose::FullClose(FullClose {
+            payout: Some(Payout {
+                currency: currency::dto::<PaymentCurrency, _>(),
+                max_slippage: Percent::from_permille(15),
+            }),
+        }),
+        exp_loan_close,
+        exp_change,
+        LeaseCoin::ZERO,
+    );
+    let state = super::state_query(&test_case, lease.clone());
+    assert_eq!(StateResponse::Closed(), state);
+
+    assert_eq!(
+        lease_balance(&test_case, lease),
+        common::cwcoin_as_balance(LeaseCoin::ZERO),
+    );
+
+    leaser::assert_no_leases(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        customer.clone(),
+    );
+
+    assert_eq!(
+        LpnCoin::ZERO,
+        user_balance::<LpnCurrency>(&customer, &test_case)
+    );
+    assert_eq!(
+        user_balance_before - DOWNPAYMENT + payout_of(exp_change),
+        user_balance::<PaymentCurrency>(&customer, &test_case)
+    );
+}
+
+#[test]
+fn full_close_invalid_payout_slippage() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::ClosePosition(PositionClose::FullClose(FullClose {
+                payout: Some(Payout {
+                    currency: currency::dto::<PaymentCurrency, _>(),
+                    max_slippage: Percent::HUNDRED,
+                }),
+            })),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::InvalidPayoutSlippage(Percent::HUNDRED))
+    );
+}
+
+#[test]
+fn close_preview() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    let close = PositionClose::PartialClose(PartialClose {
+        amount: LeaseCoin::from(1234414).into(),
+        repay_share: None,
+    });
+
+    let preview = query_close_preview(
+        &test_case,
+        lease.clone(),
+        Some(close.clone()),
+        testing::user(USER),
+    )
+    .unwrap();
+    assert!(matches!(
+        super::state_query(&test_case, lease.clone()),
+        StateResponse::Opened {
+            in_progress: None,
+            ..
+        }
+    ));
+
+    let mut response_close: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease.clone(),
+            &ExecuteMsg::ClosePosition(close),
+            &[],
+        )
+        .unwrap();
+    let _requests = common::swap::expect_swap(
+        &mut response_close,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    let response_close: AppResponse = response_close.unwrap_response();
+
+    // the only message is the swap transaction sent over the Dex account,
+    // no contract is called and no funds are sent
+    assert_eq!(
+        vec![MessagePreview {
+            kind: MsgKind::InterChain,
+            target: None,
+        }],
+        preview.messages
+    );
+    assert!(response_close
+        .events
+        .iter()
+        .filter(|event| event.ty == "execute")
+        .all(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "_contract_address" && attr.value == lease.as_str())));
+    assert!(!response_close
+        .events
+        .iter()
+        .any(|event| event.ty == "transfer"));
+}
+
+#[test]
+fn close_preview_by_another_user() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let err = query_close_preview(
+        &test_case,
+        lease,
+        Some(PositionClose::FullClose(FullClose::default())),
+        testing::user(ADMIN),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains(
+        &ContractError::Unauthorized(access_control::error::Error::Unauthorized {}).to_string()
+    ));
+}
+
 #[test]
 fn partial_close_loan_not_closed() {
     let lease_amount: LeaseCoin = lease_amount();
@@ -107,6 +262,7 @@ fn partial_close_loan_not_closed() {
         close_amount,
         PositionClose::PartialClose(PartialClose {
             amount: close_amount.into(),
+            repay_share: None,
         }),
         exp_loan_close,
         exp_change,
@@ -116,6 +272,7 @@ fn partial_close_loan_not_closed() {
     assert_eq!(
         super::expected_open_state(
             &test_case,
+            &lease,
             DOWNPAYMENT,
             repay_principal,
             close_amount,
@@ -158,6 +315,7 @@ fn partial_close_loan_closed() {
         close_amount,
         PositionClose::PartialClose(PartialClose {
             amount: close_amount.into(),
+            repay_share: None,
         }),
         exp_loan_close,
         exp_change,
@@ -187,6 +345,84 @@ fn partial_close_loan_closed() {
     );
 }
 
+#[test]
+fn partial_close_repay_all() {
+    partial_close_split(Percent::HUNDRED);
+}
+
+#[test]
+fn partial_close_payout_all() {
+    partial_close_split(Percent::ZERO);
+}
+
+#[test]
+fn partial_close_split_fractional() {
+    partial_close_split(Percent::from_permille(333));
+}
+
+#[test]
+fn partial_close_invalid_repay_share() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    let repay_share = Percent::from_permille(1001);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
+                amount: LeaseCoin::from(1234414).into(),
+                repay_share: Some(repay_share),
+            })),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::InvalidRepayShare(repay_share))
+    );
+}
+
+#[test]
+fn partial_close_payout_all_over_leveraged() {
+    partial_close_over_leveraged(Percent::ZERO);
+}
+
+#[test]
+fn partial_close_low_repay_share_over_leveraged() {
+    partial_close_over_leveraged(Percent::from_percent(10));
+}
+
+fn partial_close_over_leveraged(repay_share: Percent) {
+    let lease_amount: LeaseCoin = lease_amount();
+    let principal: LpnCoin = price::total(lease_amount, super::price_lpn_of())
+        - price::total(DOWNPAYMENT, super::price_lpn_of());
+    let close_amount: LeaseCoin =
+        price::total(principal - 1234567.into(), super::price_lpn_of().inv());
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
+                amount: close_amount.into(),
+                repay_share: Some(repay_share),
+            })),
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::PositionError(
+            PositionError::PositionCloseLiquidation { .. }
+        ))
+    ));
+}
+
 #[test]
 fn partial_close_invalid_currency() {
     let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
@@ -200,6 +436,7 @@ fn partial_close_invalid_currency() {
             lease,
             &(&ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
                 amount: Coin::<PaymentC5>::from(12345678).into(),
+                repay_share: None,
             }))),
             &[],
         )
@@ -227,6 +464,7 @@ fn partial_close_min_asset() {
     let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
     let msg = &ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
         amount: (lease_amount - min_asset + 1.into()).into(),
+        repay_share: None,
     }));
 
     let err = test_case
@@ -251,6 +489,7 @@ fn partial_close_min_transaction() {
     let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
     let msg = &ExecuteMsg::ClosePosition(PositionClose::PartialClose(PartialClose {
         amount: (min_transaction - 1.into()).into(),
+        repay_share: None,
     }));
 
     let err = test_case
@@ -265,6 +504,60 @@ fn partial_close_min_transaction() {
     );
 }
 
+fn partial_close_split(repay_share: Percent) {
+    let lease_amount: LeaseCoin = lease_amount();
+    // small enough to keep the position healthy even if nothing is repaid
+    let close_amount: LeaseCoin = Percent::from_percent(10).of(lease_amount);
+    let close_amount_in_lpn: LpnCoin = price::total(close_amount, super::price_lpn_of());
+    let exp_payout = exp_split_payout(repay_share, close_amount_in_lpn);
+    let repay_principal = close_amount_in_lpn - exp_payout;
+    let customer = testing::user(USER);
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+
+    let exp_loan_close = false;
+    let exp_change = LpnCoin::ZERO;
+    let lease = do_close(
+        &mut test_case,
+        &customer,
+        close_amount,
+        PositionClose::PartialClose(PartialClose {
+            amount: close_amount.into(),
+            repay_share: Some(repay_share),
+        }),
+        exp_loan_close,
+        exp_change,
+        lease_amount - close_amount,
+    );
+    let state = super::state_query(&test_case, lease.clone());
+    assert_eq!(
+        super::expected_open_state(
+            &test_case,
+            &lease,
+            DOWNPAYMENT,
+            repay_principal,
+            close_amount,
+            Instantiator::REPAYMENT_PERIOD,
+        ),
+        state
+    );
+    assert_eq!(
+        lease_balance(&test_case, lease),
+        common::cwcoin_as_balance(exp_change),
+    );
+
+    assert_eq!(
+        exp_payout,
+        user_balance::<LpnCurrency>(&customer, &test_case)
+    );
+}
+
+/// The part of the partial close proceeds paid out to the customer
+///
+/// The remainder of the rounding goes to the repayment.
+fn exp_split_payout(repay_share: Percent, proceeds: LpnCoin) -> LpnCoin {
+    (Percent::HUNDRED - repay_share).of(proceeds)
+}
+
 fn do_close(
     test_case: &mut LeaseTestCase,
     customer_addr: &Addr,
@@ -278,11 +571,27 @@ fn do_close(
     let lease_addr: Addr = super::open_lease(test_case, DOWNPAYMENT, None);
 
     assert!(matches!(
-        super::expected_newly_opened_state(test_case, DOWNPAYMENT, Coin::<LpnCurrency>::ZERO),
+        super::expected_newly_opened_state(
+            test_case,
+            &lease_addr,
+            DOWNPAYMENT,
+            Coin::<LpnCurrency>::ZERO
+        ),
         StateResponse::Opened { .. }
     ));
 
+    let payout: Option<Payout> = match &close_msg {
+        PositionClose::FullClose(FullClose { payout }) => payout.clone(),
+        PositionClose::PartialClose(_) => None,
+    };
     let close_amount_in_lpn: LpnCoin = price::total(close_amount, super::price_lpn_of());
+    let exp_repayment: LpnCoin = match &close_msg {
+        PositionClose::PartialClose(PartialClose {
+            repay_share: Some(repay_share),
+            ..
+        }) => close_amount_in_lpn - exp_split_payout(*repay_share, close_amount_in_lpn),
+        _ => close_amount_in_lpn,
+    };
     let mut response_close: ResponseWithInterChainMsgs<'_, ()> = send_close(
         test_case,
         lease_addr.clone(),
@@ -316,27 +625,34 @@ fn do_close(
 
     assert_eq!(transfer_amount, to_cosmwasm_on_dex(close_amount_in_lpn));
 
-    let response_transfer_in: AppResponse = ibc::do_transfer(
+    let mut response_transfer_in: ResponseWithInterChainMsgs<'_, AppResponse> = ibc::do_transfer(
         &mut test_case.app,
         TestCase::ica_addr(&lease_addr, TestCase::LEASE_ICA_ID),
         lease_addr.clone(),
         true,
         &transfer_amount,
-    )
-    .unwrap_response();
+    );
+
+    let payout_transfer: Option<CwCoin> = payout.as_ref().map(|_| {
+        ibc::expect_transfer(
+            &mut response_transfer_in,
+            TestCase::LEASER_IBC_CHANNEL,
+            lease_addr.as_str(),
+            TestCase::ica_addr(&lease_addr, TestCase::LEASE_ICA_ID).as_str(),
+        )
+    });
+
+    let response_transfer_in: AppResponse = response_transfer_in.unwrap_response();
 
     response_transfer_in.assert_event(
         &Event::new("wasm-ls-close-position")
             .add_attribute("to", lease_addr.clone())
-            .add_attribute(
-                "payment-amount",
-                Amount::from(close_amount_in_lpn).to_string(),
-            )
+            .add_attribute("payment-amount", Amount::from(exp_repayment).to_string())
             .add_attribute("payment-symbol", LpnCurrency::ticker())
             .add_attribute("loan-close", exp_loan_close.to_string())
             .add_attribute(
                 "principal",
-                Amount::from(close_amount_in_lpn - exp_change).to_string(),
+                Amount::from(exp_repayment - exp_change).to_string(),
             )
             .add_attribute("change", Amount::from(exp_change).to_string())
             .add_attribute("amount-amount", Amount::from(close_amount).to_string())
@@ -360,9 +676,124 @@ fn do_close(
         );
     }
 
+    if let Some(payout) = payout {
+        do_payout(
+            test_case,
+            lease_addr.clone(),
+            &payout,
+            payout_transfer.expect("the change to be transferred out"),
+            exp_change,
+        );
+    }
+
     lease_addr
 }
 
+fn do_payout(
+    test_case: &mut LeaseTestCase,
+    lease_addr: Addr,
+    payout: &Payout,
+    change_transfer: CwCoin,
+    exp_change: LpnCoin,
+) {
+    assert_eq!(change_transfer, common::cwcoin(exp_change));
+
+    let ica_addr: Addr = TestCase::ica_addr(&lease_addr, TestCase::LEASE_ICA_ID);
+    let exp_payout: PaymentCoin = payout_of(exp_change);
+
+    assert_paying_out(test_case, lease_addr.clone(), payout, exp_change);
+
+    let mut response_transfer_out: ResponseWithInterChainMsgs<'_, ()> = ibc::do_transfer(
+        &mut test_case.app,
+        lease_addr.clone(),
+        ica_addr.clone(),
+        false,
+        &change_transfer,
+    )
+    .ignore_response();
+
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response_transfer_out,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+
+    () = response_transfer_out.unwrap_response();
+
+    assert_eq!(
+        requests
+            .iter()
+            .map(|request| request.min_token_out)
+            .collect::<Vec<_>>(),
+        [(Percent::HUNDRED - payout.max_slippage)
+            .of(exp_payout)
+            .into()],
+    );
+
+    assert_paying_out(test_case, lease_addr.clone(), payout, exp_change);
+
+    let mut response_swap: ResponseWithInterChainMsgs<'_, ()> = common::swap::do_swap(
+        &mut test_case.app,
+        lease_addr.clone(),
+        ica_addr.clone(),
+        requests.into_iter(),
+        |amount: Amount, _, _| {
+            assert_eq!(amount, exp_change.into());
+
+            exp_payout.into()
+        },
+    )
+    .ignore_response();
+
+    let transfer_amount: CwCoin = ibc::expect_remote_transfer(
+        &mut response_swap,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+
+    assert_eq!(transfer_amount, to_cosmwasm_on_dex(exp_payout));
+
+    assert_paying_out(test_case, lease_addr.clone(), payout, exp_change);
+
+    let response_transfer_in: AppResponse = ibc::do_transfer(
+        &mut test_case.app,
+        ica_addr,
+        lease_addr.clone(),
+        true,
+        &transfer_amount,
+    )
+    .unwrap_response();
+
+    response_transfer_in.assert_event(
+        &Event::new("wasm-ls-payout")
+            .add_attribute("id", lease_addr)
+            .add_attribute("payout-amount", Amount::from(exp_payout).to_string())
+            .add_attribute("payout-symbol", PaymentCurrency::ticker()),
+    );
+}
+
+fn assert_paying_out(
+    test_case: &LeaseTestCase,
+    lease_addr: Addr,
+    payout: &Payout,
+    exp_change: LpnCoin,
+) {
+    let StateResponse::PayingOut {
+        change,
+        payout_currency,
+        ..
+    } = super::state_query(test_case, lease_addr)
+    else {
+        unreachable!("the lease is not paying out")
+    };
+    assert_eq!(change, exp_change.into());
+    assert_eq!(payout_currency, payout.currency);
+}
+
+fn payout_of(change: LpnCoin) -> PaymentCoin {
+    price::total(change, super::price_lpn_of::<PaymentCurrency>().inv())
+}
+
 fn send_close<'r>(
     test_case: &'r mut LeaseTestCase,
     contract_addr: Addr,
@@ -375,6 +806,18 @@ fn send_close<'r>(
         .ignore_response()
 }
 
+fn query_close_preview(
+    test_case: &LeaseTestCase,
+    lease: Addr,
+    position: Option<PositionClose>,
+    sender: Addr,
+) -> StdResult<ClosePreviewResponse> {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(lease, &QueryMsg::ClosePreview { position, sender })
+}
+
 fn assert_unauthorized(test_case: &mut LeaseTestCase, lease: Addr, close_msg: ExecuteMsg) {
     let sender = testing::user(ADMIN);
     {
@@ -0,0 +1,214 @@
+use std::slice;
+
+use ::swap::testing::SwapRequest;
+use access_control::error::Error;
+use currencies::PaymentGroup;
+use currency::CurrencyDef;
+use finance::{
+    coin::{Amount, Coin},
+    percent::Percent,
+};
+use lease::{
+    api::{query::StateResponse, ExecuteMsg},
+    error::ContractError,
+};
+use sdk::{
+    cosmwasm_std::{Addr, Event},
+    testing,
+};
+
+use crate::common::{
+    cwcoin, ibc, swap,
+    test_case::{response::ResponseWithInterChainMsgs, TestCase},
+    CwCoin, USER,
+};
+
+use super::{
+    LeaseCoin, LeaseCurrency, LeaseTestCase, LpnCoin, PaymentCoin, PaymentCurrency, DOWNPAYMENT,
+};
+
+#[test]
+fn add_collateral_in_lease_currency() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<LeaseCurrency>();
+    let downpayment = LeaseCoin::new(10_000);
+    let lease = super::open_lease(&mut test_case, downpayment, None);
+
+    let state_before = super::state_query(&test_case, lease.clone());
+
+    let collateral = LeaseCoin::new(5_000);
+    () = send_collateral_and_transfer(&mut test_case, lease.clone(), collateral).unwrap_response();
+
+    let state_after = super::state_query(&test_case, lease);
+    assert_collateral_added(state_before, state_after, collateral);
+}
+
+#[test]
+fn add_collateral_in_payment_currency() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let state_before = super::state_query(&test_case, lease.clone());
+
+    let collateral = PaymentCoin::new(Amount::from(DOWNPAYMENT) / 2);
+    let mut response = send_collateral_and_transfer(&mut test_case, lease.clone(), collateral);
+
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+
+    // the payment and the lease currency prices are both 1:1 to the LPN
+    let swap_out = LeaseCoin::new(collateral.into());
+    () = swap::do_swap(
+        &mut test_case.app,
+        lease.clone(),
+        TestCase::ica_addr(&lease, TestCase::LEASE_ICA_ID),
+        requests.into_iter(),
+        |amount, _, _| amount,
+    )
+    .ignore_response()
+    .unwrap_response();
+
+    let state_after = super::state_query(&test_case, lease);
+    assert_collateral_added(state_before, state_after, swap_out);
+}
+
+#[test]
+fn add_collateral_zero_swap_output() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let state_before = super::state_query(&test_case, lease.clone());
+
+    let collateral = PaymentCoin::new(Amount::from(DOWNPAYMENT) / 2);
+    let mut response = send_collateral_and_transfer(&mut test_case, lease.clone(), collateral);
+
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+
+    let response = swap::do_swap(
+        &mut test_case.app,
+        lease.clone(),
+        TestCase::ica_addr(&lease, TestCase::LEASE_ICA_ID),
+        requests.into_iter(),
+        |_, _, _| 0,
+    )
+    .unwrap_response();
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-collateral-swap")
+            .add_attribute("id", lease.clone())
+            .add_attribute("swap", "zero-output"),
+    );
+
+    // the lease stays in the swap state without accounting any collateral
+    let (amount_before, _) = amount_and_principal(state_before);
+    assert!(matches!(
+        super::state_query(&test_case, lease.clone()),
+        StateResponse::Opened {
+            amount,
+            in_progress: Some(_),
+            ..
+        } if amount == amount_before.into()
+    ));
+
+    // the swap input is already consumed, so a heal finishes without replaying the swap
+    () = test_case
+        .app
+        .execute(testing::user(USER), lease.clone(), &ExecuteMsg::Heal(), &[])
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    let (amount_after, _) = amount_and_principal(super::state_query(&test_case, lease));
+    assert_eq!(amount_before, amount_after);
+}
+
+#[test]
+fn add_collateral_not_customer() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<LeaseCurrency>();
+    let lease = super::open_lease(&mut test_case, LeaseCoin::new(10_000), None);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user("not_the_customer"),
+            lease,
+            &ExecuteMsg::AddCollateral(),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::Unauthorized(Error::Unauthorized {}))
+    );
+}
+
+fn send_collateral_and_transfer<C>(
+    test_case: &mut LeaseTestCase,
+    lease: Addr,
+    collateral: Coin<C>,
+) -> ResponseWithInterChainMsgs<'_, ()>
+where
+    C: CurrencyDef,
+{
+    let collateral_cw: CwCoin = cwcoin(collateral);
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease.clone(),
+            &ExecuteMsg::AddCollateral(),
+            slice::from_ref(&collateral_cw),
+        )
+        .unwrap()
+        .ignore_response();
+
+    let ica_addr: Addr = TestCase::ica_addr(&lease, TestCase::LEASE_ICA_ID);
+    let transfer_amount: CwCoin = ibc::expect_transfer(
+        &mut response,
+        TestCase::LEASER_IBC_CHANNEL,
+        lease.as_str(),
+        ica_addr.as_str(),
+    );
+    assert_eq!(transfer_amount, collateral_cw);
+    () = response.unwrap_response();
+
+    ibc::do_transfer(&mut test_case.app, lease, ica_addr, false, &transfer_amount).ignore_response()
+}
+
+fn assert_collateral_added(before: StateResponse, after: StateResponse, collateral: LeaseCoin) {
+    let (amount_before, principal_before) = amount_and_principal(before);
+    let (amount_after, principal_after) = amount_and_principal(after);
+
+    assert_eq!(amount_after, amount_before + collateral);
+    assert_eq!(principal_after, principal_before);
+    // the lease currency price is 1:1 to the LPN
+    assert!(ltv(amount_after, principal_after) < ltv(amount_before, principal_before));
+}
+
+fn amount_and_principal(state: StateResponse) -> (LeaseCoin, LpnCoin) {
+    let StateResponse::Opened {
+        amount,
+        principal_due,
+        in_progress: None,
+        ..
+    } = state
+    else {
+        unreachable!("the lease is not in an idle opened state")
+    };
+    (
+        amount.try_into().unwrap(),
+        principal_due.try_into().unwrap(),
+    )
+}
+
+fn ltv(amount: LeaseCoin, principal: LpnCoin) -> Percent {
+    Percent::from_ratio(principal, LpnCoin::new(amount.into()))
+}
@@ -19,8 +19,12 @@ fn manual_calculation() {
     let quote_result = lease::quote_query(&test_case, downpayment);
 
     let query_result = super::state_query(&test_case, lease_address.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease_address,
+        downpayment,
+        super::create_payment_coin(0),
+    );
 
     assert_eq!(query_result, expected_result);
 
@@ -29,7 +33,7 @@ fn manual_calculation() {
             - Duration::from_nanos(1),
     );
 
-    let query_result = super::state_query(&test_case, lease_address);
+    let query_result = super::state_query(&test_case, lease_address.clone());
     let expected_result = StateResponse::Opened {
         amount: LeaseCoin::from(Amount::from(DOWNPAYMENT + 1_857_142_857_142.into())).into(),
         loan_interest_rate: quote_result.annual_interest_rate,
@@ -44,6 +48,8 @@ fn manual_calculation() {
         close_policy: ClosePolicy::default(),
         validity: crate::block_time(&test_case),
         in_progress: None,
+        needs_realarm: false,
+        dex_account: super::expected_dex_account(&lease_address),
     };
 
     assert_eq!(query_result, expected_result);
@@ -56,8 +62,12 @@ fn lpp_state_implicit_time() {
     let lease_address = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease_address.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease_address,
+        downpayment,
+        super::create_payment_coin(0),
+    );
 
     assert_eq!(query_result, expected_result);
 
@@ -110,8 +120,12 @@ fn lpp_state_explicit_time() {
     let lease_address = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease_address.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease_address,
+        downpayment,
+        super::create_payment_coin(0),
+    );
 
     assert_eq!(query_result, expected_result);
 
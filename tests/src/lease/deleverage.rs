@@ -0,0 +1,155 @@
+use currencies::Lpns;
+use finance::{fraction::Fraction, percent::Percent, price};
+use lease::api::{
+    query::{QueryMsg, SummaryResponse},
+    ExecuteMsg,
+};
+use sdk::{cosmwasm_std::Addr, testing};
+
+use crate::common::{cwcoin, USER};
+
+use super::{
+    LeaseCoin, LeaseCurrency, LeaseTestCase, LpnCoin, LpnCurrency, PaymentCurrency, DOWNPAYMENT,
+};
+
+const TARGET_LTV: Percent = Percent::from_permille(400);
+
+#[test]
+fn deleverage_to_target() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let summary_before = summary(&test_case, lease.clone());
+    assert!(TARGET_LTV < summary_before.ltv);
+    let lease_amount: LeaseCoin = summary_before.amount.try_into().unwrap();
+    let principal_due: LpnCoin = summary_before.principal_due.try_into().unwrap();
+    let exp_repayment = principal_due
+        - TARGET_LTV.of(price::total(
+            lease_amount,
+            super::price_lpn_of::<LeaseCurrency>(),
+        ));
+
+    let payment = principal_due;
+    test_case.send_funds_from_admin(testing::user(USER), &[cwcoin(payment)]);
+    let balance_before = lpn_balance(&test_case, &testing::user(USER));
+
+    () = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease.clone(),
+            &ExecuteMsg::Deleverage {
+                target_ltv: TARGET_LTV,
+            },
+            &[cwcoin(payment)],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    assert_eq!(
+        balance_before - exp_repayment,
+        lpn_balance(&test_case, &testing::user(USER))
+    );
+    let summary_after = summary(&test_case, lease);
+    assert_eq!(
+        principal_due - exp_repayment,
+        LpnCoin::try_from(summary_after.principal_due).unwrap()
+    );
+    assert!(summary_after.ltv <= TARGET_LTV);
+}
+
+#[test]
+fn deleverage_insufficient_payment() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    let summary_before = summary(&test_case, lease.clone());
+
+    let payment = LpnCoin::new(100);
+    test_case.send_funds_from_admin(testing::user(USER), &[cwcoin(payment)]);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease.clone(),
+            &ExecuteMsg::Deleverage {
+                target_ltv: TARGET_LTV,
+            },
+            &[cwcoin(payment)],
+        )
+        .unwrap_err();
+
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("[Lease] Insufficient deleverage payment!"));
+    assert_eq!(
+        summary_before.principal_due,
+        summary(&test_case, lease).principal_due
+    );
+}
+
+#[test]
+fn deleverage_target_reached() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    // the summary LTV is rounded down, so target the next permille above it
+    let target_ltv = summary(&test_case, lease.clone()).ltv + Percent::from_permille(1);
+
+    let payment = LpnCoin::new(1_000);
+    test_case.send_funds_from_admin(testing::user(USER), &[cwcoin(payment)]);
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::Deleverage { target_ltv },
+            &[cwcoin(payment)],
+        )
+        .unwrap_err();
+
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("[Lease] The position LTV is already at or below the deleverage target"));
+}
+
+#[test]
+fn deleverage_not_lpn_payment() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let payment = cwcoin::<PaymentCurrency, _>(1_000);
+    test_case.send_funds_from_admin(testing::user(USER), std::slice::from_ref(&payment));
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease,
+            &ExecuteMsg::Deleverage {
+                target_ltv: TARGET_LTV,
+            },
+            &[payment],
+        )
+        .unwrap_err();
+
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("[Lease] [Platform] [Currency] Found a symbol"));
+}
+
+fn summary(test_case: &LeaseTestCase, lease: Addr) -> SummaryResponse {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(lease, &QueryMsg::Summary {})
+        .unwrap()
+}
+
+fn lpn_balance(test_case: &LeaseTestCase, account: &Addr) -> LpnCoin {
+    platform::bank::balance::<LpnCurrency, Lpns>(account, test_case.app.query()).unwrap()
+}
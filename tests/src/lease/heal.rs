@@ -1,6 +1,20 @@
-use currencies::Lpns;
-use lease::{api::ExecuteMsg, error::ContractError};
-use sdk::{cosmwasm_std::Addr, cw_multi_test::AppResponse, testing};
+use std::cell::Cell;
+
+use currencies::{
+    LeaseGroup as AlarmCurrencies, Lpn as BaseCurrency, Lpns, Lpns as BaseCurrencies,
+    PaymentGroup as PriceCurrencies,
+};
+use lease::{
+    api::{query::StateResponse, ExecuteMsg},
+    error::ContractError,
+};
+use oracle::{api::ExecuteMsg as OracleExecuteMsg, error::Error as OracleError};
+use sdk::{
+    cosmwasm_ext::Response as CwResponse,
+    cosmwasm_std::{Addr, DepsMut, Env, MessageInfo},
+    cw_multi_test::AppResponse,
+    testing::{self, CwContractWrapper},
+};
 
 use crate::{
     common::{
@@ -12,7 +26,7 @@ use crate::{
         },
         USER,
     },
-    lease::{repay, LeaseCoin, LeaseCurrency, LpnCoin, LpnCurrency},
+    lease::{repay, LeaseCoin, LeaseCurrency, LeaseTestCase, LpnCoin, LpnCurrency},
 };
 
 #[test]
@@ -22,8 +36,12 @@ fn active_state() {
     let lease = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease,
+        downpayment,
+        super::create_payment_coin(0),
+    );
     assert_eq!(query_result, expected_result);
 
     let unutilized_amount: LpnCoin = 100.into();
@@ -38,7 +56,7 @@ fn active_state() {
 
     let query_result = super::state_query(&test_case, lease.clone());
     let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, unutilized_amount);
+        super::expected_newly_opened_state(&test_case, &lease, downpayment, unutilized_amount);
     assert_eq!(query_result, expected_result);
 
     heal_no_inconsistency(&mut test_case.app, lease);
@@ -51,8 +69,12 @@ fn swap_on_repay() {
     let lease = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease,
+        downpayment,
+        super::create_payment_coin(0),
+    );
     assert_eq!(query_result, expected_result);
 
     let payment = super::create_payment_coin(1_000);
@@ -81,7 +103,47 @@ fn swap_on_repay() {
     });
 
     let query_result = super::state_query(&test_case, lease.clone());
-    let expected_result = super::expected_newly_opened_state(&test_case, downpayment, payment);
+    let expected_result =
+        super::expected_newly_opened_state(&test_case, &lease, downpayment, payment);
+    assert_eq!(query_result, expected_result);
+
+    heal_no_inconsistency(&mut test_case.app, lease);
+}
+
+thread_local! {
+    static REJECT_PRICE_ALARMS: Cell<bool> = const { Cell::new(false) };
+}
+
+#[test]
+fn realarm() {
+    let mut test_case = super::create_test_case_with_oracle::<LeaseCurrency>(Some(
+        CwContractWrapper::new(
+            oracle_execute_rejecting_alarms,
+            oracle::contract::instantiate,
+            oracle::contract::query,
+        )
+        .with_reply(oracle::contract::reply)
+        .with_sudo(oracle::contract::sudo)
+        .with_migrate(oracle::contract::migrate),
+    ));
+    let downpayment = LeaseCoin::new(10_000);
+
+    REJECT_PRICE_ALARMS.set(true);
+    let lease = super::open_lease(&mut test_case, downpayment, None);
+    assert!(needs_realarm(&test_case, lease.clone()));
+
+    heal_ok(&mut test_case.app, lease.clone()).expect_empty();
+    assert!(needs_realarm(&test_case, lease.clone()));
+
+    REJECT_PRICE_ALARMS.set(false);
+    heal_ok(&mut test_case.app, lease.clone()).expect_empty();
+    let query_result = super::state_query(&test_case, lease.clone());
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease,
+        downpayment,
+        super::create_payment_coin(0),
+    );
     assert_eq!(query_result, expected_result);
 
     heal_no_inconsistency(&mut test_case.app, lease);
@@ -112,3 +174,23 @@ fn try_heal(
 fn heal_ok(app: &mut App, lease: Addr) -> ResponseWithInterChainMsgs<'_, ()> {
     try_heal(app, lease).unwrap().ignore_response()
 }
+
+fn needs_realarm(test_case: &LeaseTestCase, lease: Addr) -> bool {
+    let StateResponse::Opened { needs_realarm, .. } = super::state_query(test_case, lease) else {
+        unreachable!()
+    };
+    needs_realarm
+}
+
+fn oracle_execute_rejecting_alarms(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    msg: OracleExecuteMsg<BaseCurrency, BaseCurrencies, AlarmCurrencies, PriceCurrencies>,
+) -> Result<CwResponse, OracleError<PriceCurrencies>> {
+    if REJECT_PRICE_ALARMS.get() && matches!(msg, OracleExecuteMsg::AddPriceAlarm { .. }) {
+        Err(OracleError::InvalidAlarmAddress(info.sender))
+    } else {
+        oracle::contract::execute(deps, env, info, msg)
+    }
+}
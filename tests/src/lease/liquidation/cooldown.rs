@@ -0,0 +1,194 @@
+use currencies::PaymentGroup;
+use finance::{coin::Amount, duration::Duration};
+use lease::api::{query::StateResponse, ExecuteMsg};
+use sdk::{cosmwasm_std::Addr, cw_multi_test::AppResponse, testing};
+use swap::testing::SwapRequest;
+
+use crate::{
+    common::{
+        self, ibc,
+        leaser::Instantiator as LeaserInstantiator,
+        test_case::{response::ResponseWithInterChainMsgs, TestCase},
+        CwCoin, ADMIN,
+    },
+    lease::{self as lease_mod, LeaseTestCase},
+};
+
+use super::{LeaseCoin, LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+const COOLDOWN: Duration = Duration::from_hours(1);
+
+#[test]
+fn liquidation_within_cooldown() {
+    let mut test_case = lease_mod::create_test_case::<PaymentCurrency>();
+    set_liquidation_cooldown(&mut test_case, COOLDOWN);
+    let lease = lease_mod::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    // the price drop brings the LTV to 85%, above the max one
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = lease_mod::deliver_new_price(
+        &mut test_case,
+        LeaseCoin::new(2085713),
+        LpnCoin::new(1595000),
+    )
+    .ignore_response();
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+    complete_liquidation(&mut test_case, &lease, requests);
+
+    let (amount, principal_due) = opened_without_trx(&test_case, &lease);
+    // the asset is worth the principal due plus 1/9 of it, i.e. 90% LTV
+    let low_price = (LeaseCoin::new(amount), LpnCoin::new(principal_due * 10 / 9));
+
+    // a next price drop within the cooldown does not start a liquidation
+    test_case.app.time_shift(COOLDOWN - Duration::from_nanos(1));
+    () = lease_mod::deliver_new_price(&mut test_case, low_price.0, low_price.1)
+        .ignore_response()
+        .unwrap_response();
+    assert_eq!(
+        (amount, principal_due),
+        opened_without_trx(&test_case, &lease)
+    );
+
+    // the time alarm at the cooldown end starts it
+    test_case.app.time_shift(Duration::from_nanos(1));
+    common::oracle::feed_price(
+        &mut test_case,
+        testing::user(ADMIN),
+        low_price.0,
+        low_price.1,
+    );
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .execute(
+            test_case.address_book.time_alarms().clone(),
+            lease.clone(),
+            &ExecuteMsg::TimeAlarm {},
+            &[],
+        )
+        .unwrap()
+        .ignore_response();
+    let _requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+}
+
+#[test]
+fn full_liquidation_within_cooldown() {
+    let mut test_case = lease_mod::create_test_case::<PaymentCurrency>();
+    set_liquidation_cooldown(&mut test_case, COOLDOWN);
+    let lease = lease_mod::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = lease_mod::deliver_new_price(
+        &mut test_case,
+        LeaseCoin::new(2085713),
+        LpnCoin::new(1595000),
+    )
+    .ignore_response();
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+    complete_liquidation(&mut test_case, &lease, requests);
+
+    let (amount, principal_due) = opened_without_trx(&test_case, &lease);
+
+    // the asset is worth about the principal due so a full liquidation is started right away
+    test_case.app.time_shift(COOLDOWN - Duration::from_nanos(1));
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = lease_mod::deliver_new_price(
+        &mut test_case,
+        LeaseCoin::new(amount - 2),
+        LpnCoin::new(principal_due),
+    )
+    .ignore_response();
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+    assert_eq!(
+        vec![amount],
+        requests
+            .iter()
+            .map(|request| request.token_in.amount())
+            .collect::<Vec<_>>()
+    );
+}
+
+fn set_liquidation_cooldown(test_case: &mut LeaseTestCase, cooldown: Duration) {
+    () = test_case
+        .app
+        .sudo(
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::SudoMsg::Config {
+                lease_interest_rate_margin: LeaserInstantiator::INTEREST_RATE_MARGIN,
+                lease_position_spec: LeaserInstantiator::position_spec()
+                    .with_liquidation_cooldown(cooldown),
+                lease_due_period: LeaserInstantiator::REPAYMENT_PERIOD,
+                lease_origination_fee: None,
+                lease_open_cooldown: None,
+                dex_timeouts: None,
+            },
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+}
+
+fn complete_liquidation(
+    test_case: &mut LeaseTestCase,
+    lease: &Addr,
+    requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>>,
+) {
+    let ica_addr: Addr = TestCase::ica_addr(lease, TestCase::LEASE_ICA_ID);
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = common::swap::do_swap(
+        &mut test_case.app,
+        lease.clone(),
+        ica_addr.clone(),
+        requests.into_iter(),
+        |amount: u128, _, _| amount,
+    )
+    .ignore_response();
+    let transfer_amount: CwCoin = ibc::expect_remote_transfer(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+
+    let response: AppResponse = ibc::do_transfer(
+        &mut test_case.app,
+        ica_addr,
+        lease.clone(),
+        true,
+        &transfer_amount,
+    )
+    .unwrap_response();
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-liquidation"));
+}
+
+/// Return the lease and principal due amounts of an open lease with no ongoing transaction
+fn opened_without_trx(test_case: &LeaseTestCase, lease: &Addr) -> (Amount, Amount) {
+    let StateResponse::Opened {
+        amount,
+        principal_due,
+        in_progress: None,
+        ..
+    } = lease_mod::state_query(test_case, lease.clone())
+    else {
+        panic!("the lease should be opened with no ongoing transaction");
+    };
+    (amount.amount(), principal_due.amount())
+}
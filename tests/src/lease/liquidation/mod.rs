@@ -1,4 +1,5 @@
 use super::{LeaseCoin, LeaseCurrency, LpnCoin, PaymentCoin, PaymentCurrency, DOWNPAYMENT};
 
+mod cooldown;
 mod price;
 mod time;
@@ -1,7 +1,7 @@
 use currencies::{Lpns, PaymentGroup};
 use currency::CurrencyDef as _;
-use finance::{coin::Amount, percent::Percent};
-use lease::api::query::StateResponse;
+use finance::{coin::Amount, fraction::Fraction, percent::Percent, price};
+use lease::api::query::{LiquidationPriceResponse, QueryMsg, StateResponse, SummaryResponse};
 use platform::coin_legacy::to_cosmwasm_on_dex;
 use sdk::{
     cosmwasm_std::{Addr, Event},
@@ -141,7 +141,9 @@ fn full_liquidation() {
     response.assert_event(
         &Event::new("wasm-ls-liquidation")
             .add_attribute("payment-amount", borrowed_amount.to_string())
-            .add_attribute("loan-close", "true"),
+            .add_attribute("loan-close", "true")
+            .add_attribute("cause", "high liability")
+            .add_attribute("kind", "full"),
     );
     assert!(
         platform::bank::balance::<LpnCurrency, Lpns>(&reserve, test_case.app.query())
@@ -170,6 +172,54 @@ fn full_liquidation() {
     )
 }
 
+#[test]
+fn liquidation_at_queried_price() {
+    let mut test_case = lease_mod::create_test_case::<PaymentCurrency>();
+    let lease_addr: Addr = lease_mod::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let summary: SummaryResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(lease_addr.clone(), &QueryMsg::Summary {})
+        .unwrap();
+    let lease_amount: LeaseCoin = summary.amount.try_into().unwrap();
+    let total_due: LpnCoin = [
+        summary.overdue_margin,
+        summary.overdue_interest,
+        summary.due_margin,
+        summary.due_interest,
+    ]
+    .into_iter()
+    .map(|due| LpnCoin::try_from(due).unwrap())
+    .fold(summary.principal_due.try_into().unwrap(), |total, due| {
+        total + due
+    });
+    let liquidation_base: LeaseCoin = LeaserInstantiator::liability().max().of(lease_amount);
+
+    let liquidation_price: LiquidationPriceResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(lease_addr, &QueryMsg::LiquidationPrice {})
+        .unwrap();
+    assert_eq!(summary.at, liquidation_price.at);
+    assert_eq!(
+        Some(price::total_of(liquidation_base).is(total_due).into()),
+        liquidation_price.price
+    );
+
+    // the lease gets liquidated at exactly that price
+    let mut response: ResponseWithInterChainMsgs<'_, ()> =
+        lease_mod::deliver_new_price(&mut test_case, liquidation_base, total_due).ignore_response();
+
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response.unwrap_response();
+    assert!(!requests.is_empty());
+}
+
 fn liquidation_warning(base: LeaseCoin, quote: LpnCoin, liability: Percent, level: &str) {
     let mut test_case = lease_mod::create_test_case::<PaymentCurrency>();
     let _lease = lease_mod::open_lease(&mut test_case, DOWNPAYMENT, None);
@@ -126,6 +126,8 @@ fn liquidation_time_alarm(
         .into();
 
     assert_eq!(liquidated_amount, liquidation_amount);
+    assert_eq!(liquidation_attributes["cause"], "overdue interest");
+    assert_eq!(liquidation_attributes["kind"], "partial");
 
     if let StateResponse::Opened {
         amount,
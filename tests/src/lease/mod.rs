@@ -10,7 +10,7 @@ use finance::{
     percent::Percent,
     price::{self, Price},
 };
-use lease::api::query::{ClosePolicy, StateResponse};
+use lease::api::query::{ClosePolicy, DexAccount, StateResponse};
 use leaser::msg::QuoteResponse;
 use sdk::{
     cosmwasm_std::{coin, Addr},
@@ -25,7 +25,7 @@ use crate::common::{
     test_case::{
         builder::Builder as TestCaseBuilder,
         response::{RemoteChain, ResponseWithInterChainMsgs},
-        TestCase,
+        OptionalOracleWrapper, TestCase,
     },
     ADDON_OPTIMAL_INTEREST_RATE, ADMIN, BASE_INTEREST_RATE, USER, UTILIZATION_OPTIMAL,
 };
@@ -33,10 +33,13 @@ use crate::common::{
 mod close;
 mod close_policy;
 mod close_position;
+mod collateral;
 mod compare_with_lpp;
+mod deleverage;
 mod heal;
 mod liquidation;
 mod open;
+mod open_order;
 mod repay;
 
 type LpnCurrency = Lpn;
@@ -94,6 +97,15 @@ pub(super) fn deliver_new_price(
 }
 
 pub(super) fn create_test_case<InitFundsC>() -> LeaseTestCase
+where
+    InitFundsC: CurrencyDef,
+{
+    create_test_case_with_oracle::<InitFundsC>(None)
+}
+
+pub(super) fn create_test_case_with_oracle<InitFundsC>(
+    oracle: OptionalOracleWrapper,
+) -> LeaseTestCase
 where
     InitFundsC: CurrencyDef,
 {
@@ -120,7 +132,7 @@ where
     )
     .init_time_alarms()
     .init_protocols_registry(Registry::SingleProtocol)
-    .init_oracle(None)
+    .init_oracle(oracle)
     .init_treasury()
     .init_profit(24)
     .init_reserve()
@@ -215,6 +227,7 @@ where
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: currency::dto::<LeaseCurrency, _>(),
                 max_ltd,
+                open_order: None,
             },
             downpayment.as_ref().map_or(&[], std::slice::from_ref),
         )
@@ -379,6 +392,7 @@ pub(super) fn expected_open_state<
         Oracle,
         TimeAlarms,
     >,
+    lease: &Addr,
     downpayment: Coin<DownpaymentC>,
     payments: Coin<PaymentC>,
     closed: Coin<AssetC>,
@@ -442,6 +456,15 @@ where
         close_policy: ClosePolicy::default(),
         validity: now,
         in_progress: None,
+        needs_realarm: false,
+        dex_account: expected_dex_account(lease),
+    }
+}
+
+pub(super) fn expected_dex_account(lease: &Addr) -> DexAccount {
+    DexAccount {
+        ica_account: TestCase::ica_addr(lease, TestCase::LEASE_ICA_ID).into_string(),
+        connection: LeaserInstantiator::dex(),
     }
 }
 
@@ -466,6 +489,7 @@ pub(super) fn expected_newly_opened_state<
         Oracle,
         TimeAlarms,
     >,
+    lease: &Addr,
     downpayment: Coin<DownpaymentC>,
     payments: Coin<PaymentC>,
 ) -> StateResponse
@@ -476,6 +500,7 @@ where
 {
     expected_open_state(
         test_case,
+        lease,
         downpayment,
         payments,
         Coin::<LeaseCurrency>::default(),
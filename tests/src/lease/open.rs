@@ -1,8 +1,38 @@
-use finance::{coin::Coin, zero::Zero};
+use std::{collections::HashSet, num::NonZeroU16, ops::Add};
 
-use crate::{common::leaser::Instantiator, lease::heal};
+use currencies::{testing::PaymentC8 as NotSwappableCurrency, Lpns, PaymentGroup};
+use currency::CurrencyDef as _;
+use finance::{
+    coin::{Amount, Coin},
+    duration::Duration,
+    fraction::Fraction,
+    liability::Level,
+    percent::Percent,
+    price,
+    zero::Zero,
+};
+use lease::api::{
+    query::{LiabilityZone, QueryMsg, StateResponse, SummaryResponse},
+    ExecuteMsg,
+};
+use leaser::{
+    error::ContractError as LeaserError,
+    msg::{AtRiskLease, AtRiskResponse, PendingLease, PendingLeasesResponse},
+};
+use sdk::{cosmwasm_std::Addr, cw_multi_test::AppResponse, testing};
+use swap::testing::SwapRequest;
 
-use super::{LeaseCoin, LeaseCurrency, PaymentCurrency, DOWNPAYMENT};
+use crate::{
+    common::{
+        self, cwcoin,
+        leaser::Instantiator,
+        test_case::{response::ResponseWithInterChainMsgs, TestCase},
+        USER,
+    },
+    lease::heal,
+};
+
+use super::{LeaseCoin, LeaseCurrency, LpnCoin, LpnCurrency, PaymentCurrency, DOWNPAYMENT};
 
 #[test]
 #[should_panic = "[Lease] No payment sent"]
@@ -19,8 +49,12 @@ fn open_downpayment_lease_currency() {
     let lease = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease,
+        downpayment,
+        super::create_payment_coin(0),
+    );
     assert_eq!(expected_result, query_result);
 
     heal::heal_no_inconsistency(&mut test_case.app, lease);
@@ -33,13 +67,48 @@ fn open_downpayment_different_than_lease_currency() {
     let lease = super::open_lease(&mut test_case, downpayment, None);
 
     let query_result = super::state_query(&test_case, lease.clone());
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, super::create_payment_coin(0));
+    let expected_result = super::expected_newly_opened_state(
+        &test_case,
+        &lease,
+        downpayment,
+        super::create_payment_coin(0),
+    );
     assert_eq!(query_result, expected_result);
 
     heal::heal_no_inconsistency(&mut test_case.app, lease);
 }
 
+#[test]
+#[should_panic = "cannot be swapped to the lease currency"]
+fn open_downpayment_not_swappable() {
+    let mut test_case = super::create_test_case::<NotSwappableCurrency>();
+    let downpayment = Coin::<NotSwappableCurrency>::new(1_000);
+    super::try_init_lease(&mut test_case, downpayment, None);
+}
+
+#[test]
+fn open_no_dex_account_before_ica() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::try_init_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let StateResponse::Opening { dex_account, .. } = super::state_query(&test_case, lease) else {
+        panic!("the lease should be opening");
+    };
+    assert_eq!(None, dex_account);
+}
+
+#[test]
+fn open_dex_account() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let StateResponse::Opened { dex_account, .. } = super::state_query(&test_case, lease.clone())
+    else {
+        panic!("the lease should be opened");
+    };
+    assert_eq!(super::expected_dex_account(&lease), dex_account);
+}
+
 #[test]
 fn open_takes_longer() {
     let mut test_case = super::create_test_case::<LeaseCurrency>();
@@ -53,3 +122,616 @@ fn open_takes_longer() {
 
     heal::heal_no_inconsistency(&mut test_case.app, lease);
 }
+
+#[test]
+fn open_pending() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::try_init_lease(&mut test_case, DOWNPAYMENT, None);
+    assert_eq!(
+        PendingLeasesResponse {
+            leases: vec![PendingLease {
+                lease: lease.clone(),
+                age: Duration::default(),
+            }],
+            next: None,
+        },
+        pending_leases(&test_case, None, None)
+    );
+
+    let stuck_for = Duration::from_hours(3);
+    test_case.app.time_shift(stuck_for);
+    assert_eq!(
+        PendingLeasesResponse {
+            leases: vec![PendingLease {
+                lease: lease.clone(),
+                age: stuck_for,
+            }],
+            next: None,
+        },
+        pending_leases(&test_case, None, None)
+    );
+
+    super::feed_price(&mut test_case);
+    super::complete_init_lease(&mut test_case, DOWNPAYMENT, None, &lease);
+    assert_eq!(
+        PendingLeasesResponse {
+            leases: vec![],
+            next: None,
+        },
+        pending_leases(&test_case, None, None)
+    );
+}
+
+#[test]
+fn open_pending_paginated() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let customer = testing::user("another_customer");
+    test_case.send_funds_from_admin(customer.clone(), &[cwcoin(DOWNPAYMENT + DOWNPAYMENT)]);
+    let lease_currency = currency::dto::<LeaseCurrency, _>();
+    let mut leases = common::leaser::open_leases(
+        &mut test_case,
+        customer,
+        &[
+            (lease_currency, cwcoin(DOWNPAYMENT)),
+            (lease_currency, cwcoin(DOWNPAYMENT)),
+        ],
+        None,
+    );
+    leases.sort();
+
+    let first_page = pending_leases(&test_case, None, NonZeroU16::new(1));
+    assert_eq!(Some(&leases[0]), first_page.next.as_ref());
+    let second_page = pending_leases(&test_case, first_page.next, NonZeroU16::new(1));
+    assert_eq!(None, second_page.next);
+    assert_eq!(
+        leases,
+        first_page
+            .leases
+            .into_iter()
+            .chain(second_page.leases)
+            .map(|pending| pending.lease)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn at_risk_leases() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let at_risk = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let another_customer = testing::user("another_customer");
+    test_case.send_funds_from_admin(
+        another_customer.clone(),
+        &[cwcoin(DOWNPAYMENT + DOWNPAYMENT)],
+    );
+    let lease_currency = currency::dto::<LeaseCurrency, _>();
+    let [healthy, opening]: [Addr; 2] = common::leaser::open_leases(
+        &mut test_case,
+        another_customer.clone(),
+        &[
+            (lease_currency, cwcoin(DOWNPAYMENT)),
+            (lease_currency, cwcoin(DOWNPAYMENT)),
+        ],
+        None,
+    )
+    .try_into()
+    .unwrap();
+    super::complete_init_lease(&mut test_case, DOWNPAYMENT, None, &healthy);
+
+    // more than half of the principal is repaid to keep the lease healthy on the price drop
+    let payment = LpnCoin::new(1_000_000_000_000);
+    test_case.send_funds_from_admin(another_customer.clone(), &[cwcoin(payment)]);
+    () = test_case
+        .app
+        .execute(
+            another_customer.clone(),
+            healthy.clone(),
+            &ExecuteMsg::Repay {},
+            &[cwcoin(payment)],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    // the opening lease is skipped
+    assert_eq!(
+        HashSet::from([at_risk.clone(), healthy.clone()]),
+        at_risk_addrs(query_at_risk(&test_case, 0, None, None).leases)
+    );
+    assert_eq!(
+        AtRiskResponse {
+            leases: vec![],
+            unavailable: vec![],
+            next: None,
+        },
+        query_at_risk(&test_case, 1, None, None)
+    );
+
+    // the price drop brings the LTV of the not repaid lease in the second warning zone
+    () = super::deliver_new_price(
+        &mut test_case,
+        LeaseCoin::new(2085713),
+        LpnCoin::new(1757159),
+    )
+    .ignore_response()
+    .unwrap_response();
+    let summary: SummaryResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(at_risk.clone(), &QueryMsg::Summary {})
+        .unwrap();
+    let at_risk_second_zone = AtRiskResponse {
+        leases: vec![AtRiskLease {
+            lease: at_risk.clone(),
+            customer: testing::user(USER),
+            ltv: summary.ltv,
+            zone: 2,
+        }],
+        unavailable: vec![],
+        next: None,
+    };
+    assert_eq!(
+        at_risk_second_zone,
+        query_at_risk(&test_case, 1, None, None)
+    );
+    assert_eq!(
+        at_risk_second_zone,
+        query_at_risk(&test_case, 2, None, None)
+    );
+    assert_eq!(
+        AtRiskResponse {
+            leases: vec![],
+            unavailable: vec![],
+            next: None,
+        },
+        query_at_risk(&test_case, 3, None, None)
+    );
+
+    // each page visits the leases of a single customer
+    let first_page = query_at_risk(&test_case, 0, None, NonZeroU16::new(1));
+    let next = first_page
+        .next
+        .clone()
+        .expect("the visit should stop after the first customer");
+    let second_page = query_at_risk(&test_case, 0, Some(next), NonZeroU16::new(1));
+    assert_eq!(None, second_page.next);
+    assert_eq!(
+        HashSet::from([at_risk, healthy]),
+        at_risk_addrs(first_page.leases.into_iter().chain(second_page.leases))
+    );
+    assert!(!at_risk_addrs(query_at_risk(&test_case, 0, None, None).leases).contains(&opening));
+}
+
+#[test]
+fn at_risk_lease_summary_failure() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    // the prices expire, so the summary of the opened lease fails
+    test_case.app.time_shift(Duration::from_nanos(
+        Instantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+
+    assert_eq!(
+        AtRiskResponse {
+            leases: vec![],
+            unavailable: vec![lease],
+            next: None,
+        },
+        query_at_risk(&test_case, 0, None, None)
+    );
+}
+
+#[test]
+fn open_batch() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let customer = testing::user(USER);
+    let lease_currency = currency::dto::<LeaseCurrency, _>();
+
+    let leases = common::leaser::open_leases(
+        &mut test_case,
+        customer.clone(),
+        &[
+            (lease_currency, cwcoin::<PaymentCurrency, _>(1_000_000)),
+            (lease_currency, cwcoin::<PaymentCurrency, _>(2_000_000)),
+            (lease_currency, cwcoin::<PaymentCurrency, _>(3_000_000)),
+        ],
+        None,
+    );
+    assert_eq!(3, leases.len());
+    assert_eq!(3, leases.iter().collect::<HashSet<_>>().len());
+
+    leases.iter().for_each(|lease| {
+        common::leaser::assert_lease(
+            &test_case.app,
+            test_case.address_book.leaser().clone(),
+            customer.clone(),
+            lease,
+        );
+        assert!(matches!(
+            super::state_query(&test_case, lease.clone()),
+            StateResponse::Opening { .. }
+        ));
+    });
+}
+
+#[test]
+fn open_with_origination_fee() {
+    let origination_fee = Percent::from_permille(10);
+
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    () = test_case
+        .app
+        .sudo(
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::SudoMsg::Config {
+                lease_interest_rate_margin: Instantiator::INTEREST_RATE_MARGIN,
+                lease_position_spec: Instantiator::position_spec(),
+                lease_due_period: Instantiator::REPAYMENT_PERIOD,
+                lease_origination_fee: Some(origination_fee),
+                lease_open_cooldown: None,
+                dex_timeouts: None,
+            },
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    let downpayment = DOWNPAYMENT;
+    let quote = super::quote_query(&test_case, downpayment);
+    let borrow: LpnCoin = quote.borrow.try_into().unwrap();
+    let fee = origination_fee.of(borrow);
+    assert!(!fee.is_zero());
+
+    let profit_balance_before = profit_lpn_balance(&test_case);
+    let lease = super::try_init_lease(&mut test_case, downpayment, None);
+    assert_eq!(profit_balance_before + fee, profit_lpn_balance(&test_case));
+
+    common::lease::complete_initialization(
+        &mut test_case.app,
+        TestCase::DEX_CONNECTION_ID,
+        lease.clone(),
+        downpayment,
+        borrow - fee,
+    );
+
+    let StateResponse::Opened {
+        amount,
+        principal_due,
+        ..
+    } = super::state_query(&test_case, lease.clone())
+    else {
+        panic!("the lease should be opened");
+    };
+    assert_eq!(quote.total, amount);
+    assert_eq!(quote.borrow, principal_due);
+
+    heal::heal_no_inconsistency(&mut test_case.app, lease);
+}
+
+#[test]
+fn opened_summary() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    test_case.app.time_shift(Duration::from_nanos(
+        Instantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+    super::feed_price(&mut test_case);
+
+    let summary: SummaryResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(lease.clone(), &QueryMsg::Summary {})
+        .unwrap();
+    let StateResponse::Opened {
+        amount,
+        loan_interest_rate,
+        margin_interest_rate,
+        principal_due,
+        overdue_margin,
+        overdue_interest,
+        overdue_collect_in,
+        due_margin,
+        due_interest,
+        validity,
+        in_progress: None,
+        ..
+    } = super::state_query(&test_case, lease)
+    else {
+        panic!("the lease should be opened with no transaction in progress");
+    };
+
+    assert_eq!(amount, summary.amount);
+    assert_eq!(principal_due, summary.principal_due);
+    assert_eq!(overdue_margin, summary.overdue_margin);
+    assert_eq!(overdue_interest, summary.overdue_interest);
+    assert_eq!(due_margin, summary.due_margin);
+    assert_eq!(due_interest, summary.due_interest);
+    assert_eq!(
+        loan_interest_rate + margin_interest_rate,
+        summary.effective_annual_interest_rate
+    );
+    assert_eq!(validity, summary.at);
+    assert_eq!(validity + overdue_collect_in, summary.grace_period_end);
+
+    let total_due = [overdue_margin, overdue_interest, due_margin, due_interest]
+        .into_iter()
+        .map(|coin| LpnCoin::try_from(coin).unwrap())
+        .fold(LpnCoin::try_from(principal_due).unwrap(), Add::add);
+    let amount_lpn = price::total(
+        LeaseCoin::try_from(amount).unwrap(),
+        super::price_lpn_of::<LeaseCurrency>(),
+    );
+    let ltv = Percent::from_ratio(total_due, amount_lpn);
+    assert_eq!(ltv, summary.ltv);
+
+    let zone = Instantiator::liability().zone_of(ltv);
+    assert_eq!(
+        Some(LiabilityZone {
+            level: zone.low().map_or(0, Level::ordinal),
+            low: zone.low().map(Into::into),
+            high: zone.high().into(),
+        }),
+        summary.liability_zone
+    );
+}
+
+#[test]
+fn open_exposure_cap() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = DOWNPAYMENT;
+    let position_value = price::total(downpayment, super::price_lpn_of::<PaymentCurrency>())
+        + super::quote_borrow(&test_case, downpayment);
+
+    let cap_below = position_value - LpnCoin::new(1);
+    set_exposure_cap(&mut test_case, cap_below);
+    assert_eq!(
+        Some(&LeaserError::ExposureCapExceeded {
+            currency: currency::dto::<LeaseCurrency, _>(),
+            headroom: cap_below.into(),
+        }),
+        try_open_lease_err(&mut test_case, downpayment).downcast_ref::<LeaserError>()
+    );
+
+    set_exposure_cap(&mut test_case, position_value);
+    let _lease = super::open_lease(&mut test_case, downpayment, None);
+    assert_eq!(
+        Some(&LeaserError::ExposureCapExceeded {
+            currency: currency::dto::<LeaseCurrency, _>(),
+            headroom: LpnCoin::ZERO.into(),
+        }),
+        try_open_lease_err(&mut test_case, downpayment).downcast_ref::<LeaserError>()
+    );
+}
+
+#[test]
+fn open_slippage_within_min_asset() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(100);
+    let lease = super::try_init_lease(&mut test_case, downpayment, None);
+
+    // 1% slippage leaves the position above the minimum asset amount
+    let response: AppResponse =
+        complete_init_lease_with_slippage(&mut test_case, downpayment, &lease, 99)
+            .unwrap_response();
+    assert!(!response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-undersized-close-position"));
+
+    assert!(matches!(
+        super::state_query(&test_case, lease),
+        StateResponse::Opened {
+            in_progress: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn open_slippage_below_min_asset() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    // the intended position is worth 214, above the minimum asset amount of 200
+    let downpayment = super::create_payment_coin(75);
+    let lease = super::try_init_lease(&mut test_case, downpayment, None);
+
+    // 10% slippage brings the position down to 192
+    let mut response = complete_init_lease_with_slippage(&mut test_case, downpayment, &lease, 90);
+    let _requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    let response: AppResponse = response.unwrap_response();
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-undersized-close-position"));
+
+    assert!(matches!(
+        super::state_query(&test_case, lease),
+        StateResponse::Opened {
+            in_progress: Some(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn open_slippage_below_min_asset_refund() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let customer = testing::user(USER);
+    let downpayment = super::create_payment_coin(75);
+    let lease = super::try_init_lease(&mut test_case, downpayment, None);
+
+    let mut response = complete_init_lease_with_slippage(&mut test_case, downpayment, &lease, 90);
+    let requests: Vec<SwapRequest<PaymentGroup, PaymentGroup>> = common::swap::expect_swap(
+        &mut response,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    let _: AppResponse = response.unwrap_response();
+
+    let StateResponse::Opened {
+        amount,
+        principal_due,
+        due_margin,
+        due_interest,
+        ..
+    } = super::state_query(&test_case, lease.clone())
+    else {
+        unreachable!("the undersized lease is being closed")
+    };
+    let amount: LeaseCoin = amount.try_into().unwrap();
+    let proceeds: LpnCoin = price::total(amount, super::price_lpn_of::<LeaseCurrency>());
+    let debt = [principal_due, due_margin, due_interest]
+        .into_iter()
+        .map(|due| LpnCoin::try_from(due).unwrap())
+        .fold(LpnCoin::ZERO, Add::add);
+    assert!(proceeds > debt);
+    let balance_before = lpn_balance(&test_case, &customer);
+
+    let ica = TestCase::ica_addr(&lease, TestCase::LEASE_ICA_ID);
+    let mut response_swap = common::swap::do_swap(
+        &mut test_case.app,
+        lease.clone(),
+        ica.clone(),
+        requests.into_iter(),
+        |swapped, _, _| {
+            assert_eq!(Amount::from(amount), swapped);
+            proceeds.into()
+        },
+    )
+    .ignore_response();
+    let transfer_amount = common::ibc::expect_remote_transfer(
+        &mut response_swap,
+        TestCase::DEX_CONNECTION_ID,
+        TestCase::LEASE_ICA_ID,
+    );
+    () = response_swap.unwrap_response();
+    let _: AppResponse = common::ibc::do_transfer(
+        &mut test_case.app,
+        ica,
+        lease.clone(),
+        true,
+        &transfer_amount,
+    )
+    .unwrap_response();
+
+    assert_eq!(
+        StateResponse::Closed(),
+        super::state_query(&test_case, lease)
+    );
+    common::leaser::assert_no_leases(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        customer.clone(),
+    );
+    assert_eq!(
+        balance_before + proceeds - debt,
+        lpn_balance(&test_case, &customer)
+    );
+}
+
+/// Complete the lease opening with swaps yielding `output_percent`% of their input
+fn complete_init_lease_with_slippage<'r>(
+    test_case: &'r mut super::LeaseTestCase,
+    downpayment: super::PaymentCoin,
+    lease: &Addr,
+    output_percent: Amount,
+) -> ResponseWithInterChainMsgs<'r, AppResponse> {
+    let borrow = super::quote_borrow(test_case, downpayment);
+    common::lease::complete_initialization_with_swap(
+        &mut test_case.app,
+        TestCase::DEX_CONNECTION_ID,
+        lease.clone(),
+        downpayment,
+        borrow,
+        |amount, _, _| amount * output_percent / 100,
+    )
+}
+
+fn query_at_risk(
+    test_case: &super::LeaseTestCase,
+    zone: u8,
+    start_after: Option<Addr>,
+    limit: Option<NonZeroU16>,
+) -> AtRiskResponse {
+    common::leaser::query_at_risk(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        zone,
+        start_after,
+        limit,
+    )
+}
+
+fn at_risk_addrs<Leases>(leases: Leases) -> HashSet<Addr>
+where
+    Leases: IntoIterator<Item = AtRiskLease>,
+{
+    leases.into_iter().map(|lease| lease.lease).collect()
+}
+
+fn pending_leases(
+    test_case: &super::LeaseTestCase,
+    start_after: Option<Addr>,
+    limit: Option<NonZeroU16>,
+) -> PendingLeasesResponse {
+    common::leaser::query_pending_leases(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        start_after,
+        limit,
+    )
+}
+
+fn profit_lpn_balance(test_case: &super::LeaseTestCase) -> LpnCoin {
+    test_case
+        .app
+        .query()
+        .query_balance(test_case.address_book.profit().clone(), LpnCurrency::bank())
+        .unwrap()
+        .amount
+        .u128()
+        .into()
+}
+
+fn set_exposure_cap(test_case: &mut super::LeaseTestCase, cap: LpnCoin) {
+    () = test_case
+        .app
+        .sudo(
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::SudoMsg::SetLeaseExposureCap {
+                currency: currency::dto::<LeaseCurrency, _>(),
+                cap: Some(cap.into()),
+            },
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+}
+
+fn try_open_lease_err(
+    test_case: &mut super::LeaseTestCase,
+    downpayment: super::PaymentCoin,
+) -> anyhow::Error {
+    test_case
+        .app
+        .execute(
+            testing::user(USER),
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::ExecuteMsg::OpenLease {
+                currency: currency::dto::<LeaseCurrency, _>(),
+                max_ltd: None,
+                open_order: None,
+            },
+            &[common::cwcoin(downpayment)],
+        )
+        .unwrap_err()
+}
+
+fn lpn_balance(test_case: &super::LeaseTestCase, account: &Addr) -> LpnCoin {
+    platform::bank::balance::<LpnCurrency, Lpns>(account, test_case.app.query()).unwrap()
+}
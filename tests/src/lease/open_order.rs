@@ -0,0 +1,289 @@
+use currencies::PaymentGroup;
+use finance::{duration::Duration, price};
+use lease::api::{
+    open::{OpenOrder, OrderPrice},
+    query::StateResponse,
+    ExecuteMsg,
+};
+use leaser::msg::PendingLease;
+use sdk::{
+    cosmwasm_std::{Addr, Event},
+    cw_multi_test::AppResponse,
+    testing,
+};
+
+use crate::common::{
+    cwcoin, leaser as leaser_mod,
+    test_case::{
+        response::{RemoteChain, ResponseWithInterChainMsgs},
+        TestCase,
+    },
+    USER,
+};
+
+use super::{
+    LeaseCoin, LeaseCurrency, LeaseTestCase, LpnCoin, PaymentCoin, PaymentCurrency, DOWNPAYMENT,
+};
+
+const VALIDITY: Duration = Duration::from_days(2);
+
+#[test]
+fn trigger() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let lease = place_order(&mut test_case, DOWNPAYMENT);
+    assert!(pending_leases(&test_case).is_empty());
+
+    let mut response = super::deliver_new_price(&mut test_case, LeaseCoin::new(3), LpnCoin::new(1));
+    response.expect_register_ica(TestCase::DEX_CONNECTION_ID, TestCase::LEASE_ICA_ID);
+    let response = response.unwrap_response();
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-open-order-close")
+            .add_attribute("id", lease.clone())
+            .add_attribute("reason", "trigger"),
+    );
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-request-loan").add_attribute("id", lease.clone()),
+    );
+    assert_eq!(
+        vec![lease.clone()],
+        pending_leases(&test_case)
+            .into_iter()
+            .map(|pending| pending.lease)
+            .collect::<Vec<_>>()
+    );
+
+    // restore the initial prices to complete the opening at them
+    super::feed_price(&mut test_case);
+    super::complete_init_lease(&mut test_case, DOWNPAYMENT, None, &lease);
+    assert!(matches!(
+        super::state_query(&test_case, lease),
+        StateResponse::Opened { .. }
+    ));
+}
+
+#[test]
+fn price_above_target() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let lease = place_order(&mut test_case, DOWNPAYMENT);
+
+    () = super::deliver_new_price(&mut test_case, LeaseCoin::new(3), LpnCoin::new(2))
+        .ignore_response()
+        .unwrap_response();
+    assert!(matches!(
+        super::state_query(&test_case, lease),
+        StateResponse::Pending { .. }
+    ));
+}
+
+#[test]
+fn cancel() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let customer = testing::user(USER);
+    let balance_before = payment_balance(&test_case, &customer);
+    let lease = place_order(&mut test_case, DOWNPAYMENT);
+    assert_eq!(
+        balance_before - DOWNPAYMENT,
+        payment_balance(&test_case, &customer)
+    );
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user("not_the_customer"),
+            lease.clone(),
+            &ExecuteMsg::Close(),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<lease::error::ContractError>(),
+        Some(&lease::error::ContractError::Unauthorized(
+            access_control::error::Error::Unauthorized {}
+        ))
+    );
+
+    let response = test_case
+        .app
+        .execute(customer.clone(), lease.clone(), &ExecuteMsg::Close(), &[])
+        .unwrap()
+        .unwrap_response();
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-open-order-close")
+            .add_attribute("id", lease.clone())
+            .add_attribute("reason", "cancel"),
+    );
+
+    assert_refunded(&test_case, lease, customer, balance_before);
+}
+
+#[test]
+fn expiry() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+    let customer = testing::user(USER);
+    let balance_before = payment_balance(&test_case, &customer);
+    let lease = place_order(&mut test_case, DOWNPAYMENT);
+
+    test_case.app.time_shift(VALIDITY - Duration::from_secs(1));
+    () = send_time_alarm(&mut test_case, lease.clone())
+        .ignore_response()
+        .unwrap_response();
+    assert!(matches!(
+        super::state_query(&test_case, lease.clone()),
+        StateResponse::Pending { .. }
+    ));
+
+    test_case.app.time_shift(Duration::from_secs(1));
+    let response = send_time_alarm(&mut test_case, lease.clone()).unwrap_response();
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-open-order-close")
+            .add_attribute("id", lease.clone())
+            .add_attribute("reason", "expiry"),
+    );
+
+    assert_refunded(&test_case, lease.clone(), customer, balance_before);
+
+    // a late price alarm is ignored
+    () = super::deliver_new_price(&mut test_case, LeaseCoin::new(3), LpnCoin::new(1))
+        .ignore_response()
+        .unwrap_response();
+    assert_eq!(
+        StateResponse::Closed(),
+        super::state_query(&test_case, lease)
+    );
+}
+
+#[test]
+fn invalid_validity() {
+    let mut test_case: LeaseTestCase = super::create_test_case::<PaymentCurrency>();
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::ExecuteMsg::OpenLease {
+                currency: currency::dto::<LeaseCurrency, _>(),
+                max_ltd: None,
+                open_order: Some(OpenOrder {
+                    price: target_price(),
+                    valid_for: OpenOrder::MAX_VALIDITY + Duration::from_secs(1),
+                }),
+            },
+            &[cwcoin(DOWNPAYMENT)],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<lease::error::ContractError>(),
+        Some(lease::error::ContractError::InvalidOrderValidity { .. })
+    ));
+}
+
+fn place_order(test_case: &mut LeaseTestCase, downpayment: PaymentCoin) -> Addr {
+    let response = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::ExecuteMsg::OpenLease {
+                currency: currency::dto::<LeaseCurrency, _>(),
+                max_ltd: None,
+                open_order: Some(OpenOrder {
+                    price: target_price(),
+                    valid_for: VALIDITY,
+                }),
+            },
+            &[cwcoin(downpayment)],
+        )
+        .unwrap()
+        .unwrap_response();
+
+    let lease = leaser_mod::expect_a_lease(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        testing::user(USER),
+    );
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-open-order")
+            .add_attribute("id", lease.clone())
+            .add_attribute("customer", testing::user(USER)),
+    );
+
+    assert_eq!(
+        StateResponse::Pending {
+            currency: currency::dto::<LeaseCurrency, _>(),
+            downpayment: downpayment.into(),
+            price: target_price(),
+            expiration: test_case.app.block_info().time + VALIDITY,
+        },
+        super::state_query(test_case, lease.clone())
+    );
+    lease
+}
+
+/// The lease currency price, initially 1:1 to the LPN, below which the order triggers
+fn target_price() -> OrderPrice {
+    price::total_of(LeaseCoin::new(2))
+        .is(LpnCoin::new(1))
+        .into()
+}
+
+fn send_time_alarm(
+    test_case: &mut LeaseTestCase,
+    lease: Addr,
+) -> ResponseWithInterChainMsgs<'_, AppResponse> {
+    test_case
+        .app
+        .execute(
+            test_case.address_book.time_alarms().clone(),
+            lease,
+            &ExecuteMsg::TimeAlarm {},
+            &[],
+        )
+        .unwrap()
+}
+
+fn pending_leases(test_case: &LeaseTestCase) -> Vec<PendingLease> {
+    leaser_mod::query_pending_leases(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        None,
+        None,
+    )
+    .leases
+}
+
+fn payment_balance(test_case: &LeaseTestCase, customer: &Addr) -> PaymentCoin {
+    platform::bank::balance::<_, PaymentGroup>(customer, test_case.app.query()).unwrap()
+}
+
+fn assert_refunded(
+    test_case: &LeaseTestCase,
+    lease: Addr,
+    customer: Addr,
+    balance_before: PaymentCoin,
+) {
+    assert_eq!(
+        StateResponse::Closed(),
+        super::state_query(test_case, lease.clone())
+    );
+    assert_eq!(balance_before, payment_balance(test_case, &customer));
+    assert_eq!(
+        test_case
+            .app
+            .query()
+            .query_all_balances(lease)
+            .unwrap()
+            .as_slice(),
+        []
+    );
+    leaser_mod::assert_no_leases(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        customer,
+    );
+}
@@ -1,7 +1,7 @@
 use std::slice;
 
 use ::swap::testing::SwapRequest;
-use currencies::PaymentGroup;
+use currencies::{Lpns, PaymentGroup};
 use currency::CurrencyDef;
 use finance::{
     coin::{Amount, Coin},
@@ -13,12 +13,15 @@ use finance::{
     zero::Zero,
 };
 use lease::api::{
-    query::{ClosePolicy, StateResponse},
+    query::{
+        ClosePolicy, InterestSplitResponse, PayoffResponse, QueryMsg, StateResponse,
+        SummaryResponse,
+    },
     ExecuteMsg,
 };
 use platform::coin_legacy::to_cosmwasm_on_dex;
 use sdk::{
-    cosmwasm_std::{Addr, Timestamp},
+    cosmwasm_std::{Addr, Event, Timestamp},
     cw_multi_test::AppResponse,
     testing,
 };
@@ -46,10 +49,9 @@ fn partial_repay() {
         &Rational::new(1, 2),
         super::create_payment_coin(amount.into()),
     );
-    let expected_result =
-        super::expected_newly_opened_state(&test_case, downpayment, partial_payment);
-
     let lease_addr: Addr = super::open_lease(&mut test_case, downpayment, None);
+    let expected_result =
+        super::expected_newly_opened_state(&test_case, &lease_addr, downpayment, partial_payment);
 
     repay(&mut test_case, lease_addr.clone(), partial_payment);
 
@@ -123,6 +125,200 @@ fn partial_repay_after_time() {
     }
 }
 
+#[test]
+fn state_at_past_time() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment: PaymentCoin = DOWNPAYMENT;
+
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+    let opened_at = validity(super::state_query(&test_case, lease_address.clone()));
+
+    let projection = Duration::from_days(20);
+    let projected = common::lease::query_state(
+        &test_case.app,
+        lease_address.clone(),
+        projection.secs().try_into().unwrap(),
+        None,
+    )
+    .unwrap();
+
+    test_case.app.time_shift(Duration::from_days(45));
+
+    let at = opened_at + projection;
+    let past = common::lease::query_state(&test_case.app, lease_address, 0, Some(at)).unwrap();
+
+    assert_eq!(at, validity(past.clone()));
+    assert_eq!(loan_state(projected), loan_state(past));
+}
+
+#[test]
+fn state_before_due_period() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment: PaymentCoin = DOWNPAYMENT;
+
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+    let opened_at = validity(super::state_query(&test_case, lease_address.clone()));
+
+    test_case.app.time_shift(Duration::from_days(1));
+
+    let err = common::lease::query_state(
+        &test_case.app,
+        lease_address,
+        0,
+        Some(opened_at - Duration::from_nanos(1)),
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("[Lease] The state cannot be estimated at"));
+}
+
+#[test]
+fn repay_lpn() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease_addr = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    let principal_before = principal_due(&test_case, lease_addr.clone());
+
+    let payment = LpnCoin::new(1_000_000);
+    test_case.send_funds_from_admin(testing::user(USER), &[cwcoin(payment)]);
+
+    () = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease_addr.clone(),
+            &ExecuteMsg::Repay {},
+            &[cwcoin(payment)],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    assert_eq!(
+        principal_before - payment,
+        principal_due(&test_case, lease_addr)
+    );
+}
+
+#[test]
+fn repay_accrual_snapshot() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease_addr = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    // accrue some interest but stay within the due period not to collect it as overdue
+    test_case.app.time_shift(Duration::from_nanos(
+        LeaserInstantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+    super::feed_price(&mut test_case);
+
+    let payment = LpnCoin::new(1_000_000);
+    test_case.send_funds_from_admin(testing::user(USER), &[cwcoin(payment)]);
+
+    let response: AppResponse = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease_addr.clone(),
+            &ExecuteMsg::Repay {},
+            &[cwcoin(payment)],
+        )
+        .unwrap()
+        .unwrap_response();
+
+    let StateResponse::Opened {
+        principal_due,
+        overdue_margin,
+        overdue_interest,
+        due_margin,
+        due_interest,
+        validity,
+        ..
+    } = super::state_query(&test_case, lease_addr.clone())
+    else {
+        unreachable!()
+    };
+    let amount = |coin| LpnCoin::try_from(coin).unwrap();
+    let (principal_due, overdue_margin, overdue_interest, due_margin, due_interest) = (
+        amount(principal_due),
+        amount(overdue_margin),
+        amount(overdue_interest),
+        amount(due_margin),
+        amount(due_interest),
+    );
+
+    platform::tests::assert_event(
+        &response.events,
+        &Event::new("wasm-ls-accrual-snapshot")
+            .add_attribute("to", lease_addr)
+            .add_attribute("at", validity.nanos().to_string())
+            .add_attribute(
+                "total-due-amount",
+                Amount::from(
+                    principal_due + overdue_margin + overdue_interest + due_margin + due_interest,
+                )
+                .to_string(),
+            )
+            .add_attribute("principal", Amount::from(principal_due).to_string())
+            .add_attribute(
+                "overdue",
+                Amount::from(overdue_margin + overdue_interest).to_string(),
+            )
+            .add_attribute(
+                "margin-interest",
+                Amount::from(overdue_margin + due_margin).to_string(),
+            )
+            .add_attribute(
+                "loan-interest",
+                Amount::from(overdue_interest + due_interest).to_string(),
+            ),
+    );
+}
+
+#[test]
+fn summary_during_repay() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease_addr = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+    let payment = super::create_payment_coin(1_000);
+
+    let lease = lease_addr.clone();
+    let _: AppResponse =
+        repay_with_hook_on_swap(&mut test_case, lease_addr.clone(), payment, |app| {
+            let summary = summary(app, lease.clone());
+            let StateResponse::Opened { in_progress, .. } = common::lease::fetch_state(app, lease)
+            else {
+                unreachable!()
+            };
+            assert!(in_progress.is_some());
+            assert_eq!(in_progress, summary.in_progress);
+        });
+
+    assert_eq!(None, summary(&test_case.app, lease_addr).in_progress);
+}
+
+#[test]
+fn repay_unsupported_currency() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease_addr = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    let payment: CwCoin = common::cwcoin_dex(PaymentCoin::new(1_000_000));
+    test_case.send_funds_from_admin(testing::user(USER), slice::from_ref(&payment));
+
+    let err = test_case
+        .app
+        .execute(
+            testing::user(USER),
+            lease_addr,
+            &ExecuteMsg::Repay {},
+            slice::from_ref(&payment),
+        )
+        .unwrap_err();
+
+    assert!(err.root_cause().to_string().starts_with(&format!(
+        "[Lease] Unsupported payment currency '{}'",
+        payment.denom
+    )));
+}
+
 #[test]
 #[should_panic = "[Lease] [Position] The transaction amount should worth at least"]
 fn insufficient_payment() {
@@ -161,6 +357,149 @@ fn full_repay() {
     assert_eq!(query_result, expected_result);
 }
 
+#[test]
+fn full_repay_with_payoff() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment: PaymentCoin = DOWNPAYMENT;
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    test_case.app.time_shift(Duration::from_nanos(
+        LeaserInstantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+    super::feed_price(&mut test_case);
+
+    let payoff: PayoffResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            lease_address.clone(),
+            &QueryMsg::Payoff { due_projection: 0 },
+        )
+        .unwrap();
+    assert_eq!(payoff.at, test_case.app.block_info().time);
+
+    let StateResponse::Opened { principal_due, .. } =
+        super::state_query(&test_case, lease_address.clone())
+    else {
+        unreachable!()
+    };
+    let payoff_amount = LpnCoin::try_from(payoff.amount).unwrap();
+    assert!(payoff_amount > LpnCoin::try_from(principal_due).unwrap());
+
+    repay(
+        &mut test_case,
+        lease_address.clone(),
+        price::total(
+            payoff_amount,
+            super::price_lpn_of::<PaymentCurrency>().inv(),
+        ),
+    );
+
+    assert!(matches!(
+        super::state_query(&test_case, lease_address.clone()),
+        StateResponse::Paid { .. }
+    ));
+    assert_eq!(
+        test_case
+            .app
+            .query()
+            .query_all_balances(lease_address)
+            .unwrap(),
+        &[]
+    );
+}
+
+#[test]
+fn full_repay_interest_split() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment: PaymentCoin = DOWNPAYMENT;
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    test_case.app.time_shift(Duration::from_nanos(
+        LeaserInstantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+    super::feed_price(&mut test_case);
+
+    let split: InterestSplitResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(lease_address.clone(), &QueryMsg::InterestSplit { at: None })
+        .unwrap();
+    assert_eq!(split.at, test_case.app.block_info().time);
+    let lender_interest = LpnCoin::try_from(split.lender_interest).unwrap();
+    let protocol_margin = LpnCoin::try_from(split.protocol_margin).unwrap();
+    assert!(!lender_interest.is_zero());
+    assert!(!protocol_margin.is_zero());
+
+    let StateResponse::Opened { principal_due, .. } =
+        super::state_query(&test_case, lease_address.clone())
+    else {
+        unreachable!()
+    };
+    let principal_due = LpnCoin::try_from(principal_due).unwrap();
+
+    let lpp_balance = lpn_balance(&test_case, test_case.address_book.lpp());
+    let profit_balance = lpn_balance(&test_case, test_case.address_book.profit());
+
+    repay(
+        &mut test_case,
+        lease_address.clone(),
+        price::total(
+            principal_due + lender_interest + protocol_margin,
+            super::price_lpn_of::<PaymentCurrency>().inv(),
+        ),
+    );
+
+    assert!(matches!(
+        super::state_query(&test_case, lease_address),
+        StateResponse::Paid { .. }
+    ));
+    // the loan interest goes to the LPP along with the principal, and the margin to the Profit
+    assert_eq!(
+        lpp_balance + principal_due + lender_interest,
+        lpn_balance(&test_case, test_case.address_book.lpp())
+    );
+    assert_eq!(
+        profit_balance + protocol_margin,
+        lpn_balance(&test_case, test_case.address_book.profit())
+    );
+}
+
+#[test]
+fn interest_split_in_past() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let lease_address = super::open_lease(&mut test_case, DOWNPAYMENT, None);
+
+    test_case.app.time_shift(Duration::from_nanos(
+        LeaserInstantiator::REPAYMENT_PERIOD.nanos() >> 1,
+    ));
+    super::feed_price(&mut test_case);
+    let now = test_case.app.block_info().time;
+
+    let split: InterestSplitResponse = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            lease_address.clone(),
+            &QueryMsg::InterestSplit { at: Some(now) },
+        )
+        .unwrap();
+    assert_eq!(now, split.at);
+
+    let past = now.minus_nanos(1);
+    let err = test_case
+        .app
+        .query()
+        .query_wasm_smart::<InterestSplitResponse>(
+            lease_address,
+            &QueryMsg::InterestSplit { at: Some(past) },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&lease::error::ContractError::InterestSplitInPast { at: past, now }.to_string()));
+}
+
 #[test]
 fn full_repay_with_max_ltd() {
     let mut test_case = super::create_test_case::<PaymentCurrency>();
@@ -187,6 +526,8 @@ fn full_repay_with_max_ltd() {
         close_policy: ClosePolicy::default(),
         validity: Timestamp::from_nanos(1537237459879305533),
         in_progress: None,
+        needs_realarm: false,
+        dex_account: super::expected_dex_account(&lease_address),
     };
     let query_result = super::state_query(&test_case, lease_address.clone());
 
@@ -339,6 +680,10 @@ where
     .unwrap_response()
 }
 
+fn lpn_balance(test_case: &LeaseTestCase, account: &Addr) -> LpnCoin {
+    platform::bank::balance::<LpnCurrency, Lpns>(account, test_case.app.query()).unwrap()
+}
+
 pub(crate) fn repay<ProtocolsRegistry, Treasury, Profit, Reserve, Leaser, Lpp, Oracle>(
     test_case: &mut TestCase<
         ProtocolsRegistry,
@@ -416,3 +761,63 @@ where
     )
     .ignore_response()
 }
+
+fn summary(app: &App, lease_addr: Addr) -> SummaryResponse {
+    app.query()
+        .query_wasm_smart(lease_addr, &QueryMsg::Summary {})
+        .unwrap()
+}
+
+fn principal_due(test_case: &LeaseTestCase, lease_addr: Addr) -> LpnCoin {
+    let StateResponse::Opened { principal_due, .. } = super::state_query(test_case, lease_addr)
+    else {
+        unreachable!()
+    };
+    principal_due.try_into().unwrap()
+}
+
+fn validity(state: StateResponse) -> Timestamp {
+    let StateResponse::Opened { validity, .. } = state else {
+        unreachable!()
+    };
+    validity
+}
+
+fn loan_state(state: StateResponse) -> StateResponse {
+    let StateResponse::Opened {
+        amount,
+        loan_interest_rate,
+        margin_interest_rate,
+        principal_due,
+        overdue_margin,
+        overdue_interest,
+        overdue_collect_in,
+        due_margin,
+        due_interest,
+        close_policy,
+        in_progress,
+        needs_realarm,
+        dex_account,
+        ..
+    } = state
+    else {
+        unreachable!()
+    };
+    StateResponse::Opened {
+        amount,
+        loan_interest_rate,
+        margin_interest_rate,
+        principal_due,
+        overdue_margin,
+        overdue_interest,
+        overdue_collect_in,
+        due_margin,
+        due_interest,
+        due_projection: Duration::default(),
+        close_policy,
+        validity: Timestamp::default(),
+        in_progress,
+        needs_realarm,
+        dex_account,
+    }
+}
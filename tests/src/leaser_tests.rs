@@ -7,7 +7,10 @@ use finance::{
     coin::{Amount, Coin},
     percent::Percent,
     price::{total, total_of, Price},
+    zero::Zero,
 };
+use leaser::msg::QuoteParamsResponse;
+use lpp::borrow::InterestRate;
 use sdk::{
     cosmwasm_ext::Response,
     cosmwasm_std::{coin, Addr, DepsMut, Env, Event, MessageInfo},
@@ -89,6 +92,7 @@ fn open_multiple_loans() {
                 &leaser::msg::ExecuteMsg::OpenLease {
                     currency: currency::dto::<LeaseCurrency, _>(),
                     max_ltd: None,
+                    open_order: None,
                 },
                 &[cwcoin::<Lpn, _>(75)],
             )
@@ -116,6 +120,7 @@ fn open_multiple_loans() {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: currency::dto::<LeaseCurrency, _>(),
                 max_ltd: None,
+                open_order: None,
             },
             &[cwcoin::<Lpn, _>(78)],
         )
@@ -192,6 +197,11 @@ fn test_quote() {
 
     assert_eq!(resp.annual_interest_rate_margin, Percent::from_permille(30),);
 
+    assert_eq!(
+        resp.effective_annual_interest_rate,
+        Percent::from_permille(102)
+    );
+
     let leaser = test_case.address_book.leaser().clone();
     let resp = leaser_mod::query_quote::<Downpayment, LeaseCurrency>(
         &test_case.app,
@@ -379,6 +389,200 @@ fn test_quote_fixed_rate() {
     assert_eq!(resp.annual_interest_rate, Percent::HUNDRED);
 
     assert_eq!(resp.annual_interest_rate_margin, Percent::from_percent(3));
+
+    assert_eq!(
+        resp.effective_annual_interest_rate,
+        Percent::from_percent(103)
+    );
+}
+
+#[test]
+fn test_quote_rate_overflow() {
+    type Lpn = TheCurrency;
+    type Downpayment = Lpn;
+    type LeaseCurrency = LeaseC2;
+
+    let mut test_case = TestCaseBuilder::<Lpn>::new()
+        .init_lpp(
+            Some(
+                ContractWrapper::new(
+                    lpp::contract::execute,
+                    lpp::contract::instantiate,
+                    lpp_mod::mock_max_quote_query,
+                )
+                .with_sudo(lpp::contract::sudo),
+            ),
+            BASE_INTEREST_RATE,
+            UTILIZATION_OPTIMAL,
+            ADDON_OPTIMAL_INTEREST_RATE,
+            TestCase::DEFAULT_LPP_MIN_UTILIZATION,
+        )
+        .init_time_alarms()
+        .init_protocols_registry(Registry::NoProtocol)
+        .init_oracle(None)
+        .init_treasury()
+        .init_profit(24)
+        .init_reserve()
+        .init_leaser()
+        .into_generic();
+
+    let feeder = setup_feeder(&mut test_case);
+    oracle_mod::feed_price(
+        &mut test_case,
+        feeder,
+        Coin::<LeaseCurrency>::new(3),
+        Coin::<Lpn>::new(1),
+    );
+    let err = leaser_mod::try_query_quote::<Downpayment, LeaseCurrency>(
+        &test_case.app,
+        test_case.address_book.leaser().clone(),
+        Coin::<Downpayment>::new(100),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("[Finance] [OverflowError]"));
+}
+
+#[test]
+fn test_quote_params() {
+    type Lpn = TheCurrency;
+    type Downpayment = Lpn;
+    type LeaseCurrency = LeaseC2;
+
+    let user_addr = testing::user(USER);
+
+    let mut test_case = TestCaseBuilder::<Lpn>::with_reserve(&[
+        cwcoin::<Lpn, _>(1_000_000_000),
+        cwcoin_dex::<Lpn, _>(1_000_000_000),
+        cwcoin::<LeaseCurrency, _>(1_000_000_000),
+        cwcoin_dex::<LeaseCurrency, _>(1_000_000_000),
+    ])
+    .init_lpp(
+        None,
+        BASE_INTEREST_RATE,
+        UTILIZATION_OPTIMAL,
+        ADDON_OPTIMAL_INTEREST_RATE,
+        TestCase::DEFAULT_LPP_MIN_UTILIZATION,
+    )
+    .init_time_alarms()
+    .init_protocols_registry(Registry::NoProtocol)
+    .init_oracle(None)
+    .init_treasury()
+    .init_profit(24)
+    .init_reserve()
+    .init_leaser()
+    .into_generic();
+
+    let feeder = setup_feeder(&mut test_case);
+    let price_lease_lpn: Price<LeaseCurrency, Lpn> = total_of(3.into()).is(1.into());
+    oracle_mod::feed_price(
+        &mut test_case,
+        feeder,
+        Coin::<LeaseCurrency>::new(3),
+        Coin::<Lpn>::new(1),
+    );
+
+    let leaser = test_case.address_book.leaser().clone();
+    let params = leaser_mod::query_quote_params(&test_case.app, leaser.clone());
+    let min_borrow: Coin<Lpn> = params
+        .lease_position_spec
+        .min_transaction
+        .try_into()
+        .unwrap();
+    assert_eq!(Percent::from_percent(3), params.lease_interest_rate_margin);
+    assert_eq!(None, params.lease_origination_fee);
+    assert_eq!(
+        InterestRate::new(
+            BASE_INTEREST_RATE,
+            UTILIZATION_OPTIMAL,
+            ADDON_OPTIMAL_INTEREST_RATE
+        ),
+        Some(params.lpp_borrow_rate)
+    );
+    let lpp_balance: Coin<Lpn> = params.lpp_balance.try_into().unwrap();
+    assert_eq!(Ok(Coin::<Lpn>::ZERO), params.lpp_total_due.try_into());
+    assert_eq!(
+        lpp_annual_interest_rate(&params, min_borrow),
+        params.lpp_annual_interest_rate
+    );
+
+    let downpayment = Coin::<Downpayment>::new(1_000);
+    let quote = leaser_mod::query_quote::<Downpayment, LeaseCurrency>(
+        &test_case.app,
+        leaser.clone(),
+        downpayment,
+        None,
+    );
+
+    let borrow = params
+        .lease_position_spec
+        .liability
+        .init_borrow_amount(downpayment, None);
+    assert_eq!(quote.borrow.try_into(), Ok(borrow));
+    assert_eq!(
+        quote.total.try_into(),
+        Ok(total(downpayment + borrow, price_lease_lpn.inv()))
+    );
+    assert_eq!(
+        lpp_annual_interest_rate(&params, borrow),
+        quote.annual_interest_rate
+    );
+    assert_eq!(
+        quote.annual_interest_rate_margin,
+        params.lease_interest_rate_margin
+    );
+
+    // the loan moves the utilization past the optimal one
+    test_case.send_funds_from_admin(user_addr.clone(), &[cwcoin(downpayment)]);
+    let mut response: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
+        .app
+        .execute(
+            user_addr,
+            leaser.clone(),
+            &leaser::msg::ExecuteMsg::OpenLease {
+                currency: currency::dto::<LeaseCurrency, _>(),
+                max_ltd: None,
+                open_order: None,
+            },
+            &[cwcoin(downpayment)],
+        )
+        .unwrap();
+    response.expect_register_ica(TestCase::DEX_CONNECTION_ID, TestCase::LEASE_ICA_ID);
+    let lease = lease_addr(&response.unwrap_response().events);
+    () = lease_mod::complete_initialization_with_swap(
+        &mut test_case.app,
+        TestCase::DEX_CONNECTION_ID,
+        lease,
+        downpayment,
+        borrow,
+        |amount, _, _| total(Coin::<Lpn>::new(amount), price_lease_lpn.inv()).into(),
+    )
+    .ignore_response()
+    .unwrap_response();
+
+    let params_past_loan = leaser_mod::query_quote_params(&test_case.app, leaser);
+    assert_eq!(params.lpp_borrow_rate, params_past_loan.lpp_borrow_rate);
+    assert_eq!(
+        Ok(lpp_balance - borrow),
+        params_past_loan.lpp_balance.try_into()
+    );
+    assert_eq!(Ok(borrow), params_past_loan.lpp_total_due.try_into());
+    assert!(params.lpp_annual_interest_rate < params_past_loan.lpp_annual_interest_rate);
+    assert_eq!(
+        lpp_annual_interest_rate(&params_past_loan, min_borrow),
+        params_past_loan.lpp_annual_interest_rate
+    );
+}
+
+/// Reproduce the LPP annual interest rate of a borrow out of the quote parameters
+fn lpp_annual_interest_rate(params: &QuoteParamsResponse, borrow: Coin<TheCurrency>) -> Percent {
+    let balance: Coin<TheCurrency> = params.lpp_balance.try_into().unwrap();
+    let total_due: Coin<TheCurrency> = params.lpp_total_due.try_into().unwrap();
+
+    params
+        .lpp_borrow_rate
+        .calculate(total_due + borrow, balance - borrow)
 }
 
 fn setup_feeder<ProtocolsRegistry, Treasury, Profit, Reserve, Leaser, Lpp, TimeAlarms>(
@@ -454,6 +658,7 @@ fn open_loans_lpp_fails() {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: currency::dto::<LeaseCurrency, _>(),
                 max_ltd: None,
+                open_order: None,
             },
             &[downpayment],
         )
@@ -551,6 +756,7 @@ where
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: currency::dto::<LeaseC, _>(),
                 max_ltd: None,
+                open_order: None,
             },
             &[cwcoin(downpayment)],
         )
@@ -616,6 +822,7 @@ fn open_loans_insufficient_amount(downpayment: Amount) {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: currency::dto::<LeaseCurrency, _>(),
                 max_ltd: None,
+                open_order: None,
             },
             &[downpayment_amount],
         )
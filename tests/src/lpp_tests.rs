@@ -13,7 +13,7 @@ use lpp::{
     contract::ContractError,
     msg::{
         BalanceResponse, LppBalanceResponse, PriceResponse, QueryLoanResponse, QueryQuoteResponse,
-        RewardsResponse, SudoMsg,
+        RewardsResponse, SudoMsg, UtilizationResponse,
     },
     state::Config,
 };
@@ -25,12 +25,7 @@ use sdk::{
 };
 
 use crate::common::{
-    cwcoin,
-    lease::{
-        InitConfig as LeaseInitConfig, Instantiator as LeaseInstantiator,
-        InstantiatorAddresses as LeaseInstantiatorAddresses,
-        InstantiatorConfig as LeaseInstantiatorConfig,
-    },
+    self, cwcoin,
     lpp::{LppExecuteMsg, LppQueryMsg},
     protocols::Registry,
     test_case::{builder::BlankBuilder as TestCaseBuilder, TestCase},
@@ -47,7 +42,7 @@ fn general_interest_rate(
 ) -> Percent {
     let utilization_rate = Percent::from_ratio(loan, balance).min(Percent::from_ratio(
         optimal_rate.units(),
-        (Percent::HUNDRED - optimal_rate).units(),
+        Percent::HUNDRED.saturating_sub(optimal_rate).units(),
     ));
 
     base_rate
@@ -359,24 +354,14 @@ fn deposit_and_withdraw() {
     );
 
     // loans should not change asserts for lender2, the default loan
-    let _: Addr = LeaseInstantiator::instantiate::<Lpn>(
-        &mut test_case.app,
-        test_case.address_book.lease_code(),
-        LeaseInstantiatorAddresses {
-            lpp: test_case.address_book.lpp().clone(),
-            time_alarms: test_case.address_book.time_alarms().clone(),
-            oracle: test_case.address_book.oracle().clone(),
-            profit: test_case.address_book.profit().clone(),
-            reserve: test_case.address_book.reserve().clone(),
-            finalizer: test_case.address_book.leaser().clone(),
-        },
-        LeaseInitConfig::new(currency::dto::<LeaseCurrency, _>(), loan.into(), None),
-        LeaseInstantiatorConfig {
-            liability_init_percent: Percent::from_percent(50), // simplify case: borrow == downpayment
-            ..LeaseInstantiatorConfig::default()
-        },
-        TestCase::DEX_CONNECTION_ID,
-        TestCase::LEASE_ICA_ID,
+    let _: Vec<Addr> = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
     );
 
     let balance_nlpn2: BalanceResponse = test_case
@@ -466,6 +451,122 @@ fn deposit_and_withdraw() {
     assert_eq!(balance_nlpn.balance.u128(), 0);
 }
 
+#[test]
+fn withdraw_at_liquidity_boundary() {
+    let app_balance = 10_000_000_000;
+    let deposit = 3_000_000;
+    let loan = 1_000_000;
+
+    let lender = testing::user("lender");
+
+    let mut test_case = TestCaseBuilder::<Lpn>::with_reserve(&[lpn_cwcoin(app_balance)])
+        .init_lpp_with_funds(
+            None,
+            &[],
+            BASE_INTEREST_RATE,
+            UTILIZATION_OPTIMAL,
+            ADDON_OPTIMAL_INTEREST_RATE,
+            TestCase::DEFAULT_LPP_MIN_UTILIZATION,
+        )
+        .init_time_alarms()
+        .init_protocols_registry(Registry::NoProtocol)
+        .init_oracle(None)
+        .init_treasury()
+        .init_profit(24)
+        .init_reserve()
+        .init_leaser()
+        .into_generic();
+
+    test_case.send_funds_from_admin(lender.clone(), &[lpn_cwcoin(deposit)]);
+
+    let _: AppResponse = test_case
+        .app
+        .execute(
+            lender.clone(),
+            test_case.address_book.lpp().clone(),
+            &LppExecuteMsg::Deposit(),
+            &[lpn_cwcoin(deposit)],
+        )
+        .unwrap()
+        .unwrap_response();
+
+    let _: Vec<Addr> = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
+    );
+
+    let lpp_balance: LppBalanceResponse<Lpns> = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            test_case.address_book.lpp().clone(),
+            &LppQueryMsg::LppBalance(),
+        )
+        .unwrap();
+    assert_eq!(lpp_balance.balance, Coin::<Lpn>::new(deposit - loan).into());
+    assert_eq!(
+        lpp_balance.total_principal_due,
+        Coin::<Lpn>::new(loan).into()
+    );
+
+    let price: PriceResponse<Lpn> = test_case
+        .app
+        .query()
+        .query_wasm_smart(test_case.address_book.lpp().clone(), &LppQueryMsg::Price())
+        .unwrap();
+    let max_withdrawal: Amount =
+        price::total(Coin::<Lpn>::new(deposit - loan), price.0.inv()).into();
+
+    let err = test_case
+        .app
+        .execute(
+            lender.clone(),
+            test_case.address_book.lpp().clone(),
+            &LppExecuteMsg::Burn {
+                amount: (max_withdrawal + 1).into(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::InsufficientLiquidity(max_withdrawal.into()))
+    );
+
+    () = test_case
+        .app
+        .execute(
+            lender.clone(),
+            test_case.address_book.lpp().clone(),
+            &LppExecuteMsg::Burn {
+                amount: max_withdrawal.into(),
+            },
+            &[],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    let lpp_balance: LppBalanceResponse<Lpns> = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            test_case.address_book.lpp().clone(),
+            &LppQueryMsg::LppBalance(),
+        )
+        .unwrap();
+    assert_eq!(lpp_balance.balance, Coin::<Lpn>::new(0).into());
+    assert_eq!(
+        lpp_balance.total_principal_due,
+        Coin::<Lpn>::new(loan).into()
+    );
+}
+
 #[test]
 fn loan_open_wrong_id() {
     let _admin = testing::user(ADMIN);
@@ -566,15 +667,6 @@ fn loan_open_and_repay() {
         .send_funds_from_admin(lender.clone(), &[lpn_cwcoin(init_deposit)])
         .send_funds_from_admin(hacker.clone(), &[lpn_cwcoin(hacker_balance)]);
 
-    let lease_addresses = LeaseInstantiatorAddresses {
-        lpp: test_case.address_book.lpp().clone(),
-        time_alarms: test_case.address_book.time_alarms().clone(),
-        oracle: test_case.address_book.oracle().clone(),
-        profit: test_case.address_book.profit().clone(),
-        reserve: test_case.address_book.reserve().clone(),
-        finalizer: test_case.address_book.leaser().clone(),
-    };
-
     // initial deposit
     () = test_case
         .app
@@ -621,18 +713,17 @@ fn loan_open_and_repay() {
     }
 
     // borrow
-    let loan_addr1 = LeaseInstantiator::instantiate::<Lpn>(
-        &mut test_case.app,
-        test_case.address_book.lease_code(),
-        lease_addresses.clone(),
-        LeaseInitConfig::new(currency::dto::<LeaseCurrency, _>(), loan1.into(), None),
-        LeaseInstantiatorConfig {
-            liability_init_percent: Percent::from_percent(50), // simplify case: borrow == downpayment
-            ..LeaseInstantiatorConfig::default()
-        },
-        TestCase::DEX_CONNECTION_ID,
-        TestCase::LEASE_ICA_ID,
-    );
+    let [loan_addr1]: [Addr; 1] = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan1)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
+    )
+    .try_into()
+    .unwrap();
 
     // double borrow
     _ = test_case
@@ -666,6 +757,25 @@ fn loan_open_and_repay() {
         Coin::<Lpn>::new(total_interest_due).into()
     );
 
+    let utilization: UtilizationResponse<Lpns> = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            test_case.address_book.lpp().clone(),
+            &LppQueryMsg::Utilization {},
+        )
+        .unwrap();
+    assert_eq!(resp.total_principal_due, utilization.total_principal_due);
+    assert_eq!(resp.total_interest_due, utilization.total_interest_due);
+    assert_eq!(resp.balance, utilization.balance);
+    assert_eq!(
+        Percent::from_ratio(
+            loan1_u32 + total_interest_due_u32,
+            loan1_u32 + total_interest_due_u32 + balance1_u32
+        ),
+        utilization.utilization
+    );
+
     let interest2 = interest_rate(loan1_u32 + loan2_u32 + total_interest_due_u32, balance1_u32);
 
     let quote: QueryQuoteResponse = test_case
@@ -684,18 +794,17 @@ fn loan_open_and_repay() {
     }
 
     // borrow 2
-    let loan_addr2 = LeaseInstantiator::instantiate::<Lpn>(
-        &mut test_case.app,
-        test_case.address_book.lease_code(),
-        lease_addresses,
-        LeaseInitConfig::new(currency::dto::<LeaseCurrency, _>(), loan2.into(), None),
-        LeaseInstantiatorConfig {
-            liability_init_percent: Percent::from_percent(50), // simplify case: borrow == downpayment
-            ..LeaseInstantiatorConfig::default()
-        },
-        TestCase::DEX_CONNECTION_ID,
-        TestCase::LEASE_ICA_ID,
-    );
+    let [loan_addr2]: [Addr; 1] = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan2)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
+    )
+    .try_into()
+    .unwrap();
 
     test_case.app.time_shift(Duration::from_nanos(YEAR / 2));
 
@@ -979,25 +1088,17 @@ fn compare_lpp_states() {
     }
 
     // borrow
-    let loan_addr1 = LeaseInstantiator::instantiate::<Lpn>(
-        &mut test_case.app,
-        test_case.address_book.lease_code(),
-        LeaseInstantiatorAddresses {
-            lpp: test_case.address_book.lpp().clone(),
-            time_alarms: test_case.address_book.time_alarms().clone(),
-            oracle: test_case.address_book.oracle().clone(),
-            profit: test_case.address_book.profit().clone(),
-            reserve: test_case.address_book.reserve().clone(),
-            finalizer: test_case.address_book.leaser().clone(),
-        },
-        LeaseInitConfig::new(currency::dto::<LeaseCurrency, _>(), loan1.into(), None),
-        LeaseInstantiatorConfig {
-            liability_init_percent: Percent::from_percent(50), // simplify case: borrow == downpayment
-            ..LeaseInstantiatorConfig::default()
-        },
-        TestCase::DEX_CONNECTION_ID,
-        TestCase::LEASE_ICA_ID,
-    );
+    let [loan_addr1]: [Addr; 1] = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan1)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
+    )
+    .try_into()
+    .unwrap();
 
     // double borrow
     _ = test_case
@@ -1048,25 +1149,17 @@ fn compare_lpp_states() {
     }
 
     // borrow 2
-    let loan_addr2 = LeaseInstantiator::instantiate::<Lpn>(
-        &mut test_case.app,
-        test_case.address_book.lease_code(),
-        LeaseInstantiatorAddresses {
-            lpp: test_case.address_book.lpp().clone(),
-            time_alarms: test_case.address_book.time_alarms().clone(),
-            oracle: test_case.address_book.oracle().clone(),
-            profit: test_case.address_book.profit().clone(),
-            reserve: test_case.address_book.reserve().clone(),
-            finalizer: test_case.address_book.leaser().clone(),
-        },
-        LeaseInitConfig::new(currency::dto::<LeaseCurrency, _>(), loan2.into(), None),
-        LeaseInstantiatorConfig {
-            liability_init_percent: Percent::from_percent(50), // simplify case: borrow == downpayment
-            ..LeaseInstantiatorConfig::default()
-        },
-        TestCase::DEX_CONNECTION_ID,
-        TestCase::LEASE_ICA_ID,
-    );
+    let [loan_addr2]: [Addr; 1] = common::leaser::open_leases(
+        &mut test_case,
+        testing::user(ADMIN),
+        &[(
+            currency::dto::<LeaseCurrency, _>(),
+            lpn_cwcoin(Coin::<Lpn>::new(loan2)),
+        )],
+        Some(Percent::HUNDRED), // simplify case: borrow == downpayment
+    )
+    .try_into()
+    .unwrap();
 
     test_case.app.time_shift(Duration::from_nanos(YEAR / 2));
 
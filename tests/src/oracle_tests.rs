@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use currencies::{
-    testing::{LeaseC1, LeaseC2, PaymentC1, PaymentC4, PaymentC5, PaymentC6, PaymentC7},
+    testing::{LeaseC2, LeaseC3, PaymentC1, PaymentC4, PaymentC5, PaymentC6, PaymentC7},
     LeaseGroup, LeaseGroup as AlarmCurrencies, Lpn as BaseCurrency, Lpn, Lpns,
     Lpns as BaseCurrencies, PaymentGroup, PaymentGroup as PriceCurrencies,
 };
@@ -38,18 +38,13 @@ use versioning::{ProtocolMigrationMessage, ProtocolPackageReleaseId, ReleaseId};
 use crate::common::{
     leaser as leaser_mod, oracle as oracle_mod, oracle as oracle_common,
     protocols::Registry,
-    test_case::{
-        app::App,
-        builder::BlankBuilder as TestCaseBuilder,
-        response::{RemoteChain as _, ResponseWithInterChainMsgs},
-        TestCase,
-    },
+    test_case::{app::App, builder::BlankBuilder as TestCaseBuilder, TestCase},
     CwCoin, ADDON_OPTIMAL_INTEREST_RATE, ADMIN, BASE_INTEREST_RATE, USER, UTILIZATION_OPTIMAL,
 };
 
 const DISPACHER: &str = "unlisted_client";
 
-type LeaseCurrency = LeaseC1;
+type LeaseCurrency = LeaseC3;
 type TheCoin = Coin<Lpn>;
 type BaseC = PaymentC4;
 type Alarm = oracle::api::Alarm<AlarmCurrencies, BaseCurrency, BaseCurrencies>;
@@ -284,29 +279,15 @@ fn open_lease<ProtocolsRegistry, Treasury, Profit, Reserve, Lpp, Oracle, TimeAla
     >,
     downpayment: TheCoin,
 ) -> Addr {
-    let customer = testing::user(ADMIN);
-    let mut response: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
-        .app
-        .execute(
-            customer.clone(),
-            test_case.address_book.leaser().clone(),
-            &leaser::msg::ExecuteMsg::OpenLease {
-                currency: currency::dto::<LeaseCurrency, _>(),
-                max_ltd: None,
-            },
-            &[cw_coin(downpayment)],
-        )
-        .unwrap();
-
-    response.expect_register_ica(TestCase::DEX_CONNECTION_ID, TestCase::LEASE_ICA_ID);
-
-    () = response.ignore_response().unwrap_response();
-
-    leaser_mod::expect_a_lease(
-        &test_case.app,
-        test_case.address_book.leaser().clone(),
-        customer,
+    let [lease]: [Addr; 1] = leaser_mod::open_leases(
+        test_case,
+        testing::user(ADMIN),
+        &[(currency::dto::<LeaseCurrency, _>(), cw_coin(downpayment))],
+        None,
     )
+    .try_into()
+    .unwrap();
+    lease
 }
 
 #[test]
@@ -1,19 +1,20 @@
 use std::slice;
 
-use currencies::{Lpn, Lpns, Native, Nls};
+use currencies::{Lpn, Lpns, Native, Nls, PaymentGroup};
 use currency::{CurrencyDef, MemberOf};
 use finance::{
-    coin::{Amount, Coin},
+    coin::{Amount, Coin, CoinDTO},
     duration::Duration,
+    percent::Percent,
     zero::Zero,
 };
 use platform::bank;
 use profit::{
-    msg::{ConfigResponse, ExecuteMsg, QueryMsg},
+    msg::{ConfigResponse, ExecuteMsg, PendingResponse, QueryMsg, ReserveFunding},
     typedefs::CadenceHours,
 };
 use sdk::{
-    cosmwasm_std::{from_json, Addr, Event},
+    cosmwasm_std::{from_json, Addr, Event, Uint128},
     cw_multi_test::AppResponse,
     testing,
 };
@@ -65,7 +66,7 @@ fn update_config() {
 
     let mut test_case = test_case_with::<Lpn>(INITIAL_CACDENCE_HOURS, None);
 
-    let ConfigResponse { cadence_hours } = test_case
+    let ConfigResponse { cadence_hours, .. } = test_case
         .app
         .query()
         .query_wasm_smart(
@@ -90,7 +91,7 @@ fn update_config() {
         .ignore_response()
         .unwrap_response();
 
-    let ConfigResponse { cadence_hours } = test_case
+    let ConfigResponse { cadence_hours, .. } = test_case
         .app
         .query()
         .query_wasm_smart(
@@ -125,6 +126,133 @@ fn update_config_unauthorized() {
         .contains("Unauthorized"));
 }
 
+#[test]
+fn update_reserve_funding() {
+    let mut test_case = test_case::<Lpn>();
+    assert_eq!(None, query_reserve_funding(&test_case));
+
+    let reserve_funding = ReserveFunding {
+        reserve: testing::user("reserve"),
+        share: Percent::from_percent(50),
+        target: Uint128::new(1000),
+    };
+    () = execute_update_reserve_funding(&mut test_case, ADMIN, Some(reserve_funding.clone()))
+        .unwrap();
+    assert_eq!(Some(reserve_funding), query_reserve_funding(&test_case));
+
+    () = execute_update_reserve_funding(&mut test_case, ADMIN, None).unwrap();
+    assert_eq!(None, query_reserve_funding(&test_case));
+}
+
+#[test]
+fn update_reserve_funding_invalid_share() {
+    let mut test_case = test_case::<Lpn>();
+
+    assert!(execute_update_reserve_funding(
+        &mut test_case,
+        ADMIN,
+        Some(ReserveFunding {
+            reserve: testing::user("reserve"),
+            share: Percent::ZERO,
+            target: Uint128::new(1000),
+        }),
+    )
+    .unwrap_err()
+    .root_cause()
+    .to_string()
+    .contains("Invalid reserve funding"));
+    assert_eq!(None, query_reserve_funding(&test_case));
+}
+
+#[test]
+fn update_reserve_funding_unauthorized() {
+    let mut test_case = test_case::<Lpn>();
+
+    assert!(execute_update_reserve_funding(&mut test_case, USER, None)
+        .unwrap_err()
+        .root_cause()
+        .to_string()
+        .contains("Unauthorized"));
+}
+
+fn execute_update_reserve_funding(
+    test_case: &mut TestCase<Addr, Addr, Addr, (), (), (), Addr, Addr>,
+    sender: &str,
+    reserve_funding: Option<ReserveFunding>,
+) -> anyhow::Result<()> {
+    test_case
+        .app
+        .execute(
+            testing::user(sender),
+            test_case.address_book.profit().clone(),
+            &ExecuteMsg::ReserveFunding { reserve_funding },
+            &[],
+        )
+        .map(|response| response.ignore_response().unwrap_response())
+}
+
+fn query_reserve_funding(
+    test_case: &TestCase<Addr, Addr, Addr, (), (), (), Addr, Addr>,
+) -> Option<ReserveFunding> {
+    let ConfigResponse {
+        reserve_funding, ..
+    } = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            test_case.address_book.profit().clone(),
+            &QueryMsg::Config {},
+        )
+        .unwrap();
+    reserve_funding
+}
+
+#[test]
+fn pending() {
+    let lpn_margin: Coin<Lpn> = 3_000.into();
+    let native_margin: Coin<Nls> = 1_000.into();
+
+    let mut test_case = test_case_with::<Lpn>(
+        2,
+        Some(&[cwcoin::<Lpn, _>(1_000_000), cwcoin::<Nls, _>(1_000_000)]),
+    );
+
+    let PendingResponse { balances } = query_pending(&test_case);
+    assert!(balances.is_empty());
+
+    test_case.send_funds_from_admin(
+        test_case.address_book.profit().clone(),
+        &[cwcoin(lpn_margin), cwcoin(native_margin)],
+    );
+
+    let PendingResponse { balances } = query_pending(&test_case);
+    assert_eq!(2, balances.len());
+    assert!(balances.contains(&CoinDTO::<PaymentGroup>::from(lpn_margin)));
+    assert!(balances.contains(&CoinDTO::<PaymentGroup>::from(native_margin)));
+}
+
+fn query_pending<ProtocolsRegistry, Treasury, Reserve, Leaser, Lpp, Oracle, TimeAlarms>(
+    test_case: &TestCase<
+        ProtocolsRegistry,
+        Treasury,
+        Addr,
+        Reserve,
+        Leaser,
+        Lpp,
+        Oracle,
+        TimeAlarms,
+    >,
+) -> PendingResponse {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            test_case.address_book.profit().clone(),
+            &QueryMsg::Pending {},
+        )
+        .unwrap()
+}
+
 #[test]
 fn on_alarm_from_unknown() {
     let user_addr: Addr = testing::user(USER);
@@ -578,3 +706,95 @@ fn integration_with_time_alarms() {
         ::profit::profit::Profit::IBC_FEE_RESERVE.into(),
     );
 }
+
+#[test]
+fn on_alarm_fund_reserve_below_target() {
+    on_alarm_fund_reserve(Coin::new(200), Coin::new(500), Coin::new(250));
+}
+
+#[test]
+fn on_alarm_fund_reserve_capped_at_target() {
+    on_alarm_fund_reserve(Coin::new(900), Coin::new(500), Coin::new(100));
+}
+
+#[test]
+fn on_alarm_fund_reserve_at_target() {
+    on_alarm_fund_reserve(Coin::new(1000), Coin::new(500), Zero::ZERO);
+}
+
+fn on_alarm_fund_reserve(
+    init_reserve_balance: Coin<Lpn>,
+    lpn_profit: Coin<Lpn>,
+    exp_funding: Coin<Lpn>,
+) {
+    const TARGET: Amount = 1000;
+
+    let reserve: Addr = testing::user("reserve");
+
+    let mut test_case: TestCase<Addr, Addr, Addr, (), (), (), Addr, Addr> =
+        TestCaseBuilder::<Lpn>::new()
+            .init_time_alarms()
+            .init_protocols_registry(Registry::NoProtocol)
+            .init_oracle(None)
+            .init_treasury()
+            .init_profit_with_reserve_funding(
+                2,
+                Some(ReserveFunding {
+                    reserve: reserve.clone(),
+                    share: Percent::from_percent(50),
+                    target: Uint128::new(TARGET),
+                }),
+            )
+            .into_generic();
+
+    if !init_reserve_balance.is_zero() {
+        test_case.send_funds_from_admin(reserve.clone(), &[cwcoin(init_reserve_balance)]);
+    }
+
+    test_case.send_funds_from_admin(
+        test_case.address_book.profit().clone(),
+        &[cwcoin(lpn_profit)],
+    );
+
+    let mut response: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
+        .app
+        .execute(
+            test_case.address_book.time_alarms().clone(),
+            test_case.address_book.profit().clone(),
+            &ExecuteMsg::TimeAlarm {},
+            &[],
+        )
+        .unwrap();
+
+    let buy_back_amount: CwCoin = ibc::expect_transfer(
+        &mut response,
+        TestCase::PROFIT_IBC_CHANNEL,
+        test_case.address_book.profit().as_str(),
+        test_case.address_book.profit_ica().as_str(),
+    );
+
+    assert_eq!(buy_back_amount, cwcoin(lpn_profit - exp_funding));
+
+    let response: AppResponse = response.unwrap_response();
+
+    if exp_funding.is_zero() {
+        assert!(!response
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-tr-profit-reserve"));
+    } else {
+        response.assert_event(
+            &Event::new("wasm-tr-profit-reserve")
+                .add_attribute(
+                    "reserve-amount-amount",
+                    Amount::from(exp_funding).to_string(),
+                )
+                .add_attribute("reserve-amount-symbol", Lpn::ticker()),
+        );
+    }
+
+    assert_eq!(
+        bank::balance::<Lpn, Lpns>(&reserve, test_case.app.query()).unwrap(),
+        init_reserve_balance + exp_funding,
+    );
+}
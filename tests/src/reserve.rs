@@ -1,8 +1,14 @@
 use currencies::{Lpn, Lpns};
-use finance::coin::{Amount, Coin};
+use finance::{
+    coin::{Amount, Coin},
+    duration::Duration,
+};
 use platform::{contract::Code, error::Error as PlatformError};
 use reserve::{
-    api::{ConfigResponse, LpnCurrencyDTO, QueryMsg},
+    api::{
+        ConfigResponse, Cover, CoverHistoryResponse, LpnCurrencyDTO, QueryMsg, SudoMsg,
+        SurplusReturn,
+    },
     error::Error as ReserveError,
 };
 use sdk::{cosmwasm_std::Addr, cw_multi_test::AppResponse, testing};
@@ -11,6 +17,7 @@ use crate::{
     common::{
         cwcoin,
         leaser::Instantiator as LeaserInstantiator,
+        lpn_coin,
         test_case::{
             app::App, builder::BlankBuilder as TestCaseBuilder,
             response::ResponseWithInterChainMsgs, TestCase,
@@ -23,6 +30,8 @@ use super::lease;
 
 type ReserveTest = TestCase<(), (), (), Addr, (), (), (), ()>;
 
+const SURPLUS_TARGET: Amount = 5_000;
+
 #[test]
 fn instantiate() {
     let test_case = TestCaseBuilder::<Lpn>::new().init_reserve().into_generic();
@@ -125,6 +134,212 @@ fn cover_losses_enough_balance() {
     assert!(balance_past_cover.is_zero());
 }
 
+#[test]
+fn cover_history() {
+    let mut test_case: LeaseTestCase = lease::create_test_case::<Lpn>();
+    let downpayment = Coin::<Lpn>::new(1_000_000);
+    let lease_addr: Addr = lease::open_lease(&mut test_case, downpayment, None);
+
+    let reserve = test_case.address_book.reserve().clone();
+    assert_eq!(
+        CoverHistoryResponse::new(),
+        query_cover_history(&test_case, reserve.clone(), None)
+    );
+
+    let losses = [1425, 20, 331];
+    test_case.send_funds_from_admin(
+        reserve.clone(),
+        &[cwcoin::<Lpn, _>(losses.iter().sum::<Amount>())],
+    );
+    let mut covers: Vec<Cover> = losses
+        .into_iter()
+        .map(|loss| {
+            test_case.app.time_shift(Duration::from_hours(1));
+            let _resp = cover_losses_ok(&mut test_case, reserve.clone(), lease_addr.clone(), loss);
+            Cover {
+                at: test_case.app.block_info().time,
+                lease: lease_addr.clone(),
+                amount: Coin::<Lpn>::new(loss).into(),
+            }
+        })
+        .collect();
+    // the history is newest-first
+    covers.reverse();
+
+    assert_eq!(
+        covers,
+        query_cover_history(&test_case, reserve.clone(), None)
+    );
+    assert_eq!(
+        covers[..2],
+        query_cover_history(&test_case, reserve.clone(), Some(2))
+    );
+
+    // a failed cover is not recorded
+    let _err = cover_losses_err(&mut test_case, reserve.clone(), lease_addr, 1);
+    assert_eq!(covers, query_cover_history(&test_case, reserve, None));
+}
+
+#[test]
+fn return_surplus_over_target() {
+    let surplus = 1_234;
+    let (mut test_case, treasury) = surplus_return_test_case();
+    let reserve = test_case.address_book.reserve().clone();
+    test_case.send_funds_from_admin(
+        reserve.clone(),
+        &[cwcoin::<Lpn, _>(SURPLUS_TARGET + surplus)],
+    );
+
+    let resp = return_surplus(&mut test_case.app, reserve.clone())
+        .unwrap()
+        .unwrap_response();
+    assert!(resp
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-reserve-return-surplus"));
+
+    assert_eq!(Coin::new(SURPLUS_TARGET), lpn_balance(&test_case, &reserve));
+    assert_eq!(Coin::new(surplus), lpn_balance(&test_case, &treasury));
+}
+
+#[test]
+fn return_surplus_at_target() {
+    let (mut test_case, treasury) = surplus_return_test_case();
+    let reserve = test_case.address_book.reserve().clone();
+    test_case.send_funds_from_admin(reserve.clone(), &[cwcoin::<Lpn, _>(SURPLUS_TARGET)]);
+
+    let resp = return_surplus(&mut test_case.app, reserve.clone())
+        .unwrap()
+        .unwrap_response();
+    assert!(!resp
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-reserve-return-surplus"));
+
+    assert_eq!(Coin::new(SURPLUS_TARGET), lpn_balance(&test_case, &reserve));
+    assert!(lpn_balance(&test_case, &treasury).is_zero());
+}
+
+#[test]
+fn return_surplus_not_configured() {
+    let mut test_case = TestCaseBuilder::<Lpn>::new().init_reserve().into_generic();
+    let reserve = test_case.address_book.reserve().clone();
+
+    let err = return_surplus(&mut test_case.app, reserve).unwrap_err();
+    assert_eq!(
+        Some(&ReserveError::NoSurplusReturn),
+        err.downcast_ref::<ReserveError>()
+    );
+}
+
+#[test]
+fn update_surplus_return_enable_disable() {
+    let surplus = 321;
+    let treasury = testing::user("treasury");
+    let mut test_case = TestCaseBuilder::<Lpn>::new().init_reserve().into_generic();
+    let reserve = test_case.address_book.reserve().clone();
+    test_case.send_funds_from_admin(
+        reserve.clone(),
+        &[cwcoin::<Lpn, _>(SURPLUS_TARGET + surplus)],
+    );
+
+    let () = update_surplus_return(
+        &mut test_case.app,
+        reserve.clone(),
+        Some(SurplusReturn {
+            treasury: treasury.to_string(),
+            target: lpn_coin(SURPLUS_TARGET),
+        }),
+    )
+    .unwrap()
+    .ignore_response()
+    .unwrap_response();
+
+    let _resp = return_surplus(&mut test_case.app, reserve.clone())
+        .unwrap()
+        .unwrap_response();
+    assert_eq!(Coin::new(SURPLUS_TARGET), lpn_balance(&test_case, &reserve));
+    assert_eq!(Coin::new(surplus), lpn_balance(&test_case, &treasury));
+
+    let () = update_surplus_return(&mut test_case.app, reserve.clone(), None)
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+    let err = return_surplus(&mut test_case.app, reserve).unwrap_err();
+    assert_eq!(
+        Some(&ReserveError::NoSurplusReturn),
+        err.downcast_ref::<ReserveError>()
+    );
+}
+
+#[test]
+fn update_surplus_return_invalid_treasury() {
+    let mut test_case = TestCaseBuilder::<Lpn>::new().init_reserve().into_generic();
+    let reserve = test_case.address_book.reserve().clone();
+
+    let err = update_surplus_return(
+        &mut test_case.app,
+        reserve.clone(),
+        Some(SurplusReturn {
+            treasury: "invalid address".into(),
+            target: lpn_coin(SURPLUS_TARGET),
+        }),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ReserveError>(),
+        Some(ReserveError::Std(_))
+    ));
+
+    let err = return_surplus(&mut test_case.app, reserve).unwrap_err();
+    assert_eq!(
+        Some(&ReserveError::NoSurplusReturn),
+        err.downcast_ref::<ReserveError>()
+    );
+}
+
+fn surplus_return_test_case() -> (ReserveTest, Addr) {
+    let treasury = testing::user("treasury");
+    let test_case = TestCaseBuilder::<Lpn>::new()
+        .init_reserve_with_surplus_return(Some(SurplusReturn {
+            treasury: treasury.to_string(),
+            target: lpn_coin(SURPLUS_TARGET),
+        }))
+        .into_generic();
+    (test_case, treasury)
+}
+
+fn update_surplus_return(
+    app: &mut App,
+    reserve: Addr,
+    surplus_return: Option<SurplusReturn>,
+) -> anyhow::Result<ResponseWithInterChainMsgs<'_, AppResponse>> {
+    app.sudo(reserve, &SudoMsg::UpdateSurplusReturn { surplus_return })
+}
+
+fn return_surplus(
+    app: &mut App,
+    reserve: Addr,
+) -> anyhow::Result<ResponseWithInterChainMsgs<'_, AppResponse>> {
+    app.sudo(reserve, &SudoMsg::ReturnSurplus {})
+}
+
+fn query_cover_history(
+    test_case: &LeaseTestCase,
+    reserve: Addr,
+    limit: Option<u16>,
+) -> CoverHistoryResponse {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(reserve, &QueryMsg::CoverHistory { limit })
+        .unwrap()
+}
+
+fn lpn_balance(test_case: &ReserveTest, account: &Addr) -> Coin<Lpn> {
+    platform::bank::balance::<Lpn, Lpns>(account, test_case.app.query()).unwrap()
+}
+
 fn cover_losses_err(
     test_case: &mut LeaseTestCase,
     reserve: Addr,